@@ -240,6 +240,7 @@ fn test_clean_for_polars_modify() {
     let transforms = vec![Transform::Pinhole(Pinhole {
         image_from_cam: [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]].into(),
         resolution: None,
+        distortion: None,
     })];
 
     let cell: DataCell = transforms.try_into().unwrap();
@@ -289,6 +290,29 @@ fn test_clean_for_polars_modify() {
                             ),
                             true,
                         ),
+                        Field::new(
+                            "distortion",
+                            DataType::Struct(vec![
+                                Field::new("model", DataType::UInt8, false),
+                                Field::new(
+                                    "radial",
+                                    DataType::FixedSizeList(
+                                        Box::new(Field::new("item", DataType::Float32, false)),
+                                        4
+                                    ),
+                                    false
+                                ),
+                                Field::new(
+                                    "tangential",
+                                    DataType::FixedSizeList(
+                                        Box::new(Field::new("item", DataType::Float32, false)),
+                                        2
+                                    ),
+                                    false
+                                ),
+                            ]),
+                            true,
+                        ),
                     ]),
                     false
                 )
@@ -333,6 +357,31 @@ fn test_clean_for_polars_modify() {
                         DataType::List(Box::new(Field::new("item", DataType::Float32, false))),
                         true,
                     ),
+                    Field::new(
+                        "distortion",
+                        DataType::Struct(vec![
+                            Field::new("model", DataType::UInt8, false),
+                            Field::new(
+                                "radial",
+                                DataType::List(Box::new(Field::new(
+                                    "item",
+                                    DataType::Float32,
+                                    false
+                                ))),
+                                false
+                            ),
+                            Field::new(
+                                "tangential",
+                                DataType::List(Box::new(Field::new(
+                                    "item",
+                                    DataType::Float32,
+                                    false
+                                ))),
+                                false
+                            ),
+                        ]),
+                        true,
+                    ),
                 ]),
                 false
             )
@@ -108,6 +108,9 @@ pub struct EntityTree {
     /// Book-keeping around whether we should clear recursively when data is added
     pub recursive_clears: BTreeMap<RowId, TimePoint>,
 
+    /// Book-keeping around whether we should clear a specific field when it is added
+    pub component_clears: BTreeMap<ComponentName, BTreeMap<RowId, TimePoint>>,
+
     /// Data logged at this entity path.
     pub components: BTreeMap<ComponentName, ComponentStats>,
 }
@@ -125,6 +128,7 @@ impl EntityTree {
             num_timeless_messages: 0,
             nonrecursive_clears: recursive_clears.clone(),
             recursive_clears,
+            component_clears: Default::default(),
             components: Default::default(),
         }
     }
@@ -162,6 +166,11 @@ impl EntityTree {
                 // If we needed to create a new leaf to hold this data, we also want to
                 // insert all of the historical pending clear operations
                 pending_clears = leaf.nonrecursive_clears.clone().into_iter().collect_vec();
+                if let Some(component_clears) =
+                    leaf.component_clears.get(&component_path.component_name)
+                {
+                    pending_clears.extend(component_clears.clone());
+                }
 
                 Default::default()
             });
@@ -232,6 +241,22 @@ impl EntityTree {
                 }
                 results
             }
+            PathOp::ClearComponent(component_path) => {
+                // Track that this field needs a Null at the right time-point
+                // if it is added in the future.
+                leaf.component_clears
+                    .entry(component_path.component_name)
+                    .or_default()
+                    .entry(row_id)
+                    .or_insert_with(|| time_point.clone());
+
+                // If the field already exists return a clear event for it
+                if leaf.components.contains_key(&component_path.component_name) {
+                    vec![component_path.clone()]
+                } else {
+                    vec![]
+                }
+            }
         }
     }
 
@@ -295,6 +320,7 @@ impl EntityTree {
             num_timeless_messages: _,
             nonrecursive_clears,
             recursive_clears,
+            component_clears,
             components: fields,
         } = self;
 
@@ -312,6 +338,13 @@ impl EntityTree {
             crate::profile_scope!("recursive_clears");
             recursive_clears.retain(|row_id, _| !drop_row_ids.contains(row_id));
         }
+        {
+            crate::profile_scope!("component_clears");
+            for clears in component_clears.values_mut() {
+                clears.retain(|row_id, _| !drop_row_ids.contains(row_id));
+            }
+            component_clears.retain(|_, clears| !clears.is_empty());
+        }
 
         {
             crate::profile_scope!("fields");
@@ -376,3 +409,70 @@ impl ComponentStats {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::{
+        ComponentName, ComponentPath, EntityPath, PathOp, RowId, TimePoint, Timeline,
+    };
+
+    use super::EntityTree;
+
+    fn time_point(frame: i64) -> TimePoint {
+        TimePoint::from([(Timeline::new_sequence("frame"), frame.into())])
+    }
+
+    #[test]
+    fn clear_component_only_clears_that_component() {
+        let entity_path = EntityPath::from("points");
+        let point = ComponentPath::new(entity_path.clone(), ComponentName::from("rerun.point2d"));
+        let color = ComponentPath::new(entity_path.clone(), ComponentName::from("rerun.color"));
+
+        let mut tree = EntityTree::root();
+        assert!(tree.add_data_msg(&time_point(1), &point).is_empty());
+        assert!(tree.add_data_msg(&time_point(1), &color).is_empty());
+
+        let cleared = tree.add_path_op(
+            RowId::random(),
+            &time_point(2),
+            &PathOp::ClearComponent(color.clone()),
+        );
+        assert_eq!(cleared, vec![color]);
+
+        // Other components added later are left alone.
+        let radius = ComponentPath::new(entity_path, ComponentName::from("rerun.radius"));
+        assert!(tree.add_data_msg(&time_point(3), &radius).is_empty());
+    }
+
+    #[test]
+    fn clear_component_is_pending_until_added() {
+        let entity_path = EntityPath::from("points");
+        let point = ComponentPath::new(entity_path.clone(), ComponentName::from("rerun.point2d"));
+        let color = ComponentPath::new(entity_path, ComponentName::from("rerun.color"));
+
+        let mut tree = EntityTree::root();
+        assert!(tree.add_data_msg(&time_point(1), &point).is_empty());
+
+        // The color doesn't exist yet, so there is nothing to clear right away…
+        let row_id = RowId::random();
+        let cleared = tree.add_path_op(
+            row_id,
+            &time_point(2),
+            &PathOp::ClearComponent(color.clone()),
+        );
+        assert!(cleared.is_empty());
+
+        // …but it gets cleared once it is added, at the time of the clear.
+        assert_eq!(
+            tree.add_data_msg(&time_point(3), &color),
+            vec![(row_id, time_point(2))]
+        );
+
+        // The other components of the entity are unaffected.
+        assert!(tree.add_data_msg(&time_point(3), &point).is_empty());
+        assert_eq!(
+            tree.subtree(&color.entity_path).unwrap().components.len(),
+            2
+        );
+    }
+}
@@ -77,6 +77,15 @@ impl<W: std::io::Write> Encoder<W> {
         }
     }
 
+    /// Write out everything appended so far, instead of waiting for the compressor to fill a block.
+    pub fn flush(&mut self) -> Result<(), EncodeError> {
+        if let Some(zstd_encoder) = &mut self.zstd_encoder {
+            zstd_encoder.flush().map_err(EncodeError::Zstd)
+        } else {
+            Err(EncodeError::AlreadyFinished)
+        }
+    }
+
     pub fn finish(&mut self) -> Result<(), EncodeError> {
         if let Some(zstd_encoder) = self.zstd_encoder.take() {
             zstd_encoder.finish().map_err(EncodeError::Zstd)?;
@@ -10,6 +10,10 @@ pub mod encoder;
 #[cfg(not(target_arch = "wasm32"))]
 mod file_sink;
 
+#[cfg(feature = "encoder")]
+#[cfg(not(target_arch = "wasm32"))]
+mod rotating_file_sink;
+
 #[cfg(feature = "decoder")]
 pub mod stream_rrd_from_http;
 
@@ -19,6 +23,10 @@ pub mod stream_rrd_from_http;
 #[cfg(not(target_arch = "wasm32"))]
 pub use file_sink::{FileSink, FileSinkError};
 
+#[cfg(feature = "encoder")]
+#[cfg(not(target_arch = "wasm32"))]
+pub use rotating_file_sink::{RotatingFileSink, RotationOptions};
+
 // ---------------------------------------------------------------------------
 
 /// Profiling macro for feature "puffin"
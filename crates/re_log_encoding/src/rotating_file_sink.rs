@@ -0,0 +1,490 @@
+use std::{
+    borrow::Cow,
+    collections::VecDeque,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        mpsc::Sender,
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use parking_lot::Mutex;
+
+use re_log_types::{LogMsg, ReplayState};
+
+use crate::{encoder::Encoder, FileSinkError};
+
+/// When a [`RotatingFileSink`] starts a new file, and how many it keeps.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RotationOptions {
+    /// Start a new file once the current one is about this large,
+    /// not counting the data replayed at the top of it.
+    ///
+    /// The file size is only known approximately, since the compressor buffers data.
+    pub max_file_bytes: Option<u64>,
+
+    /// Start a new file once the current one has been written to for this long.
+    ///
+    /// This is checked whenever a message arrives.
+    pub max_file_age: Option<Duration>,
+
+    /// Delete the oldest files written by this sink, so there are never more than this many.
+    pub max_files: Option<usize>,
+}
+
+/// Stream log messages to a series of `.rrd` files, starting a new one as the current one
+/// grows too large or too old.
+///
+/// Given the path `dir/black_box.rrd`, the files are called `dir/black_box_000000.rrd`,
+/// `dir/black_box_000001.rrd`, etc.
+///
+/// Every file starts with the [`re_log_types::BeginRecordingMsg`]s and the latest timeless data
+/// logged so far, so each one can be opened on its own.
+///
+/// Files left behind by an earlier run are never deleted: numbering continues after them.
+pub struct RotatingFileSink {
+    // None = quit
+    tx: Mutex<Sender<Option<LogMsg>>>,
+    join_handle: Option<std::thread::JoinHandle<()>>,
+}
+
+impl Drop for RotatingFileSink {
+    fn drop(&mut self) {
+        self.tx.lock().send(None).ok();
+        if let Some(join_handle) = self.join_handle.take() {
+            join_handle.join().ok();
+        }
+    }
+}
+
+impl RotatingFileSink {
+    /// Start writing log messages to files next to the given path.
+    ///
+    /// The first file is created right away, to catch errors early.
+    pub fn new(path: impl Into<PathBuf>, options: RotationOptions) -> Result<Self, FileSinkError> {
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        let path = path.into();
+
+        re_log::debug!("Saving rotating files to {path:?}…");
+
+        let mut writer = SegmentWriter::new(path, options);
+        writer.rotate()?;
+
+        let join_handle = std::thread::Builder::new()
+            .name("rotating_file_writer".into())
+            .spawn(move || {
+                while let Ok(Some(log_msg)) = rx.recv() {
+                    if let Err(err) = writer.append(log_msg) {
+                        re_log::error!("Failed to save log stream: {err}");
+                        return;
+                    }
+                }
+                writer.finish();
+            })
+            .map_err(FileSinkError::SpawnThread)?;
+
+        Ok(Self {
+            tx: tx.into(),
+            join_handle: Some(join_handle),
+        })
+    }
+
+    pub fn send(&self, log_msg: LogMsg) {
+        self.tx.lock().send(Some(log_msg)).ok();
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The file currently being written to.
+struct Segment {
+    path: PathBuf,
+    encoder: Encoder<CountingWriter<std::fs::File>>,
+    num_bytes: Arc<AtomicU64>,
+
+    /// The size of the file header and the replayed data.
+    replay_bytes: u64,
+
+    started: Instant,
+}
+
+/// Runs on the writer thread.
+struct SegmentWriter {
+    path: PathBuf,
+    options: RotationOptions,
+
+    current: Option<Segment>,
+    next_index: u64,
+
+    /// The files written so far, oldest first, including the current one.
+    files: VecDeque<PathBuf>,
+
+    /// Replayed at the top of every file.
+    replay: ReplayState,
+}
+
+impl SegmentWriter {
+    fn new(path: PathBuf, options: RotationOptions) -> Self {
+        // Don't overwrite the files of an earlier run, they may hold the log of a crash.
+        let existing = existing_segments(&path);
+        let next_index = existing.last().map_or(0, |(index, _)| index + 1);
+        if !existing.is_empty() {
+            re_log::debug!(
+                "Found {} log files from an earlier run next to {path:?}",
+                existing.len()
+            );
+        }
+
+        Self {
+            path,
+            options,
+            current: None,
+            next_index,
+            files: Default::default(),
+            replay: Default::default(),
+        }
+    }
+
+    fn append(&mut self, msg: LogMsg) -> Result<(), FileSinkError> {
+        if self.should_rotate() {
+            self.rotate()?;
+        }
+
+        self.replay.update(&msg);
+
+        let Some(segment) = &mut self.current else {
+            return Ok(()); // `rotate` never leaves us without a segment
+        };
+        segment.encoder.append(&msg)?;
+        Ok(())
+    }
+
+    fn should_rotate(&self) -> bool {
+        let Some(segment) = &self.current else {
+            return true;
+        };
+
+        let too_large = self.options.max_file_bytes.map_or(false, |max| {
+            let num_bytes = segment.num_bytes.load(Ordering::Relaxed);
+            num_bytes.saturating_sub(segment.replay_bytes) >= max
+        });
+        let too_old = self
+            .options
+            .max_file_age
+            .map_or(false, |max| segment.started.elapsed() >= max);
+        too_large || too_old
+    }
+
+    /// Finish the current file, and start a new one.
+    fn rotate(&mut self) -> Result<(), FileSinkError> {
+        self.finish();
+
+        let path = segment_path(&self.path, self.next_index);
+        self.next_index += 1;
+
+        let file = std::fs::File::create(&path)
+            .map_err(|err| FileSinkError::CreateFile(path.clone(), err))?;
+        let num_bytes = Arc::new(AtomicU64::new(0));
+        let mut encoder = Encoder::new(CountingWriter {
+            write: file,
+            num_bytes: num_bytes.clone(),
+        })?;
+
+        for msg in self.replay.messages() {
+            encoder.append(&msg)?;
+        }
+        encoder.flush()?;
+        let replay_bytes = num_bytes.load(Ordering::Relaxed);
+
+        re_log::debug!("Saving log stream to {path:?}…");
+        self.files.push_back(path.clone());
+        self.current = Some(Segment {
+            path,
+            encoder,
+            num_bytes,
+            replay_bytes,
+            started: Instant::now(),
+        });
+
+        if let Some(max_files) = self.options.max_files {
+            while self.files.len() > max_files.max(1) {
+                if let Some(oldest) = self.files.pop_front() {
+                    if let Err(err) = std::fs::remove_file(&oldest) {
+                        re_log::warn!("Failed to remove old log file {oldest:?}: {err}");
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn finish(&mut self) {
+        if let Some(mut segment) = self.current.take() {
+            let path = segment.path;
+            if let Err(err) = segment.encoder.finish() {
+                re_log::error!("Failed to save log stream to {path:?}: {err}");
+            } else {
+                re_log::debug!("Log stream saved to {path:?}");
+            }
+        }
+    }
+}
+
+/// `dir/name.rrd` -> `("name", "rrd")`
+fn stem_and_extension(path: &Path) -> (Cow<'_, str>, Cow<'_, str>) {
+    let stem = path
+        .file_stem()
+        .map_or_else(|| "recording".into(), |stem| stem.to_string_lossy());
+    let extension = path
+        .extension()
+        .map_or_else(|| "rrd".into(), |ext| ext.to_string_lossy());
+    (stem, extension)
+}
+
+/// `dir/name.rrd` -> `dir/name_000042.rrd`
+fn segment_path(path: &Path, index: u64) -> PathBuf {
+    let (stem, extension) = stem_and_extension(path);
+    path.with_file_name(format!("{stem}_{index:06}.{extension}"))
+}
+
+/// `dir/name.rrd` -> `dir/name_000000.rrd`, `dir/name_000001.rrd`, … if they exist,
+/// ordered by index.
+fn existing_segments(path: &Path) -> Vec<(u64, PathBuf)> {
+    let (stem, extension) = stem_and_extension(path);
+    let prefix = format!("{stem}_");
+    let suffix = format!(".{extension}");
+
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new(); // we'll find out when creating the first file
+    };
+
+    let mut segments = entries
+        .filter_map(|entry| {
+            let file_name = entry.ok()?.file_name();
+            let index = file_name
+                .to_str()?
+                .strip_prefix(&prefix)?
+                .strip_suffix(&suffix)?;
+            if !index.bytes().all(|byte| byte.is_ascii_digit()) {
+                return None;
+            }
+            Some((index.parse().ok()?, dir.join(&file_name)))
+        })
+        .collect::<Vec<_>>();
+    segments.sort();
+    segments
+}
+
+/// Keeps track of how many bytes went into the file.
+struct CountingWriter<W> {
+    write: W,
+    num_bytes: Arc<AtomicU64>,
+}
+
+impl<W: std::io::Write> std::io::Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.write.write(buf)?;
+        self.num_bytes.fetch_add(written as u64, Ordering::Relaxed);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.write.flush()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[test]
+fn test_segment_path() {
+    assert_eq!(
+        segment_path(Path::new("logs/black_box.rrd"), 42),
+        Path::new("logs/black_box_000042.rrd")
+    );
+    assert_eq!(
+        segment_path(Path::new("black_box"), 0),
+        Path::new("black_box_000000.rrd")
+    );
+}
+
+/// Log a recording with some timeless data and five frames.
+#[cfg(feature = "decoder")]
+#[cfg(test)]
+fn log_test_recording(path: &Path, options: RotationOptions, timeless_label: &str) {
+    use re_log_types::{
+        component_types::Label, ApplicationId, BeginRecordingMsg, DataRow, RecordingId,
+        RecordingInfo, RecordingSource, RowId, Time, TimePoint, Timeline,
+    };
+
+    let recording_id = RecordingId::random();
+    let row = |timepoint: TimePoint, label: &str| {
+        let row = DataRow::from_cells1(
+            RowId::random(),
+            "some/path",
+            timepoint,
+            1,
+            [Label(label.into())].as_slice(),
+        );
+        LogMsg::ArrowMsg(recording_id, row.into_table().to_arrow_msg().unwrap())
+    };
+
+    let sink = RotatingFileSink::new(path, options).unwrap();
+    sink.send(LogMsg::BeginRecordingMsg(BeginRecordingMsg {
+        row_id: RowId::random(),
+        info: RecordingInfo {
+            application_id: ApplicationId("test".to_owned()),
+            recording_id,
+            is_official_example: false,
+            started: Time::now(),
+            recording_source: RecordingSource::RustSdk {
+                rustc_version: String::new(),
+                llvm_version: String::new(),
+            },
+        },
+    }));
+    sink.send(row(TimePoint::timeless(), timeless_label));
+    for frame in 0..5 {
+        let timepoint = [(Timeline::new_sequence("frame"), frame.into())].into();
+        sink.send(row(timepoint, "temporal"));
+    }
+}
+
+#[cfg(feature = "decoder")]
+#[cfg(test)]
+fn files_in(dir: &Path) -> Vec<PathBuf> {
+    let mut files = std::fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect::<Vec<_>>();
+    files.sort();
+    files
+}
+
+#[cfg(feature = "decoder")]
+#[cfg(test)]
+fn read_messages(path: &Path) -> Vec<LogMsg> {
+    let file = std::fs::File::open(path).unwrap();
+    crate::decoder::Decoder::new(file)
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap()
+}
+
+#[cfg(feature = "decoder")]
+#[test]
+fn test_rotating_file_sink() {
+    use re_log_types::{DataTable, RecordingId};
+
+    let dir = std::env::temp_dir().join(format!("rerun_rotating_{}", RecordingId::random()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    log_test_recording(
+        &dir.join("black_box.rrd"),
+        RotationOptions {
+            max_file_bytes: None,
+            max_file_age: Some(Duration::ZERO),
+            max_files: Some(2),
+        },
+        "timeless",
+    );
+
+    let files = files_in(&dir);
+    assert_eq!(files.len(), 2, "old files should be removed: {files:?}");
+
+    // Every file can stand on its own.
+    for path in &files {
+        let messages = read_messages(path);
+        assert!(matches!(messages[0], LogMsg::BeginRecordingMsg(_)));
+        let has_timeless = messages.iter().any(|msg| match msg {
+            LogMsg::ArrowMsg(_, msg) => DataTable::from_arrow_msg(msg)
+                .unwrap()
+                .to_rows()
+                .any(|row| row.timepoint().is_empty()),
+            _ => false,
+        });
+        assert!(has_timeless, "{path:?} is missing the timeless data");
+    }
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[cfg(feature = "decoder")]
+#[test]
+fn test_rotating_file_sink_restart() {
+    use re_log_types::RecordingId;
+
+    let dir = std::env::temp_dir().join(format!("rerun_rotating_{}", RecordingId::random()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("black_box.rrd");
+
+    let options = RotationOptions::default();
+    log_test_recording(&path, options.clone(), "timeless");
+    log_test_recording(&path, options, "timeless");
+
+    // The second run must not overwrite the log of the first one.
+    assert_eq!(
+        files_in(&dir),
+        [segment_path(&path, 0), segment_path(&path, 1)]
+    );
+    for path in files_in(&dir) {
+        assert_eq!(read_messages(&path).len(), 7, "{path:?} is incomplete");
+    }
+
+    // Only the files of this run are removed to stay within the limit.
+    log_test_recording(
+        &path,
+        RotationOptions {
+            max_file_age: Some(Duration::ZERO),
+            max_files: Some(2),
+            ..Default::default()
+        },
+        "timeless",
+    );
+    let files = files_in(&dir);
+    assert_eq!(files.len(), 4, "{files:?}");
+    assert_eq!(files[..2], [segment_path(&path, 0), segment_path(&path, 1)]);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[cfg(feature = "decoder")]
+#[test]
+fn test_rotating_file_sink_large_replay() {
+    use re_log_types::RecordingId;
+
+    let dir = std::env::temp_dir().join(format!("rerun_rotating_{}", RecordingId::random()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    // Timeless data that is larger than a file may be, even once compressed.
+    let mut seed = 0x1234_5678_u64;
+    let noise = (0..200_000)
+        .map(|_| {
+            seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+            char::from(b'a' + (seed >> 60) as u8)
+        })
+        .collect::<String>();
+
+    log_test_recording(
+        &dir.join("black_box.rrd"),
+        RotationOptions {
+            max_file_bytes: Some(10_000),
+            ..Default::default()
+        },
+        &noise,
+    );
+
+    // One rotation once the timeless data is in, but not one per message after that.
+    let files = files_in(&dir);
+    assert_eq!(files.len(), 2, "{files:?}");
+    assert_eq!(read_messages(&files[1]).len(), 7);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -3,7 +3,10 @@
 //! We have custom implementations of [`serde::Serialize`] and [`serde::Deserialize`] that wraps
 //! the inner Arrow serialization of [`Schema`] and [`Chunk`].
 
-use crate::{TableId, TimePoint};
+use crate::{
+    data_table::{METADATA_KIND, METADATA_KIND_TIME},
+    TableId, TimePoint,
+};
 use arrow2::{array::Array, chunk::Chunk, datatypes::Schema};
 
 /// Message containing an Arrow payload
@@ -29,6 +32,30 @@ pub struct ArrowMsg {
     pub chunk: Chunk<Box<dyn Array>>,
 }
 
+impl ArrowMsg {
+    /// Does any row of this table lack a time on every timeline?
+    ///
+    /// Only looks at the validity of the time columns, without deserializing the table.
+    pub fn has_timeless_rows(&self) -> bool {
+        if self.timepoint_max.is_timeless() {
+            return !self.chunk.is_empty();
+        }
+
+        let time_columns = self
+            .schema
+            .fields
+            .iter()
+            .zip(self.chunk.arrays())
+            .filter(|(field, _)| {
+                field.metadata.get(METADATA_KIND).map(String::as_str) == Some(METADATA_KIND_TIME)
+            })
+            .map(|(_, column)| column)
+            .collect::<Vec<_>>();
+
+        (0..self.chunk.len()).any(|row| time_columns.iter().all(|column| column.is_null(row)))
+    }
+}
+
 #[cfg(feature = "serde")]
 impl serde::Serialize for ArrowMsg {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -164,3 +191,34 @@ mod tests {
         assert_eq!(table_in, table_out);
     }
 }
+
+#[test]
+fn test_has_timeless_rows() {
+    use crate::{component_types::Label, DataRow, DataTable, RowId, Timeline};
+
+    let row = |timepoint: TimePoint| {
+        DataRow::from_cells1(
+            RowId::random(),
+            "some/path",
+            timepoint,
+            1,
+            [Label("label".into())].as_slice(),
+        )
+    };
+    let frame = |frame: i64| TimePoint::from([(Timeline::new_sequence("frame"), frame.into())]);
+    let log_time = |time: i64| TimePoint::from([(Timeline::log_time(), time.into())]);
+    let has_timeless_rows = |rows: Vec<DataRow>| {
+        DataTable::from_rows(TableId::random(), rows)
+            .to_arrow_msg()
+            .unwrap()
+            .has_timeless_rows()
+    };
+
+    assert!(has_timeless_rows(vec![row(TimePoint::timeless())]));
+    assert!(has_timeless_rows(vec![
+        row(frame(0)),
+        row(TimePoint::timeless())
+    ]));
+    assert!(!has_timeless_rows(vec![row(frame(0)), row(frame(1))]));
+    assert!(!has_timeless_rows(vec![row(frame(0)), row(log_time(1))]));
+}
@@ -3,11 +3,15 @@
 use std::ops::Index;
 
 use arrow2::{
-    array::{Array, BinaryArray},
+    array::{Array, BinaryArray, ListArray, MutableListArray, MutablePrimitiveArray},
     buffer::Buffer,
+    datatypes::{DataType, Field},
+    types::f16,
 };
 use arrow2_convert::{
     deserialize::{ArrowArray, ArrowDeserialize},
+    field::ArrowField,
+    serialize::ArrowSerialize,
     ArrowField, ArrowSerialize,
 };
 
@@ -127,3 +131,190 @@ impl ArrowDeserialize for BinaryBuffer {
         v.map(BinaryBuffer)
     }
 }
+
+// ----------------------------------------------------------------------------
+
+/// Shim to enable zero-copy arrow (de)serialization of half-precision floats.
+///
+/// `arrow2_convert` has no support for `f16`, so we implement the `List<Float16>` mapping manually.
+/// Elements are exposed as [`half::f16`], which has the same memory layout as [`arrow2::types::f16`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct F16Buffer(pub Buffer<f16>);
+
+impl F16Buffer {
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[inline]
+    pub fn num_bytes(&self) -> usize {
+        self.0.len() * std::mem::size_of::<f16>()
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[half::f16] {
+        bytemuck::cast_slice(self.0.as_slice())
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &half::f16> {
+        self.as_slice().iter()
+    }
+}
+
+impl Index<usize> for F16Buffer {
+    type Output = half::f16;
+
+    #[inline]
+    fn index(&self, i: usize) -> &half::f16 {
+        &self.as_slice()[i]
+    }
+}
+
+impl From<Vec<half::f16>> for F16Buffer {
+    #[inline]
+    fn from(v: Vec<half::f16>) -> Self {
+        Self(
+            v.into_iter()
+                .map(|x| f16::from_bits(x.to_bits()))
+                .collect::<Vec<_>>()
+                .into(),
+        )
+    }
+}
+
+impl ArrowField for F16Buffer {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::List(Box::new(Field::new("item", DataType::Float16, false)))
+    }
+}
+
+impl ArrowSerialize for F16Buffer {
+    type MutableArrayType = MutableListArray<i32, MutablePrimitiveArray<f16>>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        MutableListArray::new_from(
+            MutablePrimitiveArray::<f16>::from(DataType::Float16),
+            <Self as ArrowField>::data_type(),
+            0,
+        )
+    }
+
+    #[inline]
+    fn arrow_serialize(
+        v: &<Self as ArrowField>::Type,
+        array: &mut Self::MutableArrayType,
+    ) -> arrow2::error::Result<()> {
+        array.mut_values().extend_from_slice(v.0.as_slice());
+        array.try_push_valid()
+    }
+}
+
+/// Iterator for for [`BufferF16Array`]
+pub struct BufferF16ArrayIter<'a> {
+    index: usize,
+    array: &'a ListArray<i32>,
+    values: &'a Buffer<f16>,
+}
+
+impl<'a> Iterator for BufferF16ArrayIter<'a> {
+    type Item = Option<Buffer<f16>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.array.len() {
+            None
+        } else {
+            if let Some(validity) = self.array.validity() {
+                if !validity.get_bit(self.index) {
+                    self.index += 1;
+                    return Some(None);
+                }
+            }
+            let (start, end) = self.array.offsets().start_end(self.index);
+            self.index += 1;
+            Some(Some(self.values.clone().slice(start, end - start)))
+        }
+    }
+}
+
+/// Internal `ArrowArray` helper to iterate over a `ListArray<Float16>` while exposing Buffer slices
+pub struct BufferF16Array;
+
+impl<'a> IntoIterator for &'a BufferF16Array {
+    type Item = Option<Buffer<f16>>;
+
+    type IntoIter = BufferF16ArrayIter<'a>;
+
+    #[cfg(not(target_os = "windows"))]
+    fn into_iter(self) -> Self::IntoIter {
+        #[allow(unsafe_code)]
+        // SAFETY:
+        // This exists so we get a link-error if some code tries to call into_iter
+        // Iteration should only happen via iter_from_array_ref.
+        // This is a quirk of the way the traits work in arrow2_convert.
+        unsafe {
+            do_not_call_into_iter();
+        }
+        unreachable!()
+    }
+
+    // On windows the above linker trick doesn't work.
+    // We'll still catch the issue on build in Linux, but on windows just fall back to panic.
+    #[cfg(target_os = "windows")]
+    fn into_iter(self) -> Self::IntoIter {
+        panic!("Use iter_from_array_ref. This is a quirk of the way the traits work in arrow2_convert.");
+    }
+}
+
+impl ArrowArray for BufferF16Array {
+    type BaseArrayType = ListArray<i32>;
+    #[inline]
+    fn iter_from_array_ref(a: &dyn Array) -> <&Self as IntoIterator>::IntoIter {
+        let array = a.as_any().downcast_ref::<Self::BaseArrayType>().unwrap();
+        let values = array
+            .values()
+            .as_any()
+            .downcast_ref::<arrow2::array::PrimitiveArray<f16>>()
+            .unwrap()
+            .values();
+
+        BufferF16ArrayIter {
+            index: 0,
+            array,
+            values,
+        }
+    }
+}
+
+impl ArrowDeserialize for F16Buffer {
+    type ArrayType = BufferF16Array;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<Buffer<f16>>) -> Option<Self> {
+        v.map(F16Buffer)
+    }
+}
+
+#[test]
+fn test_f16_buffer_roundtrip() {
+    use arrow2_convert::{deserialize::TryIntoCollection, serialize::TryIntoArrow};
+
+    let buffers_in: Vec<F16Buffer> = vec![
+        vec![half::f16::from_f32(1.0), half::f16::from_f32(-2.5)].into(),
+        vec![].into(),
+        vec![half::f16::MAX, half::f16::MIN, half::f16::EPSILON].into(),
+    ];
+    let array: Box<dyn Array> = buffers_in.try_into_arrow().unwrap();
+    let buffers_out: Vec<F16Buffer> = TryIntoCollection::try_into_collection(array).unwrap();
+    assert_eq!(buffers_in, buffers_out);
+}
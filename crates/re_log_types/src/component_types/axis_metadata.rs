@@ -0,0 +1,162 @@
+use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+
+use crate::Component;
+
+/// Physical unit, display name and expected range of an axis.
+///
+/// Describes either the values of the [`crate::component_types::Scalar`]s or
+/// [`crate::component_types::Tensor`]s logged to the same entity, or one dimension of a tensor.
+/// Log several of them to the same entity to describe several tensor dimensions.
+///
+/// ```
+/// use re_log_types::component_types::AxisMetadata;
+/// use arrow2_convert::field::ArrowField;
+/// use arrow2::datatypes::{DataType, Field};
+///
+/// assert_eq!(
+///     AxisMetadata::data_type(),
+///     DataType::Struct(vec![
+///         Field::new("dimension", DataType::UInt32, true),
+///         Field::new("name", DataType::Utf8, true),
+///         Field::new("unit", DataType::Utf8, true),
+///         Field::new("range_min", DataType::Float64, true),
+///         Field::new("range_max", DataType::Float64, true),
+///     ])
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct AxisMetadata {
+    /// The tensor dimension this describes, or `None` for the values themselves.
+    pub dimension: Option<u32>,
+
+    /// Display name of the axis, e.g. `"Velocity"`.
+    pub name: Option<String>,
+
+    /// Physical unit, e.g. `"m/s"`.
+    pub unit: Option<String>,
+
+    /// The smallest value expected on this axis, if known.
+    pub range_min: Option<f64>,
+
+    /// The largest value expected on this axis, if known.
+    pub range_max: Option<f64>,
+}
+
+impl AxisMetadata {
+    /// Describes the values of a scalar or tensor.
+    #[inline]
+    pub fn values() -> Self {
+        Self::default()
+    }
+
+    /// Describes the given dimension of a tensor.
+    #[inline]
+    pub fn dimension(dimension: u32) -> Self {
+        Self {
+            dimension: Some(dimension),
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    #[inline]
+    pub fn with_unit(mut self, unit: impl Into<String>) -> Self {
+        self.unit = Some(unit.into());
+        self
+    }
+
+    #[inline]
+    pub fn with_range(mut self, min: f64, max: f64) -> Self {
+        self.range_min = Some(min);
+        self.range_max = Some(max);
+        self
+    }
+
+    /// The unit, unless it is empty.
+    pub fn unit(&self) -> Option<&str> {
+        self.unit
+            .as_deref()
+            .map(str::trim)
+            .filter(|unit| !unit.is_empty())
+    }
+
+    /// An axis label like `"Velocity [m/s]"`, if there is a name or a unit.
+    pub fn label(&self) -> Option<String> {
+        match (self.name.as_deref(), self.unit()) {
+            (Some(name), Some(unit)) => Some(format!("{name} [{unit}]")),
+            (Some(name), None) => Some(name.to_owned()),
+            (None, Some(unit)) => Some(format!("[{unit}]")),
+            (None, None) => None,
+        }
+    }
+
+    /// Formats a value on this axis with its unit, e.g. `"3.5 m/s"`.
+    pub fn format_value(&self, value: impl std::fmt::Display) -> String {
+        match self.unit() {
+            Some(unit) => format!("{value} {unit}"),
+            None => value.to_string(),
+        }
+    }
+
+    /// Can values of these two axes share a plot axis?
+    ///
+    /// Axes without a unit are compatible with anything, otherwise the units must match,
+    /// ignoring whitespace.
+    pub fn is_compatible_with(&self, other: &Self) -> bool {
+        match (self.unit(), other.unit()) {
+            (Some(a), Some(b)) => a
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .eq(b.chars().filter(|c| !c.is_whitespace())),
+            _ => true,
+        }
+    }
+}
+
+impl Component for AxisMetadata {
+    #[inline]
+    fn name() -> crate::ComponentName {
+        "rerun.axis_metadata".into()
+    }
+}
+
+#[test]
+fn test_axis_metadata_units() {
+    let speed = AxisMetadata::values().with_name("Speed").with_unit("m/s");
+    assert_eq!(speed.label().as_deref(), Some("Speed [m/s]"));
+    assert_eq!(speed.format_value(3.5), "3.5 m/s");
+
+    let unitless = AxisMetadata::dimension(1)
+        .with_name("Channel")
+        .with_unit(" ");
+    assert_eq!(unitless.label().as_deref(), Some("Channel"));
+    assert_eq!(unitless.format_value(2), "2");
+    assert_eq!(AxisMetadata::values().label(), None);
+
+    assert!(speed.is_compatible_with(&AxisMetadata::values().with_unit("m / s")));
+    assert!(speed.is_compatible_with(&unitless));
+    assert!(!speed.is_compatible_with(&AxisMetadata::values().with_unit("km/h")));
+}
+
+#[test]
+fn test_axis_metadata_roundtrip() {
+    use arrow2::array::Array;
+    use arrow2_convert::{deserialize::TryIntoCollection, serialize::TryIntoArrow};
+
+    let axes_in = vec![
+        AxisMetadata::values()
+            .with_name("Temperature")
+            .with_unit("°C")
+            .with_range(-40.0, 85.0),
+        AxisMetadata::dimension(2).with_name("Time").with_unit("s"),
+    ];
+    let array: Box<dyn Array> = axes_in.try_into_arrow().unwrap();
+    let axes_out: Vec<AxisMetadata> = TryIntoCollection::try_into_collection(array).unwrap();
+    assert_eq!(axes_in, axes_out);
+}
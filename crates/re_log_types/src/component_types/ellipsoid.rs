@@ -0,0 +1,279 @@
+use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+
+use crate::Component;
+
+use super::{Vec2D, Vec3D};
+
+/// An ellipsoid in 3D space, e.g. the n-sigma surface of a 3D gaussian.
+///
+/// The center is given by a [`super::Point3D`] and the orientation by a [`super::Quaternion`] logged
+/// alongside it. Without a rotation the principal axes are aligned with the x, y and z axes.
+///
+/// ## Example
+/// ```
+/// # use re_log_types::component_types::{Ellipsoid3D, Vec3D};
+/// # use arrow2_convert::field::ArrowField;
+/// # use arrow2::datatypes::{DataType, Field};
+/// assert_eq!(
+///     Ellipsoid3D::data_type(),
+///     DataType::Struct(vec![
+///         Field::new("half_sizes", Vec3D::data_type(), false),
+///         Field::new("solid", DataType::Boolean, false),
+///     ])
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Ellipsoid3D {
+    /// Semi-axis lengths along the (rotated) x, y and z axes.
+    pub half_sizes: Vec3D,
+
+    /// Draw a shaded surface instead of a wireframe.
+    pub solid: bool,
+}
+
+impl Ellipsoid3D {
+    #[inline]
+    pub fn new(half_sizes: impl Into<Vec3D>) -> Self {
+        Self {
+            half_sizes: half_sizes.into(),
+            solid: false,
+        }
+    }
+
+    #[inline]
+    pub fn solid(mut self, solid: bool) -> Self {
+        self.solid = solid;
+        self
+    }
+
+    /// The `n_sigma` ellipsoid of a gaussian with the given (symmetric) covariance matrix.
+    ///
+    /// Returns the ellipsoid together with the rotation that has to be logged next to it.
+    #[cfg(feature = "glam")]
+    pub fn from_covariance(covariance: [[f32; 3]; 3], n_sigma: f32) -> (Self, super::Quaternion) {
+        let (eigenvalues, eigenvectors) = symmetric_eigen3(covariance);
+
+        // `eigenvectors` is row-major, with the eigenvectors as its columns.
+        let mut rotation = glam::Mat3::from_cols_array_2d(&eigenvectors).transpose();
+
+        // The columns must form a proper rotation, not a reflection.
+        if rotation.determinant() < 0.0 {
+            rotation.z_axis = -rotation.z_axis;
+        }
+
+        let half_sizes = eigenvalues.map(|ev| n_sigma * ev.max(0.0).sqrt());
+        (
+            Self::new(half_sizes),
+            glam::Quat::from_mat3(&rotation).normalize().into(),
+        )
+    }
+}
+
+impl Component for Ellipsoid3D {
+    #[inline]
+    fn name() -> crate::ComponentName {
+        "rerun.ellipsoid3d".into()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// An ellipse in 2D space, e.g. the n-sigma contour of a 2D gaussian.
+///
+/// ## Example
+/// ```
+/// # use re_log_types::component_types::{Ellipse2D, Vec2D};
+/// # use arrow2_convert::field::ArrowField;
+/// # use arrow2::datatypes::{DataType, Field};
+/// assert_eq!(
+///     Ellipse2D::data_type(),
+///     DataType::Struct(vec![
+///         Field::new("center", Vec2D::data_type(), false),
+///         Field::new("half_sizes", Vec2D::data_type(), false),
+///         Field::new("rotation", DataType::Float32, false),
+///         Field::new("solid", DataType::Boolean, false),
+///     ])
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Ellipse2D {
+    pub center: Vec2D,
+
+    /// Semi-axis lengths along the (rotated) x and y axes.
+    pub half_sizes: Vec2D,
+
+    /// Rotation around [`Self::center`] in radians, turning the x-axis towards the y-axis.
+    pub rotation: f32,
+
+    /// Fill the ellipse instead of only drawing its outline.
+    pub solid: bool,
+}
+
+impl Ellipse2D {
+    #[inline]
+    pub fn new(center: impl Into<Vec2D>, half_sizes: impl Into<Vec2D>, rotation: f32) -> Self {
+        Self {
+            center: center.into(),
+            half_sizes: half_sizes.into(),
+            rotation,
+            solid: false,
+        }
+    }
+
+    #[inline]
+    pub fn solid(mut self, solid: bool) -> Self {
+        self.solid = solid;
+        self
+    }
+
+    /// The `n_sigma` ellipse of a gaussian with the given mean and (symmetric) covariance matrix.
+    pub fn from_covariance(center: [f32; 2], covariance: [[f32; 2]; 2], n_sigma: f32) -> Self {
+        let [[a, b], [_, c]] = covariance;
+        let mean = 0.5 * (a + c);
+        let radius = (0.25 * (a - c) * (a - c) + b * b).sqrt();
+        let major = n_sigma * (mean + radius).max(0.0).sqrt();
+        let minor = n_sigma * (mean - radius).max(0.0).sqrt();
+        let rotation = 0.5 * (2.0 * b).atan2(a - c);
+        Self::new(center, [major, minor], rotation)
+    }
+
+    /// `num_segments + 1` points along the outline, the last one repeating the first.
+    pub fn outline(&self, num_segments: usize) -> Vec<[f32; 2]> {
+        let (sin, cos) = self.rotation.sin_cos();
+        let [cx, cy] = self.center.0;
+        let [rx, ry] = self.half_sizes.0;
+        (0..=num_segments)
+            .map(|i| {
+                let t = std::f32::consts::TAU * (i % num_segments.max(1)) as f32
+                    / num_segments.max(1) as f32;
+                let (x, y) = (rx * t.cos(), ry * t.sin());
+                [cx + cos * x - sin * y, cy + sin * x + cos * y]
+            })
+            .collect()
+    }
+}
+
+impl Component for Ellipse2D {
+    #[inline]
+    fn name() -> crate::ComponentName {
+        "rerun.ellipse2d".into()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Eigen-decomposition of a symmetric 3x3 matrix using cyclic Jacobi rotations.
+///
+/// Returns the eigenvalues and a matrix whose columns are the corresponding eigenvectors.
+#[cfg(feature = "glam")]
+fn symmetric_eigen3(mut m: [[f32; 3]; 3]) -> ([f32; 3], [[f32; 3]; 3]) {
+    let mut v = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+    for _sweep in 0..16 {
+        let off_diagonal = m[0][1].abs() + m[0][2].abs() + m[1][2].abs();
+        if off_diagonal <= f32::EPSILON * (m[0][0].abs() + m[1][1].abs() + m[2][2].abs()) {
+            break;
+        }
+
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            if m[p][q] == 0.0 {
+                continue;
+            }
+
+            let theta = 0.5 * (2.0 * m[p][q]).atan2(m[q][q] - m[p][p]);
+            let (s, c) = theta.sin_cos();
+
+            // m = Jᵀ m J, v = v J
+            for row in &mut m {
+                let (mp, mq) = (row[p], row[q]);
+                row[p] = c * mp - s * mq;
+                row[q] = s * mp + c * mq;
+            }
+            for k in 0..3 {
+                let (mp, mq) = (m[p][k], m[q][k]);
+                m[p][k] = c * mp - s * mq;
+                m[q][k] = s * mp + c * mq;
+            }
+            for row in &mut v {
+                let (vp, vq) = (row[p], row[q]);
+                row[p] = c * vp - s * vq;
+                row[q] = s * vp + c * vq;
+            }
+        }
+    }
+
+    ([m[0][0], m[1][1], m[2][2]], v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Each rotated axis must be an eigenvector of the covariance, with its variance as eigenvalue.
+    #[cfg(feature = "glam")]
+    fn assert_principal_axes(
+        cov: [[f32; 3]; 3],
+        rotation: crate::component_types::Quaternion,
+        variances: [f32; 3],
+    ) {
+        for (axis, variance) in variances.into_iter().enumerate() {
+            let mut unit = [0.0; 3];
+            unit[axis] = 1.0;
+            let dir = (glam::Quat::from(rotation) * glam::Vec3::from(unit)).to_array();
+            for (cov_row, d) in cov.iter().zip(dir) {
+                let cov_dir: f32 = cov_row.iter().zip(dir).map(|(c, d)| c * d).sum();
+                assert!((cov_dir - variance * d).abs() < 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "glam")]
+    fn test_ellipsoid_from_covariance() {
+        // Axis-aligned, with variances 4, 9 and 1.
+        let cov = [[4.0, 0.0, 0.0], [0.0, 9.0, 0.0], [0.0, 0.0, 1.0]];
+        let (ellipsoid, rotation) = Ellipsoid3D::from_covariance(cov, 3.0);
+
+        let mut half_sizes = ellipsoid.half_sizes.0;
+        half_sizes.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        for (actual, expected) in half_sizes.iter().zip([3.0, 6.0, 9.0]) {
+            assert!((actual - expected).abs() < 1e-4, "{half_sizes:?}");
+        }
+
+        assert_principal_axes(
+            cov,
+            rotation,
+            ellipsoid.half_sizes.0.map(|h| (h / 3.0).powi(2)),
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "glam")]
+    fn test_ellipsoid_from_correlated_covariance() {
+        let cov = [[2.0, 1.0, 0.5], [1.0, 3.0, 0.25], [0.5, 0.25, 1.0]];
+        let (ellipsoid, rotation) = Ellipsoid3D::from_covariance(cov, 1.0);
+
+        let norm =
+            (rotation.x.powi(2) + rotation.y.powi(2) + rotation.z.powi(2) + rotation.w.powi(2))
+                .sqrt();
+        assert!((norm - 1.0).abs() < 1e-5);
+
+        assert_principal_axes(cov, rotation, ellipsoid.half_sizes.0.map(|h| h.powi(2)));
+    }
+
+    #[test]
+    fn test_ellipse_from_covariance() {
+        // Major axis along the diagonal.
+        let ellipse = Ellipse2D::from_covariance([1.0, 2.0], [[5.0, 4.0], [4.0, 5.0]], 2.0);
+        assert_eq!(ellipse.center, Vec2D([1.0, 2.0]));
+        assert!((ellipse.half_sizes.0[0] - 6.0).abs() < 1e-5);
+        assert!((ellipse.half_sizes.0[1] - 2.0).abs() < 1e-5);
+        assert!((ellipse.rotation - std::f32::consts::FRAC_PI_4).abs() < 1e-5);
+
+        let outline = ellipse.outline(4);
+        assert_eq!(outline.len(), 5);
+        assert_eq!(outline.first(), outline.last());
+    }
+}
@@ -0,0 +1,178 @@
+use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+
+use crate::Component;
+
+/// Equatorial radius of the WGS 84 ellipsoid, as used by the Web-Mercator projection.
+pub const EARTH_RADIUS_METERS: f64 = 6_378_137.0;
+
+/// Latitudes beyond this can't be shown on a square Web-Mercator map.
+pub const WEB_MERCATOR_MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+/// A position on the earth in WGS 84 coordinates, e.g. a GPS fix.
+///
+/// ## Example
+/// ```
+/// # use re_log_types::component_types::GeoPoint;
+/// # use arrow2_convert::field::ArrowField;
+/// # use arrow2::datatypes::{DataType, Field};
+/// assert_eq!(
+///     GeoPoint::data_type(),
+///     DataType::Struct(vec![
+///         Field::new("latitude", DataType::Float64, false),
+///         Field::new("longitude", DataType::Float64, false),
+///         Field::new("altitude", DataType::Float32, true),
+///     ])
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct GeoPoint {
+    /// Degrees north of the equator.
+    pub latitude: f64,
+
+    /// Degrees east of the prime meridian.
+    pub longitude: f64,
+
+    /// Meters above the WGS 84 ellipsoid, if known.
+    pub altitude: Option<f32>,
+}
+
+impl GeoPoint {
+    #[inline]
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+            altitude: None,
+        }
+    }
+
+    #[inline]
+    pub fn with_altitude(mut self, altitude: f32) -> Self {
+        self.altitude = Some(altitude);
+        self
+    }
+
+    /// Projects onto the Web-Mercator plane (EPSG:3857), in meters.
+    ///
+    /// `x` grows towards the east and `y` towards the north.
+    /// Latitudes are clamped to [`WEB_MERCATOR_MAX_LATITUDE`].
+    pub fn to_web_mercator(&self) -> [f64; 2] {
+        let latitude = self
+            .latitude
+            .clamp(-WEB_MERCATOR_MAX_LATITUDE, WEB_MERCATOR_MAX_LATITUDE)
+            .to_radians();
+        let x = EARTH_RADIUS_METERS * self.longitude.to_radians();
+        let y = EARTH_RADIUS_METERS * (std::f64::consts::FRAC_PI_4 + 0.5 * latitude).tan().ln();
+        [x, y]
+    }
+
+    /// Inverse of [`Self::to_web_mercator`]. The altitude is left unknown.
+    pub fn from_web_mercator([x, y]: [f64; 2]) -> Self {
+        let longitude = (x / EARTH_RADIUS_METERS).to_degrees();
+        let latitude = (2.0 * (y / EARTH_RADIUS_METERS).exp().atan() - std::f64::consts::FRAC_PI_2)
+            .to_degrees();
+        Self::new(latitude, longitude)
+    }
+}
+
+impl std::fmt::Display for GeoPoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ns = if self.latitude < 0.0 { 'S' } else { 'N' };
+        let ew = if self.longitude < 0.0 { 'W' } else { 'E' };
+        write!(
+            f,
+            "{:.6}°{ns} {:.6}°{ew}",
+            self.latitude.abs(),
+            self.longitude.abs()
+        )?;
+        if let Some(altitude) = self.altitude {
+            write!(f, ", {altitude} m")?;
+        }
+        Ok(())
+    }
+}
+
+impl Component for GeoPoint {
+    #[inline]
+    fn name() -> crate::ComponentName {
+        "rerun.geopoint".into()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A path on the earth, e.g. a GPS track, drawn as straight segments on the map.
+///
+/// ## Example
+/// ```
+/// # use re_log_types::component_types::{GeoLineStrip, GeoPoint};
+/// # use arrow2_convert::field::ArrowField;
+/// # use arrow2::datatypes::{DataType, Field};
+/// assert_eq!(
+///     GeoLineStrip::data_type(),
+///     DataType::Struct(vec![Field::new(
+///         "points",
+///         DataType::List(Box::new(Field::new("item", GeoPoint::data_type(), false))),
+///         false
+///     )])
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct GeoLineStrip {
+    pub points: Vec<GeoPoint>,
+}
+
+impl From<Vec<GeoPoint>> for GeoLineStrip {
+    #[inline]
+    fn from(points: Vec<GeoPoint>) -> Self {
+        Self { points }
+    }
+}
+
+impl Component for GeoLineStrip {
+    #[inline]
+    fn name() -> crate::ComponentName {
+        "rerun.geo_line_strip".into()
+    }
+}
+
+#[test]
+fn test_web_mercator() {
+    let origin = GeoPoint::new(0.0, 0.0).to_web_mercator();
+    assert!(origin[0].abs() < 1e-6 && origin[1].abs() < 1e-6);
+
+    // The projected world is a square, 2π·R on each side.
+    let corner = GeoPoint::new(WEB_MERCATOR_MAX_LATITUDE, 180.0).to_web_mercator();
+    let half_world = std::f64::consts::PI * EARTH_RADIUS_METERS;
+    assert!((corner[0] - half_world).abs() < 1e-3);
+    assert!((corner[1] - half_world).abs() < 1e-3);
+
+    // Beyond the poles of the map everything is clamped.
+    assert_eq!(GeoPoint::new(90.0, 180.0).to_web_mercator(), corner);
+
+    let zurich = GeoPoint::new(47.376_887, 8.541_694);
+    let roundtrip = GeoPoint::from_web_mercator(zurich.to_web_mercator());
+    assert!((roundtrip.latitude - zurich.latitude).abs() < 1e-9);
+    assert!((roundtrip.longitude - zurich.longitude).abs() < 1e-9);
+}
+
+#[test]
+fn test_geo_roundtrip() {
+    use arrow2::array::Array;
+    use arrow2_convert::{deserialize::TryIntoCollection, serialize::TryIntoArrow};
+
+    let points_in = vec![
+        GeoPoint::new(47.376_887, 8.541_694).with_altitude(408.0),
+        GeoPoint::new(-33.856_784, 151.215_297),
+    ];
+    let array: Box<dyn Array> = points_in.try_into_arrow().unwrap();
+    let points_out: Vec<GeoPoint> = TryIntoCollection::try_into_collection(array).unwrap();
+    assert_eq!(points_in, points_out);
+
+    let strips_in = vec![GeoLineStrip::from(points_in)];
+    let array: Box<dyn Array> = strips_in.try_into_arrow().unwrap();
+    let strips_out: Vec<GeoLineStrip> = TryIntoCollection::try_into_collection(array).unwrap();
+    assert_eq!(strips_in, strips_out);
+}
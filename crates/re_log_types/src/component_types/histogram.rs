@@ -0,0 +1,234 @@
+use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+
+use crate::Component;
+
+/// The bins of a histogram.
+///
+/// ```
+/// use re_log_types::component_types::HistogramBins;
+/// use arrow2_convert::field::ArrowField;
+/// use arrow2::datatypes::{DataType, Field};
+///
+/// assert_eq!(
+///     HistogramBins::data_type(),
+///     DataType::Struct(vec![
+///         Field::new(
+///             "edges",
+///             DataType::List(Box::new(Field::new("item", DataType::Float64, false))),
+///             false
+///         ),
+///         Field::new(
+///             "counts",
+///             DataType::List(Box::new(Field::new("item", DataType::Float64, false))),
+///             false
+///         ),
+///     ])
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct HistogramBins {
+    /// The increasing edges of the bins, one more than there are `counts`.
+    ///
+    /// Bin `i` spans `edges[i]..edges[i + 1]`.
+    pub edges: Vec<f64>,
+
+    /// How many samples fell into each bin. Weighted histograms may have fractional counts.
+    pub counts: Vec<f64>,
+}
+
+impl HistogramBins {
+    /// Are there `counts.len() + 1` increasing edges?
+    pub fn is_valid(&self) -> bool {
+        self.edges.len() == self.counts.len() + 1
+            && self.edges.windows(2).all(|pair| pair[0] < pair[1])
+    }
+
+    /// `num_bins` bins of equal width spanning `min..max`, all empty.
+    pub fn uniform(min: f64, max: f64, num_bins: usize) -> Self {
+        let num_bins = num_bins.max(1);
+        let (min, max) = if min < max {
+            (min, max)
+        } else {
+            (min - 0.5, min + 0.5)
+        };
+        let width = (max - min) / num_bins as f64;
+        Self {
+            edges: (0..=num_bins).map(|i| min + i as f64 * width).collect(),
+            counts: vec![0.0; num_bins],
+        }
+    }
+
+    /// The index of the bin `value` falls into, if any.
+    ///
+    /// Bins include their lower edge; the last bin also includes its upper edge.
+    pub fn bin_index(&self, value: f64) -> Option<usize> {
+        let (&min, &max) = (self.edges.first()?, self.edges.last()?);
+        if !(min <= value && value <= max) {
+            return None; // also catches NaN
+        }
+        let upper = self.edges.partition_point(|&edge| edge <= value);
+        Some(
+            upper
+                .saturating_sub(1)
+                .min(self.counts.len().saturating_sub(1)),
+        )
+    }
+
+    /// Adds the samples or counts of `histogram` into these bins.
+    ///
+    /// Bins of `histogram` are assigned by their center.
+    pub fn add(&mut self, histogram: &Histogram) {
+        match histogram {
+            Histogram::Bins(bins) => {
+                for (edges, count) in bins.edges.windows(2).zip(&bins.counts) {
+                    if let Some(i) = self.bin_index(0.5 * (edges[0] + edges[1])) {
+                        self.counts[i] += count;
+                    }
+                }
+            }
+            Histogram::Samples(samples) => {
+                for &sample in samples {
+                    if let Some(i) = self.bin_index(sample) {
+                        self.counts[i] += 1.0;
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn total_count(&self) -> f64 {
+        self.counts.iter().sum()
+    }
+}
+
+/// A distribution of values, e.g. of network weights or latencies.
+///
+/// Either already binned, or as raw samples that the viewer bins.
+///
+/// ```
+/// use re_log_types::component_types::{Histogram, HistogramBins};
+/// use arrow2_convert::field::ArrowField;
+/// use arrow2::datatypes::{DataType, Field, UnionMode};
+///
+/// assert_eq!(
+///     Histogram::data_type(),
+///     DataType::Union(
+///         vec![
+///             Field::new("Bins", HistogramBins::data_type(), false),
+///             Field::new(
+///                 "Samples",
+///                 DataType::List(Box::new(Field::new("item", DataType::Float64, false))),
+///                 false
+///             ),
+///         ],
+///         None,
+///         UnionMode::Dense
+///     )
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[arrow_field(type = "dense")]
+pub enum Histogram {
+    /// Already binned.
+    Bins(HistogramBins),
+
+    /// Raw samples, binned by the viewer.
+    Samples(Vec<f64>),
+}
+
+impl Histogram {
+    #[inline]
+    pub fn from_bins(edges: Vec<f64>, counts: Vec<f64>) -> Self {
+        Self::Bins(HistogramBins { edges, counts })
+    }
+
+    #[inline]
+    pub fn from_samples(samples: Vec<f64>) -> Self {
+        Self::Samples(samples)
+    }
+
+    /// The smallest and largest finite value covered by the histogram, if any.
+    pub fn range(&self) -> Option<(f64, f64)> {
+        let values: &[f64] = match self {
+            Self::Bins(bins) => &bins.edges,
+            Self::Samples(samples) => samples,
+        };
+        values
+            .iter()
+            .copied()
+            .filter(|value| value.is_finite())
+            .fold(None, |range, value| {
+                let (min, max) = range.unwrap_or((value, value));
+                Some((min.min(value), max.max(value)))
+            })
+    }
+
+    /// The histogram as bins.
+    ///
+    /// Samples are sorted into `num_bins` bins of equal width spanning their range,
+    /// while bins are returned as they are.
+    pub fn to_bins(&self, num_bins: usize) -> HistogramBins {
+        match self {
+            Self::Bins(bins) => bins.clone(),
+            Self::Samples(_) => {
+                let Some((min, max)) = self.range() else {
+                    return HistogramBins::default();
+                };
+                let mut bins = HistogramBins::uniform(min, max, num_bins);
+                bins.add(self);
+                bins
+            }
+        }
+    }
+}
+
+impl Component for Histogram {
+    #[inline]
+    fn name() -> crate::ComponentName {
+        "rerun.histogram".into()
+    }
+}
+
+#[test]
+fn test_histogram_binning() {
+    let histogram = Histogram::from_samples(vec![0.0, 1.0, 1.5, 4.0, f64::NAN, f64::INFINITY]);
+    assert_eq!(histogram.range(), Some((0.0, 4.0)));
+
+    let bins = histogram.to_bins(4);
+    assert!(bins.is_valid());
+    assert_eq!(bins.edges, vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(bins.counts, vec![1.0, 2.0, 0.0, 1.0]);
+
+    // All samples the same.
+    let bins = Histogram::from_samples(vec![2.0; 3]).to_bins(2);
+    assert_eq!(bins.edges, vec![1.5, 2.0, 2.5]);
+    assert_eq!(bins.total_count(), 3.0);
+
+    let mut coarse = HistogramBins::uniform(0.0, 4.0, 2);
+    coarse.add(&Histogram::Bins(
+        Histogram::from_samples(vec![0.0, 1.0, 1.5, 4.0]).to_bins(4),
+    ));
+    assert_eq!(coarse.counts, vec![3.0, 1.0]);
+    coarse.add(&Histogram::from_samples(vec![3.0, 5.0]));
+    assert_eq!(coarse.counts, vec![3.0, 2.0]);
+
+    assert!(!Histogram::from_bins(vec![0.0, 1.0], vec![1.0, 2.0])
+        .to_bins(1)
+        .is_valid());
+}
+
+#[test]
+fn test_histogram_roundtrip() {
+    use arrow2::array::Array;
+    use arrow2_convert::{deserialize::TryIntoCollection, serialize::TryIntoArrow};
+
+    let histograms_in = vec![
+        Histogram::from_bins(vec![0.0, 1.0, 2.0], vec![3.0, 4.0]),
+        Histogram::from_samples(vec![0.5, 1.5, 1.7]),
+    ];
+    let array: Box<dyn Array> = histograms_in.try_into_arrow().unwrap();
+    let histograms_out: Vec<Histogram> = TryIntoCollection::try_into_collection(array).unwrap();
+    assert_eq!(histograms_in, histograms_out);
+}
@@ -4,13 +4,14 @@ use arrow2::{
 };
 use arrow2_convert::{
     arrow_enable_vec_for_type, deserialize::ArrowDeserialize, field::ArrowField,
-    serialize::ArrowSerialize,
+    serialize::ArrowSerialize, ArrowDeserialize, ArrowField, ArrowSerialize,
 };
 
 use crate::Component;
 
 use super::Vec2D;
 use super::Vec3D;
+use super::{ColorRGBA, Radius};
 
 /// A Line Strip of 2D points
 ///
@@ -184,6 +185,74 @@ impl ArrowDeserialize for LineStrip3D {
     }
 }
 
+/// One color per vertex of a [`LineStrip2D`] or [`LineStrip3D`], e.g. to color a trajectory by speed.
+///
+/// Takes precedence over a [`ColorRGBA`] logged for the whole strip.
+/// Vertices beyond the end of the list fall back to the color of the strip.
+///
+/// ```
+/// use re_log_types::component_types::VertexColors;
+/// use arrow2_convert::field::ArrowField;
+/// use arrow2::datatypes::{DataType, Field};
+///
+/// assert_eq!(
+///     VertexColors::data_type(),
+///     DataType::List(Box::new(Field::new("item", DataType::UInt32, false)))
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[arrow_field(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct VertexColors(pub Vec<ColorRGBA>);
+
+impl From<Vec<ColorRGBA>> for VertexColors {
+    #[inline]
+    fn from(colors: Vec<ColorRGBA>) -> Self {
+        Self(colors)
+    }
+}
+
+impl Component for VertexColors {
+    #[inline]
+    fn name() -> crate::ComponentName {
+        "rerun.vertex_colors".into()
+    }
+}
+
+/// One radius per vertex of a [`LineStrip2D`] or [`LineStrip3D`], in scene units.
+///
+/// Takes precedence over a [`Radius`] logged for the whole strip.
+/// Vertices beyond the end of the list fall back to the radius of the strip.
+///
+/// ```
+/// use re_log_types::component_types::VertexRadii;
+/// use arrow2_convert::field::ArrowField;
+/// use arrow2::datatypes::{DataType, Field};
+///
+/// assert_eq!(
+///     VertexRadii::data_type(),
+///     DataType::List(Box::new(Field::new("item", DataType::Float32, false)))
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[arrow_field(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct VertexRadii(pub Vec<Radius>);
+
+impl From<Vec<f32>> for VertexRadii {
+    #[inline]
+    fn from(radii: Vec<f32>) -> Self {
+        Self(radii.into_iter().map(Radius).collect())
+    }
+}
+
+impl Component for VertexRadii {
+    #[inline]
+    fn name() -> crate::ComponentName {
+        "rerun.vertex_radii".into()
+    }
+}
+
 #[test]
 fn test_line2d_roundtrip() {
     use arrow2::array::Array;
@@ -217,3 +286,22 @@ fn test_line3d_roundtrip() {
     let lines_out: Vec<LineStrip3D> = TryIntoCollection::try_into_collection(array).unwrap();
     assert_eq!(lines_in, lines_out);
 }
+
+#[test]
+fn test_vertex_attributes_roundtrip() {
+    use arrow2::array::Array;
+    use arrow2_convert::{deserialize::TryIntoCollection, serialize::TryIntoArrow};
+
+    let colors_in = vec![
+        VertexColors(vec![ColorRGBA(0xff0000ff), ColorRGBA(0x00ff00ff)]),
+        VertexColors::default(),
+    ];
+    let array: Box<dyn Array> = colors_in.try_into_arrow().unwrap();
+    let colors_out: Vec<VertexColors> = TryIntoCollection::try_into_collection(array).unwrap();
+    assert_eq!(colors_in, colors_out);
+
+    let radii_in: Vec<VertexRadii> = vec![vec![0.5, 1.0, 2.0].into(), vec![0.1].into()];
+    let array: Box<dyn Array> = radii_in.try_into_arrow().unwrap();
+    let radii_out: Vec<VertexRadii> = TryIntoCollection::try_into_collection(array).unwrap();
+    assert_eq!(radii_in, radii_out);
+}
@@ -62,6 +62,15 @@ impl From<glam::Mat3> for Mat3x3 {
     }
 }
 
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Matrix3<f32>> for Mat3x3 {
+    /// Column-major, like the rest of [`Mat3x3`].
+    #[inline]
+    fn from(v: nalgebra::Matrix3<f32>) -> Self {
+        Self::from(<[[f32; 3]; 3]>::from(v))
+    }
+}
+
 arrow_enable_vec_for_type!(Mat3x3);
 
 impl ArrowField for Mat3x3 {
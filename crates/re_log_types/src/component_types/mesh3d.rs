@@ -9,13 +9,14 @@ use arrow2_convert::{serialize::ArrowSerialize, ArrowDeserialize, ArrowField, Ar
 use crate::Component;
 
 use super::arrow_convert_shims::BinaryBuffer;
-use super::{FieldError, Vec4D};
+use super::{FieldError, Tensor, Vec4D};
 
 // ----------------------------------------------------------------------------
 
 /// A unique id per [`Mesh3D`].
 ///
-/// TODO(emilk): this should be a hash of the mesh (CAS).
+/// Use [`RawMesh3D::with_content_id`] or [`EncodedMesh3D::with_content_id`] to derive it from
+/// the mesh contents, so that identical meshes share an id and are only loaded once by the viewer.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct MeshId(pub uuid::Uuid);
@@ -32,10 +33,20 @@ impl std::hash::Hash for MeshId {
 }
 
 impl MeshId {
+    /// Placeholder, to be replaced using e.g. [`RawMesh3D::with_content_id`].
+    ///
+    /// Meshes that are still using it get their id from their contents in [`Mesh3D::mesh_id`],
+    /// which means hashing them every time.
+    pub const NIL: Self = Self(uuid::Uuid::nil());
+
     #[inline]
     pub fn random() -> Self {
         Self(uuid::Uuid::new_v4())
     }
+
+    fn from_hash(hash: crate::hash::Hash128) -> Self {
+        Self(uuid::Uuid::from_u128(hash.as_u128()))
+    }
 }
 
 impl ArrowField for MeshId {
@@ -105,12 +116,33 @@ pub enum RawMeshError {
         got positions={0} vs. normals={1}"
     )]
     MismatchedPositionsNormals(usize, usize),
+
+    #[error(
+        "There must be one texture coordinate pair per vertex, \
+        got positions={0} vs. texcoords={1}"
+    )]
+    MismatchedPositionsTexcoords(usize, usize),
+
+    #[error("The albedo texture must be an RGB, RGBA or grayscale image, got shape {0:?}")]
+    AlbedoTextureNotAnImage(Vec<super::TensorDimension>),
+
+    #[error(
+        "The albedo texture has shape {height}x{width}x{channels}, \
+        so it needs {expected} bytes, but got {actual}"
+    )]
+    AlbedoTextureSizeMismatch {
+        height: u64,
+        width: u64,
+        channels: u64,
+        expected: usize,
+        actual: usize,
+    },
 }
 
 /// A raw "triangle soup" mesh.
 ///
 /// ```
-/// # use re_log_types::component_types::RawMesh3D;
+/// # use re_log_types::component_types::{RawMesh3D, Tensor};
 /// # use arrow2_convert::field::ArrowField;
 /// # use arrow2::datatypes::{DataType, Field, UnionMode};
 /// assert_eq!(
@@ -126,6 +158,9 @@ pub enum RawMeshError {
 ///         Field::new("vertex_normals", DataType::List(Box::new(
 ///             Field::new("item", DataType::Float32, false)),
 ///         ), true),
+///         Field::new("vertex_texcoords", DataType::List(Box::new(
+///             Field::new("item", DataType::Float32, false)),
+///         ), true),
 ///         Field::new("indices", DataType::List(Box::new(
 ///             Field::new("item", DataType::UInt32, false)),
 ///         ), true),
@@ -133,6 +168,7 @@ pub enum RawMeshError {
 ///             Box::new(Field::new("item", DataType::Float32, false)),
 ///             4
 ///         ), true),
+///         Field::new("albedo_texture", Tensor::data_type(), true),
 ///     ]),
 /// );
 /// ```
@@ -157,6 +193,12 @@ pub struct RawMesh3D {
     /// If specified, this must match the length of `Self::positions`.
     pub vertex_normals: Option<Buffer<f32>>,
 
+    /// Optionally, the flattened texture coordinates (u, v) for this mesh.
+    ///
+    /// If specified, there must be one pair per vertex.
+    /// Used to sample [`Self::albedo_texture`].
+    pub vertex_texcoords: Option<Buffer<f32>>,
+
     /// Optionally, the flattened indices array for this mesh.
     ///
     /// Meshes are always triangle lists, i.e. the length of this vector should always be
@@ -167,15 +209,29 @@ pub struct RawMesh3D {
     ///
     /// `[1.0, 1.0, 1.0, 1.0]` if unspecified.
     pub albedo_factor: Option<Vec4D>,
-    //
-    // TODO(cmc): We need to support vertex colors and/or texturing, otherwise it's pretty
-    // hard to see anything with complex enough meshes (and hovering doesn't really help
-    // when everything's white).
-    // pub colors: Option<Vec<u8>>,
-    // pub texcoords: Option<Vec<f32>>,
+
+    /// Optionally, an albedo texture sampled using [`Self::vertex_texcoords`].
+    ///
+    /// Can be a raw `U8` image, or an encoded one (e.g. [`super::TensorData::JPEG`]).
+    /// Multiplied with the vertex colors and [`Self::albedo_factor`].
+    pub albedo_texture: Option<Tensor>,
 }
 
 impl RawMesh3D {
+    /// Hash everything but the current `mesh_id`.
+    pub fn content_id(&self) -> MeshId {
+        crate::profile_function!();
+        MeshId::from_hash(crate::hash::Hash128::hash(RawMesh3DContent(self)))
+    }
+
+    /// Replace the `mesh_id` with [`Self::content_id`].
+    pub fn with_content_id(self) -> Self {
+        Self {
+            mesh_id: self.content_id(),
+            ..self
+        }
+    }
+
     pub fn sanity_check(&self) -> Result<(), RawMeshError> {
         if self.vertex_positions.len() % 3 != 0 {
             return Err(RawMeshError::PositionsNotDivisibleBy3(
@@ -213,6 +269,35 @@ impl RawMesh3D {
             }
         }
 
+        if let Some(texcoords) = &self.vertex_texcoords {
+            if texcoords.len() != num_vertices * 2 {
+                return Err(RawMeshError::MismatchedPositionsTexcoords(
+                    self.vertex_positions.len(),
+                    texcoords.len(),
+                ));
+            }
+        }
+
+        if let Some(texture) = &self.albedo_texture {
+            let Some([height, width, channels]) = texture.image_height_width_channels() else {
+                return Err(RawMeshError::AlbedoTextureNotAnImage(
+                    texture.shape().to_vec(),
+                ));
+            };
+            if let super::TensorData::U8(buf) = &texture.data {
+                let expected = (height * width * channels) as usize;
+                if buf.num_bytes() != expected {
+                    return Err(RawMeshError::AlbedoTextureSizeMismatch {
+                        height,
+                        width,
+                        channels,
+                        expected,
+                        actual: buf.num_bytes(),
+                    });
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -231,12 +316,60 @@ impl RawMesh3D {
     }
 }
 
+/// Hashes the contents of a [`RawMesh3D`].
+///
+/// [`RawMesh3D`] can't implement [`std::hash::Hash`] itself, since it compares floats by value.
+#[derive(Clone, Copy)]
+struct RawMesh3DContent<'a>(&'a RawMesh3D);
+
+impl<'a> std::hash::Hash for RawMesh3DContent<'a> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        use bytemuck::cast_slice;
+
+        let RawMesh3D {
+            mesh_id: _,
+            vertex_positions,
+            vertex_colors,
+            vertex_normals,
+            vertex_texcoords,
+            indices,
+            albedo_factor,
+            albedo_texture,
+        } = self.0;
+
+        cast_slice::<_, u8>(vertex_positions.as_slice()).hash(state);
+        vertex_colors
+            .as_ref()
+            .map(|colors| cast_slice::<_, u8>(colors.as_slice()))
+            .hash(state);
+        vertex_normals
+            .as_ref()
+            .map(|normals| cast_slice::<_, u8>(normals.as_slice()))
+            .hash(state);
+        vertex_texcoords
+            .as_ref()
+            .map(|texcoords| cast_slice::<_, u8>(texcoords.as_slice()))
+            .hash(state);
+        indices
+            .as_ref()
+            .map(|indices| cast_slice::<_, u8>(indices.as_slice()))
+            .hash(state);
+        albedo_factor
+            .map(|albedo_factor| albedo_factor.0.map(f32::to_bits))
+            .hash(state);
+        albedo_texture
+            .as_ref()
+            .map(|texture| texture.id().0.as_u128())
+            .hash(state);
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 /// Compressed/encoded mesh format
 ///
 /// ```
-/// # use re_log_types::component_types::EncodedMesh3D;
+/// # use re_log_types::component_types::{EncodedMesh3D, Tensor};
 /// # use arrow2_convert::field::ArrowField;
 /// # use arrow2::datatypes::{DataType, Field, UnionMode};
 /// assert_eq!(
@@ -253,6 +386,7 @@ impl RawMesh3D {
 ///             Box::new(Field::new("item", DataType::Float32, false)),
 ///             12,
 ///         ), false),
+///         Field::new("albedo_texture", Tensor::data_type(), true),
 ///     ]),
 /// );
 /// ```
@@ -266,6 +400,12 @@ pub struct EncodedMesh3D {
 
     /// four columns of an affine transformation matrix
     pub transform: [[f32; 3]; 4],
+
+    /// Optionally, an albedo texture for formats that keep their textures in separate files.
+    ///
+    /// For OBJ files this is the `map_Kd` of their materials,
+    /// see [`EncodedMesh3D::load_obj_albedo_texture`].
+    pub albedo_texture: Option<Tensor>,
 }
 
 /// Helper struct for converting `EncodedMesh3D` to arrow
@@ -279,6 +419,8 @@ pub struct EncodedMesh3DArrow {
 
     #[arrow_field(type = "arrow2_convert::field::FixedSizeVec<f32, 12>")]
     pub transform: Vec<f32>,
+
+    pub albedo_texture: Option<Tensor>,
 }
 
 impl From<&EncodedMesh3D> for EncodedMesh3DArrow {
@@ -288,12 +430,38 @@ impl From<&EncodedMesh3D> for EncodedMesh3DArrow {
             format,
             bytes,
             transform,
+            albedo_texture,
         } = v;
         Self {
             mesh_id: *mesh_id,
             format: *format,
             bytes: bytes.clone(),
             transform: transform.iter().flat_map(|c| c.iter().cloned()).collect(),
+            albedo_texture: albedo_texture.clone(),
+        }
+    }
+}
+
+impl EncodedMesh3D {
+    /// Hash everything but the current `mesh_id`.
+    pub fn content_id(&self) -> MeshId {
+        crate::profile_function!();
+        let transform = self.transform.map(|column| column.map(f32::to_bits));
+        MeshId::from_hash(crate::hash::Hash128::hash((
+            self.format,
+            self.bytes.as_slice(),
+            transform,
+            self.albedo_texture
+                .as_ref()
+                .map(|texture| texture.id().0.as_u128()),
+        )))
+    }
+
+    /// Replace the `mesh_id` with [`Self::content_id`].
+    pub fn with_content_id(self) -> Self {
+        Self {
+            mesh_id: self.content_id(),
+            ..self
         }
     }
 }
@@ -307,6 +475,7 @@ impl TryFrom<EncodedMesh3DArrow> for EncodedMesh3D {
             format,
             bytes,
             transform,
+            albedo_texture,
         } = v;
 
         Ok(Self {
@@ -319,10 +488,61 @@ impl TryFrom<EncodedMesh3DArrow> for EncodedMesh3D {
                 transform.as_slice()[6..9].try_into()?,
                 transform.as_slice()[9..12].try_into()?,
             ],
+            albedo_texture,
         })
     }
 }
 
+#[cfg(all(feature = "image", not(target_arch = "wasm32")))]
+impl EncodedMesh3D {
+    /// Loads the albedo texture of an OBJ file, i.e. the first `map_Kd` of the `.mtl` files it
+    /// references.
+    ///
+    /// OBJ files refer to their materials and textures by relative paths, which the viewer can't
+    /// resolve, so this needs to happen before logging.
+    /// Returns `Ok(None)` if none of the materials has a texture.
+    pub fn load_obj_albedo_texture(
+        obj_path: impl AsRef<std::path::Path>,
+    ) -> Result<Option<Tensor>, super::TensorImageError> {
+        let obj_path = obj_path.as_ref();
+        let dir = obj_path.parent().unwrap_or(std::path::Path::new(""));
+
+        let obj = std::fs::read_to_string(obj_path)?;
+        for mtl_name in obj_statements(&obj, "mtllib").flatten() {
+            let mtl_path = dir.join(mtl_name);
+            let mtl = std::fs::read_to_string(&mtl_path)?;
+
+            // `map_Kd` may start with options such as `-s 1 1 1`, the file name comes last.
+            let texture_name = obj_statements(&mtl, "map_Kd").find_map(|args| args.last());
+            if let Some(texture_name) = texture_name {
+                let texture_path = mtl_path.parent().unwrap_or(dir).join(texture_name);
+                let is_jpeg = texture_path.extension().map_or(false, |ext| {
+                    ext.eq_ignore_ascii_case("jpg") || ext.eq_ignore_ascii_case("jpeg")
+                });
+                return if is_jpeg {
+                    Tensor::tensor_from_jpeg_file(texture_path).map(Some)
+                } else {
+                    Tensor::from_image(image::open(texture_path)?).map(Some)
+                };
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+/// The arguments of each `keyword` statement of an OBJ or MTL file, in order.
+#[cfg(all(feature = "image", not(target_arch = "wasm32")))]
+fn obj_statements<'a>(
+    source: &'a str,
+    keyword: &'a str,
+) -> impl Iterator<Item = std::str::SplitWhitespace<'a>> {
+    source.lines().filter_map(move |line| {
+        let mut tokens = line.split_whitespace();
+        (tokens.next() == Some(keyword)).then_some(tokens)
+    })
+}
+
 arrow_enable_vec_for_type!(EncodedMesh3D);
 
 impl ArrowField for EncodedMesh3D {
@@ -418,11 +638,28 @@ impl Component for Mesh3D {
 }
 
 impl Mesh3D {
+    /// The id the viewer caches this mesh by.
+    ///
+    /// Falls back to [`Self::content_id`] for meshes that were logged with [`MeshId::NIL`],
+    /// so that different meshes never end up sharing an id.
     #[inline]
     pub fn mesh_id(&self) -> MeshId {
-        match self {
+        let mesh_id = match self {
             Mesh3D::Encoded(mesh) => mesh.mesh_id,
             Mesh3D::Raw(mesh) => mesh.mesh_id,
+        };
+        if mesh_id == MeshId::NIL {
+            self.content_id()
+        } else {
+            mesh_id
+        }
+    }
+
+    /// Hash everything but the current `mesh_id`.
+    pub fn content_id(&self) -> MeshId {
+        match self {
+            Mesh3D::Encoded(mesh) => mesh.content_id(),
+            Mesh3D::Raw(mesh) => mesh.content_id(),
         }
     }
 }
@@ -430,6 +667,7 @@ impl Mesh3D {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::component_types::{TensorData, TensorDataMeaning, TensorDimension};
 
     fn example_raw_mesh() -> RawMesh3D {
         let mesh = RawMesh3D {
@@ -440,12 +678,141 @@ mod tests {
             vertex_normals: Some(
                 vec![10.0, 20.0, 30.0, 40.0, 50.0, 60.0, 80.0, 90.0, 100.0].into(),
             ),
+            vertex_texcoords: Some(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0].into()),
             albedo_factor: Vec4D([0.5, 0.5, 0.5, 1.0]).into(),
+            albedo_texture: Some(Tensor::from_content(
+                vec![
+                    TensorDimension::height(1),
+                    TensorDimension::width(2),
+                    TensorDimension::depth(3),
+                ],
+                TensorData::U8(vec![255, 0, 0, 0, 255, 0].into()),
+                TensorDataMeaning::Unknown,
+                None,
+            )),
         };
         mesh.sanity_check().unwrap();
         mesh
     }
 
+    #[test]
+    fn test_sanity_check_texcoords() {
+        let mut mesh = example_raw_mesh();
+        mesh.vertex_texcoords = Some(vec![0.0, 0.0, 1.0, 0.0].into());
+        assert!(matches!(
+            mesh.sanity_check(),
+            Err(RawMeshError::MismatchedPositionsTexcoords(9, 4))
+        ));
+
+        let mut mesh = example_raw_mesh();
+        mesh.albedo_texture = Some(Tensor::from_content(
+            vec![TensorDimension::unnamed(6)],
+            TensorData::U8(vec![0; 6].into()),
+            TensorDataMeaning::Unknown,
+            None,
+        ));
+        assert!(matches!(
+            mesh.sanity_check(),
+            Err(RawMeshError::AlbedoTextureNotAnImage(_))
+        ));
+
+        let mut mesh = example_raw_mesh();
+        mesh.albedo_texture = Some(Tensor::from_content(
+            vec![
+                TensorDimension::height(2),
+                TensorDimension::width(2),
+                TensorDimension::depth(4),
+            ],
+            TensorData::U8(vec![0; 6].into()),
+            TensorDataMeaning::Unknown,
+            None,
+        ));
+        assert!(matches!(
+            mesh.sanity_check(),
+            Err(RawMeshError::AlbedoTextureSizeMismatch {
+                expected: 16,
+                actual: 6,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_content_id() {
+        let mesh = example_raw_mesh().with_content_id();
+        assert_eq!(mesh.mesh_id, example_raw_mesh().content_id());
+
+        let mut other = example_raw_mesh();
+        other.albedo_factor = None;
+        assert_ne!(mesh.mesh_id, other.content_id());
+
+        let encoded = EncodedMesh3D {
+            mesh_id: MeshId::NIL,
+            format: MeshFormat::Glb,
+            bytes: vec![5, 9, 13, 95].into(),
+            transform: [[0.0; 3]; 4],
+            albedo_texture: None,
+        };
+        let moved = EncodedMesh3D {
+            transform: [[0.0; 3], [0.0; 3], [0.0; 3], [1.0, 0.0, 0.0]],
+            ..encoded.clone()
+        };
+        assert_ne!(encoded.content_id(), moved.content_id());
+        assert_ne!(encoded.content_id(), MeshId::NIL);
+
+        let textured = EncodedMesh3D {
+            albedo_texture: example_raw_mesh().albedo_texture,
+            ..encoded.clone()
+        };
+        assert_ne!(encoded.content_id(), textured.content_id());
+
+        // Meshes logged without an id must not share one:
+        assert_eq!(
+            Mesh3D::Encoded(encoded.clone()).mesh_id(),
+            encoded.content_id()
+        );
+        assert_ne!(
+            Mesh3D::Encoded(encoded).mesh_id(),
+            Mesh3D::Encoded(moved).mesh_id()
+        );
+        assert_eq!(Mesh3D::Raw(mesh.clone()).mesh_id(), mesh.mesh_id);
+    }
+
+    #[cfg(all(feature = "image", not(target_arch = "wasm32")))]
+    #[test]
+    fn test_load_obj_albedo_texture() {
+        let dir = std::env::temp_dir().join(format!("rerun_obj_{}", MeshId::random().0));
+        std::fs::create_dir_all(dir.join("textures")).unwrap();
+
+        let obj_path = dir.join("cube.obj");
+        std::fs::write(&obj_path, "mtllib missing.mtl\nv 0 0 0\n").unwrap();
+        assert!(EncodedMesh3D::load_obj_albedo_texture(&obj_path).is_err());
+
+        std::fs::write(&obj_path, "# no materials\nv 0 0 0\n").unwrap();
+        assert!(matches!(
+            EncodedMesh3D::load_obj_albedo_texture(&obj_path),
+            Ok(None)
+        ));
+
+        std::fs::write(&obj_path, "mtllib plain.mtl cube.mtl\nv 0 0 0\n").unwrap();
+        std::fs::write(dir.join("plain.mtl"), "newmtl plain\nKd 1 0 0\n").unwrap();
+        std::fs::write(
+            dir.join("cube.mtl"),
+            "newmtl cube\nmap_Kd -s 1 1 1 textures/albedo.png\nnewmtl other\nmap_Kd other.png\n",
+        )
+        .unwrap();
+        image::RgbImage::from_pixel(3, 2, image::Rgb([255, 0, 0]))
+            .save(dir.join("textures/albedo.png"))
+            .unwrap();
+
+        let texture = EncodedMesh3D::load_obj_albedo_texture(&obj_path)
+            .unwrap()
+            .unwrap();
+        assert_eq!(texture.image_height_width_channels(), Some([2, 3, 3]));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
     #[test]
     fn test_mesh_roundtrip() {
         use arrow2::array::Array;
@@ -463,6 +830,7 @@ mod tests {
                     [7.0, 8.0, 9.0],
                     [10.0, 11.0, 12.],
                 ],
+                albedo_texture: example_raw_mesh().albedo_texture,
             })];
             let array: Box<dyn Array> = mesh_in.try_into_arrow().unwrap();
             let mesh_out: Vec<Mesh3D> = TryIntoCollection::try_into_collection(array).unwrap();
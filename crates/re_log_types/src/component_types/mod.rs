@@ -18,11 +18,15 @@ use crate::Component;
 
 mod arrow;
 mod arrow_convert_shims;
+mod axis_metadata;
 mod bbox;
 mod class_id;
 mod color;
 pub mod context;
 pub mod coordinates;
+mod ellipsoid;
+mod geo;
+mod histogram;
 mod instance_key;
 mod keypoint_id;
 mod label;
@@ -30,59 +34,76 @@ mod linestrip;
 mod mat;
 mod mesh3d;
 mod point;
+mod polygon;
 mod quaternion;
 mod radius;
 mod rect;
 mod scalar;
 mod size;
 mod tensor;
+mod text_document;
 mod text_entry;
 mod transform;
 mod vec;
 
 pub use arrow::Arrow3D;
+pub use axis_metadata::AxisMetadata;
 pub use bbox::Box3D;
 pub use class_id::ClassId;
 pub use color::ColorRGBA;
 pub use context::{AnnotationContext, AnnotationInfo, ClassDescription};
 pub use coordinates::ViewCoordinates;
+pub use ellipsoid::{Ellipse2D, Ellipsoid3D};
+pub use geo::{GeoLineStrip, GeoPoint, EARTH_RADIUS_METERS, WEB_MERCATOR_MAX_LATITUDE};
+pub use histogram::{Histogram, HistogramBins};
 pub use instance_key::InstanceKey;
 pub use keypoint_id::KeypointId;
 pub use label::Label;
-pub use linestrip::{LineStrip2D, LineStrip3D};
+pub use linestrip::{LineStrip2D, LineStrip3D, VertexColors, VertexRadii};
 pub use mat::Mat3x3;
 pub use mesh3d::{EncodedMesh3D, Mesh3D, MeshFormat, MeshId, RawMesh3D};
 pub use point::{Point2D, Point3D};
+pub use polygon::{Polygon2D, PolygonDrawMode};
 pub use quaternion::Quaternion;
 pub use radius::Radius;
-pub use rect::Rect2D;
+pub use rect::{OrientedBox2D, Rect2D};
 pub use scalar::{Scalar, ScalarPlotProps};
 pub use size::Size3D;
 #[cfg(feature = "image")]
 pub use tensor::TensorImageError;
 pub use tensor::{
-    Tensor, TensorCastError, TensorData, TensorDataMeaning, TensorDimension, TensorId,
+    EncodedImage, ImageEncoding, Tensor, TensorCastError, TensorData, TensorDataMeaning,
+    TensorDimension, TensorId,
 };
+pub use text_document::TextDocument;
 pub use text_entry::TextEntry;
-pub use transform::{Pinhole, Rigid3, Transform};
+pub use transform::{DistortionModel, LensDistortion, Pinhole, Rigid3, Transform};
 pub use vec::{Vec2D, Vec3D, Vec4D};
 
 lazy_static! {
     //TODO(john): use a run-time type registry
-    static ref FIELDS: [Field; 25] = [
+    static ref FIELDS: [Field; 36] = [
         <AnnotationContext as Component>::field(),
         <Arrow3D as Component>::field(),
+        <AxisMetadata as Component>::field(),
         <Box3D as Component>::field(),
         <ClassId as Component>::field(),
         <ColorRGBA as Component>::field(),
+        <Ellipse2D as Component>::field(),
+        <Ellipsoid3D as Component>::field(),
+        <GeoLineStrip as Component>::field(),
+        <GeoPoint as Component>::field(),
+        <Histogram as Component>::field(),
         <InstanceKey as Component>::field(),
         <KeypointId as Component>::field(),
         <Label as Component>::field(),
         <LineStrip2D as Component>::field(),
         <LineStrip3D as Component>::field(),
         <Mesh3D as Component>::field(),
+        <OrientedBox2D as Component>::field(),
         <Point2D as Component>::field(),
         <Point3D as Component>::field(),
+        <Polygon2D as Component>::field(),
         <Quaternion as Component>::field(),
         <Radius as Component>::field(),
         <Rect2D as Component>::field(),
@@ -90,10 +111,13 @@ lazy_static! {
         <ScalarPlotProps as Component>::field(),
         <Size3D as Component>::field(),
         <Tensor as Component>::field(),
+        <TextDocument as Component>::field(),
         <TextEntry as Component>::field(),
         <Transform as Component>::field(),
         <Vec2D as Component>::field(),
         <Vec3D as Component>::field(),
+        <VertexColors as Component>::field(),
+        <VertexRadii as Component>::field(),
         <ViewCoordinates as Component>::field(),
     ];
 }
@@ -72,6 +72,22 @@ impl From<Point2D> for glam::Vec3 {
     }
 }
 
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Point2<f32>> for Point2D {
+    #[inline]
+    fn from(pt: nalgebra::Point2<f32>) -> Self {
+        Self::new(pt.x, pt.y)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Vector2<f32>> for Point2D {
+    #[inline]
+    fn from(v: nalgebra::Vector2<f32>) -> Self {
+        Self::new(v.x, v.y)
+    }
+}
+
 /// A point in 3D space.
 ///
 /// ```
@@ -141,3 +157,19 @@ impl From<Point3D> for glam::Vec3 {
         Self::new(pt.x, pt.y, pt.z)
     }
 }
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Point3<f32>> for Point3D {
+    #[inline]
+    fn from(pt: nalgebra::Point3<f32>) -> Self {
+        Self::new(pt.x, pt.y, pt.z)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Vector3<f32>> for Point3D {
+    #[inline]
+    fn from(v: nalgebra::Vector3<f32>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
@@ -0,0 +1,421 @@
+use arrow2::{array::MutableListArray, datatypes::DataType};
+use arrow2_convert::{
+    deserialize::ArrowDeserialize, field::ArrowField, serialize::ArrowSerialize, ArrowDeserialize,
+    ArrowField, ArrowSerialize,
+};
+
+use crate::Component;
+
+use super::LineStrip2D;
+
+/// How a [`Polygon2D`] should be drawn.
+///
+/// ```
+/// # use re_log_types::component_types::PolygonDrawMode;
+/// # use arrow2_convert::field::ArrowField;
+/// # use arrow2::datatypes::{DataType, Field, UnionMode};
+/// assert_eq!(
+///     PolygonDrawMode::data_type(),
+///     DataType::Union(vec![
+///         Field::new("Fill", DataType::Boolean, false),
+///         Field::new("Outline", DataType::Boolean, false),
+///         Field::new("FillAndOutline", DataType::Boolean, false),
+///     ], None, UnionMode::Dense),
+/// );
+/// ```
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, ArrowField, ArrowSerialize, ArrowDeserialize,
+)]
+#[arrow_field(type = "dense")]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum PolygonDrawMode {
+    /// Only the area is drawn.
+    #[default]
+    Fill,
+
+    /// Only the outer ring and the holes are drawn, as closed line strips.
+    Outline,
+
+    /// Both the area and its outline are drawn.
+    FillAndOutline,
+}
+
+impl PolygonDrawMode {
+    #[inline]
+    pub fn fill(&self) -> bool {
+        matches!(self, Self::Fill | Self::FillAndOutline)
+    }
+
+    #[inline]
+    pub fn outline(&self) -> bool {
+        matches!(self, Self::Outline | Self::FillAndOutline)
+    }
+}
+
+/// A simple polygon in 2D space, optionally with holes.
+///
+/// Rings may be given in either winding order, and may or may not repeat their first vertex at
+/// the end.
+///
+/// ```
+/// # use re_log_types::component_types::{LineStrip2D, Polygon2D, PolygonDrawMode};
+/// # use arrow2_convert::field::ArrowField;
+/// # use arrow2::datatypes::{DataType, Field};
+/// assert_eq!(
+///     Polygon2D::data_type(),
+///     DataType::Struct(vec![
+///         Field::new("outer", LineStrip2D::data_type(), false),
+///         Field::new(
+///             "holes",
+///             DataType::List(Box::new(Field::new("item", LineStrip2D::data_type(), false))),
+///             false
+///         ),
+///         Field::new("mode", PolygonDrawMode::data_type(), false),
+///     ])
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Polygon2D {
+    /// The outer boundary of the polygon.
+    pub outer: LineStrip2D,
+
+    /// Areas cut out of the polygon, each fully contained in [`Self::outer`].
+    #[arrow_field(type = "PolygonHoles")]
+    pub holes: Vec<LineStrip2D>,
+
+    pub mode: PolygonDrawMode,
+}
+
+impl Polygon2D {
+    /// A filled polygon without any holes.
+    #[inline]
+    pub fn new(outer: impl Into<LineStrip2D>) -> Self {
+        Self {
+            outer: outer.into(),
+            holes: Vec::new(),
+            mode: PolygonDrawMode::default(),
+        }
+    }
+
+    #[inline]
+    pub fn with_hole(mut self, hole: impl Into<LineStrip2D>) -> Self {
+        self.holes.push(hole.into());
+        self
+    }
+
+    #[inline]
+    pub fn with_mode(mut self, mode: PolygonDrawMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Splits the area of the polygon into triangles, using ear clipping.
+    ///
+    /// Returns the vertex positions along with a flat list of triangle indices into them.
+    /// Holes are connected to the outer ring with zero-width bridges before clipping.
+    ///
+    /// Self-intersecting rings don't have a well defined area: they still produce triangles, but
+    /// these may overlap or cover parts that were meant to be outside.
+    pub fn triangulate(&self) -> (Vec<[f32; 2]>, Vec<u32>) {
+        crate::profile_function!();
+
+        let mut positions = Vec::new();
+
+        let Some(mut outline) = append_ring(&mut positions, &self.outer, true) else {
+            return (Vec::new(), Vec::new());
+        };
+
+        let mut holes = self
+            .holes
+            .iter()
+            .filter_map(|hole| append_ring(&mut positions, hole, false))
+            .collect::<Vec<_>>();
+
+        // Bridge the holes from right to left, so that bridges never need to cross each other.
+        let max_x = |ring: &Vec<u32>| {
+            ring.iter()
+                .map(|&i| positions[i as usize][0])
+                .fold(f32::NEG_INFINITY, f32::max)
+        };
+        holes.sort_by(|a, b| max_x(b).total_cmp(&max_x(a)));
+
+        for hole_idx in 0..holes.len() {
+            let hole = &holes[hole_idx];
+            let remaining_holes = &holes[hole_idx..];
+            bridge_hole(&positions, &mut outline, hole, remaining_holes);
+        }
+
+        let indices = clip_ears(&positions, outline);
+
+        (positions, indices)
+    }
+}
+
+impl From<Vec<[f32; 2]>> for Polygon2D {
+    #[inline]
+    fn from(outer: Vec<[f32; 2]>) -> Self {
+        Self::new(outer)
+    }
+}
+
+impl Component for Polygon2D {
+    #[inline]
+    fn name() -> crate::ComponentName {
+        "rerun.polygon2d".into()
+    }
+}
+
+/// Arrow (de)serialization of [`Polygon2D::holes`].
+///
+/// Arrow2-convert barfs on `<Vec<LineStrip2D> as ArrowSerialize>::MutableArrayType`
+/// (see [`LineStrip2D`]), so do this one from scratch.
+pub struct PolygonHoles;
+
+impl ArrowField for PolygonHoles {
+    type Type = Vec<LineStrip2D>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        <Vec<LineStrip2D> as ArrowField>::data_type()
+    }
+}
+
+impl ArrowSerialize for PolygonHoles {
+    type MutableArrayType =
+        MutableListArray<i32, <LineStrip2D as ArrowSerialize>::MutableArrayType>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::new_from(LineStrip2D::new_array(), Self::data_type(), 0)
+    }
+
+    #[inline]
+    fn arrow_serialize(
+        v: &<Self as ArrowField>::Type,
+        array: &mut Self::MutableArrayType,
+    ) -> arrow2::error::Result<()> {
+        for hole in v {
+            LineStrip2D::arrow_serialize(hole, array.mut_values())?;
+        }
+        array.try_push_valid()
+    }
+}
+
+impl ArrowDeserialize for PolygonHoles {
+    type ArrayType = <Vec<LineStrip2D> as ArrowDeserialize>::ArrayType;
+
+    #[inline]
+    fn arrow_deserialize(
+        v: <&Self::ArrayType as IntoIterator>::Item,
+    ) -> Option<<Self as ArrowField>::Type> {
+        <Vec<LineStrip2D> as ArrowDeserialize>::arrow_deserialize(v)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Triangulation
+
+/// Appends a cleaned up ring to `positions` and returns its indices, wound counter-clockwise for
+/// outer rings and clockwise for holes.
+///
+/// Returns `None` for rings that can't enclose any area.
+fn append_ring(positions: &mut Vec<[f32; 2]>, strip: &LineStrip2D, ccw: bool) -> Option<Vec<u32>> {
+    let mut ring = strip.0.iter().map(|v| v.0).collect::<Vec<_>>();
+    ring.dedup();
+    if ring.len() > 1 && ring.first() == ring.last() {
+        ring.pop();
+    }
+    if ring.len() < 3 {
+        return None;
+    }
+
+    let area = ring
+        .iter()
+        .zip(ring.iter().cycle().skip(1))
+        .map(|(a, b)| a[0] * b[1] - b[0] * a[1])
+        .sum::<f32>();
+    if area == 0.0 {
+        return None;
+    }
+    if (area > 0.0) != ccw {
+        ring.reverse();
+    }
+
+    let first = positions.len() as u32;
+    positions.extend(ring);
+    Some((first..positions.len() as u32).collect())
+}
+
+/// Positive if `o -> a -> b` turns counter-clockwise.
+#[inline]
+fn cross(o: [f32; 2], a: [f32; 2], b: [f32; 2]) -> f32 {
+    (a[0] - o[0]) * (b[1] - o[1]) - (a[1] - o[1]) * (b[0] - o[0])
+}
+
+/// Do the two segments cross each other in a single point that isn't one of their end points?
+fn segments_cross(p0: [f32; 2], p1: [f32; 2], q0: [f32; 2], q1: [f32; 2]) -> bool {
+    let d0 = cross(q0, q1, p0);
+    let d1 = cross(q0, q1, p1);
+    let d2 = cross(p0, p1, q0);
+    let d3 = cross(p0, p1, q1);
+    d0 * d1 < 0.0 && d2 * d3 < 0.0
+}
+
+/// Connects `hole` to `outline` through the right-most vertex of the hole, splicing it in.
+fn bridge_hole(positions: &[[f32; 2]], outline: &mut Vec<u32>, hole: &[u32], holes: &[Vec<u32>]) {
+    let pos = |i: u32| positions[i as usize];
+
+    let hole_start = (0..hole.len())
+        .max_by(|&a, &b| pos(hole[a])[0].total_cmp(&pos(hole[b])[0]))
+        .unwrap_or(0);
+    let m = pos(hole[hole_start]);
+
+    let rings = std::iter::once(outline.as_slice()).chain(holes.iter().map(Vec::as_slice));
+    let edges = rings
+        .flat_map(|ring| {
+            ring.iter()
+                .zip(ring.iter().cycle().skip(1))
+                .map(|(&a, &b)| (pos(a), pos(b)))
+        })
+        .collect::<Vec<_>>();
+
+    let distance_sq = |i: u32| {
+        let p = pos(i);
+        (p[0] - m[0]).powi(2) + (p[1] - m[1]).powi(2)
+    };
+    let mut candidates = (0..outline.len()).collect::<Vec<_>>();
+    candidates.sort_by(|&a, &b| distance_sq(outline[a]).total_cmp(&distance_sq(outline[b])));
+
+    let is_visible = |candidate: usize| {
+        let p = pos(outline[candidate]);
+        !edges.iter().any(|&(a, b)| segments_cross(m, p, a, b))
+    };
+    // If nothing is visible the input is self-intersecting, so anything goes.
+    let Some(&bridge) = candidates.iter().find(|&&c| is_visible(c)).or(candidates.first()) else {
+        return;
+    };
+
+    let spliced = hole[hole_start..]
+        .iter()
+        .chain(&hole[..=hole_start])
+        .copied()
+        .chain(std::iter::once(outline[bridge]))
+        .collect::<Vec<_>>();
+    outline.splice(bridge + 1..bridge + 1, spliced);
+}
+
+/// Triangulates a counter-clockwise ring.
+fn clip_ears(positions: &[[f32; 2]], mut ring: Vec<u32>) -> Vec<u32> {
+    let pos = |i: u32| positions[i as usize];
+
+    let mut indices = Vec::with_capacity(ring.len().saturating_sub(2) * 3);
+
+    let mut i = 0;
+    let mut attempts = 0;
+    while ring.len() > 3 {
+        let n = ring.len();
+        let (ia, ib, ic) = (ring[(i + n - 1) % n], ring[i], ring[(i + 1) % n]);
+        let (a, b, c) = (pos(ia), pos(ib), pos(ic));
+
+        let is_ear = cross(a, b, c) > 0.0
+            && !ring.iter().any(|&ip| {
+                let p = pos(ip);
+                p != a
+                    && p != b
+                    && p != c
+                    && cross(a, b, p) >= 0.0
+                    && cross(b, c, p) >= 0.0
+                    && cross(c, a, p) >= 0.0
+            });
+
+        // After a full lap without finding an ear, the ring is degenerate (collinear or
+        // self-intersecting): clip anyway so that we always terminate.
+        if is_ear || attempts >= n {
+            indices.extend([ia, ib, ic]);
+            ring.remove(i);
+            attempts = 0;
+            if i >= ring.len() {
+                i = 0;
+            }
+        } else {
+            i = (i + 1) % n;
+            attempts += 1;
+        }
+    }
+    indices.extend(ring);
+
+    indices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn area(positions: &[[f32; 2]], indices: &[u32]) -> f32 {
+        indices
+            .chunks_exact(3)
+            .map(|t| {
+                let [a, b, c] = [t[0], t[1], t[2]].map(|i| positions[i as usize]);
+                cross(a, b, c).abs() * 0.5
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_triangulate_concave() {
+        // An L-shape, clockwise and explicitly closed.
+        let polygon = Polygon2D::new(vec![
+            [0.0, 0.0],
+            [0.0, 2.0],
+            [1.0, 2.0],
+            [1.0, 1.0],
+            [2.0, 1.0],
+            [2.0, 0.0],
+            [0.0, 0.0],
+        ]);
+        let (positions, indices) = polygon.triangulate();
+        assert_eq!(positions.len(), 6);
+        assert_eq!(indices.len(), 4 * 3);
+        assert_eq!(area(&positions, &indices), 3.0);
+    }
+
+    #[test]
+    fn test_triangulate_holes() {
+        let polygon = Polygon2D::new(vec![[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]])
+            .with_hole(vec![[1.0, 1.0], [2.0, 1.0], [2.0, 2.0], [1.0, 2.0]])
+            .with_hole(vec![[2.5, 2.5], [3.5, 2.5], [3.5, 3.5], [2.5, 3.5]]);
+        let (positions, indices) = polygon.triangulate();
+        assert_eq!(positions.len(), 12);
+        assert_eq!(area(&positions, &indices), 14.0);
+    }
+
+    #[test]
+    fn test_triangulate_degenerate() {
+        let (positions, indices) = Polygon2D::new(vec![[0.0, 0.0], [1.0, 1.0]]).triangulate();
+        assert!(positions.is_empty() && indices.is_empty());
+
+        let (_, indices) = Polygon2D::new(vec![[0.0, 0.0], [1.0, 0.0], [2.0, 0.0]]).triangulate();
+        assert!(indices.is_empty());
+    }
+
+    #[test]
+    fn test_polygon_roundtrip() {
+        use arrow2::array::Array;
+        use arrow2_convert::{deserialize::TryIntoCollection, serialize::TryIntoArrow};
+
+        let polygons_in = vec![
+            Polygon2D::new(vec![[0.0, 0.0], [4.0, 0.0], [4.0, 4.0]])
+                .with_hole(vec![[2.0, 1.0], [3.0, 1.0], [3.0, 2.0]])
+                .with_mode(PolygonDrawMode::FillAndOutline),
+            Polygon2D::new(vec![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]])
+                .with_mode(PolygonDrawMode::Outline),
+            Polygon2D::new(vec![[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]])
+                .with_hole(vec![[1.0, 1.0], [2.0, 1.0], [2.0, 2.0], [1.0, 2.0]])
+                .with_hole(vec![[2.5, 2.5], [3.5, 2.5], [3.5, 3.5]]),
+        ];
+        let array: Box<dyn Array> = polygons_in.try_into_arrow().unwrap();
+        let polygons_out: Vec<Polygon2D> = TryIntoCollection::try_into_collection(array).unwrap();
+        assert_eq!(polygons_in, polygons_out);
+    }
+}
@@ -12,7 +12,8 @@ use crate::Component;
 /// # use arrow2::datatypes::{DataType, Field};
 /// assert_eq!(Radius::data_type(), DataType::Float32);
 /// ```
-#[derive(Debug, Clone, Copy, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[arrow_field(transparent)]
 pub struct Radius(pub f32);
 
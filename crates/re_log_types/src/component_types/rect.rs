@@ -2,7 +2,7 @@ use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
 
 use crate::Component;
 
-use super::Vec4D;
+use super::{Vec2D, Vec4D};
 
 /// A rectangle in 2D space.
 ///
@@ -91,3 +91,124 @@ impl Component for Rect2D {
         "rerun.rect2d".into()
     }
 }
+
+// ----------------------------------------------------------------------------
+
+/// A rectangle in 2D space that may be rotated around its center.
+///
+/// ## Example
+/// ```
+/// # use re_log_types::component_types::{OrientedBox2D, Vec2D};
+/// # use arrow2_convert::field::ArrowField;
+/// # use arrow2::datatypes::{DataType, Field};
+/// assert_eq!(
+///     OrientedBox2D::data_type(),
+///     DataType::Struct(vec![
+///         Field::new("center", Vec2D::data_type(), false),
+///         Field::new("half_size", Vec2D::data_type(), false),
+///         Field::new("rotation", DataType::Float32, false),
+///     ])
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct OrientedBox2D {
+    pub center: Vec2D,
+
+    /// Half the width and height of the box, before rotation.
+    pub half_size: Vec2D,
+
+    /// Rotation around [`Self::center`] in radians, turning the x-axis towards the y-axis.
+    ///
+    /// In image space (y pointing down) a positive angle therefore appears clockwise.
+    pub rotation: f32,
+}
+
+impl OrientedBox2D {
+    /// \[x_center, y_center, width, height\] rotated by `rotation` radians.
+    #[inline]
+    pub fn from_xcycwh_rotation(x: f32, y: f32, w: f32, h: f32, rotation: f32) -> Self {
+        Self {
+            center: Vec2D([x, y]),
+            half_size: Vec2D([w / 2.0, h / 2.0]),
+            rotation,
+        }
+    }
+
+    /// Rotates an axis-aligned rectangle around its center.
+    pub fn from_rect(rect: &Rect2D, rotation: f32) -> Self {
+        let [x, y] = rect.top_left_corner();
+        let (w, h) = (rect.width(), rect.height());
+        Self::from_xcycwh_rotation(x + w / 2.0, y + h / 2.0, w, h, rotation)
+    }
+
+    #[inline]
+    pub fn width(&self) -> f32 {
+        2.0 * self.half_size.x()
+    }
+
+    #[inline]
+    pub fn height(&self) -> f32 {
+        2.0 * self.half_size.y()
+    }
+
+    /// The rotated width and height edges of the box, as vectors.
+    pub fn extents(&self) -> ([f32; 2], [f32; 2]) {
+        let (sin, cos) = self.rotation.sin_cos();
+        (
+            [cos * self.width(), sin * self.width()],
+            [-sin * self.height(), cos * self.height()],
+        )
+    }
+
+    /// The corner that is top-left before rotation.
+    pub fn first_corner(&self) -> [f32; 2] {
+        let (u, v) = self.extents();
+        [
+            self.center.x() - 0.5 * (u[0] + v[0]),
+            self.center.y() - 0.5 * (u[1] + v[1]),
+        ]
+    }
+
+    /// All four corners, in the order top-left, top-right, bottom-right, bottom-left before
+    /// rotation.
+    pub fn corners(&self) -> [[f32; 2]; 4] {
+        let [x, y] = self.first_corner();
+        let (u, v) = self.extents();
+        [
+            [x, y],
+            [x + u[0], y + u[1]],
+            [x + u[0] + v[0], y + u[1] + v[1]],
+            [x + v[0], y + v[1]],
+        ]
+    }
+}
+
+impl Component for OrientedBox2D {
+    #[inline]
+    fn name() -> crate::ComponentName {
+        "rerun.oriented_box2d".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oriented_box_corners() {
+        let rect = Rect2D::from_xywh(1.0, 2.0, 4.0, 2.0);
+
+        let unrotated = OrientedBox2D::from_rect(&rect, 0.0);
+        assert_eq!(unrotated.center, Vec2D([3.0, 3.0]));
+        assert_eq!(unrotated.first_corner(), rect.top_left_corner());
+
+        let quarter_turn = OrientedBox2D::from_rect(&rect, std::f32::consts::FRAC_PI_2);
+        let corners = quarter_turn.corners();
+        let expected = [[4.0, 1.0], [4.0, 5.0], [2.0, 5.0], [2.0, 1.0]];
+        for (corner, expected) in corners.iter().zip(expected) {
+            assert!((corner[0] - expected[0]).abs() < 1e-5, "{corners:?}");
+            assert!((corner[1] - expected[1]).abs() < 1e-5, "{corners:?}");
+        }
+    }
+}
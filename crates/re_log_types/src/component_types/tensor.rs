@@ -1,5 +1,9 @@
-use arrow2::array::{FixedSizeBinaryArray, MutableFixedSizeBinaryArray};
+use arrow2::array::{
+    FixedSizeBinaryArray, MutableFixedSizeBinaryArray, MutablePrimitiveArray, PrimitiveArray,
+    TryPush,
+};
 use arrow2::buffer::Buffer;
+use arrow2::datatypes::DataType;
 use arrow2_convert::deserialize::ArrowDeserialize;
 use arrow2_convert::field::ArrowField;
 use arrow2_convert::{serialize::ArrowSerialize, ArrowDeserialize, ArrowField, ArrowSerialize};
@@ -7,13 +11,15 @@ use arrow2_convert::{serialize::ArrowSerialize, ArrowDeserialize, ArrowField, Ar
 use crate::Component;
 use crate::{TensorDataType, TensorElement};
 
-use super::arrow_convert_shims::BinaryBuffer;
+use super::arrow_convert_shims::{BinaryBuffer, F16Buffer};
 
 // ----------------------------------------------------------------------------
 
 /// A unique id per [`Tensor`].
 ///
-/// TODO(emilk): this should be a hash of the tensor (CAS).
+/// Tensors created by this crate get an id derived from their contents
+/// (see [`TensorId::from_content`]), so identical tensors share an id and
+/// the viewer only needs to store, decode and upload them once.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct TensorId(pub uuid::Uuid);
@@ -34,6 +40,25 @@ impl TensorId {
     pub fn random() -> Self {
         Self(uuid::Uuid::new_v4())
     }
+
+    /// Hash the shape and payload of a tensor.
+    ///
+    /// The [`TensorDataMeaning`] and meter are deliberately left out, since they only affect how
+    /// the payload is interpreted.
+    pub fn from_content(shape: &[TensorDimension], data: &TensorData) -> Self {
+        let (kind, bytes) = data.raw_content();
+        Self::from_raw_content(shape, kind, bytes)
+    }
+
+    /// Hash the shape, the name of the [`TensorData`] variant and the raw bytes of a tensor.
+    ///
+    /// This is what [`Self::from_content`] uses. The Python SDK calls this too,
+    /// so that the same tensor gets the same id no matter which SDK logged it.
+    pub fn from_raw_content(shape: &[TensorDimension], kind: &str, bytes: &[u8]) -> Self {
+        crate::profile_function!();
+        let hash = crate::hash::Hash128::hash((shape, kind, bytes));
+        Self(uuid::Uuid::from_u128(hash.as_u128()))
+    }
 }
 
 impl ArrowField for TensorId {
@@ -77,6 +102,132 @@ impl ArrowDeserialize for TensorId {
 
 // ----------------------------------------------------------------------------
 
+/// The file format of an [`EncodedImage`].
+///
+/// Stored as a `u8`, since `arrow2_convert` can't nest a dense union inside of [`TensorData`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ImageEncoding {
+    Jpeg = 0,
+    Png = 1,
+    Bmp = 2,
+    Tiff = 3,
+}
+
+impl ImageEncoding {
+    #[inline]
+    fn from_u8(v: u8) -> Option<Self> {
+        match v {
+            0 => Some(Self::Jpeg),
+            1 => Some(Self::Png),
+            2 => Some(Self::Bmp),
+            3 => Some(Self::Tiff),
+            _ => None,
+        }
+    }
+
+    /// The conventional file extension for this format.
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            Self::Jpeg => "jpg",
+            Self::Png => "png",
+            Self::Bmp => "bmp",
+            Self::Tiff => "tiff",
+        }
+    }
+}
+
+impl ArrowField for ImageEncoding {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::UInt8
+    }
+}
+
+impl ArrowSerialize for ImageEncoding {
+    type MutableArrayType = MutablePrimitiveArray<u8>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        MutablePrimitiveArray::<u8>::from(<Self as ArrowField>::data_type())
+    }
+
+    #[inline]
+    fn arrow_serialize(
+        v: &<Self as ArrowField>::Type,
+        array: &mut Self::MutableArrayType,
+    ) -> arrow2::error::Result<()> {
+        array.try_push(Some(*v as u8))
+    }
+}
+
+impl ArrowDeserialize for ImageEncoding {
+    type ArrayType = PrimitiveArray<u8>;
+
+    #[inline]
+    fn arrow_deserialize(
+        v: <&Self::ArrayType as IntoIterator>::Item,
+    ) -> Option<<Self as ArrowField>::Type> {
+        v.and_then(|v| Self::from_u8(*v))
+    }
+}
+
+impl std::fmt::Display for ImageEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Jpeg => "JPEG".fmt(f),
+            Self::Png => "PNG".fmt(f),
+            Self::Bmp => "BMP".fmt(f),
+            Self::Tiff => "TIFF".fmt(f),
+        }
+    }
+}
+
+/// The contents of an image file, decoded lazily by the viewer.
+///
+/// ## Examples
+///
+/// ```
+/// # use re_log_types::component_types::{EncodedImage, ImageEncoding};
+/// # use arrow2_convert::field::ArrowField;
+/// # use arrow2::datatypes::{DataType, Field};
+/// assert_eq!(
+///     EncodedImage::data_type(),
+///     DataType::Struct(vec![
+///         Field::new("format", DataType::UInt8, false),
+///         Field::new("bytes", DataType::Binary, false),
+///     ])
+/// );
+/// ```
+#[derive(Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+pub struct EncodedImage {
+    pub format: ImageEncoding,
+    pub bytes: BinaryBuffer,
+}
+
+impl EncodedImage {
+    /// Best guess at the element type of the decoded image, without decoding it.
+    pub fn dtype(&self) -> TensorDataType {
+        match self.format {
+            ImageEncoding::Png => png_dtype(self.bytes.as_slice()),
+            ImageEncoding::Jpeg | ImageEncoding::Bmp | ImageEncoding::Tiff => TensorDataType::U8,
+        }
+    }
+}
+
+/// Peeks at the bit depth in the `IHDR` chunk of a PNG file.
+///
+/// The `IHDR` chunk is required to come first, so the bit depth is always found at byte 24.
+fn png_dtype(bytes: &[u8]) -> TensorDataType {
+    const BIT_DEPTH_OFFSET: usize = 8 + 4 + 4 + 4 + 4;
+    match bytes.get(BIT_DEPTH_OFFSET) {
+        Some(16) => TensorDataType::U16,
+        _ => TensorDataType::U8,
+    }
+}
+
 /// Flattened `Tensor` data payload
 ///
 /// ## Examples
@@ -126,6 +277,11 @@ impl ArrowDeserialize for TensorId {
 ///                 false
 ///             ),
 ///             Field::new(
+///                 "F16",
+///                 DataType::List(Box::new(Field::new("item", DataType::Float16, false))),
+///                 false
+///             ),
+///             Field::new(
 ///                 "F32",
 ///                 DataType::List(Box::new(Field::new("item", DataType::Float32, false))),
 ///                 false
@@ -136,6 +292,30 @@ impl ArrowDeserialize for TensorId {
 ///                 false
 ///             ),
 ///             Field::new("JPEG", DataType::Binary, false),
+///             Field::new("PNG", DataType::Binary, false),
+///             Field::new(
+///                 "Encoded",
+///                 DataType::Struct(vec![
+///                     Field::new(
+///                         "format",
+///                         DataType::Union(
+///                             vec![
+///                                 Field::new("Jpeg", DataType::Boolean, false),
+///                                 Field::new("Png", DataType::Boolean, false),
+///                                 Field::new("Bmp", DataType::Boolean, false),
+///                                 Field::new("Tiff", DataType::Boolean, false),
+///                             ],
+///                             None,
+///                             UnionMode::Dense
+///                         ),
+///                         false
+///                     ),
+///                     Field::new("bytes", DataType::Binary, false),
+///                 ]),
+///                 false
+///             ),
+///             Field::new("NV12", DataType::Binary, false),
+///             Field::new("YUY2", DataType::Binary, false),
 ///         ],
 ///         None,
 ///         UnionMode::Dense
@@ -155,17 +335,32 @@ pub enum TensorData {
     I32(Buffer<i32>),
     I64(Buffer<i64>),
     // ---
-    // TODO(#854): Native F16 support for arrow tensors
-    //F16(Vec<arrow2::types::f16>),
+    F16(F16Buffer),
     F32(Buffer<f32>),
     F64(Buffer<f64>),
     JPEG(BinaryBuffer),
+    PNG(BinaryBuffer),
+    Encoded(EncodedImage),
+    // ---
+    /// Chroma-subsampled YUV 4:2:0 with 8 bits per sample.
+    ///
+    /// A full-resolution Y plane is followed by a half-resolution plane of interleaved U and V
+    /// samples. The shape of the tensor is that of the RGB image, i.e. `[height, width, 3]`.
+    NV12(BinaryBuffer),
+
+    /// Chroma-subsampled YUV 4:2:2 with 8 bits per sample, also known as YUYV.
+    ///
+    /// Each pair of pixels is stored as `Y0 U Y1 V`.
+    /// The shape of the tensor is that of the RGB image, i.e. `[height, width, 3]`.
+    YUY2(BinaryBuffer),
 }
 
 impl TensorData {
     pub fn dtype(&self) -> TensorDataType {
         match self {
-            Self::U8(_) | Self::JPEG(_) => TensorDataType::U8,
+            Self::U8(_) | Self::JPEG(_) | Self::NV12(_) | Self::YUY2(_) => TensorDataType::U8,
+            Self::PNG(buf) => png_dtype(buf.as_slice()),
+            Self::Encoded(encoded) => encoded.dtype(),
             Self::U16(_) => TensorDataType::U16,
             Self::U32(_) => TensorDataType::U32,
             Self::U64(_) => TensorDataType::U64,
@@ -173,6 +368,7 @@ impl TensorData {
             Self::I16(_) => TensorDataType::I16,
             Self::I32(_) => TensorDataType::I32,
             Self::I64(_) => TensorDataType::I64,
+            Self::F16(_) => TensorDataType::F16,
             Self::F32(_) => TensorDataType::F32,
             Self::F64(_) => TensorDataType::F64,
         }
@@ -180,7 +376,12 @@ impl TensorData {
 
     pub fn size_in_bytes(&self) -> usize {
         match self {
-            Self::U8(buf) | Self::JPEG(buf) => buf.0.len(),
+            Self::U8(buf)
+            | Self::JPEG(buf)
+            | Self::PNG(buf)
+            | Self::NV12(buf)
+            | Self::YUY2(buf) => buf.0.len(),
+            Self::Encoded(encoded) => encoded.bytes.0.len(),
             Self::U16(buf) => buf.len(),
             Self::U32(buf) => buf.len(),
             Self::U64(buf) => buf.len(),
@@ -188,6 +389,7 @@ impl TensorData {
             Self::I16(buf) => buf.len(),
             Self::I32(buf) => buf.len(),
             Self::I64(buf) => buf.len(),
+            Self::F16(buf) => buf.len(),
             Self::F32(buf) => buf.len(),
             Self::F64(buf) => buf.len(),
         }
@@ -196,6 +398,61 @@ impl TensorData {
     pub fn is_empty(&self) -> bool {
         self.size_in_bytes() == 0
     }
+
+    /// Is this a compressed image that needs to be decoded before it can be used?
+    pub fn is_encoded(&self) -> bool {
+        matches!(self, Self::JPEG(_) | Self::PNG(_) | Self::Encoded(_))
+    }
+
+    /// Is this a chroma-subsampled YUV image that needs to be converted to RGB before display?
+    pub fn is_chroma_subsampled(&self) -> bool {
+        matches!(self, Self::NV12(_) | Self::YUY2(_))
+    }
+
+    /// The name of the variant (or image encoding) together with the raw bytes of the payload.
+    ///
+    /// Used to derive [`TensorId`]s, see [`TensorId::from_raw_content`].
+    fn raw_content(&self) -> (&'static str, &[u8]) {
+        use bytemuck::cast_slice;
+
+        match self {
+            Self::U8(buf) => ("U8", buf.as_slice()),
+            Self::U16(buf) => ("U16", cast_slice(buf.as_slice())),
+            Self::U32(buf) => ("U32", cast_slice(buf.as_slice())),
+            Self::U64(buf) => ("U64", cast_slice(buf.as_slice())),
+            Self::I8(buf) => ("I8", cast_slice(buf.as_slice())),
+            Self::I16(buf) => ("I16", cast_slice(buf.as_slice())),
+            Self::I32(buf) => ("I32", cast_slice(buf.as_slice())),
+            Self::I64(buf) => ("I64", cast_slice(buf.as_slice())),
+            Self::F16(buf) => ("F16", cast_slice(buf.as_slice())),
+            Self::F32(buf) => ("F32", cast_slice(buf.as_slice())),
+            Self::F64(buf) => ("F64", cast_slice(buf.as_slice())),
+            Self::NV12(buf) => ("NV12", buf.as_slice()),
+            Self::YUY2(buf) => ("YUY2", buf.as_slice()),
+            // The same file has the same id, no matter if it's logged as `JPEG` or `Encoded`.
+            Self::JPEG(buf) => ("JPEG", buf.as_slice()),
+            Self::PNG(buf) => ("PNG", buf.as_slice()),
+            Self::Encoded(encoded) => {
+                let kind = match encoded.format {
+                    ImageEncoding::Jpeg => "JPEG",
+                    ImageEncoding::Png => "PNG",
+                    ImageEncoding::Bmp => "BMP",
+                    ImageEncoding::Tiff => "TIFF",
+                };
+                (kind, encoded.bytes.as_slice())
+            }
+        }
+    }
+
+    /// The encoding and the encoded bytes, if this is a compressed image.
+    pub fn encoded_image(&self) -> Option<(ImageEncoding, &[u8])> {
+        match self {
+            Self::JPEG(buf) => Some((ImageEncoding::Jpeg, buf.as_slice())),
+            Self::PNG(buf) => Some((ImageEncoding::Png, buf.as_slice())),
+            Self::Encoded(encoded) => Some((encoded.format, encoded.bytes.as_slice())),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Debug for TensorData {
@@ -209,9 +466,16 @@ impl std::fmt::Debug for TensorData {
             Self::I16(_) => write!(f, "I16({} bytes)", self.size_in_bytes()),
             Self::I32(_) => write!(f, "I32({} bytes)", self.size_in_bytes()),
             Self::I64(_) => write!(f, "I64({} bytes)", self.size_in_bytes()),
+            Self::F16(_) => write!(f, "F16({} bytes)", self.size_in_bytes()),
             Self::F32(_) => write!(f, "F32({} bytes)", self.size_in_bytes()),
             Self::F64(_) => write!(f, "F64({} bytes)", self.size_in_bytes()),
             Self::JPEG(_) => write!(f, "JPEG({} bytes)", self.size_in_bytes()),
+            Self::PNG(_) => write!(f, "PNG({} bytes)", self.size_in_bytes()),
+            Self::Encoded(encoded) => {
+                write!(f, "{:?}({} bytes)", encoded.format, self.size_in_bytes())
+            }
+            Self::NV12(_) => write!(f, "NV12({} bytes)", self.size_in_bytes()),
+            Self::YUY2(_) => write!(f, "YUY2({} bytes)", self.size_in_bytes()),
         }
     }
 }
@@ -232,7 +496,7 @@ impl std::fmt::Debug for TensorData {
 ///     ])
 /// );
 /// ```
-#[derive(Clone, PartialEq, Eq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ArrowField, ArrowSerialize, ArrowDeserialize)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct TensorDimension {
     /// Number of elements on this dimension.
@@ -298,7 +562,7 @@ impl std::fmt::Display for TensorDimension {
 
 /// How to interpret the contents of a tensor.
 // TODO(jleibs) This should be extended to include things like rgb vs bgr
-#[derive(Clone, Copy, Debug, PartialEq, Eq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ArrowField, ArrowSerialize, ArrowDeserialize)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[arrow_field(type = "dense")]
 pub enum TensorDataMeaning {
@@ -446,9 +710,17 @@ impl Tensor {
             TensorData::I16(buf) => Some(TensorElement::I16(buf[offset])),
             TensorData::I32(buf) => Some(TensorElement::I32(buf[offset])),
             TensorData::I64(buf) => Some(TensorElement::I64(buf[offset])),
+            TensorData::F16(buf) => Some(TensorElement::F16(buf[offset])),
             TensorData::F32(buf) => Some(TensorElement::F32(buf[offset])),
             TensorData::F64(buf) => Some(TensorElement::F64(buf[offset])),
-            TensorData::JPEG(_) => None, // Too expensive to unpack here.
+            TensorData::JPEG(_) | TensorData::PNG(_) | TensorData::Encoded(_) => None, // Too expensive to unpack here.
+            TensorData::NV12(_) | TensorData::YUY2(_) => match index {
+                [y, x, channel] => self
+                    .yuv_pixel_as_rgb(*y, *x)
+                    .and_then(|rgb| rgb.get(*channel as usize).copied())
+                    .map(TensorElement::U8),
+                _ => None,
+            },
         }
     }
 
@@ -459,6 +731,88 @@ impl Tensor {
     pub fn size_in_bytes(&self) -> usize {
         self.data.size_in_bytes()
     }
+
+    /// The RGB value of the pixel at row `y` and column `x` of a chroma-subsampled image.
+    ///
+    /// Returns `None` for other tensors, or if the pixel is out of bounds.
+    pub fn yuv_pixel_as_rgb(&self, y: u64, x: u64) -> Option<[u8; 3]> {
+        let [height, width, 3] = self.image_height_width_channels()? else {
+            return None;
+        };
+        if height <= y || width <= x {
+            return None;
+        }
+        yuv_pixel_as_rgb(
+            &self.data,
+            [height as usize, width as usize],
+            [y as usize, x as usize],
+        )
+    }
+
+    /// Convert a chroma-subsampled image to tightly packed 8-bit RGB.
+    ///
+    /// Returns `None` for other tensors, or if the buffer is too small for the shape.
+    pub fn yuv_to_rgb8(&self) -> Option<Vec<u8>> {
+        crate::profile_function!();
+        let [height, width, 3] = self.image_height_width_channels()? else {
+            return None;
+        };
+        let (height, width) = (height as usize, width as usize);
+        let mut rgb = Vec::with_capacity(height * width * 3);
+        for y in 0..height {
+            for x in 0..width {
+                rgb.extend_from_slice(&yuv_pixel_as_rgb(&self.data, [height, width], [y, x])?);
+            }
+        }
+        Some(rgb)
+    }
+}
+
+fn yuv_pixel_as_rgb(
+    data: &TensorData,
+    [height, width]: [usize; 2],
+    [y, x]: [usize; 2],
+) -> Option<[u8; 3]> {
+    let (luma, u, v) = match data {
+        TensorData::NV12(buf) => {
+            let buf = buf.as_slice();
+            let chroma_stride = (width + 1) / 2 * 2;
+            let chroma = width * height + y / 2 * chroma_stride + x / 2 * 2;
+            (
+                buf.get(y * width + x)?,
+                buf.get(chroma)?,
+                buf.get(chroma + 1)?,
+            )
+        }
+        TensorData::YUY2(buf) => {
+            let buf = buf.as_slice();
+            let pair = y * ((width + 1) / 2 * 4) + x / 2 * 4;
+            (
+                buf.get(pair + x % 2 * 2)?,
+                buf.get(pair + 1)?,
+                buf.get(pair + 3)?,
+            )
+        }
+        _ => return None,
+    };
+    Some(yuv_to_rgb(*luma, *u, *v))
+}
+
+/// Convert a YUV sample to RGB, using the BT.601 "limited range" coefficients common to cameras.
+fn yuv_to_rgb(y: u8, u: u8, v: u8) -> [u8; 3] {
+    let y = (y as f32 - 16.0) * 1.164;
+    let u = u as f32 - 128.0;
+    let v = v as f32 - 128.0;
+
+    let r = y + 1.596 * v;
+    let g = y - 0.392 * u - 0.813 * v;
+    let b = y + 2.017 * u;
+
+    [
+        r.round().clamp(0.0, 255.0) as u8,
+        g.round().clamp(0.0, 255.0) as u8,
+        b.round().clamp(0.0, 255.0) as u8,
+    ]
 }
 
 impl Component for Tensor {
@@ -482,10 +836,13 @@ pub enum TensorCastError {
     #[error("ndarray Array is not contiguous and in standard order")]
     NotContiguousStdOrder,
 
-    #[error(
-        "tensors do not currently support f16 data (https://github.com/rerun-io/rerun/issues/854)"
-    )]
-    F16NotSupported,
+    #[error("a {width}x{height} image needs {expected} bytes, got {actual}")]
+    BadBufferSize {
+        width: u32,
+        height: u32,
+        expected: usize,
+        actual: usize,
+    },
 }
 
 macro_rules! tensor_type {
@@ -518,22 +875,16 @@ macro_rules! tensor_type {
                     })
                     .collect();
 
-                match view.to_slice() {
-                    Some(slice) => Ok(Tensor {
-                        tensor_id: TensorId::random(),
-                        shape,
-                        data: TensorData::$variant(Vec::from(slice).into()),
-                        meaning: TensorDataMeaning::Unknown,
-                        meter: None,
-                    }),
-                    None => Ok(Tensor {
-                        tensor_id: TensorId::random(),
-                        shape,
-                        data: TensorData::$variant(view.iter().cloned().collect::<Vec<_>>().into()),
-                        meaning: TensorDataMeaning::Unknown,
-                        meter: None,
-                    }),
-                }
+                let data = match view.to_slice() {
+                    Some(slice) => TensorData::$variant(Vec::from(slice).into()),
+                    None => TensorData::$variant(view.iter().cloned().collect::<Vec<_>>().into()),
+                };
+                Ok(Tensor::from_content(
+                    shape,
+                    data,
+                    TensorDataMeaning::Unknown,
+                    None,
+                ))
             }
         }
 
@@ -551,12 +902,13 @@ macro_rules! tensor_type {
                     .collect();
                 value
                     .is_standard_layout()
-                    .then(|| Tensor {
-                        tensor_id: TensorId::random(),
-                        shape,
-                        data: TensorData::$variant(value.into_raw_vec().into()),
-                        meaning: TensorDataMeaning::Unknown,
-                        meter: None,
+                    .then(|| {
+                        Tensor::from_content(
+                            shape,
+                            TensorData::$variant(value.into_raw_vec().into()),
+                            TensorDataMeaning::Unknown,
+                            None,
+                        )
                     })
                     .ok_or(TensorCastError::NotContiguousStdOrder)
             }
@@ -574,18 +926,10 @@ tensor_type!(i16, I16);
 tensor_type!(i32, I32);
 tensor_type!(i64, I64);
 
+tensor_type!(half::f16, F16);
 tensor_type!(f32, F32);
 tensor_type!(f64, F64);
 
-// TODO(#854) Switch back to `tensor_type!` once we have F16 tensors
-impl<'a> TryFrom<&'a Tensor> for ::ndarray::ArrayViewD<'a, half::f16> {
-    type Error = TensorCastError;
-
-    fn try_from(_: &'a Tensor) -> Result<Self, Self::Error> {
-        Err(TensorCastError::F16NotSupported)
-    }
-}
-
 // ----------------------------------------------------------------------------
 
 #[cfg(feature = "image")]
@@ -597,6 +941,9 @@ pub enum TensorImageError {
     #[error("Unsupported JPEG color type: {0:?}. Only RGB Jpegs are supported")]
     UnsupportedJpegColorType(image::ColorType),
 
+    #[error("Unsupported PNG color type: {0:?}. We support 8-bit and 16-bit Luminance, RGB, RGBA, and Luminance-Alpha.")]
+    UnsupportedPngColorType(image::ColorType),
+
     #[error("Unsupported color type: {0:?}. We support 8-bit, 16-bit, and f32 images, and RGB, RGBA, Luminance, and Luminance-Alpha.")]
     UnsupportedImageColorType(image::ColorType),
 
@@ -620,6 +967,69 @@ impl Tensor {
             meter,
         }
     }
+
+    /// Create a tensor with an id derived from its shape and payload.
+    ///
+    /// See [`TensorId::from_content`].
+    pub fn from_content(
+        shape: Vec<TensorDimension>,
+        data: TensorData,
+        meaning: TensorDataMeaning,
+        meter: Option<f32>,
+    ) -> Self {
+        Self {
+            tensor_id: TensorId::from_content(&shape, &data),
+            shape,
+            data,
+            meaning,
+            meter,
+        }
+    }
+
+    /// An RGB image stored as NV12, see [`TensorData::NV12`].
+    ///
+    /// Fails if `bytes` isn't exactly one NV12 image of the given size.
+    pub fn from_nv12(width: u32, height: u32, bytes: Vec<u8>) -> Result<Self, TensorCastError> {
+        let (w, h) = (width as usize, height as usize);
+        let expected = w * h + (h + 1) / 2 * ((w + 1) / 2 * 2);
+        Self::from_chroma_subsampled(width, height, expected, TensorData::NV12(bytes.into()))
+    }
+
+    /// An RGB image stored as YUY2, see [`TensorData::YUY2`].
+    ///
+    /// Fails if `bytes` isn't exactly one YUY2 image of the given size.
+    pub fn from_yuy2(width: u32, height: u32, bytes: Vec<u8>) -> Result<Self, TensorCastError> {
+        let (w, h) = (width as usize, height as usize);
+        let expected = h * ((w + 1) / 2 * 4);
+        Self::from_chroma_subsampled(width, height, expected, TensorData::YUY2(bytes.into()))
+    }
+
+    fn from_chroma_subsampled(
+        width: u32,
+        height: u32,
+        expected: usize,
+        data: TensorData,
+    ) -> Result<Self, TensorCastError> {
+        let actual = data.size_in_bytes();
+        if actual != expected {
+            return Err(TensorCastError::BadBufferSize {
+                width,
+                height,
+                expected,
+                actual,
+            });
+        }
+        Ok(Self::from_content(
+            vec![
+                TensorDimension::height(height as _),
+                TensorDimension::width(width as _),
+                TensorDimension::depth(3),
+            ],
+            data,
+            TensorDataMeaning::Unknown,
+            None,
+        ))
+    }
 }
 
 #[cfg(feature = "image")]
@@ -649,17 +1059,107 @@ impl Tensor {
         }
         let (w, h) = jpeg.dimensions();
 
-        Ok(Self {
-            tensor_id: TensorId::random(),
-            shape: vec![
+        Ok(Self::from_content(
+            vec![
                 TensorDimension::height(h as _),
                 TensorDimension::width(w as _),
                 TensorDimension::depth(3),
             ],
-            data: TensorData::JPEG(jpeg_bytes.into()),
-            meaning: TensorDataMeaning::Unknown,
-            meter: None,
-        })
+            TensorData::JPEG(jpeg_bytes.into()),
+            TensorDataMeaning::Unknown,
+            None,
+        ))
+    }
+
+    /// Construct a tensor from the contents of a PNG file on disk.
+    ///
+    /// Requires the `image` feature.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn tensor_from_png_file(
+        image_path: impl AsRef<std::path::Path>,
+    ) -> Result<Self, TensorImageError> {
+        let png_bytes = std::fs::read(image_path)?;
+        Self::tensor_from_png_bytes(png_bytes)
+    }
+
+    /// Construct a tensor from the contents of a PNG file.
+    ///
+    /// The image is kept compressed and will be decoded lazily by the viewer.
+    ///
+    /// Requires the `image` feature.
+    pub fn tensor_from_png_bytes(png_bytes: Vec<u8>) -> Result<Self, TensorImageError> {
+        use image::ImageDecoder as _;
+        let png = image::codecs::png::PngDecoder::new(std::io::Cursor::new(&png_bytes))?;
+        let depth = match png.color_type() {
+            image::ColorType::L8 | image::ColorType::L16 => 1,
+            image::ColorType::Rgb8 | image::ColorType::Rgb16 => 3,
+            // Luminance-Alpha is converted to RGBA when decoded, see [`Self::from_dynamic_image`].
+            image::ColorType::Rgba8
+            | image::ColorType::Rgba16
+            | image::ColorType::La8
+            | image::ColorType::La16 => 4,
+            color_type => return Err(TensorImageError::UnsupportedPngColorType(color_type)),
+        };
+        let (w, h) = png.dimensions();
+
+        Ok(Self::from_content(
+            vec![
+                TensorDimension::height(h as _),
+                TensorDimension::width(w as _),
+                TensorDimension::depth(depth),
+            ],
+            TensorData::PNG(png_bytes.into()),
+            TensorDataMeaning::Unknown,
+            None,
+        ))
+    }
+
+    /// Losslessly compress the tensor as a PNG if it is a [`TensorDataMeaning::ClassId`]
+    /// or [`TensorDataMeaning::Depth`] image.
+    ///
+    /// Class ids and depth values must survive compression exactly, so this never picks a lossy
+    /// encoding. All other tensors, as well as images PNG cannot represent (anything but
+    /// single-channel `U8` and `U16`), are returned unchanged.
+    ///
+    /// Requires the `image` feature.
+    pub fn compress_lossless(self) -> Result<Self, TensorImageError> {
+        if !matches!(
+            self.meaning,
+            TensorDataMeaning::ClassId | TensorDataMeaning::Depth
+        ) {
+            return Ok(self);
+        }
+        let Some([h, w, 1]) = self.image_height_width_channels() else {
+            return Ok(self);
+        };
+        let (w, h) = (w as u32, h as u32);
+
+        let image = match &self.data {
+            TensorData::U8(buf) => image::GrayImage::from_raw(w, h, buf.as_slice().to_vec())
+                .map(image::DynamicImage::ImageLuma8),
+            TensorData::U16(buf) => image::ImageBuffer::from_raw(w, h, buf.as_slice().to_vec())
+                .map(image::DynamicImage::ImageLuma16),
+            _ => None,
+        };
+        let Some(image) = image else {
+            return Ok(self);
+        };
+
+        let mut png_bytes = Vec::new();
+        {
+            crate::profile_scope!("encode_png");
+            image.write_to(
+                &mut std::io::Cursor::new(&mut png_bytes),
+                image::ImageOutputFormat::Png,
+            )?;
+        }
+
+        Ok(Self::from_content(
+            self.shape,
+            TensorData::PNG(png_bytes.into()),
+            self.meaning,
+            self.meter,
+        ))
     }
 
     /// Construct a tensor from something that can be turned into a [`image::DynamicImage`].
@@ -710,17 +1210,16 @@ impl Tensor {
             }
         };
 
-        Ok(Self {
-            tensor_id: TensorId::random(),
-            shape: vec![
+        Ok(Self::from_content(
+            vec![
                 TensorDimension::height(h as _),
                 TensorDimension::width(w as _),
                 TensorDimension::depth(depth),
             ],
             data,
-            meaning: TensorDataMeaning::Unknown,
-            meter: None,
-        })
+            TensorDataMeaning::Unknown,
+            None,
+        ))
     }
 }
 
@@ -778,9 +1277,175 @@ fn test_arrow() {
             meaning: TensorDataMeaning::Unknown,
             meter: None,
         },
+        Tensor {
+            tensor_id: TensorId(std::default::Default::default()),
+            shape: vec![TensorDimension {
+                size: 3,
+                name: None,
+            }],
+            data: TensorData::F16(
+                vec![
+                    half::f16::from_f32(0.5),
+                    half::f16::from_f32(-1.0),
+                    half::f16::INFINITY,
+                ]
+                .into(),
+            ),
+            meaning: TensorDataMeaning::Depth,
+            meter: None,
+        },
+        Tensor {
+            tensor_id: TensorId(std::default::Default::default()),
+            shape: vec![TensorDimension::height(1), TensorDimension::width(1)],
+            data: TensorData::PNG(vec![0x89, b'P', b'N', b'G'].into()),
+            meaning: TensorDataMeaning::ClassId,
+            meter: None,
+        },
+        Tensor {
+            tensor_id: TensorId(std::default::Default::default()),
+            shape: vec![TensorDimension::height(1), TensorDimension::width(1)],
+            data: TensorData::Encoded(EncodedImage {
+                format: ImageEncoding::Tiff,
+                bytes: vec![b'I', b'I', 42, 0].into(),
+            }),
+            meaning: TensorDataMeaning::Depth,
+            meter: Some(1000.0),
+        },
+        Tensor {
+            tensor_id: TensorId(std::default::Default::default()),
+            shape: vec![
+                TensorDimension::height(2),
+                TensorDimension::width(2),
+                TensorDimension::depth(3),
+            ],
+            data: TensorData::NV12(vec![16, 16, 16, 16, 128, 128].into()),
+            meaning: TensorDataMeaning::Unknown,
+            meter: None,
+        },
     ];
 
     let array: Box<dyn arrow2::array::Array> = tensors_in.iter().try_into_arrow().unwrap();
     let tensors_out: Vec<Tensor> = TryIntoCollection::try_into_collection(array).unwrap();
     assert_eq!(tensors_in, tensors_out);
 }
+
+#[test]
+fn test_content_id() {
+    let image = |pixels: Vec<u8>| {
+        Tensor::from_content(
+            vec![TensorDimension::height(2), TensorDimension::width(2)],
+            TensorData::U8(pixels.into()),
+            TensorDataMeaning::Unknown,
+            None,
+        )
+    };
+
+    assert_eq!(image(vec![1, 2, 3, 4]).id(), image(vec![1, 2, 3, 4]).id());
+    assert_ne!(image(vec![1, 2, 3, 4]).id(), image(vec![1, 2, 3, 5]).id());
+
+    // Same bytes, different interpretation:
+    let jpeg = Tensor::from_content(
+        vec![TensorDimension::height(2), TensorDimension::width(2)],
+        TensorData::JPEG(vec![1, 2, 3, 4].into()),
+        TensorDataMeaning::Unknown,
+        None,
+    );
+    assert_ne!(image(vec![1, 2, 3, 4]).id(), jpeg.id());
+
+    let transposed = Tensor::from_content(
+        vec![TensorDimension::height(1), TensorDimension::width(4)],
+        TensorData::U8(vec![1, 2, 3, 4].into()),
+        TensorDataMeaning::Unknown,
+        None,
+    );
+    assert_ne!(image(vec![1, 2, 3, 4]).id(), transposed.id());
+
+    // What the Python SDK does:
+    let shape = vec![TensorDimension::unnamed(2)];
+    let floats = Tensor::from_content(
+        shape.clone(),
+        TensorData::F32(vec![1.0, 2.0].into()),
+        TensorDataMeaning::Unknown,
+        None,
+    );
+    let bytes = [1.0_f32.to_ne_bytes(), 2.0_f32.to_ne_bytes()].concat();
+    assert_eq!(
+        floats.id(),
+        TensorId::from_raw_content(&shape, "F32", &bytes)
+    );
+}
+
+#[test]
+fn test_chroma_subsampled() {
+    let shape = vec![
+        TensorDimension::height(2),
+        TensorDimension::width(2),
+        TensorDimension::depth(3),
+    ];
+
+    // Two white pixels on top, two black pixels below, no chroma:
+    let nv12 = Tensor::from_nv12(2, 2, vec![235, 235, 16, 16, 128, 128]).unwrap();
+    assert_eq!(nv12.shape(), shape.as_slice());
+    assert_eq!(nv12.get(&[0, 1, 0]), Some(TensorElement::U8(255)));
+    assert_eq!(nv12.get(&[1, 0, 2]), Some(TensorElement::U8(0)));
+    assert_eq!(nv12.get(&[2, 0, 0]), None);
+    assert_eq!(
+        nv12.yuv_to_rgb8().unwrap(),
+        [[255; 3], [255; 3], [0; 3], [0; 3]].concat()
+    );
+
+    // Same image, stored as `Y0 U Y1 V` per pixel pair:
+    let yuy2 = Tensor::from_yuy2(2, 2, vec![235, 128, 235, 128, 16, 128, 16, 128]).unwrap();
+    assert_eq!(yuy2.yuv_to_rgb8(), nv12.yuv_to_rgb8());
+    assert_eq!(
+        Tensor::from_yuy2(3, 1, vec![0; 6]),
+        Err(TensorCastError::BadBufferSize {
+            width: 3,
+            height: 1,
+            expected: 8,
+            actual: 6
+        })
+    );
+
+    // A truncated buffer can't be converted:
+    let truncated = Tensor {
+        data: TensorData::NV12(vec![235, 235, 16, 16].into()),
+        ..nv12
+    };
+    assert_eq!(truncated.yuv_to_rgb8(), None);
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn test_compress_lossless() {
+    let depth: Vec<u16> = (0..12).map(|i| i * 1000).collect();
+    let tensor = Tensor {
+        tensor_id: TensorId::random(),
+        shape: vec![TensorDimension::height(3), TensorDimension::width(4)],
+        data: TensorData::U16(depth.clone().into()),
+        meaning: TensorDataMeaning::Depth,
+        meter: Some(1000.0),
+    };
+
+    let compressed = tensor.clone().compress_lossless().unwrap();
+    assert!(matches!(compressed.data, TensorData::PNG(_)));
+    assert_eq!(compressed.dtype(), TensorDataType::U16);
+    assert_eq!(compressed.shape(), tensor.shape());
+    assert_eq!(compressed.meter, tensor.meter);
+
+    let (_, png_bytes) = compressed.data.encoded_image().unwrap();
+    let decoded = image::load_from_memory_with_format(png_bytes, image::ImageFormat::Png)
+        .unwrap()
+        .into_luma16();
+    assert_eq!(decoded.into_raw(), depth);
+
+    // Color images are left alone:
+    let color = Tensor {
+        meaning: TensorDataMeaning::Unknown,
+        ..tensor
+    };
+    assert!(matches!(
+        color.compress_lossless().unwrap().data,
+        TensorData::U16(_)
+    ));
+}
@@ -0,0 +1,62 @@
+use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+
+use crate::Component;
+
+/// A text document in Markdown, e.g. an experiment description or evaluation report.
+///
+/// Unlike a [`super::TextEntry`], which is one line of a log, a document is shown
+/// in full in its own space view.
+///
+/// ```
+/// use re_log_types::component_types::TextDocument;
+/// use arrow2_convert::field::ArrowField;
+/// use arrow2::datatypes::{DataType, Field};
+///
+/// assert_eq!(TextDocument::data_type(), DataType::Utf8);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[arrow_field(transparent)]
+pub struct TextDocument(pub String);
+
+impl TextDocument {
+    #[inline]
+    pub fn new(markdown: impl Into<String>) -> Self {
+        Self(markdown.into())
+    }
+}
+
+impl Component for TextDocument {
+    #[inline]
+    fn name() -> crate::ComponentName {
+        "rerun.text_document".into()
+    }
+}
+
+impl From<String> for TextDocument {
+    #[inline]
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for TextDocument {
+    #[inline]
+    fn from(value: &str) -> Self {
+        Self(value.to_owned())
+    }
+}
+
+#[test]
+fn test_text_document_roundtrip() {
+    use arrow2::array::Array;
+    use arrow2_convert::{deserialize::TryIntoCollection, serialize::TryIntoArrow};
+
+    let documents_in = vec![
+        TextDocument::new("# Experiment 42\n\nTrained with `lr = 1e-3`."),
+        TextDocument::new(""),
+    ];
+    let array: Box<dyn Array> = documents_in.try_into_arrow().unwrap();
+    let documents_out: Vec<TextDocument> = TryIntoCollection::try_into_collection(array).unwrap();
+    assert_eq!(documents_in, documents_out);
+}
@@ -1,8 +1,15 @@
-use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use arrow2::{
+    array::{MutablePrimitiveArray, PrimitiveArray, TryPush},
+    datatypes::DataType,
+};
+use arrow2_convert::{
+    deserialize::ArrowDeserialize, field::ArrowField, serialize::ArrowSerialize, ArrowDeserialize,
+    ArrowField, ArrowSerialize,
+};
 
 use crate::Component;
 
-use super::{mat::Mat3x3, Quaternion, Vec2D, Vec3D};
+use super::{mat::Mat3x3, Quaternion, Vec2D, Vec3D, Vec4D};
 
 /// A proper rigid 3D transform, i.e. a rotation and a translation.
 ///
@@ -87,11 +94,191 @@ impl Rigid3 {
     }
 }
 
+/// The lens distortion model used by a [`LensDistortion`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum DistortionModel {
+    /// Brown-Conrady model with radial coefficients `k1, k2, k3` and tangential coefficients `p1, p2`.
+    ///
+    /// This is the model used by OpenCV's `calibrateCamera`.
+    #[default]
+    BrownConrady = 0,
+
+    /// Fisheye model (a.k.a. Kannala-Brandt) with coefficients `k1, k2, k3, k4`.
+    ///
+    /// With all coefficients set to zero this is the equidistant projection.
+    /// This is the model used by OpenCV's `fisheye` module.
+    Fisheye = 1,
+}
+
+impl DistortionModel {
+    #[inline]
+    fn from_u8(v: u8) -> Option<Self> {
+        match v {
+            0 => Some(Self::BrownConrady),
+            1 => Some(Self::Fisheye),
+            _ => None,
+        }
+    }
+}
+
+impl ArrowField for DistortionModel {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::UInt8
+    }
+}
+
+impl ArrowSerialize for DistortionModel {
+    type MutableArrayType = MutablePrimitiveArray<u8>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        MutablePrimitiveArray::<u8>::from(<Self as ArrowField>::data_type())
+    }
+
+    #[inline]
+    fn arrow_serialize(
+        v: &<Self as ArrowField>::Type,
+        array: &mut Self::MutableArrayType,
+    ) -> arrow2::error::Result<()> {
+        array.try_push(Some(*v as u8))
+    }
+}
+
+impl ArrowDeserialize for DistortionModel {
+    type ArrayType = PrimitiveArray<u8>;
+
+    #[inline]
+    fn arrow_deserialize(
+        v: <&Self::ArrayType as IntoIterator>::Item,
+    ) -> Option<<Self as ArrowField>::Type> {
+        v.and_then(|v| Self::from_u8(*v))
+    }
+}
+
+/// Lens distortion of a [`Pinhole`] camera.
+///
+/// All coefficients operate on normalized image coordinates,
+/// i.e. `((u - cx) / fx, (v - cy) / fy)`, and follow the OpenCV conventions.
+///
+/// ```
+/// use re_log_types::component_types::LensDistortion;
+/// use arrow2_convert::field::ArrowField;
+/// use arrow2::datatypes::{DataType, Field};
+///
+/// assert_eq!(
+///     LensDistortion::data_type(),
+///     DataType::Struct(vec![
+///         Field::new("model", DataType::UInt8, false),
+///         Field::new(
+///             "radial",
+///             DataType::FixedSizeList(
+///                 Box::new(Field::new("item", DataType::Float32, false)),
+///                 4
+///             ),
+///             false
+///         ),
+///         Field::new(
+///             "tangential",
+///             DataType::FixedSizeList(
+///                 Box::new(Field::new("item", DataType::Float32, false)),
+///                 2
+///             ),
+///             false
+///         ),
+///     ]),
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct LensDistortion {
+    /// Which model the coefficients belong to.
+    pub model: DistortionModel,
+
+    /// Radial distortion coefficients `[k1, k2, k3, k4]`.
+    ///
+    /// [`DistortionModel::BrownConrady`] ignores `k4`.
+    pub radial: Vec4D,
+
+    /// Tangential distortion coefficients `[p1, p2]`.
+    ///
+    /// Ignored by [`DistortionModel::Fisheye`].
+    pub tangential: Vec2D,
+}
+
+impl LensDistortion {
+    /// Brown-Conrady distortion, using the OpenCV coefficient order `k1, k2, p1, p2, k3`.
+    #[inline]
+    pub fn brown_conrady(k1: f32, k2: f32, p1: f32, p2: f32, k3: f32) -> Self {
+        Self {
+            model: DistortionModel::BrownConrady,
+            radial: Vec4D([k1, k2, k3, 0.0]),
+            tangential: Vec2D([p1, p2]),
+        }
+    }
+
+    /// Fisheye (Kannala-Brandt) distortion with coefficients `k1, k2, k3, k4`.
+    #[inline]
+    pub fn fisheye(k1: f32, k2: f32, k3: f32, k4: f32) -> Self {
+        Self {
+            model: DistortionModel::Fisheye,
+            radial: Vec4D([k1, k2, k3, k4]),
+            tangential: Vec2D([0.0, 0.0]),
+        }
+    }
+
+    /// Equidistant fisheye projection, i.e. a [`Self::fisheye`] without any coefficients.
+    #[inline]
+    pub fn equidistant() -> Self {
+        Self::fisheye(0.0, 0.0, 0.0, 0.0)
+    }
+
+    /// Maps an undistorted normalized image coordinate to its distorted position.
+    ///
+    /// For [`DistortionModel::Fisheye`] the input is the pinhole projection `x/z, y/z`.
+    ///
+    /// The inverse is `DepthCloudDistortion::undistort` in `re_renderer`,
+    /// which has to match the depth cloud shader.
+    pub fn distort(&self, [x, y]: [f32; 2]) -> [f32; 2] {
+        let [k1, k2, k3, k4] = self.radial.0;
+        match self.model {
+            DistortionModel::BrownConrady => {
+                let [p1, p2] = self.tangential.0;
+                let r2 = x * x + y * y;
+                let radial = 1.0 + r2 * (k1 + r2 * (k2 + r2 * k3));
+                [
+                    x * radial + 2.0 * p1 * x * y + p2 * (r2 + 2.0 * x * x),
+                    y * radial + p1 * (r2 + 2.0 * y * y) + 2.0 * p2 * x * y,
+                ]
+            }
+            DistortionModel::Fisheye => {
+                let r = (x * x + y * y).sqrt();
+                if r < f32::EPSILON {
+                    return [x, y];
+                }
+                let theta = r.atan();
+                let theta_d = fisheye_theta_d(theta, [k1, k2, k3, k4]);
+                let scale = theta_d / r;
+                [x * scale, y * scale]
+            }
+        }
+    }
+}
+
+#[inline]
+fn fisheye_theta_d(theta: f32, [k1, k2, k3, k4]: [f32; 4]) -> f32 {
+    let t2 = theta * theta;
+    theta * (1.0 + t2 * (k1 + t2 * (k2 + t2 * (k3 + t2 * k4))))
+}
+
 /// Camera perspective projection (a.k.a. intrinsics).
 ///
 ///
 /// ```
-/// use re_log_types::component_types::Pinhole;
+/// use re_log_types::component_types::{LensDistortion, Pinhole};
 /// use arrow2_convert::field::ArrowField;
 /// use arrow2::datatypes::{DataType, Field};
 ///
@@ -114,6 +301,7 @@ impl Rigid3 {
 ///             ),
 ///             true,
 ///         ),
+///         Field::new("distortion", LensDistortion::data_type(), true),
 ///     ]),
 /// );
 /// ```
@@ -142,6 +330,12 @@ pub struct Pinhole {
     ///
     /// [`Self::image_from_cam`] project onto the space spanned by `(0,0)` and `resolution - 1`.
     pub resolution: Option<Vec2D>,
+
+    /// Optional lens distortion.
+    ///
+    /// If set, [`Self::image_from_cam`] describes the ideal pinhole camera
+    /// and the distortion is applied on top of it.
+    pub distortion: Option<LensDistortion>,
 }
 
 impl Pinhole {
@@ -186,6 +380,21 @@ impl Pinhole {
     pub fn aspect_ratio(&self) -> Option<f32> {
         self.resolution.map(|r| r[0] / r[1])
     }
+
+    /// Projects a point in camera space onto the (distorted) image.
+    ///
+    /// Returns x, y in pixels and the depth along the optical axis.
+    #[cfg(feature = "glam")]
+    pub fn project(&self, pos_in_cam: glam::Vec3) -> glam::Vec3 {
+        let image_from_cam: glam::Mat3 = self.image_from_cam.into();
+        let normalized = pos_in_cam.truncate() / pos_in_cam.z;
+        let normalized = match &self.distortion {
+            Some(distortion) => glam::Vec2::from(distortion.distort(normalized.into())),
+            None => normalized,
+        };
+        let pos_in_image = image_from_cam * normalized.extend(1.0);
+        (pos_in_image.truncate() / pos_in_image.z).extend(pos_in_cam.z)
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -249,6 +458,7 @@ fn test_transform_roundtrip() {
         Transform::Pinhole(Pinhole {
             image_from_cam: [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]].into(),
             resolution: None,
+            distortion: None,
         }),
         Transform::Rigid3(Rigid3 {
             rotation: Quaternion {
@@ -262,6 +472,12 @@ fn test_transform_roundtrip() {
         Transform::Pinhole(Pinhole {
             image_from_cam: [[21.0, 22.0, 23.0], [24.0, 25.0, 26.0], [27.0, 28.0, 29.0]].into(),
             resolution: Some([123.0, 456.0].into()),
+            distortion: Some(LensDistortion::brown_conrady(0.1, -0.2, 0.001, 0.002, 0.03)),
+        }),
+        Transform::Pinhole(Pinhole {
+            image_from_cam: [[31.0, 32.0, 33.0], [34.0, 35.0, 36.0], [37.0, 38.0, 39.0]].into(),
+            resolution: Some([640.0, 480.0].into()),
+            distortion: Some(LensDistortion::fisheye(0.1, 0.01, -0.002, 0.0003)),
         }),
     ];
     let array: Box<dyn Array> = transforms_in.try_into_arrow().unwrap();
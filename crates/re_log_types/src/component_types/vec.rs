@@ -78,6 +78,13 @@ impl From<glam::Vec2> for Vec2D {
     }
 }
 
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Vector2<f32>> for Vec2D {
+    fn from(v: nalgebra::Vector2<f32>) -> Self {
+        Self([v.x, v.y])
+    }
+}
+
 impl std::fmt::Display for Vec2D {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -175,6 +182,14 @@ impl From<glam::Vec3> for Vec3D {
     }
 }
 
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Vector3<f32>> for Vec3D {
+    #[inline]
+    fn from(v: nalgebra::Vector3<f32>) -> Self {
+        Self([v.x, v.y, v.z])
+    }
+}
+
 impl std::fmt::Display for Vec3D {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -15,6 +15,10 @@ pub enum DataCellError {
     #[error("Could not serialize/deserialize data to/from Arrow: {0}")]
     Arrow(#[from] arrow2::error::Error),
 
+    #[cfg(feature = "serde")]
+    #[error("Could not convert data to Arrow using serde: {0}")]
+    Serde(#[from] crate::serde_arrow::SerdeArrowError),
+
     // Needed to handle TryFrom<T> -> T
     #[error("Infallible")]
     Unreachable(#[from] std::convert::Infallible),
@@ -248,6 +252,18 @@ impl DataCell {
         Self::try_from_arrow(name, values).unwrap()
     }
 
+    /// Builds a new `DataCell` from any values that can be serialized with serde,
+    /// for logging data that doesn't have a matching [`Component`].
+    ///
+    /// See [`crate::serde_arrow`] for how the values are turned into arrow.
+    #[cfg(feature = "serde")]
+    pub fn try_from_serde<'a, T: serde::Serialize + serde::Deserialize<'static> + 'a>(
+        name: ComponentName,
+        values: impl IntoIterator<Item = &'a T>,
+    ) -> DataCellResult<Self> {
+        Self::try_from_arrow(name, crate::serde_arrow::try_to_arrow(values)?)
+    }
+
     // ---
 
     /// Builds an empty `DataCell` from a native component type.
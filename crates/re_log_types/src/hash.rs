@@ -79,6 +79,11 @@ impl Hash128 {
     pub fn second64(&self) -> u64 {
         self.0[1]
     }
+
+    #[inline]
+    pub fn as_u128(&self) -> u128 {
+        ((self.0[0] as u128) << 64) | self.0[1] as u128
+    }
 }
 
 impl std::hash::Hash for Hash128 {
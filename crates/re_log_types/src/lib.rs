@@ -17,6 +17,9 @@ mod data_table;
 pub mod hash;
 mod index;
 pub mod path;
+mod replay;
+#[cfg(feature = "serde")]
+pub mod serde_arrow;
 mod size_bytes;
 mod time;
 pub mod time_point;
@@ -33,6 +36,9 @@ pub mod external {
 
     #[cfg(feature = "image")]
     pub use image;
+
+    #[cfg(feature = "nalgebra")]
+    pub use nalgebra;
 }
 
 pub use self::arrow_msg::ArrowMsg;
@@ -54,6 +60,7 @@ pub use self::data_table::{
 };
 pub use self::index::*;
 pub use self::path::*;
+pub use self::replay::ReplayState;
 pub use self::size_bytes::SizeBytes;
 pub use self::time::{Duration, Time};
 pub use self::time_point::{TimeInt, TimePoint, TimeType, Timeline, TimelineName};
@@ -330,6 +337,9 @@ pub enum PathOp {
 
     /// Clear all the components of an `[EntityPath]` and any descendants.
     ClearRecursive(EntityPath),
+
+    /// Clear a single component of an [`EntityPath`], leaving its other components alone.
+    ClearComponent(ComponentPath),
 }
 
 impl PathOp {
@@ -341,9 +351,14 @@ impl PathOp {
         }
     }
 
+    pub fn clear_component(entity_path: EntityPath, component_name: ComponentName) -> Self {
+        PathOp::ClearComponent(ComponentPath::new(entity_path, component_name))
+    }
+
     pub fn entity_path(&self) -> &EntityPath {
         match &self {
             PathOp::ClearComponents(path) | PathOp::ClearRecursive(path) => path,
+            PathOp::ClearComponent(component_path) => &component_path.entity_path,
         }
     }
 }
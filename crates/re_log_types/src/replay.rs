@@ -0,0 +1,343 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    ComponentName, ComponentPath, DataRow, DataTable, EntityPath, LogMsg, PathOp, RecordingId,
+    RowId, TableId,
+};
+
+/// What a reader needs to make sense of the rest of a recording:
+/// the [`crate::BeginRecordingMsg`]s and the latest timeless data.
+///
+/// Used to start a new file, or to catch up a server after reconnecting,
+/// without having to resend everything.
+#[derive(Default)]
+pub struct ReplayState {
+    begin_msgs: BTreeMap<RecordingId, LogMsg>,
+
+    /// The latest timeless row per entity and set of components,
+    /// together with the value of [`Self::num_timeless_rows`] when it was logged.
+    timeless_rows: BTreeMap<(RecordingId, EntityPath, Vec<ComponentName>), (u64, DataRow)>,
+
+    /// How many timeless rows we've seen, so we can replay them in the order they were logged.
+    num_timeless_rows: u64,
+}
+
+impl ReplayState {
+    /// Does this message change what gets replayed?
+    ///
+    /// Cheap, unlike [`Self::update`].
+    pub fn is_affected_by(msg: &LogMsg) -> bool {
+        match msg {
+            LogMsg::BeginRecordingMsg(_) | LogMsg::EntityPathOpMsg(..) => true,
+            LogMsg::ArrowMsg(_, arrow_msg) => arrow_msg.has_timeless_rows(),
+            LogMsg::Goodbye(_) => false,
+        }
+    }
+
+    /// Keep track of this message, if it needs replaying.
+    ///
+    /// Only decodes tables that actually contain timeless rows.
+    pub fn update(&mut self, msg: &LogMsg) {
+        match msg {
+            LogMsg::BeginRecordingMsg(begin) => {
+                self.begin_msgs.insert(begin.info.recording_id, msg.clone());
+            }
+            LogMsg::ArrowMsg(recording_id, arrow_msg) => {
+                if !arrow_msg.has_timeless_rows() {
+                    return;
+                }
+                crate::profile_scope!("timeless rows");
+                match DataTable::from_arrow_msg(arrow_msg) {
+                    Ok(table) => {
+                        for row in table.to_rows() {
+                            if row.timepoint().is_timeless() {
+                                let key = (
+                                    *recording_id,
+                                    row.entity_path().clone(),
+                                    row.component_names().collect(),
+                                );
+                                self.num_timeless_rows += 1;
+                                self.timeless_rows
+                                    .insert(key, (self.num_timeless_rows, row));
+                            }
+                        }
+                    }
+                    Err(err) => {
+                        re_log::warn_once!("Failed to read timeless data for replay: {err}");
+                    }
+                }
+            }
+            LogMsg::EntityPathOpMsg(recording_id, msg) => {
+                self.apply_path_op(*recording_id, &msg.path_op);
+            }
+            LogMsg::Goodbye(..) => {}
+        }
+    }
+
+    /// Forget the timeless data that a clear removes, so that replaying doesn't bring it back.
+    fn apply_path_op(&mut self, recording_id: RecordingId, path_op: &PathOp) {
+        match path_op {
+            PathOp::ClearComponents(entity_path) => {
+                self.timeless_rows
+                    .retain(|(id, path, _), _| *id != recording_id || path != entity_path);
+            }
+            PathOp::ClearRecursive(entity_path) => {
+                self.timeless_rows.retain(|(id, path, _), _| {
+                    *id != recording_id
+                        || (path != entity_path && !path.is_descendant_of(entity_path))
+                });
+            }
+            PathOp::ClearComponent(ComponentPath {
+                entity_path,
+                component_name,
+            }) => {
+                let keys = self
+                    .timeless_rows
+                    .keys()
+                    .filter(|(id, path, names)| {
+                        *id == recording_id && path == entity_path && names.contains(component_name)
+                    })
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                // Keep the other components of those rows.
+                for key in keys {
+                    let Some((index, row)) = self.timeless_rows.remove(&key) else {
+                        continue;
+                    };
+                    let cells = row
+                        .cells()
+                        .iter()
+                        .filter(|cell| cell.component_name() != *component_name)
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    if cells.is_empty() {
+                        continue;
+                    }
+
+                    let key = (
+                        recording_id,
+                        entity_path.clone(),
+                        cells.iter().map(|cell| cell.component_name()).collect(),
+                    );
+                    let is_newer = self
+                        .timeless_rows
+                        .get(&key)
+                        .map_or(true, |(other_index, _)| *other_index < index);
+                    if is_newer {
+                        let row = DataRow::from_cells(
+                            row.row_id(),
+                            row.timepoint().clone(),
+                            entity_path.clone(),
+                            row.num_instances(),
+                            cells,
+                        );
+                        self.timeless_rows.insert(key, (index, row));
+                    }
+                }
+            }
+        }
+    }
+
+    /// The messages to replay: the [`crate::BeginRecordingMsg`]s, each followed by a single
+    /// table with the timeless data of that recording.
+    ///
+    /// The timeless rows get new [`RowId`]s, so a reader that did see them already
+    /// doesn't get the same row twice.
+    pub fn messages(&self) -> Vec<LogMsg> {
+        crate::profile_function!();
+
+        let mut messages = Vec::new();
+
+        for (recording_id, begin_msg) in &self.begin_msgs {
+            messages.push(begin_msg.clone());
+
+            let mut rows = self
+                .timeless_rows
+                .iter()
+                .filter(|((id, _, _), _)| id == recording_id)
+                .map(|(_, row)| row)
+                .collect::<Vec<_>>();
+            if rows.is_empty() {
+                continue;
+            }
+            rows.sort_by_key(|(index, _)| *index);
+
+            let rows = rows.into_iter().map(|(_, row)| {
+                DataRow::from_cells(
+                    RowId::random(),
+                    row.timepoint().clone(),
+                    row.entity_path().clone(),
+                    row.num_instances(),
+                    row.cells().to_vec(),
+                )
+            });
+            match DataTable::from_rows(TableId::random(), rows).to_arrow_msg() {
+                Ok(arrow_msg) => messages.push(LogMsg::ArrowMsg(*recording_id, arrow_msg)),
+                Err(err) => re_log::warn!("Failed to replay timeless data: {err}"),
+            }
+        }
+
+        messages
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+fn begin_recording_msg(recording_id: RecordingId) -> LogMsg {
+    use crate::{ApplicationId, BeginRecordingMsg, RecordingInfo, RecordingSource, Time};
+
+    LogMsg::BeginRecordingMsg(BeginRecordingMsg {
+        row_id: RowId::random(),
+        info: RecordingInfo {
+            application_id: ApplicationId("test".to_owned()),
+            recording_id,
+            is_official_example: false,
+            started: Time::now(),
+            recording_source: RecordingSource::RustSdk {
+                rustc_version: String::new(),
+                llvm_version: String::new(),
+            },
+        },
+    })
+}
+
+#[test]
+fn test_replay_state() {
+    use crate::{component_types::Label, TimePoint, Timeline};
+
+    let recording_id = RecordingId::random();
+    let row = |entity_path: &str, timepoint: TimePoint, label: &str| {
+        DataRow::from_cells1(
+            RowId::random(),
+            entity_path,
+            timepoint,
+            1,
+            [Label(label.into())].as_slice(),
+        )
+    };
+    let arrow_msg = |rows: Vec<DataRow>| {
+        let table = DataTable::from_rows(TableId::random(), rows);
+        LogMsg::ArrowMsg(recording_id, table.to_arrow_msg().unwrap())
+    };
+    let frame = |frame: i64| TimePoint::from([(Timeline::new_sequence("frame"), frame.into())]);
+
+    let mut replay = ReplayState::default();
+    assert!(replay.messages().is_empty());
+
+    replay.update(&begin_recording_msg(recording_id));
+
+    // "b" is logged last, even though it sorts after "a".
+    let new_row_id = RowId::random();
+    replay.update(&arrow_msg(vec![
+        row("a", TimePoint::timeless(), "old"),
+        row("b", frame(0), "temporal"),
+    ]));
+    replay.update(&arrow_msg(vec![row("b", TimePoint::timeless(), "b")]));
+    replay.update(&arrow_msg(vec![
+        DataRow::from_cells1(
+            new_row_id,
+            "a",
+            TimePoint::timeless(),
+            1,
+            [Label("new".into())].as_slice(),
+        ),
+        row("a", frame(1), "temporal"),
+    ]));
+
+    let messages = replay.messages();
+    assert_eq!(messages.len(), 2);
+    assert!(matches!(messages[0], LogMsg::BeginRecordingMsg(_)));
+    let LogMsg::ArrowMsg(_, msg) = &messages[1] else {
+        panic!("expected timeless data, got {:?}", messages[1]);
+    };
+    let rows = DataTable::from_arrow_msg(msg)
+        .unwrap()
+        .to_rows()
+        .collect::<Vec<_>>();
+    assert_eq!(rows.len(), 2, "only the latest timeless row is replayed");
+    assert!(rows.iter().all(|row| row.timepoint().is_timeless()));
+    assert_eq!(
+        rows.iter()
+            .map(|row| row.entity_path().to_string())
+            .collect::<Vec<_>>(),
+        ["b", "a"],
+        "rows are replayed in the order they were logged"
+    );
+    assert_ne!(rows[1].row_id(), new_row_id);
+}
+
+#[test]
+fn test_replay_state_clears() {
+    use crate::{
+        component_types::{ColorRGBA, Label},
+        Component as _, EntityPathOpMsg, TimePoint, Timeline,
+    };
+
+    let recording_id = RecordingId::random();
+    let label = |entity_path: &str| {
+        DataRow::from_cells1(
+            RowId::random(),
+            entity_path,
+            TimePoint::timeless(),
+            1,
+            [Label(entity_path.into())].as_slice(),
+        )
+    };
+    let label_and_color = DataRow::from_cells2(
+        RowId::random(),
+        "c",
+        TimePoint::timeless(),
+        1,
+        (
+            [Label("c".into())].as_slice(),
+            [ColorRGBA(0xff00_00ff)].as_slice(),
+        ),
+    );
+    let table = DataTable::from_rows(
+        TableId::random(),
+        [label("a"), label("a/b"), label("ab"), label_and_color],
+    );
+    let path_op = |path_op| {
+        LogMsg::EntityPathOpMsg(
+            recording_id,
+            EntityPathOpMsg {
+                row_id: RowId::random(),
+                time_point: [(Timeline::new_sequence("frame"), 1.into())].into(),
+                path_op,
+            },
+        )
+    };
+
+    let mut replay = ReplayState::default();
+    replay.update(&begin_recording_msg(recording_id));
+    replay.update(&LogMsg::ArrowMsg(
+        recording_id,
+        table.to_arrow_msg().unwrap(),
+    ));
+    replay.update(&path_op(PathOp::clear(true, "a".into())));
+    replay.update(&path_op(PathOp::clear_component("c".into(), Label::name())));
+
+    let messages = replay.messages();
+    let [LogMsg::BeginRecordingMsg(_), LogMsg::ArrowMsg(_, msg)] = messages.as_slice() else {
+        panic!("expected timeless data, got {messages:?}");
+    };
+    let rows = DataTable::from_arrow_msg(msg)
+        .unwrap()
+        .to_rows()
+        .map(|row| {
+            (
+                row.entity_path().to_string(),
+                row.component_names().collect::<Vec<_>>(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        rows,
+        [
+            ("ab".to_owned(), vec![Label::name()]),
+            ("c".to_owned(), vec![ColorRGBA::name()]),
+        ]
+    );
+}
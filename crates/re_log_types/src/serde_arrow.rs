@@ -0,0 +1,1353 @@
+//! Convert any [`serde::Serialize`] value into an arrow array, so it can be logged as a component
+//! without implementing `arrow2_convert` traits for it.
+//!
+//! Each value becomes one element of the array. The arrow type is derived from the Rust type
+//! through its [`serde::Deserialize`] implementation, never from the values themselves,
+//! so logging the same type always results in the same arrow type:
+//! * integers and floats keep their size, e.g. `u32` becomes `UInt32`,
+//! * structs and struct-like enum variants become arrow structs,
+//! * sequences and fixed-size arrays become arrow lists,
+//! * maps become lists of `{key, value}` structs,
+//! * unit enum variants become strings,
+//! * everything is nullable, so `None` and skipped fields are fine.
+//!
+//! An enum with both unit and non-unit variants can't be stored in one field.
+//! Neither can untagged or internally tagged enums, nor anything else that only decides
+//! at runtime how to deserialize itself.
+
+use arrow2::{
+    array::{
+        Array, BinaryArray, BooleanArray, ListArray, NullArray, PrimitiveArray, StructArray,
+        Utf8Array,
+    },
+    bitmap::Bitmap,
+    datatypes::{DataType, Field},
+    offset::Offsets,
+    types::NativeType,
+};
+use serde::{
+    de::{self, value::StrDeserializer, Deserialize, Visitor},
+    ser::{self, Serialize},
+};
+
+// ---
+
+#[derive(thiserror::Error, Debug)]
+pub enum SerdeArrowError {
+    #[error("Failed to serialize: {0}")]
+    Custom(String),
+
+    #[error("Can't store both {0} and {1} in the same field")]
+    MismatchedTypes(&'static str, &'static str),
+
+    #[error("{0} doesn't fit in a {1} field")]
+    OutOfRange(String, &'static str),
+
+    #[error(
+        "Can't derive an arrow type for a type that decides at runtime how to deserialize itself, \
+         e.g. an untagged enum"
+    )]
+    Untraceable,
+
+    #[error("Recursive types can't be stored in arrow")]
+    Recursive,
+
+    #[error(transparent)]
+    Arrow(#[from] arrow2::error::Error),
+}
+
+impl ser::Error for SerdeArrowError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Self::Custom(msg.to_string())
+    }
+}
+
+impl de::Error for SerdeArrowError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Self::Custom(msg.to_string())
+    }
+}
+
+pub type SerdeArrowResult<T> = ::std::result::Result<T, SerdeArrowError>;
+
+/// Serialize each of the values into one element of an arrow array.
+///
+/// The arrow type only depends on `T`, see the [module docs](self).
+///
+/// ```
+/// # use re_log_types::serde_arrow::try_to_arrow;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Config {
+///     name: String,
+///     gains: Vec<f32>,
+///     limit: Option<u32>,
+/// }
+///
+/// let configs = [Config { name: "pid".to_owned(), gains: vec![0.5, 0.1], limit: None }];
+/// let array = try_to_arrow(&configs)?;
+/// assert_eq!(array.len(), 1);
+/// # Ok::<(), re_log_types::serde_arrow::SerdeArrowError>(())
+/// ```
+pub fn try_to_arrow<'a, T: Serialize + Deserialize<'static> + 'a>(
+    values: impl IntoIterator<Item = &'a T>,
+) -> SerdeArrowResult<Box<dyn Array>> {
+    let ty = trace::<T>()?;
+    let values = values
+        .into_iter()
+        .map(|value| value.serialize(ValueSerializer))
+        .collect::<SerdeArrowResult<Vec<_>>>()?;
+    to_array(&ty, &values.iter().collect::<Vec<_>>())
+}
+
+// ----------------------------------------------------------------------------
+
+/// A serialized value, before it is turned into arrow.
+#[derive(Clone, Debug, PartialEq)]
+enum Value {
+    Null,
+    Bool(bool),
+    I64(i64),
+    U64(u64),
+    F64(f64),
+    Str(String),
+    Bytes(Vec<u8>),
+    List(Vec<Value>),
+    Struct(Vec<(String, Value)>),
+}
+
+static NULL: Value = Value::Null;
+
+impl Value {
+    fn field(&self, name: &str) -> &Value {
+        match self {
+            Value::Struct(fields) => fields
+                .iter()
+                .find(|(field_name, _)| field_name == name)
+                .map_or(&NULL, |(_, value)| value),
+            _ => &NULL,
+        }
+    }
+
+    fn items(&self) -> &[Value] {
+        match self {
+            Value::List(items) => items,
+            _ => &[],
+        }
+    }
+
+    /// `{variant: self}`, the way serde tags enums by default.
+    fn tagged(self, variant: &'static str) -> Value {
+        Value::Struct(vec![(variant.to_owned(), self)])
+    }
+}
+
+/// The type of a field, derived from the Rust type by [`trace`].
+#[derive(Clone, Debug, PartialEq)]
+enum Ty {
+    /// Unit types, and structs without fields.
+    Null,
+    Bool,
+    I8,
+    I16,
+    I32,
+    I64,
+    U8,
+    U16,
+    U32,
+    U64,
+    F32,
+    F64,
+    Str,
+    Bytes,
+    List(Box<Ty>),
+    Struct(Vec<(String, Ty)>),
+}
+
+impl Ty {
+    /// A type that can hold the values of both,
+    /// e.g. for the different variants of an enum, or the elements of a tuple.
+    fn merge(self, other: Ty) -> SerdeArrowResult<Self> {
+        Ok(match (self, other) {
+            (a, b) if a == b => a,
+            (Ty::Null, ty) | (ty, Ty::Null) => ty,
+            (a, b) if a.is_number() && b.is_number() => {
+                if a == Ty::F32 || a == Ty::F64 || b == Ty::F32 || b == Ty::F64 {
+                    Ty::F64
+                } else if a.is_unsigned() && b.is_unsigned() {
+                    Ty::U64
+                } else {
+                    Ty::I64
+                }
+            }
+            (Ty::List(a), Ty::List(b)) => Ty::List(Box::new(a.merge(*b)?)),
+            (Ty::Struct(mut fields), Ty::Struct(other_fields)) => {
+                for (name, ty) in other_fields {
+                    if let Some((_, field_ty)) = fields.iter_mut().find(|(n, _)| *n == name) {
+                        *field_ty = std::mem::replace(field_ty, Ty::Null).merge(ty)?;
+                    } else {
+                        fields.push((name, ty));
+                    }
+                }
+                Ty::Struct(fields)
+            }
+            (a, b) => return Err(SerdeArrowError::MismatchedTypes(a.name(), b.name())),
+        })
+    }
+
+    fn merge_all(tys: impl IntoIterator<Item = Ty>) -> SerdeArrowResult<Self> {
+        tys.into_iter().try_fold(Ty::Null, Ty::merge)
+    }
+
+    /// `{variant: contents}`, like [`Value::tagged`].
+    fn tagged(variant: &'static str, contents: Ty) -> Self {
+        Ty::Struct(vec![(variant.to_owned(), contents)])
+    }
+
+    /// The fields of a tuple struct or tuple variant are called `0`, `1`, etc.
+    fn tuple_struct(tys: Vec<Ty>) -> Self {
+        if tys.is_empty() {
+            Ty::Null
+        } else {
+            Ty::Struct(
+                tys.into_iter()
+                    .enumerate()
+                    .map(|(i, ty)| (i.to_string(), ty))
+                    .collect(),
+            )
+        }
+    }
+
+    fn is_unsigned(&self) -> bool {
+        matches!(self, Ty::U8 | Ty::U16 | Ty::U32 | Ty::U64)
+    }
+
+    fn is_number(&self) -> bool {
+        self.is_unsigned()
+            || matches!(
+                self,
+                Ty::I8 | Ty::I16 | Ty::I32 | Ty::I64 | Ty::F32 | Ty::F64
+            )
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Ty::Null => "null",
+            Ty::Bool => "booleans",
+            Ty::I8 | Ty::I16 | Ty::I32 | Ty::I64 => "signed integers",
+            Ty::U8 | Ty::U16 | Ty::U32 | Ty::U64 => "unsigned integers",
+            Ty::F32 | Ty::F64 => "floats",
+            Ty::Str => "strings",
+            Ty::Bytes => "bytes",
+            Ty::List(_) => "lists",
+            Ty::Struct(_) => "structs",
+        }
+    }
+
+    fn data_type(&self) -> DataType {
+        match self {
+            Ty::Null => DataType::Null,
+            Ty::Bool => DataType::Boolean,
+            Ty::I8 => DataType::Int8,
+            Ty::I16 => DataType::Int16,
+            Ty::I32 => DataType::Int32,
+            Ty::I64 => DataType::Int64,
+            Ty::U8 => DataType::UInt8,
+            Ty::U16 => DataType::UInt16,
+            Ty::U32 => DataType::UInt32,
+            Ty::U64 => DataType::UInt64,
+            Ty::F32 => DataType::Float32,
+            Ty::F64 => DataType::Float64,
+            Ty::Str => DataType::Utf8,
+            Ty::Bytes => DataType::Binary,
+            Ty::List(item) => ListArray::<i32>::default_datatype(item.data_type()),
+            Ty::Struct(fields) => DataType::Struct(
+                fields
+                    .iter()
+                    .map(|(name, ty)| Field::new(name, ty.data_type(), true))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+fn validity(values: &[&Value]) -> Option<Bitmap> {
+    values
+        .iter()
+        .any(|value| matches!(value, Value::Null))
+        .then(|| Bitmap::from_iter(values.iter().map(|value| !matches!(value, Value::Null))))
+}
+
+/// Integers that don't fit in `T` can only come from a type with a different
+/// [`Serialize`] and [`Deserialize`] implementation.
+fn ints<T: NativeType + TryFrom<i64> + TryFrom<u64>>(
+    values: &[&Value],
+) -> SerdeArrowResult<Box<dyn Array>> {
+    let out_of_range = |value: &dyn ToString| {
+        SerdeArrowError::OutOfRange(value.to_string(), std::any::type_name::<T>())
+    };
+    let ints = values
+        .iter()
+        .map(|value| match value {
+            Value::I64(i) => T::try_from(*i).map(Some).map_err(|_err| out_of_range(i)),
+            Value::U64(u) => T::try_from(*u).map(Some).map_err(|_err| out_of_range(u)),
+            _ => Ok(None),
+        })
+        .collect::<SerdeArrowResult<Vec<_>>>()?;
+    Ok(PrimitiveArray::<T>::from(ints).boxed())
+}
+
+fn floats<T: NativeType>(values: &[&Value], from_f64: impl Fn(f64) -> T) -> Box<dyn Array> {
+    PrimitiveArray::<T>::from(
+        values
+            .iter()
+            .map(|value| match value {
+                Value::F64(f) => Some(from_f64(*f)),
+                Value::I64(i) => Some(from_f64(*i as f64)),
+                Value::U64(u) => Some(from_f64(*u as f64)),
+                _ => None,
+            })
+            .collect::<Vec<_>>(),
+    )
+    .boxed()
+}
+
+/// Values of type `ty` to an arrow array. Values of other types become nulls.
+fn to_array(ty: &Ty, values: &[&Value]) -> SerdeArrowResult<Box<dyn Array>> {
+    Ok(match ty {
+        Ty::Null => NullArray::new(DataType::Null, values.len()).boxed(),
+        Ty::Bool => BooleanArray::from(
+            values
+                .iter()
+                .map(|value| match value {
+                    Value::Bool(b) => Some(*b),
+                    _ => None,
+                })
+                .collect::<Vec<_>>(),
+        )
+        .boxed(),
+        Ty::I8 => ints::<i8>(values)?,
+        Ty::I16 => ints::<i16>(values)?,
+        Ty::I32 => ints::<i32>(values)?,
+        Ty::I64 => ints::<i64>(values)?,
+        Ty::U8 => ints::<u8>(values)?,
+        Ty::U16 => ints::<u16>(values)?,
+        Ty::U32 => ints::<u32>(values)?,
+        Ty::U64 => ints::<u64>(values)?,
+        Ty::F32 => floats(values, |f| f as f32),
+        Ty::F64 => floats(values, |f| f),
+        Ty::Str => Utf8Array::<i32>::from(
+            values
+                .iter()
+                .map(|value| match value {
+                    Value::Str(s) => Some(s.as_str()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>(),
+        )
+        .boxed(),
+        Ty::Bytes => BinaryArray::<i32>::from(
+            values
+                .iter()
+                .map(|value| match value {
+                    Value::Bytes(b) => Some(b.as_slice()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>(),
+        )
+        .boxed(),
+        Ty::List(item_ty) => {
+            let offsets =
+                Offsets::try_from_lengths(values.iter().map(|value| value.items().len()))?;
+            let items = values
+                .iter()
+                .flat_map(|value| value.items())
+                .collect::<Vec<_>>();
+            ListArray::<i32>::new(
+                ty.data_type(),
+                offsets.into(),
+                to_array(item_ty, &items)?,
+                validity(values),
+            )
+            .boxed()
+        }
+        Ty::Struct(fields) => {
+            let children = fields
+                .iter()
+                .map(|(name, field_ty)| {
+                    let field_values = values
+                        .iter()
+                        .map(|value| value.field(name))
+                        .collect::<Vec<_>>();
+                    to_array(field_ty, &field_values)
+                })
+                .collect::<SerdeArrowResult<Vec<_>>>()?;
+            StructArray::new(ty.data_type(), children, validity(values)).boxed()
+        }
+    })
+}
+
+// ----------------------------------------------------------------------------
+
+/// Turns anything serializable into a [`Value`].
+struct ValueSerializer;
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = Value;
+    type Error = SerdeArrowError;
+
+    type SerializeSeq = ListSerializer;
+    type SerializeTuple = ListSerializer;
+    type SerializeTupleStruct = StructSerializer;
+    type SerializeTupleVariant = StructSerializer;
+    type SerializeMap = MapSerializer;
+    type SerializeStruct = StructSerializer;
+    type SerializeStructVariant = StructSerializer;
+
+    fn serialize_bool(self, v: bool) -> SerdeArrowResult<Value> {
+        Ok(Value::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> SerdeArrowResult<Value> {
+        Ok(Value::I64(v.into()))
+    }
+
+    fn serialize_i16(self, v: i16) -> SerdeArrowResult<Value> {
+        Ok(Value::I64(v.into()))
+    }
+
+    fn serialize_i32(self, v: i32) -> SerdeArrowResult<Value> {
+        Ok(Value::I64(v.into()))
+    }
+
+    fn serialize_i64(self, v: i64) -> SerdeArrowResult<Value> {
+        Ok(Value::I64(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> SerdeArrowResult<Value> {
+        Ok(Value::U64(v.into()))
+    }
+
+    fn serialize_u16(self, v: u16) -> SerdeArrowResult<Value> {
+        Ok(Value::U64(v.into()))
+    }
+
+    fn serialize_u32(self, v: u32) -> SerdeArrowResult<Value> {
+        Ok(Value::U64(v.into()))
+    }
+
+    fn serialize_u64(self, v: u64) -> SerdeArrowResult<Value> {
+        Ok(Value::U64(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> SerdeArrowResult<Value> {
+        Ok(Value::F64(v.into()))
+    }
+
+    fn serialize_f64(self, v: f64) -> SerdeArrowResult<Value> {
+        Ok(Value::F64(v))
+    }
+
+    fn serialize_char(self, v: char) -> SerdeArrowResult<Value> {
+        Ok(Value::Str(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> SerdeArrowResult<Value> {
+        Ok(Value::Str(v.to_owned()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> SerdeArrowResult<Value> {
+        Ok(Value::Bytes(v.to_vec()))
+    }
+
+    fn serialize_none(self) -> SerdeArrowResult<Value> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> SerdeArrowResult<Value> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> SerdeArrowResult<Value> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> SerdeArrowResult<Value> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> SerdeArrowResult<Value> {
+        Ok(Value::Str(variant.to_owned()))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> SerdeArrowResult<Value> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> SerdeArrowResult<Value> {
+        Ok(value.serialize(self)?.tagged(variant))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> SerdeArrowResult<ListSerializer> {
+        Ok(ListSerializer {
+            items: Vec::with_capacity(len.unwrap_or_default()),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> SerdeArrowResult<ListSerializer> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> SerdeArrowResult<StructSerializer> {
+        Ok(StructSerializer::new(None, len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> SerdeArrowResult<StructSerializer> {
+        Ok(StructSerializer::new(Some(variant), len))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> SerdeArrowResult<MapSerializer> {
+        Ok(MapSerializer {
+            entries: Vec::with_capacity(len.unwrap_or_default()),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> SerdeArrowResult<StructSerializer> {
+        Ok(StructSerializer::new(None, len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> SerdeArrowResult<StructSerializer> {
+        Ok(StructSerializer::new(Some(variant), len))
+    }
+}
+
+/// Sequences and tuples.
+struct ListSerializer {
+    items: Vec<Value>,
+}
+
+impl ser::SerializeSeq for ListSerializer {
+    type Ok = Value;
+    type Error = SerdeArrowError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> SerdeArrowResult<()> {
+        self.items.push(value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> SerdeArrowResult<Value> {
+        Ok(Value::List(self.items))
+    }
+}
+
+impl ser::SerializeTuple for ListSerializer {
+    type Ok = Value;
+    type Error = SerdeArrowError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> SerdeArrowResult<()> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> SerdeArrowResult<Value> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+/// Structs, tuple structs, and the struct and tuple variants of enums.
+///
+/// The fields of tuple structs are called `0`, `1`, etc.
+struct StructSerializer {
+    variant: Option<&'static str>,
+    fields: Vec<(String, Value)>,
+}
+
+impl StructSerializer {
+    fn new(variant: Option<&'static str>, len: usize) -> Self {
+        Self {
+            variant,
+            fields: Vec::with_capacity(len),
+        }
+    }
+
+    fn push(&mut self, name: String, value: &(impl ?Sized + Serialize)) -> SerdeArrowResult<()> {
+        self.fields.push((name, value.serialize(ValueSerializer)?));
+        Ok(())
+    }
+
+    fn push_unnamed(&mut self, value: &(impl ?Sized + Serialize)) -> SerdeArrowResult<()> {
+        self.push(self.fields.len().to_string(), value)
+    }
+
+    fn end(self) -> SerdeArrowResult<Value> {
+        // Arrow structs need at least one field.
+        let value = if self.fields.is_empty() {
+            Value::Null
+        } else {
+            Value::Struct(self.fields)
+        };
+        Ok(match self.variant {
+            Some(variant) => value.tagged(variant),
+            None => value,
+        })
+    }
+}
+
+impl ser::SerializeStruct for StructSerializer {
+    type Ok = Value;
+    type Error = SerdeArrowError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> SerdeArrowResult<()> {
+        self.push(key.to_owned(), value)
+    }
+
+    fn end(self) -> SerdeArrowResult<Value> {
+        StructSerializer::end(self)
+    }
+}
+
+impl ser::SerializeStructVariant for StructSerializer {
+    type Ok = Value;
+    type Error = SerdeArrowError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> SerdeArrowResult<()> {
+        self.push(key.to_owned(), value)
+    }
+
+    fn end(self) -> SerdeArrowResult<Value> {
+        StructSerializer::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for StructSerializer {
+    type Ok = Value;
+    type Error = SerdeArrowError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> SerdeArrowResult<()> {
+        self.push_unnamed(value)
+    }
+
+    fn end(self) -> SerdeArrowResult<Value> {
+        StructSerializer::end(self)
+    }
+}
+
+impl ser::SerializeTupleVariant for StructSerializer {
+    type Ok = Value;
+    type Error = SerdeArrowError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> SerdeArrowResult<()> {
+        self.push_unnamed(value)
+    }
+
+    fn end(self) -> SerdeArrowResult<Value> {
+        StructSerializer::end(self)
+    }
+}
+
+/// Maps, as lists of `{key, value}` structs.
+struct MapSerializer {
+    entries: Vec<Value>,
+    key: Option<Value>,
+}
+
+impl ser::SerializeMap for MapSerializer {
+    type Ok = Value;
+    type Error = SerdeArrowError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> SerdeArrowResult<()> {
+        self.key = Some(key.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> SerdeArrowResult<()> {
+        let key = self.key.take().unwrap_or(Value::Null);
+        self.entries.push(Value::Struct(vec![
+            ("key".to_owned(), key),
+            ("value".to_owned(), value.serialize(ValueSerializer)?),
+        ]));
+        Ok(())
+    }
+
+    fn end(self) -> SerdeArrowResult<Value> {
+        Ok(Value::List(self.entries))
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// How deep types can nest before we assume they are recursive.
+const MAX_DEPTH: usize = 64;
+
+/// Derives the arrow type of `T` from its [`Deserialize`] implementation,
+/// by deserializing made-up values and recording what gets asked for.
+///
+/// Each pass picks a different variant of every enum, until all variants have been seen.
+fn trace<T: Deserialize<'static>>() -> SerdeArrowResult<Ty> {
+    let mut ty = Ty::Null;
+    let mut pass = 0;
+    loop {
+        let mut num_variants = 0;
+        let mut pass_ty = Ty::Null;
+        T::deserialize(Tracer {
+            ty: &mut pass_ty,
+            cx: TraceContext {
+                pass,
+                num_variants: &mut num_variants,
+                depth: 0,
+            },
+        })?;
+        ty = ty.merge(pass_ty)?;
+
+        pass += 1;
+        if pass >= num_variants {
+            return Ok(ty);
+        }
+    }
+}
+
+/// What all the [`Tracer`]s of one pass share.
+struct TraceContext<'a> {
+    pass: usize,
+
+    /// The most variants of any enum we've come across.
+    num_variants: &'a mut usize,
+
+    depth: usize,
+}
+
+impl<'a> TraceContext<'a> {
+    /// A [`Tracer`] for something nested in what we are tracing.
+    fn tracer<'b>(&'b mut self, ty: &'b mut Ty) -> SerdeArrowResult<Tracer<'b>> {
+        if self.depth >= MAX_DEPTH {
+            return Err(SerdeArrowError::Recursive);
+        }
+        Ok(Tracer {
+            ty,
+            cx: TraceContext {
+                pass: self.pass,
+                num_variants: &mut *self.num_variants,
+                depth: self.depth + 1,
+            },
+        })
+    }
+
+    /// Visit a sequence of `len` elements, returning their types.
+    fn seq<V: Visitor<'static>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> SerdeArrowResult<(V::Value, Vec<Ty>)> {
+        let mut seq = SeqTracer {
+            cx: self,
+            len,
+            tys: Vec::with_capacity(len),
+        };
+        let value = visitor.visit_seq(&mut seq)?;
+        Ok((value, seq.tys))
+    }
+
+    /// Visit a struct with these fields, returning its type.
+    fn fields<V: Visitor<'static>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> SerdeArrowResult<(V::Value, Ty)> {
+        let mut map = StructTracer {
+            cx: self,
+            fields,
+            tys: Vec::with_capacity(fields.len()),
+        };
+        let value = visitor.visit_map(&mut map)?;
+        let ty = if map.tys.is_empty() {
+            Ty::Null // Arrow structs need at least one field.
+        } else {
+            Ty::Struct(
+                fields
+                    .iter()
+                    .map(|name| (*name).to_owned())
+                    .zip(map.tys)
+                    .collect(),
+            )
+        };
+        Ok((value, ty))
+    }
+}
+
+/// Records the type of whatever it deserializes into [`Self::ty`].
+struct Tracer<'a> {
+    ty: &'a mut Ty,
+    cx: TraceContext<'a>,
+}
+
+impl<'a> Tracer<'a> {
+    fn leaf(self, ty: Ty) {
+        *self.ty = ty;
+    }
+}
+
+impl<'a> de::Deserializer<'static> for Tracer<'a> {
+    type Error = SerdeArrowError;
+
+    fn deserialize_any<V: Visitor<'static>>(self, _visitor: V) -> SerdeArrowResult<V::Value> {
+        Err(SerdeArrowError::Untraceable)
+    }
+
+    fn deserialize_bool<V: Visitor<'static>>(self, visitor: V) -> SerdeArrowResult<V::Value> {
+        self.leaf(Ty::Bool);
+        visitor.visit_bool(false)
+    }
+
+    fn deserialize_i8<V: Visitor<'static>>(self, visitor: V) -> SerdeArrowResult<V::Value> {
+        self.leaf(Ty::I8);
+        visitor.visit_i8(0)
+    }
+
+    fn deserialize_i16<V: Visitor<'static>>(self, visitor: V) -> SerdeArrowResult<V::Value> {
+        self.leaf(Ty::I16);
+        visitor.visit_i16(0)
+    }
+
+    fn deserialize_i32<V: Visitor<'static>>(self, visitor: V) -> SerdeArrowResult<V::Value> {
+        self.leaf(Ty::I32);
+        visitor.visit_i32(0)
+    }
+
+    fn deserialize_i64<V: Visitor<'static>>(self, visitor: V) -> SerdeArrowResult<V::Value> {
+        self.leaf(Ty::I64);
+        visitor.visit_i64(0)
+    }
+
+    fn deserialize_u8<V: Visitor<'static>>(self, visitor: V) -> SerdeArrowResult<V::Value> {
+        self.leaf(Ty::U8);
+        visitor.visit_u8(0)
+    }
+
+    fn deserialize_u16<V: Visitor<'static>>(self, visitor: V) -> SerdeArrowResult<V::Value> {
+        self.leaf(Ty::U16);
+        visitor.visit_u16(0)
+    }
+
+    fn deserialize_u32<V: Visitor<'static>>(self, visitor: V) -> SerdeArrowResult<V::Value> {
+        self.leaf(Ty::U32);
+        visitor.visit_u32(0)
+    }
+
+    fn deserialize_u64<V: Visitor<'static>>(self, visitor: V) -> SerdeArrowResult<V::Value> {
+        self.leaf(Ty::U64);
+        visitor.visit_u64(0)
+    }
+
+    fn deserialize_f32<V: Visitor<'static>>(self, visitor: V) -> SerdeArrowResult<V::Value> {
+        self.leaf(Ty::F32);
+        visitor.visit_f32(0.0)
+    }
+
+    fn deserialize_f64<V: Visitor<'static>>(self, visitor: V) -> SerdeArrowResult<V::Value> {
+        self.leaf(Ty::F64);
+        visitor.visit_f64(0.0)
+    }
+
+    fn deserialize_char<V: Visitor<'static>>(self, visitor: V) -> SerdeArrowResult<V::Value> {
+        self.leaf(Ty::Str);
+        visitor.visit_char(' ')
+    }
+
+    fn deserialize_str<V: Visitor<'static>>(self, visitor: V) -> SerdeArrowResult<V::Value> {
+        self.leaf(Ty::Str);
+        visitor.visit_borrowed_str("")
+    }
+
+    fn deserialize_string<V: Visitor<'static>>(self, visitor: V) -> SerdeArrowResult<V::Value> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'static>>(self, visitor: V) -> SerdeArrowResult<V::Value> {
+        self.leaf(Ty::Bytes);
+        visitor.visit_borrowed_bytes(&[])
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'static>>(self, visitor: V) -> SerdeArrowResult<V::Value> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'static>>(mut self, visitor: V) -> SerdeArrowResult<V::Value> {
+        // Everything is nullable anyway, so an option has the type of its contents.
+        let value = visitor.visit_some(self.cx.tracer(self.ty)?)?;
+        Ok(value)
+    }
+
+    fn deserialize_unit<V: Visitor<'static>>(self, visitor: V) -> SerdeArrowResult<V::Value> {
+        self.leaf(Ty::Null);
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'static>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> SerdeArrowResult<V::Value> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'static>>(
+        mut self,
+        _name: &'static str,
+        visitor: V,
+    ) -> SerdeArrowResult<V::Value> {
+        let value = visitor.visit_newtype_struct(self.cx.tracer(self.ty)?)?;
+        Ok(value)
+    }
+
+    fn deserialize_seq<V: Visitor<'static>>(self, visitor: V) -> SerdeArrowResult<V::Value> {
+        // One element is enough to know the type of all of them.
+        let (value, tys) = self.cx.seq(1, visitor)?;
+        *self.ty = Ty::List(Box::new(Ty::merge_all(tys)?));
+        Ok(value)
+    }
+
+    fn deserialize_tuple<V: Visitor<'static>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> SerdeArrowResult<V::Value> {
+        let (value, tys) = self.cx.seq(len, visitor)?;
+        *self.ty = Ty::List(Box::new(Ty::merge_all(tys)?));
+        Ok(value)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'static>>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> SerdeArrowResult<V::Value> {
+        let (value, tys) = self.cx.seq(len, visitor)?;
+        *self.ty = Ty::tuple_struct(tys);
+        Ok(value)
+    }
+
+    fn deserialize_map<V: Visitor<'static>>(self, visitor: V) -> SerdeArrowResult<V::Value> {
+        let mut map = MapTracer {
+            cx: self.cx,
+            key: None,
+            value: None,
+        };
+        let value = visitor.visit_map(&mut map)?;
+        *self.ty = Ty::List(Box::new(Ty::Struct(vec![
+            ("key".to_owned(), map.key.unwrap_or(Ty::Null)),
+            ("value".to_owned(), map.value.unwrap_or(Ty::Null)),
+        ])));
+        Ok(value)
+    }
+
+    fn deserialize_struct<V: Visitor<'static>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> SerdeArrowResult<V::Value> {
+        let (value, ty) = self.cx.fields(fields, visitor)?;
+        *self.ty = ty;
+        Ok(value)
+    }
+
+    fn deserialize_enum<V: Visitor<'static>>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> SerdeArrowResult<V::Value> {
+        *self.cx.num_variants = (*self.cx.num_variants).max(variants.len());
+        let Some(variant) = variants.get(self.cx.pass.min(variants.len().saturating_sub(1))) else {
+            return Err(SerdeArrowError::Custom("enum without variants".to_owned()));
+        };
+        visitor.visit_enum(EnumTracer {
+            ty: self.ty,
+            cx: self.cx,
+            variant,
+        })
+    }
+
+    fn deserialize_identifier<V: Visitor<'static>>(self, visitor: V) -> SerdeArrowResult<V::Value> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'static>>(
+        self,
+        visitor: V,
+    ) -> SerdeArrowResult<V::Value> {
+        self.deserialize_unit(visitor)
+    }
+}
+
+/// Sequences and tuples.
+struct SeqTracer<'a> {
+    cx: TraceContext<'a>,
+    len: usize,
+    tys: Vec<Ty>,
+}
+
+impl<'a> de::SeqAccess<'static> for SeqTracer<'a> {
+    type Error = SerdeArrowError;
+
+    fn next_element_seed<S: de::DeserializeSeed<'static>>(
+        &mut self,
+        seed: S,
+    ) -> SerdeArrowResult<Option<S::Value>> {
+        if self.tys.len() == self.len {
+            return Ok(None);
+        }
+        let mut ty = Ty::Null;
+        let value = seed.deserialize(self.cx.tracer(&mut ty)?)?;
+        self.tys.push(ty);
+        Ok(Some(value))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len - self.tys.len())
+    }
+}
+
+/// Structs, and the struct variants of enums.
+struct StructTracer<'a> {
+    cx: TraceContext<'a>,
+    fields: &'static [&'static str],
+    tys: Vec<Ty>,
+}
+
+impl<'a> de::MapAccess<'static> for StructTracer<'a> {
+    type Error = SerdeArrowError;
+
+    fn next_key_seed<S: de::DeserializeSeed<'static>>(
+        &mut self,
+        seed: S,
+    ) -> SerdeArrowResult<Option<S::Value>> {
+        let Some(field) = self.fields.get(self.tys.len()) else {
+            return Ok(None);
+        };
+        seed.deserialize(StrDeserializer::<SerdeArrowError>::new(field))
+            .map(Some)
+    }
+
+    fn next_value_seed<S: de::DeserializeSeed<'static>>(
+        &mut self,
+        seed: S,
+    ) -> SerdeArrowResult<S::Value> {
+        let mut ty = Ty::Null;
+        let value = seed.deserialize(self.cx.tracer(&mut ty)?)?;
+        self.tys.push(ty);
+        Ok(value)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.fields.len() - self.tys.len())
+    }
+}
+
+/// Maps, with a single entry to learn the types of the keys and values from.
+struct MapTracer<'a> {
+    cx: TraceContext<'a>,
+    key: Option<Ty>,
+    value: Option<Ty>,
+}
+
+impl<'a> de::MapAccess<'static> for MapTracer<'a> {
+    type Error = SerdeArrowError;
+
+    fn next_key_seed<S: de::DeserializeSeed<'static>>(
+        &mut self,
+        seed: S,
+    ) -> SerdeArrowResult<Option<S::Value>> {
+        if self.key.is_some() {
+            return Ok(None);
+        }
+        let mut ty = Ty::Null;
+        let key = seed.deserialize(self.cx.tracer(&mut ty)?)?;
+        self.key = Some(ty);
+        Ok(Some(key))
+    }
+
+    fn next_value_seed<S: de::DeserializeSeed<'static>>(
+        &mut self,
+        seed: S,
+    ) -> SerdeArrowResult<S::Value> {
+        let mut ty = Ty::Null;
+        let value = seed.deserialize(self.cx.tracer(&mut ty)?)?;
+        self.value = Some(ty);
+        Ok(value)
+    }
+}
+
+/// One variant of an enum, chosen by [`TraceContext::pass`].
+///
+/// Unit variants are strings, the others `{variant: contents}` structs,
+/// matching [`ValueSerializer`].
+struct EnumTracer<'a> {
+    ty: &'a mut Ty,
+    cx: TraceContext<'a>,
+    variant: &'static str,
+}
+
+impl<'a> de::EnumAccess<'static> for EnumTracer<'a> {
+    type Error = SerdeArrowError;
+    type Variant = Self;
+
+    fn variant_seed<S: de::DeserializeSeed<'static>>(
+        self,
+        seed: S,
+    ) -> SerdeArrowResult<(S::Value, Self)> {
+        let variant = seed.deserialize(StrDeserializer::<SerdeArrowError>::new(self.variant))?;
+        Ok((variant, self))
+    }
+}
+
+impl<'a> de::VariantAccess<'static> for EnumTracer<'a> {
+    type Error = SerdeArrowError;
+
+    fn unit_variant(self) -> SerdeArrowResult<()> {
+        *self.ty = Ty::Str;
+        Ok(())
+    }
+
+    fn newtype_variant_seed<S: de::DeserializeSeed<'static>>(
+        mut self,
+        seed: S,
+    ) -> SerdeArrowResult<S::Value> {
+        let mut contents = Ty::Null;
+        let value = seed.deserialize(self.cx.tracer(&mut contents)?)?;
+        *self.ty = Ty::tagged(self.variant, contents);
+        Ok(value)
+    }
+
+    fn tuple_variant<V: Visitor<'static>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> SerdeArrowResult<V::Value> {
+        let (value, tys) = self.cx.seq(len, visitor)?;
+        *self.ty = Ty::tagged(self.variant, Ty::tuple_struct(tys));
+        Ok(value)
+    }
+
+    fn struct_variant<V: Visitor<'static>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> SerdeArrowResult<V::Value> {
+        let (value, contents) = self.cx.fields(fields, visitor)?;
+        *self.ty = Ty::tagged(self.variant, contents);
+        Ok(value)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[test]
+fn test_try_to_arrow() {
+    use std::collections::BTreeMap;
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    enum Mode {
+        Manual,
+        Auto,
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Config {
+        name: String,
+        gains: [f32; 3],
+        limit: Option<u32>,
+        mode: Mode,
+        tags: BTreeMap<String, i32>,
+    }
+
+    let configs = [
+        Config {
+            name: "a".to_owned(),
+            gains: [1.0, 2.0, 3.0],
+            limit: None,
+            mode: Mode::Manual,
+            tags: BTreeMap::new(),
+        },
+        Config {
+            name: "b".to_owned(),
+            gains: [4.0, 5.0, 6.0],
+            limit: Some(7),
+            mode: Mode::Auto,
+            tags: [("x".to_owned(), -1)].into(),
+        },
+    ];
+    let array = try_to_arrow(&configs).unwrap();
+    assert_eq!(array.len(), 2);
+
+    let DataType::Struct(fields) = array.data_type() else {
+        panic!("expected a struct, got {:?}", array.data_type());
+    };
+    let field_types = fields
+        .iter()
+        .map(|field| (field.name.as_str(), field.data_type.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        field_types,
+        vec![
+            ("name", DataType::Utf8),
+            (
+                "gains",
+                ListArray::<i32>::default_datatype(DataType::Float32)
+            ),
+            ("limit", DataType::UInt32),
+            ("mode", DataType::Utf8),
+            (
+                "tags",
+                ListArray::<i32>::default_datatype(DataType::Struct(vec![
+                    Field::new("key", DataType::Utf8, true),
+                    Field::new("value", DataType::Int32, true),
+                ]))
+            ),
+        ]
+    );
+
+    let limits = array
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap()
+        .values()[2]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<u32>>()
+        .unwrap()
+        .clone();
+    assert_eq!(limits, PrimitiveArray::<u32>::from([None, Some(7)]));
+}
+
+#[test]
+fn test_data_type_only_depends_on_type() {
+    #[derive(serde::Serialize, serde::Deserialize)]
+    enum Shape {
+        Circle { radius: f32 },
+        Polygon(Vec<[f32; 2]>),
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Annotation {
+        shape: Option<Shape>,
+        labels: Vec<String>,
+    }
+
+    let empty = Annotation {
+        shape: None,
+        labels: Vec::new(),
+    };
+    let circle = Annotation {
+        shape: Some(Shape::Circle { radius: 1.0 }),
+        labels: vec!["round".to_owned()],
+    };
+    let polygon = Annotation {
+        shape: Some(Shape::Polygon(vec![[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]])),
+        labels: Vec::new(),
+    };
+
+    let data_type = try_to_arrow(&[empty]).unwrap().data_type().clone();
+    assert_eq!(
+        data_type,
+        DataType::Struct(vec![
+            Field::new(
+                "shape",
+                DataType::Struct(vec![
+                    Field::new(
+                        "Circle",
+                        DataType::Struct(vec![Field::new("radius", DataType::Float32, true)]),
+                        true
+                    ),
+                    Field::new(
+                        "Polygon",
+                        ListArray::<i32>::default_datatype(ListArray::<i32>::default_datatype(
+                            DataType::Float32
+                        )),
+                        true
+                    ),
+                ]),
+                true
+            ),
+            Field::new(
+                "labels",
+                ListArray::<i32>::default_datatype(DataType::Utf8),
+                true
+            ),
+        ])
+    );
+    assert_eq!(try_to_arrow(&[circle]).unwrap().data_type(), &data_type);
+    assert_eq!(try_to_arrow(&[polygon]).unwrap().data_type(), &data_type);
+}
+
+#[test]
+fn test_untraceable_types() {
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(untagged)]
+    enum Either {
+        Number(f64),
+        Text(String),
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Tree {
+        children: Vec<Tree>,
+    }
+
+    assert!(matches!(
+        try_to_arrow(&[(1.0, "one".to_owned())]),
+        Err(SerdeArrowError::MismatchedTypes("floats", "strings"))
+    ));
+    assert!(matches!(
+        try_to_arrow(&[Either::Number(1.0)]),
+        Err(SerdeArrowError::Untraceable)
+    ));
+    assert!(matches!(
+        try_to_arrow(&[Tree {
+            children: Vec::new()
+        }]),
+        Err(SerdeArrowError::Recursive)
+    ));
+}
@@ -77,6 +77,21 @@ impl Timeline {
         Timeline::new("log_time", TimeType::Time)
     }
 
+    /// The log tick timeline to which all API functions will always log.
+    ///
+    /// Counts up by one for every logging call, so it preserves the order of calls
+    /// that happen within the same [`Self::log_time`].
+    #[inline]
+    pub fn log_tick() -> Self {
+        Timeline::new("log_tick", TimeType::Sequence)
+    }
+
+    /// Is this one of the timelines that the SDKs log to automatically?
+    #[inline]
+    pub fn is_automatic(&self) -> bool {
+        *self == Self::log_time() || *self == Self::log_tick()
+    }
+
     /// Returns a formatted string of `time_range` on this `Timeline`.
     #[inline]
     pub fn format_time_range(&self, time_range: &TimeRange) -> String {
@@ -7,6 +7,7 @@ fn test_transform_to_polars() {
         Some(Transform::Pinhole(Pinhole {
             image_from_cam: [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]].into(),
             resolution: None,
+            distortion: None,
         })),
         Some(Transform::Rigid3(Rigid3 {
             rotation: Quaternion {
@@ -20,6 +21,7 @@ fn test_transform_to_polars() {
         Some(Transform::Pinhole(Pinhole {
             image_from_cam: [[21.0, 22.0, 23.0], [24.0, 25.0, 26.0], [27.0, 28.0, 29.0]].into(),
             resolution: Some([123.0, 456.0].into()),
+            distortion: None,
         })),
     ];
 
@@ -175,6 +175,7 @@ impl RenderDepthClouds {
                 clouds: vec![DepthCloud {
                     world_from_obj,
                     depth_camera_intrinsics: *intrinsics,
+                    depth_camera_distortion: Default::default(),
                     world_depth_from_data_depth: 1.0,
                     point_radius_from_world_depth: *point_radius_from_world_depth,
                     max_depth_in_world: 5.0,
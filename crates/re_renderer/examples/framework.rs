@@ -349,6 +349,7 @@ pub fn load_rerun_mesh(re_ctx: &mut RenderContext) -> Vec<re_renderer::renderer:
     std::io::Read::read_to_end(&mut zipped_obj, &mut obj_data).unwrap();
     re_renderer::importer::obj::load_obj_from_buffer(
         &obj_data,
+        None,
         re_renderer::resource_managers::ResourceLifeTime::LongLived,
         re_ctx,
     )
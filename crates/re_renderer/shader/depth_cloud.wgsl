@@ -43,10 +43,26 @@ struct DepthCloudInfo {
     /// Configures color mapping mode, see `colormap.wgsl`.
     colormap: u32,
 
+    /// Radial lens distortion coefficients `k1..k4`.
+    distortion_radial: Vec4,
+
+    /// Tangential lens distortion coefficients `p1, p2`.
+    distortion_tangential: Vec2,
+
+    /// Lens distortion model, one of the `DISTORTION_MODEL_*` constants.
+    distortion_model: u32,
+
     /// Changes between the opaque and outline draw-phases.
     radius_boost_in_ui_points: f32,
 };
 
+const DISTORTION_MODEL_NONE: u32 = 0u;
+const DISTORTION_MODEL_BROWN_CONRADY: u32 = 1u;
+const DISTORTION_MODEL_FISHEYE: u32 = 2u;
+
+/// Keep in sync with `DepthCloudDistortion::UNDISTORT_ITERATIONS` in `depth_cloud.rs`.
+const UNDISTORT_ITERATIONS: i32 = 20;
+
 @group(1) @binding(0)
 var<uniform> depth_cloud_info: DepthCloudInfo;
 
@@ -75,6 +91,55 @@ struct VertexOut {
 
 // ---
 
+/// Maps a distorted normalized image coordinate to its undistorted position.
+///
+/// Keep in sync with `DepthCloudDistortion::undistort` in `depth_cloud.rs`.
+fn undistort(distorted: Vec2) -> Vec2 {
+    let k = depth_cloud_info.distortion_radial;
+
+    if depth_cloud_info.distortion_model == DISTORTION_MODEL_BROWN_CONRADY {
+        let p1 = depth_cloud_info.distortion_tangential.x;
+        let p2 = depth_cloud_info.distortion_tangential.y;
+        var p = distorted;
+        for (var i = 0; i < UNDISTORT_ITERATIONS; i += 1) {
+            let r2 = dot(p, p);
+            let radial = 1.0 + r2 * (k.x + r2 * (k.y + r2 * k.z));
+            if abs(radial) < f32eps {
+                break;
+            }
+            let delta = Vec2(
+                2.0 * p1 * p.x * p.y + p2 * (r2 + 2.0 * p.x * p.x),
+                p1 * (r2 + 2.0 * p.y * p.y) + 2.0 * p2 * p.x * p.y,
+            );
+            p = (distorted - delta) / radial;
+        }
+        return p;
+    }
+
+    if depth_cloud_info.distortion_model == DISTORTION_MODEL_FISHEYE {
+        let theta_d = length(distorted);
+        if theta_d < f32eps {
+            return distorted;
+        }
+        var theta = theta_d;
+        for (var i = 0; i < UNDISTORT_ITERATIONS; i += 1) {
+            let t2 = theta * theta;
+            let f = theta * (1.0 + t2 * (k.x + t2 * (k.y + t2 * (k.z + t2 * k.w)))) - theta_d;
+            let df = 1.0 + t2 * (3.0 * k.x + t2 * (5.0 * k.y + t2 * (7.0 * k.z + t2 * 9.0 * k.w)));
+            if abs(df) < f32eps {
+                break;
+            }
+            theta -= f / df;
+        }
+        // Clamp to just under 90° - everything beyond can't be expressed as a pinhole projection.
+        theta = clamp(theta, 0.0, 1.5706963); // pi/2 - 1e-4
+        return distorted * (tan(theta) / theta_d);
+    }
+
+    // DISTORTION_MODEL_NONE
+    return distorted;
+}
+
 struct PointData {
     pos_in_world: Vec3,
     unresolved_radius: f32,
@@ -95,15 +160,13 @@ fn compute_point_data(quad_idx: i32) -> PointData {
         // TODO(cmc): albedo textures
         let color = Vec4(colormap_linear(depth_cloud_info.colormap, world_space_depth / depth_cloud_info.max_depth_in_world), 1.0);
 
-        // TODO(cmc): This assumes a pinhole camera; need to support other kinds at some point.
+        // TODO(cmc): This assumes a pinhole camera (with optional lens distortion); need to support other kinds at some point.
         let intrinsics = depth_cloud_info.depth_camera_intrinsics;
         let focal_length = Vec2(intrinsics[0][0], intrinsics[1][1]);
         let offset = Vec2(intrinsics[2][0], intrinsics[2][1]);
 
-        let pos_in_obj = Vec3(
-            (Vec2(texcoords) - offset) * world_space_depth / focal_length,
-            world_space_depth,
-        );
+        let normalized = undistort((Vec2(texcoords) - offset) / focal_length);
+        let pos_in_obj = Vec3(normalized * world_space_depth, world_space_depth);
 
         let pos_in_world = depth_cloud_info.world_from_obj * Vec4(pos_in_obj, 1.0);
 
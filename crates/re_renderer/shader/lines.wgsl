@@ -23,6 +23,8 @@ struct DrawDataUniformBuffer {
 };
 @group(1) @binding(3)
 var<uniform> draw_data: DrawDataUniformBuffer;
+@group(1) @binding(4)
+var vertex_attribute_texture: texture_2d<u32>;
 
 struct BatchUniformBuffer {
     world_from_obj: Mat4,
@@ -48,6 +50,11 @@ const CAP_START_ROUND: u32 = 16u;
 const CAP_START_EXTEND_OUTWARDS: u32 = 32u;
 const NO_COLOR_GRADIENT: u32 = 64u;
 
+// Vertex flags
+// See lines.rs#LineVertexFlags
+const VERTEX_COLOR: u32 = 1u;
+const VERTEX_RADIUS: u32 = 2u;
+
 // A lot of the attributes don't need to be interpolated across triangles.
 // To document that and safe some time we mark them up with @interpolate(flat)
 // (see https://www.w3.org/TR/WGSL/#interpolation)
@@ -55,7 +62,7 @@ struct VertexOut {
     @builtin(position)
     position: Vec4,
 
-    @location(0) @interpolate(flat)
+    @location(0) @interpolate(perspective)
     color: Vec4,
 
     @location(1) @interpolate(perspective)
@@ -64,7 +71,7 @@ struct VertexOut {
     @location(2) @interpolate(perspective)
     center_position: Vec3,
 
-    @location(3) @interpolate(flat)
+    @location(3) @interpolate(perspective)
     active_radius: f32,
 
     @location(4) @interpolate(perspective)
@@ -121,6 +128,27 @@ fn read_position_data(idx: u32) -> PositionData {
     return data;
 }
 
+struct VertexData {
+    color: Vec4,
+    unresolved_radius: f32,
+}
+
+// Read the color and radius at a given vertex, falling back to the ones of its strip.
+fn read_vertex_data(idx: u32, strip_data: LineStripData) -> VertexData {
+    // can be u32 once https://github.com/gfx-rs/naga/issues/1997 is solved
+    let idx = i32(idx);
+    let raw_data = textureLoad(vertex_attribute_texture, IVec2(idx % POSITION_TEXTURE_SIZE, idx / POSITION_TEXTURE_SIZE), 0).xy;
+
+    // raw_data.y packs { radius: float16, flags: u16 }
+    // See `gpu_data::LineVertexAttributes` in `lines.rs`
+    let flags = raw_data.y >> 16u;
+
+    var data: VertexData;
+    data.color = select(strip_data.color, linear_from_srgba(unpack4x8unorm_workaround(raw_data.x)), has_any_flag(flags, VERTEX_COLOR));
+    data.unresolved_radius = select(strip_data.unresolved_radius, unpack2x16float(raw_data.y).x, has_any_flag(flags, VERTEX_RADIUS));
+    return data;
+}
+
 @vertex
 fn vs_main(@builtin(vertex_index) vertex_idx: u32) -> VertexOut {
     //
@@ -172,10 +200,13 @@ fn vs_main(@builtin(vertex_index) vertex_idx: u32) -> VertexOut {
     //
     // For caps, we determine the "only valid one" (as one of them belongs to another strip)
     var pos_data_current: PositionData;
+    var pos_data_current_idx: u32;
     if (is_cap_triangle && is_right_triangle) || (!is_cap_triangle && !is_at_quad_end) {
         pos_data_current = pos_data_quad_begin;
+        pos_data_current_idx = pos_data_idx;
     } else {
         pos_data_current = pos_data_quad_end;
+        pos_data_current_idx = pos_data_idx + 1u;
     }
 
     // The closest "line strip skeleton" position to the current vertex.
@@ -185,6 +216,9 @@ fn vs_main(@builtin(vertex_index) vertex_idx: u32) -> VertexOut {
     // Data valid for the entire strip that this vertex belongs to.
     let strip_data = read_strip_data(pos_data_current.strip_index);
 
+    // Color and radius may vary along the strip and get interpolated between its vertices.
+    let vertex_data = read_vertex_data(pos_data_current_idx, strip_data);
+
     // Compute quad_dir & correct center_position for triangle caps.
     var quad_dir: Vec3;
     var is_at_pointy_end = false;
@@ -208,7 +242,7 @@ fn vs_main(@builtin(vertex_index) vertex_idx: u32) -> VertexOut {
     // (slight inaccuracy: End caps are going to adjust their center_position)
     let camera_ray = camera_ray_to_world_pos(center_position);
     let camera_distance = distance(camera_ray.origin, center_position);
-    var strip_radius = unresolved_size_to_world(strip_data.unresolved_radius, camera_distance, frame.auto_size_lines);
+    var strip_radius = unresolved_size_to_world(vertex_data.unresolved_radius, camera_distance, frame.auto_size_lines);
 
     // Make space for the end cap if this is either the cap itself or the cap follows right after/before this quad.
     if !has_any_flag(strip_data.flags, CAP_END_EXTEND_OUTWARDS) &&
@@ -267,7 +301,7 @@ fn vs_main(@builtin(vertex_index) vertex_idx: u32) -> VertexOut {
     out.position_world = pos;
     out.center_position = center_position;
     out.round_cap_circle_center = round_cap_circle_center;
-    out.color = strip_data.color;
+    out.color = vertex_data.color;
     out.active_radius = active_radius;
     out.fragment_flags = strip_data.flags &
                     (NO_COLOR_GRADIENT | (u32(is_cap_triangle) * select(CAP_START_ROUND, CAP_END_ROUND, is_right_triangle)));
@@ -6,14 +6,19 @@ use smallvec::smallvec;
 use crate::{
     mesh::{Material, Mesh},
     renderer::MeshInstance,
-    resource_managers::ResourceLifeTime,
+    resource_managers::{GpuTexture2DHandle, ResourceLifeTime},
     RenderContext, Rgba32Unmul,
 };
 
 /// Load a [Wavefront .obj file](https://en.wikipedia.org/wiki/Wavefront_.obj_file)
 /// into the mesh & texture manager.
+///
+/// Materials live in separate `.mtl` files that can't be resolved from a buffer.
+/// Instead, all meshes share the given albedo texture (typically the `map_Kd` of the material),
+/// or a white texture if there is none.
 pub fn load_obj_from_buffer(
     buffer: &[u8],
+    albedo_texture: Option<GpuTexture2DHandle>,
     lifetime: ResourceLifeTime,
     ctx: &mut RenderContext,
 ) -> anyhow::Result<Vec<MeshInstance>> {
@@ -30,6 +35,9 @@ pub fn load_obj_from_buffer(
     )
     .context("failed loading obj")?;
 
+    let albedo = albedo_texture
+        .unwrap_or_else(|| ctx.texture_manager_2d.white_texture_unorm_handle().clone());
+
     // TODO(andreas) Merge all obj meshes into a single re_renderer mesh with multiple materials.
     models
         .into_iter()
@@ -67,12 +75,11 @@ pub fn load_obj_from_buffer(
             let mut vertex_texcoords: Vec<glam::Vec2> = mesh
                 .texcoords
                 .chunks_exact(2)
-                .map(|t| glam::vec2(t[0], t[1]))
+                // OBJ texture coordinates start at the bottom left, ours at the top left.
+                .map(|t| glam::vec2(t[0], 1.0 - t[1]))
                 .collect();
             vertex_texcoords.resize(vertex_positions.len(), glam::Vec2::ZERO);
 
-            let texture = ctx.texture_manager_2d.white_texture_unorm_handle();
-
             let num_indices = mesh.indices.len();
 
             let mesh = Mesh {
@@ -83,11 +90,10 @@ pub fn load_obj_from_buffer(
                 vertex_normals,
                 vertex_texcoords,
 
-                // TODO(andreas): proper material loading
                 materials: smallvec![Material {
                     label: "default material".into(),
                     index_range: 0..num_indices as u32,
-                    albedo: texture.clone(),
+                    albedo: albedo.clone(),
                     albedo_multiplier: crate::Rgba::WHITE,
                 }],
             };
@@ -4,6 +4,7 @@ use crate::{
     allocator::CpuWriteGpuReadBuffer,
     renderer::{
         LineBatchInfo, LineDrawData, LineDrawDataError, LineStripFlags, LineStripInfo, LineVertex,
+        LineVertexAttributes, LineVertexFlags,
     },
     Color32, DebugLabel, OutlineMaskPreference, PickingLayerInstanceId, PickingLayerObjectId,
     RenderContext, Size,
@@ -18,6 +19,9 @@ use crate::{
 pub struct LineStripSeriesBuilder {
     pub vertices: Vec<LineVertex>,
 
+    /// Per-vertex color & radius, one for each of [`Self::vertices`].
+    pub vertex_attributes: Vec<LineVertexAttributes>,
+
     pub batches: Vec<LineBatchInfo>,
 
     pub strips: Vec<LineStripInfo>,
@@ -45,6 +49,7 @@ impl LineStripSeriesBuilder {
 
         Self {
             vertices: Vec::with_capacity(RESERVE_SIZE * 2),
+            vertex_attributes: Vec::with_capacity(RESERVE_SIZE * 2),
             strips: Vec::with_capacity(RESERVE_SIZE),
             batches: Vec::with_capacity(16),
             picking_instance_ids_buffer,
@@ -93,6 +98,19 @@ impl LineStripSeriesBuilder {
         })
     }
 
+    // Iterate over all batches, yielding the batch info and all line vertices, both mutable.
+    pub fn iter_vertices_by_batch_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (&mut LineBatchInfo, &mut [LineVertex])> {
+        let mut vertices = self.vertices.as_mut_slice();
+        self.batches.iter_mut().map(move |batch| {
+            let (batch_vertices, rest) =
+                std::mem::take(&mut vertices).split_at_mut(batch.line_vertex_count as usize);
+            vertices = rest;
+            (batch, batch_vertices)
+        })
+    }
+
     /// Finalizes the builder and returns a line draw data with all the lines added so far.
     pub fn to_draw_data(
         self,
@@ -133,6 +151,9 @@ impl<'a> LineBatchBuilder<'a> {
             position: pos,
             strip_index,
         }));
+        self.0
+            .vertex_attributes
+            .resize(self.0.vertices.len(), bytemuck::Zeroable::zeroed());
         self.batch_mut().line_vertex_count += (self.0.vertices.len() - old_len) as u32;
     }
 
@@ -390,6 +411,36 @@ impl<'a> LineStripBuilder<'a> {
         self
     }
 
+    /// Sets the radius at each vertex in this builder, interpolating along the segments in between.
+    ///
+    /// Vertices without a corresponding radius keep the one of their strip.
+    #[inline]
+    pub fn radii(self, radii: impl Iterator<Item = Size>) -> Self {
+        for (vertex, radius) in self.builder.vertex_attributes[self.vertex_range.clone()]
+            .iter_mut()
+            .zip(radii)
+        {
+            vertex.radius = radius.into();
+            vertex.flags = vertex.flags | LineVertexFlags::RADIUS;
+        }
+        self
+    }
+
+    /// Sets the color at each vertex in this builder, interpolating along the segments in between.
+    ///
+    /// Vertices without a corresponding color keep the one of their strip.
+    #[inline]
+    pub fn colors(self, colors: impl Iterator<Item = Color32>) -> Self {
+        for (vertex, color) in self.builder.vertex_attributes[self.vertex_range.clone()]
+            .iter_mut()
+            .zip(colors)
+        {
+            vertex.color = color;
+            vertex.flags = vertex.flags | LineVertexFlags::COLOR;
+        }
+        self
+    }
+
     #[inline]
     pub fn flags(self, flags: LineStripFlags) -> Self {
         for strip in self.builder.strips[self.strip_range.clone()].iter_mut() {
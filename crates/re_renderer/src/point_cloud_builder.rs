@@ -97,6 +97,19 @@ impl PointCloudBuilder {
         })
     }
 
+    // Iterate over all batches, yielding the batch info and the point vertices, both mutable.
+    pub fn iter_vertices_by_batch_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (&mut PointCloudBatchInfo, &mut [PointCloudVertex])> {
+        let mut vertices = self.vertices.as_mut_slice();
+        self.batches.iter_mut().map(move |batch| {
+            let (batch_vertices, rest) =
+                std::mem::take(&mut vertices).split_at_mut(batch.point_count as usize);
+            vertices = rest;
+            (batch, batch_vertices)
+        })
+    }
+
     /// Finalizes the builder and returns a point cloud draw data with all the points added so far.
     pub fn to_draw_data(
         self,
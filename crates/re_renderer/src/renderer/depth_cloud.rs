@@ -61,10 +61,19 @@ mod gpu_data {
         /// Which colormap should be used.
         pub colormap: u32,
 
+        /// Radial lens distortion coefficients `k1..k4`.
+        pub distortion_radial: wgpu_buffer_types::Vec4,
+
+        /// Tangential lens distortion coefficients `p1, p2`.
+        pub distortion_tangential: wgpu_buffer_types::Vec2,
+
+        /// Which lens distortion model should be used, see `depth_cloud.wgsl`.
+        pub distortion_model: u32,
+
         /// Changes over different draw-phases.
-        pub radius_boost_in_ui_points: wgpu_buffer_types::F32RowPadded,
+        pub radius_boost_in_ui_points: f32,
 
-        pub end_padding: [wgpu_buffer_types::PaddingRow; 16 - 4 - 3 - 1 - 1 - 1],
+        pub end_padding: [wgpu_buffer_types::PaddingRow; 16 - 4 - 3 - 1 - 1 - 1 - 1],
     }
 
     impl DepthCloudInfoUBO {
@@ -75,6 +84,7 @@ mod gpu_data {
             let super::DepthCloud {
                 world_from_obj,
                 depth_camera_intrinsics,
+                depth_camera_distortion,
                 world_depth_from_data_depth,
                 point_radius_from_world_depth,
                 max_depth_in_world,
@@ -92,6 +102,17 @@ mod gpu_data {
             let world_depth_from_texture_value =
                 world_depth_from_data_depth * user_depth_from_texture_value;
 
+            let (distortion_model, distortion_radial, distortion_tangential) =
+                match *depth_camera_distortion {
+                    super::DepthCloudDistortion::None => (0, glam::Vec4::ZERO, glam::Vec2::ZERO),
+                    super::DepthCloudDistortion::BrownConrady { radial, tangential } => {
+                        (1, radial.extend(0.0), tangential)
+                    }
+                    super::DepthCloudDistortion::Fisheye { radial } => {
+                        (2, radial, glam::Vec2::ZERO)
+                    }
+                };
+
             Self {
                 world_from_obj: (*world_from_obj).into(),
                 depth_camera_intrinsics: (*depth_camera_intrinsics).into(),
@@ -100,7 +121,10 @@ mod gpu_data {
                 point_radius_from_world_depth: *point_radius_from_world_depth,
                 max_depth_in_world: *max_depth_in_world,
                 colormap: *colormap as u32,
-                radius_boost_in_ui_points: radius_boost_in_ui_points.into(),
+                distortion_radial: distortion_radial.into(),
+                distortion_tangential: distortion_tangential.into(),
+                distortion_model,
+                radius_boost_in_ui_points,
                 picking_layer_object_id: *picking_object_id,
                 end_padding: Default::default(),
             }
@@ -134,6 +158,85 @@ impl Default for DepthCloudDepthData {
     }
 }
 
+/// Lens distortion of the camera used for the projection.
+///
+/// All coefficients operate on normalized image coordinates, i.e. `(pixel - principal_point) / focal_length`,
+/// and follow the OpenCV conventions.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum DepthCloudDistortion {
+    /// Ideal pinhole camera.
+    #[default]
+    None,
+
+    /// Brown-Conrady model with radial coefficients `k1, k2, k3` and tangential coefficients `p1, p2`.
+    BrownConrady {
+        radial: glam::Vec3,
+        tangential: glam::Vec2,
+    },
+
+    /// Fisheye (Kannala-Brandt) model with coefficients `k1, k2, k3, k4`.
+    Fisheye { radial: glam::Vec4 },
+}
+
+impl DepthCloudDistortion {
+    /// Number of iterations used to invert the distortion.
+    ///
+    /// Keep in sync with `depth_cloud.wgsl`.
+    const UNDISTORT_ITERATIONS: usize = 20;
+
+    /// Maps a distorted normalized image coordinate to its undistorted position.
+    ///
+    /// This is the only CPU implementation, keep it in sync with `undistort` in `depth_cloud.wgsl`.
+    pub fn undistort(&self, distorted: glam::Vec2) -> glam::Vec2 {
+        match *self {
+            Self::None => distorted,
+
+            Self::BrownConrady { radial, tangential } => {
+                let [k1, k2, k3] = radial.to_array();
+                let [p1, p2] = tangential.to_array();
+                let mut p = distorted;
+                // Fixed-point iteration, same as OpenCV's `undistortPoints`.
+                for _ in 0..Self::UNDISTORT_ITERATIONS {
+                    let r2 = p.length_squared();
+                    let radial = 1.0 + r2 * (k1 + r2 * (k2 + r2 * k3));
+                    if radial.abs() < f32::EPSILON {
+                        break;
+                    }
+                    let delta = glam::vec2(
+                        2.0 * p1 * p.x * p.y + p2 * (r2 + 2.0 * p.x * p.x),
+                        p1 * (r2 + 2.0 * p.y * p.y) + 2.0 * p2 * p.x * p.y,
+                    );
+                    p = (distorted - delta) / radial;
+                }
+                p
+            }
+
+            Self::Fisheye { radial } => {
+                let [k1, k2, k3, k4] = radial.to_array();
+                let theta_d = distorted.length();
+                if theta_d < f32::EPSILON {
+                    return distorted;
+                }
+                // Newton's method on `theta_d = theta * (1 + k1 theta^2 + ...)`.
+                let mut theta = theta_d;
+                for _ in 0..Self::UNDISTORT_ITERATIONS {
+                    let t2 = theta * theta;
+                    let f = theta * (1.0 + t2 * (k1 + t2 * (k2 + t2 * (k3 + t2 * k4)))) - theta_d;
+                    let df =
+                        1.0 + t2 * (3.0 * k1 + t2 * (5.0 * k2 + t2 * (7.0 * k3 + t2 * 9.0 * k4)));
+                    if df.abs() < f32::EPSILON {
+                        break;
+                    }
+                    theta -= f / df;
+                }
+                // Clamp to just under 90° - everything beyond can't be expressed as a pinhole projection.
+                let theta = theta.clamp(0.0, std::f32::consts::FRAC_PI_2 - 1e-4);
+                distorted * (theta.tan() / theta_d)
+            }
+        }
+    }
+}
+
 pub struct DepthCloud {
     /// The extrinsics of the camera used for the projection.
     pub world_from_obj: glam::Mat4,
@@ -143,6 +246,9 @@ pub struct DepthCloud {
     /// Only supports pinhole cameras at the moment.
     pub depth_camera_intrinsics: glam::Mat3,
 
+    /// The lens distortion of the camera used for the projection.
+    pub depth_camera_distortion: DepthCloudDistortion,
+
     /// Multiplier to get world-space depth from whatever is in [`Self::depth_data`].
     pub world_depth_from_data_depth: f32,
 
@@ -192,7 +298,10 @@ impl DepthCloud {
 
         for corner in corners {
             let depth = corner.z;
-            let pos_in_obj = ((corner.truncate() - offset) * depth / focal_length).extend(depth);
+            let normalized = self
+                .depth_camera_distortion
+                .undistort((corner.truncate() - offset) / focal_length);
+            let pos_in_obj = (normalized * depth).extend(depth);
             let pos_in_world = self.world_from_obj.project_point3(pos_in_obj);
             bbox.extend(pos_in_world);
         }
@@ -24,8 +24,10 @@
 //! All data we fetch in the vertex shader is uploaded as textures in order to maintain WebGL compatibility.
 //! (at the full webgpu feature level we could use raw buffers instead which are easier to handle and a better match for our access pattern)
 //!
-//! Data is provided in two separate textures, the "position data texture" and the "line strip texture".
+//! Data is provided in three separate textures, the "position data texture", the "vertex attribute texture" and the "line strip texture".
 //! The "line strip texture" contains packed information over properties that are global to a single strip (see `gpu_data::LineStripInfo`)
+//! The "vertex attribute texture" is laid out like the position data and holds optional per-vertex overrides of
+//! the strip's color and radius, which are interpolated along each quad (see `gpu_data::LineVertexAttributes`).
 //! Data in the "position data texture" is laid out a follows (see `gpu_data::PositionData`):
 //! ```raw
 //!                   ___________________________________________________________________
@@ -99,7 +101,6 @@
 //! Things we might try in the future
 //! ----------------------------------
 //! * more line properties
-//! * more per-position attributes
 //! * experiment with indexed primitives to lower amount of vertices processed
 //!    * note that this would let us remove the degenerated quads between lines, making the approach cleaner and removing the "restart bit"
 //!
@@ -138,7 +139,7 @@ pub mod gpu_data {
 
     use crate::{size::SizeHalf, wgpu_buffer_types, Color32, PickingLayerObjectId};
 
-    use super::LineStripFlags;
+    use super::{LineStripFlags, LineVertexFlags};
 
     #[repr(C, packed)]
     #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
@@ -151,6 +152,18 @@ pub mod gpu_data {
     // (unlike the fields in a uniform buffer)
     static_assertions::assert_eq_size!(LineVertex, glam::Vec4);
 
+    /// Overrides the color and radius of a line strip at a single vertex.
+    ///
+    /// Laid out like [`LineVertex`], i.e. there is one per vertex.
+    #[repr(C, packed)]
+    #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+    pub struct LineVertexAttributes {
+        pub color: Color32,
+        pub radius: SizeHalf,
+        pub flags: LineVertexFlags,
+    }
+    static_assertions::assert_eq_size!(LineVertexAttributes, [u32; 2]);
+
     #[repr(C, packed)]
     #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
     pub struct LineStripInfo {
@@ -234,6 +247,21 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// Which properties of its line strip a vertex overrides.
+    ///
+    /// Needs to be kept in sync with `lines.wgsl`
+    #[repr(C)]
+    #[derive(Default, bytemuck::Pod, bytemuck::Zeroable)]
+    pub struct LineVertexFlags : u16 {
+        /// Use the color of the vertex instead of the strip's.
+        const COLOR = 0b0000_0001;
+
+        /// Use the radius of the vertex instead of the strip's.
+        const RADIUS = 0b0000_0010;
+    }
+}
+
 /// Data that is valid for a batch of line strips.
 pub struct LineBatchInfo {
     pub label: DebugLabel,
@@ -346,6 +374,7 @@ impl LineDrawData {
 
         let LineStripSeriesBuilder {
             vertices,
+            mut vertex_attributes,
             batches,
             strips,
             mut picking_instance_ids_buffer,
@@ -371,6 +400,11 @@ impl LineDrawData {
                 % wgpu::COPY_BYTES_PER_ROW_ALIGNMENT,
             0
         );
+        static_assertions::const_assert_eq!(
+            POSITION_TEXTURE_SIZE * std::mem::size_of::<gpu_data::LineVertexAttributes>() as u32
+                % wgpu::COPY_BYTES_PER_ROW_ALIGNMENT,
+            0
+        );
         static_assertions::const_assert_eq!(
             LINE_STRIP_TEXTURE_SIZE * std::mem::size_of::<gpu_data::LineStripInfo>() as u32
                 % wgpu::COPY_BYTES_PER_ROW_ALIGNMENT,
@@ -384,6 +418,8 @@ impl LineDrawData {
         } else {
             &vertices[..]
         };
+        // Vertices that were added without attributes don't override anything.
+        vertex_attributes.resize(vertices.len(), gpu_data::LineVertexAttributes::zeroed());
         let strips = if strips.len() > Self::MAX_NUM_STRIPS {
             re_log::error_once!("Reached maximum number of supported line strips. Clamping down to {}, passed were {}. This may lead to rendering artifacts.
  See also https://github.com/rerun-io/rerun/issues/957", Self::MAX_NUM_STRIPS, strips.len());
@@ -423,6 +459,15 @@ impl LineDrawData {
             .textures
             .alloc(&ctx.device, &position_data_texture_desc);
 
+        let vertex_attribute_texture = ctx.gpu_resources.textures.alloc(
+            &ctx.device,
+            &TextureDesc {
+                label: "LineDrawData::vertex_attribute_texture".into(),
+                format: wgpu::TextureFormat::Rg32Uint,
+                ..position_data_texture_desc
+            },
+        );
+
         let line_strip_texture_desc = TextureDesc {
             label: "LineDrawData::line_strip_texture".into(),
             size: wgpu::Extent3d {
@@ -492,6 +537,47 @@ impl LineDrawData {
             );
         }
 
+        // Upload vertex attributes, laid out like the position data.
+        {
+            let mut vertex_attribute_staging = Vec::with_capacity(wgpu::util::align_to(
+                num_segments,
+                POSITION_TEXTURE_SIZE,
+            ) as usize);
+            // Sentinels at the beginning and the end, like for the position data.
+            vertex_attribute_staging.push(gpu_data::LineVertexAttributes::zeroed());
+            vertex_attribute_staging.extend(vertex_attributes.iter());
+            vertex_attribute_staging.extend(
+                std::iter::repeat(gpu_data::LineVertexAttributes::zeroed()).take(
+                    (wgpu::util::align_to(num_segments, POSITION_TEXTURE_SIZE) - num_segments + 1)
+                        as usize,
+                ),
+            );
+
+            // TODO(andreas): Use staging belt here.
+            ctx.queue.write_texture(
+                wgpu::ImageCopyTexture {
+                    texture: &vertex_attribute_texture.texture,
+                    mip_level: 0,
+                    origin: wgpu::Origin3d::ZERO,
+                    aspect: wgpu::TextureAspect::All,
+                },
+                bytemuck::cast_slice(&vertex_attribute_staging),
+                wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: NonZeroU32::new(
+                        POSITION_TEXTURE_SIZE
+                            * std::mem::size_of::<gpu_data::LineVertexAttributes>() as u32,
+                    ),
+                    rows_per_image: None,
+                },
+                wgpu::Extent3d {
+                    width: POSITION_TEXTURE_SIZE,
+                    height: (num_segments + POSITION_TEXTURE_SIZE - 1) / POSITION_TEXTURE_SIZE,
+                    depth_or_array_layers: 1,
+                },
+            );
+        }
+
         // Upload strip data.
         {
             let mut line_strip_info_staging = Vec::with_capacity(wgpu::util::align_to(
@@ -577,6 +663,7 @@ impl LineDrawData {
                     BindGroupEntry::DefaultTextureView(line_strip_texture.handle),
                     BindGroupEntry::DefaultTextureView(picking_instance_id_texture.handle),
                     draw_data_uniform_buffer_bindings[0].clone(),
+                    BindGroupEntry::DefaultTextureView(vertex_attribute_texture.handle),
                 ],
                 layout: line_renderer.bind_group_layout_all_lines,
             },
@@ -591,6 +678,7 @@ impl LineDrawData {
                     BindGroupEntry::DefaultTextureView(line_strip_texture.handle),
                     BindGroupEntry::DefaultTextureView(picking_instance_id_texture.handle),
                     draw_data_uniform_buffer_bindings[1].clone(),
+                    BindGroupEntry::DefaultTextureView(vertex_attribute_texture.handle),
                 ],
                 layout: line_renderer.bind_group_layout_all_lines,
             },
@@ -792,6 +880,16 @@ impl Renderer for LineRenderer {
                         },
                         count: None,
                     },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 4,
+                        visibility: wgpu::ShaderStages::VERTEX,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Uint,
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
                 ],
             },
         );
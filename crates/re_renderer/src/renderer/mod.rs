@@ -3,8 +3,8 @@ pub use generic_skybox::GenericSkyboxDrawData;
 
 mod lines;
 pub use lines::{
-    gpu_data::LineVertex, LineBatchInfo, LineDrawData, LineDrawDataError, LineStripFlags,
-    LineStripInfo,
+    gpu_data::{LineVertex, LineVertexAttributes},
+    LineBatchInfo, LineDrawData, LineDrawDataError, LineStripFlags, LineStripInfo, LineVertexFlags,
 };
 
 mod point_cloud;
@@ -15,7 +15,8 @@ pub use point_cloud::{
 
 mod depth_cloud;
 pub use self::depth_cloud::{
-    DepthCloud, DepthCloudDepthData, DepthCloudDrawData, DepthCloudRenderer, DepthClouds,
+    DepthCloud, DepthCloudDepthData, DepthCloudDistortion, DepthCloudDrawData, DepthCloudRenderer,
+    DepthClouds,
 };
 
 mod test_triangle;
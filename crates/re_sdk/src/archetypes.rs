@@ -0,0 +1,673 @@
+//! Typed builders for the common combinations of components.
+//!
+//! Logging e.g. a colored point cloud by hand takes one [`MsgSender::with_component`] per
+//! component, and they all need to have the same number of instances.
+//! An [`Archetype`] knows which components belong together, and checks their instance counts
+//! before anything is logged:
+//!
+//! ```
+//! use re_sdk::{archetypes::Points3D, MsgSender};
+//!
+//! # fn log(session: &re_sdk::Session) -> Result<(), Box<dyn std::error::Error>> {
+//! let points = Points3D::new([[0.0, 0.0, 0.0], [1.0, 1.0, 1.0]])
+//!     .colors([[255, 0, 0, 255]]) // one value applies to all the points
+//!     .radii([0.1, 0.2])
+//!     .labels(["origin", "corner"]);
+//! MsgSender::new("world/points")
+//!     .with_archetype(points)?
+//!     .send(session)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! A component with a single value applies to all instances (it is splatted),
+//! otherwise it must have one value per instance.
+
+use re_log_types::{
+    component_types::{
+        ClassId, ColorRGBA, Label, LensDistortion, Mat3x3, Pinhole, Point3D, Radius, Rect2D,
+        Tensor, TensorCastError, TensorData, TensorDataMeaning, TensorDimension, Transform, Vec2D,
+        Vec4D, ViewCoordinates,
+    },
+    DataRow,
+};
+
+use crate::{
+    time::TimePoint, Component, ComponentName, EntityPath, MsgSender, MsgSenderError,
+    SerializableComponent,
+};
+
+// ---
+
+/// Errors when building an [`Archetype`].
+#[derive(thiserror::Error, Debug)]
+pub enum ArchetypeError {
+    /// A component has neither a single value, nor one value per instance.
+    #[error("Expected 1 or {expected} values of {component}, got {actual}")]
+    InstanceCountMismatch {
+        /// The offending component.
+        component: ComponentName,
+
+        /// The number of instances.
+        expected: usize,
+
+        /// The number of values of the component.
+        actual: usize,
+    },
+
+    /// The image data doesn't match its size.
+    #[error(
+        "A {width}x{height} image with {channels} channel(s) needs {} values, got {actual}",
+        num_values_str(.expected)
+    )]
+    ImageSizeMismatch {
+        /// Width in pixels.
+        width: u64,
+
+        /// Height in pixels.
+        height: u64,
+
+        /// Number of values per pixel.
+        channels: u64,
+
+        /// `width * height * channels`, or `None` if that doesn't fit in a `usize`.
+        expected: Option<usize>,
+
+        /// The number of values given.
+        actual: usize,
+    },
+
+    /// The tensor isn't shaped like the expected kind of image.
+    #[error("Expected a tensor shaped like {expected}, got shape {actual:?}")]
+    BadTensorShape {
+        /// What the tensor should look like.
+        expected: &'static str,
+
+        /// The shape of the tensor.
+        actual: Vec<u64>,
+    },
+
+    /// See [`TensorCastError`].
+    #[error(transparent)]
+    TensorCast(#[from] TensorCastError),
+
+    /// See [`re_log_types::component_types::TensorImageError`].
+    #[cfg(feature = "image")]
+    #[error(transparent)]
+    TensorImage(#[from] re_log_types::component_types::TensorImageError),
+}
+
+/// A typed collection of components that are logged together, see the [module docs](self).
+pub trait Archetype {
+    /// Check that the components fit together, then add them to the message.
+    fn add_to(self, msg_sender: MsgSender) -> Result<MsgSender, MsgSenderError>;
+
+    /// The rows logging this at the given path and time.
+    ///
+    /// Like everything logged with a [`MsgSender`], these are also on the automatic timelines.
+    fn into_data_rows(
+        self,
+        ent_path: impl Into<EntityPath>,
+        timepoint: TimePoint,
+    ) -> Result<Vec<DataRow>, MsgSenderError>
+    where
+        Self: Sized,
+    {
+        let msg_sender = MsgSender::new(ent_path)
+            .with_thread_time(false)
+            .with_timepoint(timepoint);
+        Ok(self.add_to(msg_sender)?.into_data_rows())
+    }
+}
+
+/// A component must have a single value, or one per instance.
+fn check_num_instances<C: Component>(
+    num_instances: usize,
+    values: &[C],
+) -> Result<(), ArchetypeError> {
+    if values.len() <= 1 || values.len() == num_instances {
+        Ok(())
+    } else {
+        Err(ArchetypeError::InstanceCountMismatch {
+            component: C::name(),
+            expected: num_instances,
+            actual: values.len(),
+        })
+    }
+}
+
+/// Add the values of a component checked with [`check_num_instances`], splatting a single value.
+fn with_values<C: SerializableComponent>(
+    msg_sender: MsgSender,
+    num_instances: usize,
+    values: Vec<C>,
+) -> Result<MsgSender, MsgSenderError> {
+    if values.len() == 1 && num_instances != 1 {
+        msg_sender.with_splat_opt(values.into_iter().next())
+    } else if values.is_empty() {
+        Ok(msg_sender)
+    } else {
+        msg_sender.with_component(&values)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A point cloud in 3D, with optional colors, radii, labels and class ids.
+///
+/// The positions can be anything that converts to [`Point3D`], e.g. `[f32; 3]`,
+/// or `glam::Vec3` and `nalgebra::Point3<f32>` with the `glam` and `nalgebra` features.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Points3D {
+    positions: Vec<Point3D>,
+    colors: Vec<ColorRGBA>,
+    radii: Vec<Radius>,
+    labels: Vec<Label>,
+    class_ids: Vec<ClassId>,
+}
+
+impl Points3D {
+    /// Points at these positions.
+    pub fn new(positions: impl IntoIterator<Item = impl Into<Point3D>>) -> Self {
+        Self {
+            positions: positions.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Points at the rows of an `N×3` array.
+    ///
+    /// Requires the `ndarray` feature.
+    #[cfg(feature = "ndarray")]
+    pub fn from_ndarray(positions: ndarray::ArrayView2<'_, f32>) -> Result<Self, ArchetypeError> {
+        if positions.ncols() != 3 {
+            return Err(ArchetypeError::BadTensorShape {
+                expected: "N×3",
+                actual: positions.shape().iter().map(|&dim| dim as u64).collect(),
+            });
+        }
+        Ok(Self::new(
+            positions
+                .rows()
+                .into_iter()
+                .map(|row| Point3D::new(row[0], row[1], row[2])),
+        ))
+    }
+
+    /// One color for all points, or one per point.
+    pub fn colors(mut self, colors: impl IntoIterator<Item = impl Into<ColorRGBA>>) -> Self {
+        self.colors = colors.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// One radius for all points, or one per point.
+    pub fn radii(mut self, radii: impl IntoIterator<Item = f32>) -> Self {
+        self.radii = radii.into_iter().map(Radius).collect();
+        self
+    }
+
+    /// One label for all points, or one per point.
+    pub fn labels(mut self, labels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.labels = labels
+            .into_iter()
+            .map(|label| Label(label.into()))
+            .collect();
+        self
+    }
+
+    /// One class id for all points, or one per point.
+    ///
+    /// These are looked up in the [`crate::components::AnnotationContext`].
+    pub fn class_ids(mut self, class_ids: impl IntoIterator<Item = u16>) -> Self {
+        self.class_ids = class_ids.into_iter().map(ClassId).collect();
+        self
+    }
+}
+
+impl Archetype for Points3D {
+    fn add_to(self, msg_sender: MsgSender) -> Result<MsgSender, MsgSenderError> {
+        let Self {
+            positions,
+            colors,
+            radii,
+            labels,
+            class_ids,
+        } = self;
+
+        let num_instances = positions.len();
+        check_num_instances(num_instances, &colors)?;
+        check_num_instances(num_instances, &radii)?;
+        check_num_instances(num_instances, &labels)?;
+        check_num_instances(num_instances, &class_ids)?;
+
+        let msg_sender = msg_sender.with_component(&positions)?;
+        let msg_sender = with_values(msg_sender, num_instances, colors)?;
+        let msg_sender = with_values(msg_sender, num_instances, radii)?;
+        let msg_sender = with_values(msg_sender, num_instances, labels)?;
+        with_values(msg_sender, num_instances, class_ids)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Axis-aligned boxes in 2D, with optional colors, stroke widths, labels and class ids.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Boxes2D {
+    rects: Vec<Rect2D>,
+    colors: Vec<ColorRGBA>,
+    radii: Vec<Radius>,
+    labels: Vec<Label>,
+    class_ids: Vec<ClassId>,
+}
+
+impl Boxes2D {
+    /// These boxes.
+    pub fn new(rects: impl IntoIterator<Item = Rect2D>) -> Self {
+        Self {
+            rects: rects.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Boxes given as `[x, y, width, height]`, with `x, y` the top left corner.
+    pub fn from_xywh(rects: impl IntoIterator<Item = [f32; 4]>) -> Self {
+        Self::new(rects.into_iter().map(|xywh| Rect2D::XYWH(Vec4D(xywh))))
+    }
+
+    /// One color for all boxes, or one per box.
+    pub fn colors(mut self, colors: impl IntoIterator<Item = impl Into<ColorRGBA>>) -> Self {
+        self.colors = colors.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Half the stroke width: one for all boxes, or one per box.
+    pub fn radii(mut self, radii: impl IntoIterator<Item = f32>) -> Self {
+        self.radii = radii.into_iter().map(Radius).collect();
+        self
+    }
+
+    /// One label for all boxes, or one per box.
+    pub fn labels(mut self, labels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.labels = labels
+            .into_iter()
+            .map(|label| Label(label.into()))
+            .collect();
+        self
+    }
+
+    /// One class id for all boxes, or one per box.
+    ///
+    /// These are looked up in the [`crate::components::AnnotationContext`].
+    pub fn class_ids(mut self, class_ids: impl IntoIterator<Item = u16>) -> Self {
+        self.class_ids = class_ids.into_iter().map(ClassId).collect();
+        self
+    }
+}
+
+impl Archetype for Boxes2D {
+    fn add_to(self, msg_sender: MsgSender) -> Result<MsgSender, MsgSenderError> {
+        let Self {
+            rects,
+            colors,
+            radii,
+            labels,
+            class_ids,
+        } = self;
+
+        let num_instances = rects.len();
+        check_num_instances(num_instances, &colors)?;
+        check_num_instances(num_instances, &radii)?;
+        check_num_instances(num_instances, &labels)?;
+        check_num_instances(num_instances, &class_ids)?;
+
+        let msg_sender = msg_sender.with_component(&rects)?;
+        let msg_sender = with_values(msg_sender, num_instances, colors)?;
+        let msg_sender = with_values(msg_sender, num_instances, radii)?;
+        let msg_sender = with_values(msg_sender, num_instances, labels)?;
+        with_values(msg_sender, num_instances, class_ids)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// `[height, width]` for a single channel, `[height, width, channels]` otherwise.
+fn image_shape(width: u64, height: u64, channels: u64) -> Vec<TensorDimension> {
+    let mut shape = vec![
+        TensorDimension::height(height),
+        TensorDimension::width(width),
+    ];
+    if channels > 1 {
+        shape.push(TensorDimension::depth(channels));
+    }
+    shape
+}
+
+fn check_image_size(
+    width: u64,
+    height: u64,
+    channels: u64,
+    actual: usize,
+) -> Result<(), ArchetypeError> {
+    let expected = width
+        .checked_mul(height)
+        .and_then(|num_pixels| num_pixels.checked_mul(channels))
+        .and_then(|num_values| usize::try_from(num_values).ok());
+    if expected == Some(actual) {
+        Ok(())
+    } else {
+        Err(ArchetypeError::ImageSizeMismatch {
+            width,
+            height,
+            channels,
+            expected,
+            actual,
+        })
+    }
+}
+
+fn num_values_str(num_values: &Option<usize>) -> String {
+    match num_values {
+        Some(num_values) => num_values.to_string(),
+        None => "too many".to_owned(),
+    }
+}
+
+fn tensor_shape(tensor: &Tensor) -> Vec<u64> {
+    tensor.shape().iter().map(|dim| dim.size).collect()
+}
+
+/// A color or grayscale image.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Image {
+    tensor: Tensor,
+}
+
+impl Image {
+    /// An image from tightly packed RGB pixels, row by row.
+    pub fn from_rgb8(width: u32, height: u32, data: Vec<u8>) -> Result<Self, ArchetypeError> {
+        Self::from_u8(width, height, 3, data)
+    }
+
+    /// An image from tightly packed RGBA pixels, row by row.
+    pub fn from_rgba8(width: u32, height: u32, data: Vec<u8>) -> Result<Self, ArchetypeError> {
+        Self::from_u8(width, height, 4, data)
+    }
+
+    /// A grayscale image, row by row.
+    pub fn from_gray8(width: u32, height: u32, data: Vec<u8>) -> Result<Self, ArchetypeError> {
+        Self::from_u8(width, height, 1, data)
+    }
+
+    fn from_u8(
+        width: u32,
+        height: u32,
+        channels: u64,
+        data: Vec<u8>,
+    ) -> Result<Self, ArchetypeError> {
+        let (width, height) = (width as u64, height as u64);
+        check_image_size(width, height, channels, data.len())?;
+        Ok(Self {
+            tensor: Tensor::from_content(
+                image_shape(width, height, channels),
+                TensorData::U8(data.into()),
+                TensorDataMeaning::Unknown,
+                None,
+            ),
+        })
+    }
+
+    /// An image from a tensor shaped `[height, width]` or `[height, width, 1|3|4]`.
+    pub fn from_tensor(tensor: Tensor) -> Result<Self, ArchetypeError> {
+        if tensor.is_shaped_like_an_image() {
+            Ok(Self { tensor })
+        } else {
+            Err(ArchetypeError::BadTensorShape {
+                expected: "an image",
+                actual: tensor_shape(&tensor),
+            })
+        }
+    }
+
+    /// An image from an array shaped `[height, width]` or `[height, width, 1|3|4]`.
+    ///
+    /// Requires the `ndarray` feature.
+    #[cfg(feature = "ndarray")]
+    pub fn from_ndarray<T, D>(array: ndarray::Array<T, D>) -> Result<Self, ArchetypeError>
+    where
+        D: ndarray::Dimension,
+        Tensor: TryFrom<ndarray::Array<T, D>, Error = TensorCastError>,
+    {
+        Self::from_tensor(Tensor::try_from(array)?)
+    }
+
+    /// An image from the [`image`](crate::external::image) crate.
+    ///
+    /// Requires the `image` feature.
+    #[cfg(feature = "image")]
+    pub fn from_image(
+        image: impl Into<re_log_types::external::image::DynamicImage>,
+    ) -> Result<Self, ArchetypeError> {
+        Self::from_tensor(Tensor::from_image(image)?)
+    }
+}
+
+impl Archetype for Image {
+    fn add_to(self, msg_sender: MsgSender) -> Result<MsgSender, MsgSenderError> {
+        msg_sender.with_component(&[self.tensor])
+    }
+}
+
+/// A depth image, with the depth in each pixel.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DepthImage {
+    tensor: Tensor,
+}
+
+impl DepthImage {
+    /// A depth image from 16-bit depths, row by row.
+    pub fn from_u16(width: u32, height: u32, data: Vec<u16>) -> Result<Self, ArchetypeError> {
+        let (width, height) = (width as u64, height as u64);
+        check_image_size(width, height, 1, data.len())?;
+        Ok(Self::from_data(width, height, TensorData::U16(data.into())))
+    }
+
+    /// A depth image from floating point depths, row by row.
+    pub fn from_f32(width: u32, height: u32, data: Vec<f32>) -> Result<Self, ArchetypeError> {
+        let (width, height) = (width as u64, height as u64);
+        check_image_size(width, height, 1, data.len())?;
+        Ok(Self::from_data(width, height, TensorData::F32(data.into())))
+    }
+
+    fn from_data(width: u64, height: u64, data: TensorData) -> Self {
+        Self {
+            tensor: Tensor::from_content(
+                image_shape(width, height, 1),
+                data,
+                TensorDataMeaning::Depth,
+                None,
+            ),
+        }
+    }
+
+    /// A depth image from a tensor shaped `[height, width]` or `[height, width, 1]`.
+    pub fn from_tensor(mut tensor: Tensor) -> Result<Self, ArchetypeError> {
+        if tensor
+            .image_height_width_channels()
+            .map_or(false, |[_, _, channels]| channels == 1)
+        {
+            tensor.meaning = TensorDataMeaning::Depth;
+            Ok(Self { tensor })
+        } else {
+            Err(ArchetypeError::BadTensorShape {
+                expected: "a depth image",
+                actual: tensor_shape(&tensor),
+            })
+        }
+    }
+
+    /// A depth image from an array shaped `[height, width]` or `[height, width, 1]`.
+    ///
+    /// Requires the `ndarray` feature.
+    #[cfg(feature = "ndarray")]
+    pub fn from_ndarray<T, D>(array: ndarray::Array<T, D>) -> Result<Self, ArchetypeError>
+    where
+        D: ndarray::Dimension,
+        Tensor: TryFrom<ndarray::Array<T, D>, Error = TensorCastError>,
+    {
+        Self::from_tensor(Tensor::try_from(array)?)
+    }
+
+    /// How many units of depth make a meter, e.g. `1000.0` for depths in millimeters.
+    pub fn meter(mut self, meter: f32) -> Self {
+        self.tensor.meter = Some(meter);
+        self
+    }
+}
+
+impl Archetype for DepthImage {
+    fn add_to(self, msg_sender: MsgSender) -> Result<MsgSender, MsgSenderError> {
+        msg_sender.with_component(&[self.tensor])
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A pinhole camera, projecting its children in 3D onto the image plane.
+///
+/// Images logged as children of the camera are shown in its image plane.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Camera {
+    pinhole: Pinhole,
+    view_coordinates: Option<ViewCoordinates>,
+}
+
+impl Camera {
+    /// A camera with these focal lengths in pixels, and the principal point at the center of
+    /// an image of the given width and height.
+    pub fn pinhole(focal_length: impl Into<Vec2D>, resolution: impl Into<Vec2D>) -> Self {
+        let Vec2D([fx, fy]) = focal_length.into();
+        let resolution = resolution.into();
+        let [width, height] = resolution.0;
+        Self::from_image_from_cam(
+            [
+                [fx, 0.0, 0.0],
+                [0.0, fy, 0.0],
+                [width / 2.0, height / 2.0, 1.0],
+            ],
+            resolution,
+        )
+    }
+
+    /// A camera with the given column-major intrinsics matrix, for an image of the given
+    /// width and height.
+    pub fn from_image_from_cam(
+        image_from_cam: impl Into<Mat3x3>,
+        resolution: impl Into<Vec2D>,
+    ) -> Self {
+        Self {
+            pinhole: Pinhole {
+                image_from_cam: image_from_cam.into(),
+                resolution: Some(resolution.into()),
+                distortion: None,
+            },
+            view_coordinates: None,
+        }
+    }
+
+    /// The lens distortion on top of the ideal pinhole.
+    pub fn distortion(mut self, distortion: LensDistortion) -> Self {
+        self.pinhole.distortion = Some(distortion);
+        self
+    }
+
+    /// Which way the camera is looking, e.g. `"RDF"` for right, down, forward.
+    pub fn view_coordinates(mut self, view_coordinates: ViewCoordinates) -> Self {
+        self.view_coordinates = Some(view_coordinates);
+        self
+    }
+}
+
+impl Archetype for Camera {
+    fn add_to(self, msg_sender: MsgSender) -> Result<MsgSender, MsgSenderError> {
+        let Self {
+            pinhole,
+            view_coordinates,
+        } = self;
+
+        let msg_sender = msg_sender.with_component(&[Transform::Pinhole(pinhole)])?;
+        if let Some(view_coordinates) = view_coordinates {
+            msg_sender.with_component(&[view_coordinates])
+        } else {
+            Ok(msg_sender)
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[test]
+fn test_points3d() {
+    let rows = Points3D::new([[0.0, 0.0, 0.0], [1.0, 1.0, 1.0]])
+        .colors([[255, 0, 0, 255]])
+        .radii([0.1, 0.2])
+        .into_data_rows("points", TimePoint::timeless())
+        .unwrap();
+    assert_eq!(rows.len(), 2, "one row for the splatted color");
+    let instanced = rows.iter().find(|row| row.num_instances() == 2).unwrap();
+    assert!(instanced.find_cell(&Point3D::name()).is_some());
+    assert!(instanced.find_cell(&Radius::name()).is_some());
+    let splatted = rows.iter().find(|row| row.num_instances() == 1).unwrap();
+    assert!(splatted.find_cell(&ColorRGBA::name()).is_some());
+
+    let err = Points3D::new([[0.0, 0.0, 0.0], [1.0, 1.0, 1.0]])
+        .labels(["a", "b", "c"])
+        .add_to(MsgSender::new("points"))
+        .err()
+        .unwrap();
+    assert!(matches!(
+        err,
+        MsgSenderError::Archetype(ArchetypeError::InstanceCountMismatch {
+            expected: 2,
+            actual: 3,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_images() {
+    let image = Image::from_rgb8(2, 1, vec![0; 6]).unwrap();
+    assert_eq!(tensor_shape(&image.tensor), vec![1, 2, 3]);
+    assert!(matches!(
+        Image::from_rgb8(2, 2, vec![0; 6]),
+        Err(ArchetypeError::ImageSizeMismatch {
+            expected: Some(12),
+            ..
+        })
+    ));
+    assert!(matches!(
+        check_image_size(u64::MAX, 2, 1, 0),
+        Err(ArchetypeError::ImageSizeMismatch { expected: None, .. })
+    ));
+
+    let depth = DepthImage::from_u16(2, 2, vec![0; 4])
+        .unwrap()
+        .meter(1000.0);
+    assert_eq!(depth.tensor.meaning, TensorDataMeaning::Depth);
+    assert_eq!(depth.tensor.meter, Some(1000.0));
+    assert!(DepthImage::from_tensor(image.tensor).is_err());
+}
+
+#[test]
+fn test_camera() {
+    let camera = Camera::pinhole([500.0, 500.0], [640.0, 480.0]);
+    assert_eq!(
+        camera.pinhole.focal_length_in_pixels(),
+        Vec2D([500.0, 500.0])
+    );
+    let rows = camera
+        .into_data_rows("camera", TimePoint::timeless())
+        .unwrap();
+    assert_eq!(rows.len(), 1);
+    assert!(rows[0].find_cell(&Transform::name()).is_some());
+}
@@ -0,0 +1,459 @@
+use std::{collections::VecDeque, sync::Arc};
+
+use instant::{Duration, Instant};
+use parking_lot::Mutex;
+
+use re_log_types::{DataRow, DataTable, LogMsg, RecordingId, SizeBytes as _, TableId};
+
+use crate::sink::LogSink;
+
+// ----------------------------------------------------------------------------
+
+/// Decides when the [`DataRow`]s logged to a [`crate::Session`] are packed into a
+/// [`DataTable`] and sent to the sink.
+///
+/// Whichever threshold is hit first triggers a flush.
+/// Pending rows are always flushed on [`crate::Session::flush`], before any other kind of
+/// message (e.g. a [`re_log_types::PathOp`]), and when the last clone of the session is dropped.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BatcherConfig {
+    /// Flush once the oldest pending row has been waiting for this long.
+    ///
+    /// Can be overridden with the `RERUN_FLUSH_TICK_SECS` environment variable.
+    pub flush_tick: Duration,
+
+    /// Flush once the pending rows take up this many bytes.
+    ///
+    /// Can be overridden with the `RERUN_FLUSH_NUM_BYTES` environment variable.
+    pub flush_num_bytes: u64,
+
+    /// Flush once this many rows are pending.
+    ///
+    /// Can be overridden with the `RERUN_FLUSH_NUM_ROWS` environment variable.
+    pub flush_num_rows: u64,
+}
+
+impl Default for BatcherConfig {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl BatcherConfig {
+    /// Default configuration, applicable to most use cases.
+    pub const DEFAULT: Self = Self {
+        flush_tick: Duration::from_millis(8),
+        flush_num_bytes: 1024 * 1024, // 1 MiB
+        flush_num_rows: u64::MAX,
+    };
+
+    /// Send every row on its own, i.e. no batching.
+    pub const ALWAYS: Self = Self {
+        flush_tick: Duration::ZERO,
+        flush_num_bytes: 0,
+        flush_num_rows: 0,
+    };
+
+    /// Only send rows on explicit flushes, other messages, and drop.
+    pub const NEVER: Self = Self {
+        flush_tick: Duration::MAX,
+        flush_num_bytes: u64::MAX,
+        flush_num_rows: u64::MAX,
+    };
+
+    /// Environment variable to override [`Self::flush_tick`], in (fractional) seconds.
+    pub const ENV_FLUSH_TICK: &str = "RERUN_FLUSH_TICK_SECS";
+
+    /// Environment variable to override [`Self::flush_num_bytes`].
+    pub const ENV_FLUSH_NUM_BYTES: &str = "RERUN_FLUSH_NUM_BYTES";
+
+    /// Environment variable to override [`Self::flush_num_rows`].
+    pub const ENV_FLUSH_NUM_ROWS: &str = "RERUN_FLUSH_NUM_ROWS";
+
+    /// The [default configuration](Self::DEFAULT), with any overrides from the environment.
+    ///
+    /// Invalid values are warned about and ignored.
+    pub fn from_env() -> Self {
+        let mut config = Self::DEFAULT;
+
+        if let Some(secs) = parse_env_var::<f64>(Self::ENV_FLUSH_TICK) {
+            match Duration::try_from_secs_f64(secs) {
+                Ok(flush_tick) => config.flush_tick = flush_tick,
+                Err(err) => re_log::warn!(
+                    "Invalid value for environment variable {}={secs}: {err}. It will be ignored",
+                    Self::ENV_FLUSH_TICK
+                ),
+            }
+        }
+        if let Some(flush_num_bytes) = parse_env_var(Self::ENV_FLUSH_NUM_BYTES) {
+            config.flush_num_bytes = flush_num_bytes;
+        }
+        if let Some(flush_num_rows) = parse_env_var(Self::ENV_FLUSH_NUM_ROWS) {
+            config.flush_num_rows = flush_num_rows;
+        }
+
+        config
+    }
+}
+
+fn parse_env_var<T: std::str::FromStr>(name: &str) -> Option<T>
+where
+    T::Err: std::fmt::Display,
+{
+    let value = std::env::var(name).ok()?;
+    match value.trim().parse() {
+        Ok(value) => Some(value),
+        Err(err) => {
+            re_log::warn!(
+                "Invalid value for environment variable {name}={value:?}: {err}. It will be ignored"
+            );
+            None
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+enum Outgoing {
+    Rows(Vec<DataRow>),
+    Msg(LogMsg),
+}
+
+#[derive(Default)]
+struct PendingRows {
+    rows: Vec<DataRow>,
+    num_bytes: u64,
+
+    /// When the oldest of the `rows` was added.
+    oldest: Option<Instant>,
+
+    /// Batches and other messages that are ready to be sent, in order.
+    outbox: VecDeque<Outgoing>,
+}
+
+impl PendingRows {
+    /// Move the pending rows to the outbox, as one batch.
+    fn batch_rows(&mut self) {
+        if !self.rows.is_empty() {
+            let rows = std::mem::take(&mut self.rows);
+            self.outbox.push_back(Outgoing::Rows(rows));
+        }
+        self.num_bytes = 0;
+        self.oldest = None;
+    }
+}
+
+/// Accumulates [`DataRow`]s and sends them on to a [`LogSink`] as multi-row [`DataTable`]s.
+///
+/// Everything sent through the batcher reaches the sink in the order it was sent:
+/// other messages first flush the pending rows.
+///
+/// The batcher is itself a [`LogSink`], so that messages sent to the sink of a
+/// [`crate::Session`] can't overtake the rows it's still holding on to.
+pub(crate) struct Batcher {
+    config: BatcherConfig,
+    recording_id: RecordingId,
+    sink: Arc<dyn LogSink>,
+
+    /// Only held briefly: never while sending to a (possibly blocking) sink.
+    pending: Mutex<PendingRows>,
+
+    /// Held while emptying the outbox, so that it is sent in order.
+    sending: Mutex<()>,
+}
+
+impl Batcher {
+    pub fn new(
+        config: BatcherConfig,
+        recording_id: RecordingId,
+        sink: Arc<dyn LogSink>,
+    ) -> Arc<Self> {
+        let flush_tick = config.flush_tick;
+        let batcher = Arc::new(Self {
+            config,
+            recording_id,
+            sink,
+            pending: Default::default(),
+            sending: Default::default(),
+        });
+
+        // Rows are otherwise only flushed when more rows come in, which may never happen.
+        #[cfg(not(target_arch = "wasm32"))]
+        if Duration::ZERO < flush_tick && flush_tick < Duration::MAX {
+            let batcher = Arc::downgrade(&batcher);
+            let spawned = std::thread::Builder::new()
+                .name("rerun_batcher".to_owned())
+                .spawn(move || loop {
+                    std::thread::sleep(flush_tick);
+                    let Some(batcher) = batcher.upgrade() else {
+                        break; // the session is gone
+                    };
+                    batcher.flush_if_stale();
+                });
+            if let Err(err) = spawned {
+                re_log::warn!("Failed to spawn batching thread, rows may be delayed: {err}");
+            }
+        }
+
+        batcher
+    }
+
+    /// Queue a row, and flush if that hits one of the thresholds.
+    pub fn push_row(&self, mut row: DataRow) {
+        let num_bytes = if self.config.flush_num_bytes < u64::MAX {
+            row.compute_all_size_bytes();
+            row.cells().iter().map(|cell| cell.total_size_bytes()).sum()
+        } else {
+            0 // computing sizes is costly, and unneeded
+        };
+
+        {
+            let mut pending = self.pending.lock();
+            pending.rows.push(row);
+            pending.num_bytes += num_bytes;
+            let oldest = *pending.oldest.get_or_insert_with(Instant::now);
+
+            if pending.rows.len() as u64 >= self.config.flush_num_rows
+                || pending.num_bytes >= self.config.flush_num_bytes
+                || oldest.elapsed() >= self.config.flush_tick
+            {
+                pending.batch_rows();
+            } else {
+                return;
+            }
+        }
+        self.send_outbox();
+    }
+
+    /// Send all pending rows.
+    fn flush_rows(&self) {
+        self.pending.lock().batch_rows();
+        self.send_outbox();
+    }
+
+    fn flush_if_stale(&self) {
+        {
+            let mut pending = self.pending.lock();
+            if pending
+                .oldest
+                .map_or(true, |oldest| oldest.elapsed() < self.config.flush_tick)
+            {
+                return;
+            }
+            pending.batch_rows();
+        }
+        self.send_outbox();
+    }
+
+    /// Send everything in the outbox, including what other threads put there
+    /// while we were waiting for our turn.
+    fn send_outbox(&self) {
+        let _sending = self.sending.lock();
+        loop {
+            let outgoing = self.pending.lock().outbox.pop_front();
+            match outgoing {
+                Some(Outgoing::Rows(rows)) => self.send_rows(rows),
+                Some(Outgoing::Msg(msg)) => self.sink.send(msg),
+                None => break,
+            }
+        }
+    }
+
+    fn send_rows(&self, rows: Vec<DataRow>) {
+        let table = DataTable::from_rows(TableId::random(), rows);
+        match table.to_arrow_msg() {
+            Ok(msg) => self.sink.send(LogMsg::ArrowMsg(self.recording_id, msg)),
+            Err(err) => {
+                // Don't let a single bad row take the rest of the batch down with it.
+                re_log::warn!("Failed to pack {} rows: {err}", table.num_rows());
+                for row in table.to_rows() {
+                    match row.into_table().to_arrow_msg() {
+                        Ok(msg) => self.sink.send(LogMsg::ArrowMsg(self.recording_id, msg)),
+                        Err(err) => re_log::error!("Dropping row that can't be sent: {err}"),
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl LogSink for Batcher {
+    /// Send a message after all pending rows.
+    fn send(&self, msg: LogMsg) {
+        {
+            let mut pending = self.pending.lock();
+            pending.batch_rows();
+            pending.outbox.push_back(Outgoing::Msg(msg));
+        }
+        self.send_outbox();
+    }
+
+    fn drain_backlog(&self) -> Vec<LogMsg> {
+        self.flush_rows();
+        self.sink.drain_backlog()
+    }
+
+    /// Send all pending rows, then flush the sink.
+    fn flush(&self) {
+        self.flush_rows();
+        self.sink.flush();
+    }
+
+    fn drop_msgs_if_disconnected(&self) {
+        self.sink.drop_msgs_if_disconnected();
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.sink.is_enabled()
+    }
+}
+
+impl Drop for Batcher {
+    fn drop(&mut self) {
+        self.flush_rows();
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use re_log_types::{component_types::Label, EntityPathOpMsg, PathOp, RowId, TimePoint};
+
+    use crate::sink::BufferedSink;
+
+    fn row(label: &str) -> DataRow {
+        DataRow::from_cells1(
+            RowId::random(),
+            "some/path",
+            TimePoint::default(),
+            1,
+            [Label(label.into())].as_slice(),
+        )
+    }
+
+    fn num_rows_per_msg(messages: &[LogMsg]) -> Vec<u32> {
+        messages
+            .iter()
+            .map(|msg| match msg {
+                LogMsg::ArrowMsg(_, msg) => DataTable::from_arrow_msg(msg).unwrap().num_rows(),
+                _ => 0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn batches_by_num_rows() {
+        let sink: Arc<dyn LogSink> = Arc::new(BufferedSink::new());
+        let config = BatcherConfig {
+            flush_num_rows: 3,
+            ..BatcherConfig::NEVER
+        };
+        let batcher = Batcher::new(config, RecordingId::random(), sink.clone());
+
+        for i in 0..7 {
+            batcher.push_row(row(&i.to_string()));
+        }
+        assert_eq!(num_rows_per_msg(&sink.drain_backlog()), vec![3, 3]);
+
+        drop(batcher);
+        assert_eq!(num_rows_per_msg(&sink.drain_backlog()), vec![1]);
+    }
+
+    #[test]
+    fn always_sends_every_row() {
+        let sink: Arc<dyn LogSink> = Arc::new(BufferedSink::new());
+        let batcher = Batcher::new(BatcherConfig::ALWAYS, RecordingId::random(), sink.clone());
+
+        batcher.push_row(row("a"));
+        batcher.push_row(row("b"));
+        assert_eq!(num_rows_per_msg(&sink.drain_backlog()), vec![1, 1]);
+    }
+
+    #[test]
+    fn keeps_order_of_messages() {
+        let sink: Arc<dyn LogSink> = Arc::new(BufferedSink::new());
+        let recording_id = RecordingId::random();
+        let batcher = Batcher::new(BatcherConfig::NEVER, recording_id, sink.clone());
+
+        let rows = [row("a"), row("b"), row("c")];
+        let row_ids = rows.iter().map(|row| row.row_id()).collect::<Vec<_>>();
+        let [a, b, c] = rows;
+        batcher.push_row(a);
+        batcher.push_row(b);
+        batcher.send(LogMsg::EntityPathOpMsg(
+            recording_id,
+            EntityPathOpMsg {
+                row_id: RowId::random(),
+                time_point: TimePoint::default(),
+                path_op: PathOp::clear(false, "some/path".into()),
+            },
+        ));
+        batcher.push_row(c);
+        batcher.flush();
+
+        let messages = sink.drain_backlog();
+        assert_eq!(num_rows_per_msg(&messages), vec![2, 0, 1]);
+        assert!(matches!(messages[1], LogMsg::EntityPathOpMsg(..)));
+
+        let sent_row_ids = messages
+            .iter()
+            .filter_map(|msg| match msg {
+                LogMsg::ArrowMsg(_, msg) => Some(DataTable::from_arrow_msg(msg).unwrap()),
+                _ => None,
+            })
+            .flat_map(|table| table.to_rows().map(|row| row.row_id()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(sent_row_ids, row_ids);
+    }
+
+    #[test]
+    fn logging_does_not_wait_for_a_blocked_sink() {
+        struct BlockedSink {
+            unblock: Mutex<std::sync::mpsc::Receiver<()>>,
+            sink: BufferedSink,
+        }
+
+        impl LogSink for BlockedSink {
+            fn send(&self, msg: LogMsg) {
+                self.unblock.lock().recv().ok();
+                self.sink.send(msg);
+            }
+
+            fn drain_backlog(&self) -> Vec<LogMsg> {
+                self.sink.drain_backlog()
+            }
+        }
+
+        let (unblock, blocked) = std::sync::mpsc::channel();
+        let sink = Arc::new(BlockedSink {
+            unblock: Mutex::new(blocked),
+            sink: BufferedSink::new(),
+        });
+        let batcher = Batcher::new(BatcherConfig::NEVER, RecordingId::random(), sink.clone());
+
+        batcher.push_row(row("a"));
+        let flushing = std::thread::spawn({
+            let batcher = batcher.clone();
+            move || batcher.flush_rows()
+        });
+        // Wait for the flushing thread to get stuck in the sink.
+        let handed_to_sink = || {
+            let pending = batcher.pending.lock();
+            pending.rows.is_empty() && pending.outbox.is_empty()
+        };
+        while !handed_to_sink() {
+            std::thread::yield_now();
+        }
+
+        batcher.push_row(row("b"));
+
+        unblock.send(()).unwrap();
+        unblock.send(()).unwrap();
+        flushing.join().unwrap();
+        batcher.flush_rows();
+        assert_eq!(num_rows_per_msg(&sink.drain_backlog()), vec![1, 1]);
+    }
+}
@@ -5,6 +5,9 @@ use crate::session::Session;
 /// The default [`Session`] is a disabled dummy-session that ignore all log calls,
 /// so you need to explicitly set the global session for it to be useful
 ///
+/// To log several recordings at once, use one [`Session`] per recording instead,
+/// together with [`Session::set_thread_local`] and [`Session::current`].
+///
 /// Example usage:
 ///
 /// ```
@@ -12,9 +12,17 @@
 #[cfg(feature = "global_session")]
 mod global;
 
+#[cfg(any(feature = "log", feature = "tracing"))]
+mod log_integration;
+
+mod batcher;
 mod log_sink;
 mod msg_sender;
 mod session;
+mod thread_info;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod spawn;
 
 // -------------
 // Public items:
@@ -22,9 +30,19 @@ mod session;
 #[cfg(feature = "global_session")]
 pub use self::global::global_session;
 
+#[cfg(feature = "log")]
+pub use self::log_integration::RerunLogger;
+
+#[cfg(feature = "tracing")]
+pub use self::log_integration::RerunLayer;
+
+pub use self::batcher::BatcherConfig;
 pub use self::msg_sender::{MsgSender, MsgSenderError};
 pub use self::session::{Session, SessionBuilder};
 
+#[cfg(not(target_arch = "wasm32"))]
+pub use self::spawn::{spawn, SpawnError, SpawnOptions};
+
 pub use re_sdk_comms::default_server_addr;
 
 pub use re_log_types::{
@@ -38,9 +56,18 @@ impl crate::sink::LogSink for re_log_encoding::FileSink {
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+impl crate::sink::LogSink for re_log_encoding::RotatingFileSink {
+    fn send(&self, msg: re_log_types::LogMsg) {
+        re_log_encoding::RotatingFileSink::send(self, msg);
+    }
+}
+
 // ---------------
 // Public modules:
 
+pub mod archetypes;
+
 #[cfg(feature = "demo")]
 pub mod demo_util;
 
@@ -50,11 +77,13 @@ pub mod demo_util;
 /// sent over TCP, written to file, etc.
 pub mod sink {
     pub use crate::log_sink::{
-        disabled, BufferedSink, LogSink, MemorySink, MemorySinkStorage, TcpSink,
+        disabled, BufferedSink, LogSink, MemorySink, MemorySinkStorage, TcpSink, TeeSink,
     };
 
+    pub use re_sdk_comms::{ClientOptions, DropPolicy};
+
     #[cfg(not(target_arch = "wasm32"))]
-    pub use re_log_encoding::{FileSink, FileSinkError};
+    pub use re_log_encoding::{FileSink, FileSinkError, RotatingFileSink, RotationOptions};
 }
 
 /// Things directly related to logging.
@@ -62,9 +91,13 @@ pub mod log {
     pub use re_log_types::{DataCell, DataRow, DataTable, LogMsg, PathOp, RowId, TableId};
 }
 
-/// Time-related types.
+/// Time-related types, and the time of the current thread.
 pub mod time {
     pub use re_log_types::{Time, TimeInt, TimePoint, TimeType, Timeline};
+
+    pub use crate::thread_info::{
+        reset_time, set_time, set_time_nanos, set_time_seconds, set_time_sequence, thread_time,
+    };
 }
 
 /// These are the different _components_ you can log.
@@ -73,11 +106,14 @@ pub mod time {
 /// and can be used in [`MsgSender::with_component`].
 pub mod components {
     pub use re_log_types::component_types::{
-        AnnotationContext, AnnotationInfo, Arrow3D, Box3D, ClassDescription, ClassId, ColorRGBA,
-        EncodedMesh3D, InstanceKey, KeypointId, Label, LineStrip2D, LineStrip3D, Mat3x3, Mesh3D,
-        MeshFormat, MeshId, Pinhole, Point2D, Point3D, Quaternion, Radius, RawMesh3D, Rect2D,
-        Rigid3, Scalar, ScalarPlotProps, Size3D, Tensor, TensorData, TensorDataMeaning,
-        TensorDimension, TensorId, TextEntry, Transform, Vec2D, Vec3D, Vec4D, ViewCoordinates,
+        AnnotationContext, AnnotationInfo, Arrow3D, AxisMetadata, Box3D, ClassDescription, ClassId,
+        ColorRGBA, DistortionModel, Ellipse2D, Ellipsoid3D, EncodedImage, EncodedMesh3D,
+        GeoLineStrip, GeoPoint, Histogram, HistogramBins, ImageEncoding, InstanceKey, KeypointId,
+        Label, LensDistortion, LineStrip2D, LineStrip3D, Mat3x3, Mesh3D, MeshFormat, MeshId,
+        OrientedBox2D, Pinhole, Point2D, Point3D, Polygon2D, PolygonDrawMode, Quaternion, Radius,
+        RawMesh3D, Rect2D, Rigid3, Scalar, ScalarPlotProps, Size3D, Tensor, TensorData,
+        TensorDataMeaning, TensorDimension, TensorId, TextDocument, TextEntry, Transform, Vec2D,
+        Vec3D, Vec4D, VertexColors, VertexRadii, ViewCoordinates,
     };
 }
 
@@ -98,6 +134,12 @@ pub mod external {
 
     #[cfg(feature = "image")]
     pub use re_log_types::external::image;
+
+    #[cfg(feature = "nalgebra")]
+    pub use re_log_types::external::nalgebra;
+
+    #[cfg(feature = "ndarray")]
+    pub use ndarray;
 }
 
 // -----
@@ -0,0 +1,482 @@
+//! Forward text logging from the [`log`] and [`tracing`] crates to Rerun,
+//! as [`TextEntry`] rows.
+//!
+//! Each message ends up at an entity path derived from its target,
+//! e.g. a message from `my_crate::io` is logged to `logs/my_crate/io`.
+//! Messages are logged like any other [`MsgSender`], so they are on the automatic timelines
+//! as well as the time of the calling thread and session (see [`crate::time`]).
+
+use std::cell::Cell;
+
+use re_log_types::EntityPathPart;
+
+use crate::{components::TextEntry, EntityPath, MsgSender, Session};
+
+/// Where messages go when nothing else is specified.
+const DEFAULT_PREFIX: &str = "logs";
+
+/// Where to send the messages, and where to put them.
+struct Destination {
+    /// `None` means [`Session::current`], at the time of each message.
+    session: Option<Session>,
+    prefix: EntityPath,
+
+    /// Targets whose messages are not forwarded, in addition to the Rerun crates.
+    ignored_targets: Vec<String>,
+}
+
+impl Destination {
+    fn new(session: Option<Session>) -> Self {
+        Self {
+            session,
+            prefix: DEFAULT_PREFIX.into(),
+            ignored_targets: Vec::new(),
+        }
+    }
+
+    /// Messages from the Rerun crates themselves are never forwarded, to avoid logging the SDK's
+    /// own messages about sending the log messages, etc.
+    ///
+    /// The Rerun crates are recognized by their names: `rerun`, `rerun_*` and `re_*`.
+    ///
+    /// `my_crate::io::disk` is ignored if `my_crate` or `my_crate::io` is one of the
+    /// [`Self::ignored_targets`].
+    fn is_ignored(&self, target: &str) -> bool {
+        let crate_name = target.split("::").next().unwrap_or(target);
+        let is_rerun_crate = crate_name == "rerun"
+            || crate_name.starts_with("rerun_")
+            || crate_name.starts_with("re_");
+
+        is_rerun_crate
+            || self.ignored_targets.iter().any(|ignored| {
+                target
+                    .strip_prefix(ignored.as_str())
+                    .map_or(false, |rest| rest.is_empty() || rest.starts_with("::"))
+            })
+    }
+
+    /// `my_crate::io` -> `logs/my_crate/io`
+    fn entity_path(&self, target: &str) -> EntityPath {
+        self.prefix
+            .iter()
+            .cloned()
+            .chain(
+                target
+                    .split("::")
+                    .filter(|part| !part.is_empty())
+                    .map(EntityPathPart::from),
+            )
+            .collect()
+    }
+
+    fn send(&self, msg_sender: Result<MsgSender, crate::MsgSenderError>) {
+        let Some(_guard) = SendingGuard::new() else {
+            return;
+        };
+
+        let result: Result<(), crate::MsgSenderError> = match msg_sender {
+            Ok(msg_sender) => match &self.session {
+                Some(session) => msg_sender.send(session).map_err(Into::into),
+                None => msg_sender.send(&Session::current()).map_err(Into::into),
+            },
+            Err(err) => Err(err),
+        };
+        if let Err(err) = result {
+            re_log::warn_once!("Failed to forward log message to Rerun: {err}");
+        }
+    }
+}
+
+thread_local! {
+    static IS_SENDING: Cell<bool> = Cell::new(false);
+}
+
+/// Anything logged while sending must not be sent again.
+///
+/// Held while sending, and released even if sending panics.
+struct SendingGuard;
+
+impl SendingGuard {
+    /// `None` if this thread is already sending.
+    fn new() -> Option<Self> {
+        (!IS_SENDING.with(|is_sending| is_sending.replace(true))).then_some(Self)
+    }
+}
+
+impl Drop for SendingGuard {
+    fn drop(&mut self) {
+        IS_SENDING.with(|is_sending| is_sending.set(false));
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A [`log::Log`] that forwards log messages to Rerun as [`TextEntry`] rows.
+///
+/// ```
+/// # let session = re_sdk::Session::disabled();
+/// let logger = re_sdk::RerunLogger::new(session).with_filter(log::LevelFilter::Debug);
+/// log::set_boxed_logger(Box::new(logger)).ok();
+/// log::set_max_level(log::LevelFilter::Debug);
+/// ```
+///
+/// If you already use [`re_log`](crate::external::re_log) for your own logging,
+/// install it with [`re_log::add_boxed_logger`](crate::external::re_log::add_boxed_logger) instead.
+#[cfg(feature = "log")]
+pub struct RerunLogger {
+    destination: Destination,
+    filter: log::LevelFilter,
+}
+
+#[cfg(feature = "log")]
+impl RerunLogger {
+    /// Forward log messages to this session.
+    ///
+    /// Only messages at `INFO` or more severe are forwarded by default, see [`Self::with_filter`].
+    pub fn new(session: Session) -> Self {
+        Self {
+            destination: Destination::new(Some(session)),
+            filter: log::LevelFilter::Info,
+        }
+    }
+
+    /// Forward log messages to whatever [`Session::current`] is at the time of each message.
+    pub fn current() -> Self {
+        Self {
+            destination: Destination::new(None),
+            filter: log::LevelFilter::Info,
+        }
+    }
+
+    /// Log the messages under this entity path, instead of `logs`.
+    pub fn with_prefix(mut self, prefix: impl Into<EntityPath>) -> Self {
+        self.destination.prefix = prefix.into();
+        self
+    }
+
+    /// Only forward messages at this level or more severe.
+    pub fn with_filter(mut self, filter: log::LevelFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Don't forward messages from this crate or module, e.g. `"hyper"` or `"my_crate::io"`.
+    ///
+    /// Messages from the Rerun crates themselves are never forwarded.
+    pub fn with_ignored_target(mut self, target: impl Into<String>) -> Self {
+        self.destination.ignored_targets.push(target.into());
+        self
+    }
+}
+
+#[cfg(feature = "log")]
+impl log::Log for RerunLogger {
+    fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+        metadata.level() <= self.filter && !self.destination.is_ignored(metadata.target())
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let text_entry = TextEntry::new(
+            record.args().to_string(),
+            Some(record.level().as_str().to_owned()),
+        );
+        self.destination.send(
+            MsgSender::new(self.destination.entity_path(record.target()))
+                .with_component(&[text_entry]),
+        );
+    }
+
+    fn flush(&self) {}
+}
+
+// ----------------------------------------------------------------------------
+
+/// A [`tracing_subscriber::Layer`] that forwards events to Rerun as [`TextEntry`] rows,
+/// and optionally the durations of spans as [`Scalar`](crate::components::Scalar)s.
+///
+/// ```
+/// use tracing_subscriber::layer::SubscriberExt as _;
+///
+/// # let session = re_sdk::Session::disabled();
+/// let subscriber = tracing_subscriber::registry()
+///     .with(re_sdk::RerunLayer::new(session).with_span_durations(true));
+/// tracing::subscriber::set_global_default(subscriber).ok();
+/// ```
+///
+/// The fields of an event other than its `message` are appended to the text as `key=value`.
+#[cfg(feature = "tracing")]
+pub struct RerunLayer {
+    destination: Destination,
+    span_durations: bool,
+}
+
+#[cfg(feature = "tracing")]
+impl RerunLayer {
+    /// Forward events to this session.
+    pub fn new(session: Session) -> Self {
+        Self {
+            destination: Destination::new(Some(session)),
+            span_durations: false,
+        }
+    }
+
+    /// Forward events to whatever [`Session::current`] is at the time of each event.
+    pub fn current() -> Self {
+        Self {
+            destination: Destination::new(None),
+            span_durations: false,
+        }
+    }
+
+    /// Log the events under this entity path, instead of `logs`.
+    pub fn with_prefix(mut self, prefix: impl Into<EntityPath>) -> Self {
+        self.destination.prefix = prefix.into();
+        self
+    }
+
+    /// Also log how long each span was open for, in seconds, when it closes.
+    ///
+    /// The durations of a span called `load` in `my_crate::io` end up at `logs/my_crate/io/load`.
+    pub fn with_span_durations(mut self, span_durations: bool) -> Self {
+        self.span_durations = span_durations;
+        self
+    }
+
+    /// Don't forward events and spans from this crate or module, e.g. `"hyper"` or `"my_crate::io"`.
+    ///
+    /// Events and spans from the Rerun crates themselves are never forwarded.
+    pub fn with_ignored_target(mut self, target: impl Into<String>) -> Self {
+        self.destination.ignored_targets.push(target.into());
+        self
+    }
+}
+
+/// When a span was created, stored in its extensions.
+#[cfg(feature = "tracing")]
+struct SpanStart(instant::Instant);
+
+#[cfg(feature = "tracing")]
+impl<S> tracing_subscriber::Layer<S> for RerunLayer
+where
+    S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+{
+    fn on_event(
+        &self,
+        event: &tracing::Event<'_>,
+        _ctx: tracing_subscriber::layer::Context<'_, S>,
+    ) {
+        let metadata = event.metadata();
+        if self.destination.is_ignored(metadata.target()) {
+            return;
+        }
+
+        let mut visitor = TextVisitor::default();
+        event.record(&mut visitor);
+
+        let text_entry = TextEntry::new(
+            visitor.into_text(),
+            Some(metadata.level().as_str().to_owned()),
+        );
+        self.destination.send(
+            MsgSender::new(self.destination.entity_path(metadata.target()))
+                .with_component(&[text_entry]),
+        );
+    }
+
+    fn on_new_span(
+        &self,
+        _attrs: &tracing::span::Attributes<'_>,
+        id: &tracing::span::Id,
+        ctx: tracing_subscriber::layer::Context<'_, S>,
+    ) {
+        if !self.span_durations {
+            return;
+        }
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut()
+                .insert(SpanStart(instant::Instant::now()));
+        }
+    }
+
+    fn on_close(&self, id: tracing::span::Id, ctx: tracing_subscriber::layer::Context<'_, S>) {
+        if !self.span_durations {
+            return;
+        }
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let metadata = span.metadata();
+        if self.destination.is_ignored(metadata.target()) {
+            return;
+        }
+        let Some(duration) = span
+            .extensions()
+            .get::<SpanStart>()
+            .map(|SpanStart(start)| start.elapsed())
+        else {
+            return;
+        };
+
+        let entity_path: EntityPath = self
+            .destination
+            .entity_path(metadata.target())
+            .iter()
+            .cloned()
+            .chain(std::iter::once(EntityPathPart::from(metadata.name())))
+            .collect();
+        self.destination.send(
+            MsgSender::new(entity_path)
+                .with_component(&[crate::components::Scalar(duration.as_secs_f64())]),
+        );
+    }
+}
+
+/// Turns the fields of an event into text.
+#[cfg(feature = "tracing")]
+#[derive(Default)]
+struct TextVisitor {
+    message: String,
+    fields: Vec<String>,
+}
+
+#[cfg(feature = "tracing")]
+impl TextVisitor {
+    fn into_text(self) -> String {
+        let Self {
+            mut message,
+            fields,
+        } = self;
+        for field in fields {
+            if !message.is_empty() {
+                message.push(' ');
+            }
+            message.push_str(&field);
+        }
+        message
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl tracing::field::Visit for TextVisitor {
+    fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_owned();
+        } else {
+            self.fields.push(format!("{}={value:?}", field.name()));
+        }
+    }
+
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{value:?}");
+        } else {
+            self.fields.push(format!("{}={value:?}", field.name()));
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+fn logged_text(session: &Session) -> Vec<(EntityPath, TextEntry)> {
+    use re_log_types::{DataTable, LogMsg};
+
+    session
+        .drain_backlog()
+        .iter()
+        .filter_map(|msg| match msg {
+            LogMsg::ArrowMsg(_, msg) => Some(DataTable::from_arrow_msg(msg).unwrap()),
+            _ => None,
+        })
+        .flat_map(|table| table.to_rows().collect::<Vec<_>>())
+        .filter_map(|row| {
+            let cell = row
+                .cells()
+                .iter()
+                .find(|cell| cell.component_name() == <TextEntry as crate::Component>::name())?;
+            let text_entry = cell.to_native::<TextEntry>().next()?;
+            Some((row.entity_path().clone(), text_entry))
+        })
+        .collect()
+}
+
+#[test]
+fn test_sending_guard_released_on_panic() {
+    let result = std::panic::catch_unwind(|| {
+        let _guard = SendingGuard::new().unwrap();
+        assert!(SendingGuard::new().is_none());
+        panic!("failed to send");
+    });
+    assert!(result.is_err());
+    assert!(SendingGuard::new().is_some());
+}
+
+#[cfg(feature = "log")]
+#[test]
+fn test_rerun_logger() {
+    use log::Log as _;
+
+    let session = Session::buffered(crate::new_recording_info("test"));
+    let logger = RerunLogger::new(session.clone()).with_ignored_target("my_crate::noisy");
+
+    let record = |level, target, text| {
+        logger.log(
+            &log::Record::builder()
+                .level(level)
+                .target(target)
+                .args(format_args!("{text}"))
+                .build(),
+        );
+    };
+    record(log::Level::Warn, "my_crate::io", "disk is full");
+    record(log::Level::Debug, "my_crate::io", "filtered out");
+    record(log::Level::Error, "re_sdk::session", "ignored");
+    record(log::Level::Error, "re_some_new_crate", "ignored");
+    record(log::Level::Error, "rerun_bindings", "ignored");
+    record(log::Level::Error, "my_crate::noisy::spam", "ignored");
+    record(log::Level::Error, "my_crate::noisy_neighbor", "not ignored");
+    record(log::Level::Error, "rerunner", "not a Rerun crate");
+
+    assert_eq!(
+        logged_text(&session),
+        vec![
+            (
+                EntityPath::from("logs/my_crate/io"),
+                TextEntry::new("disk is full", Some("WARN".to_owned()))
+            ),
+            (
+                EntityPath::from("logs/my_crate/noisy_neighbor"),
+                TextEntry::new("not ignored", Some("ERROR".to_owned()))
+            ),
+            (
+                EntityPath::from("logs/rerunner"),
+                TextEntry::new("not a Rerun crate", Some("ERROR".to_owned()))
+            ),
+        ]
+    );
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn test_rerun_layer() {
+    use tracing_subscriber::layer::SubscriberExt as _;
+
+    let session = Session::buffered(crate::new_recording_info("test"));
+    let subscriber = tracing_subscriber::registry().with(RerunLayer::new(session.clone()));
+
+    tracing::subscriber::with_default(subscriber, || {
+        tracing::info!(target: "my_crate::io", bytes = 42, "wrote file");
+    });
+
+    assert_eq!(
+        logged_text(&session),
+        vec![(
+            EntityPath::from("logs/my_crate/io"),
+            TextEntry::new("wrote file bytes=42", Some("INFO".to_owned()))
+        )]
+    );
+}
@@ -125,6 +125,104 @@ impl MemorySinkStorage {
 
 // ----------------------------------------------------------------------------
 
+/// Send every log message to several sinks at once.
+///
+/// For instance, stream to a viewer while also saving to a file:
+///
+/// ``` no_run
+/// # use re_sdk::sink::{FileSink, TcpSink, TeeSink};
+/// let sink = TeeSink::new()
+///     .with_sink(Box::new(TcpSink::new(re_sdk::default_server_addr())))
+///     .with_sink(Box::new(FileSink::new("black_box.rrd")?));
+/// let session = re_sdk::SessionBuilder::new("my_app").sink(Box::new(sink));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// The sinks are independent of each other: each one gets its own copy of every message
+/// and handles its own errors, so e.g. a disconnected viewer does not stop the file from
+/// being written. [`LogSink::flush`] flushes the sinks one after the other.
+#[derive(Default)]
+pub struct TeeSink {
+    sinks: Vec<Box<dyn LogSink>>,
+}
+
+impl TeeSink {
+    /// A tee without any sinks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also send all messages to this sink.
+    ///
+    /// Disabled sinks are ignored.
+    pub fn with_sink(mut self, sink: Box<dyn LogSink>) -> Self {
+        if sink.is_enabled() {
+            self.sinks.push(sink);
+        } else {
+            re_log::debug!("Ignoring disabled sink");
+        }
+        self
+    }
+
+    /// The sinks that messages are sent to.
+    pub fn sinks(&self) -> &[Box<dyn LogSink>] {
+        &self.sinks
+    }
+}
+
+impl FromIterator<Box<dyn LogSink>> for TeeSink {
+    fn from_iter<T: IntoIterator<Item = Box<dyn LogSink>>>(iter: T) -> Self {
+        iter.into_iter()
+            .fold(Self::new(), |tee, sink| tee.with_sink(sink))
+    }
+}
+
+impl LogSink for TeeSink {
+    fn send(&self, msg: LogMsg) {
+        if let Some((last, rest)) = self.sinks.split_last() {
+            for sink in rest {
+                sink.send(msg.clone());
+            }
+            last.send(msg);
+        }
+    }
+
+    fn send_all(&self, messages: Vec<LogMsg>) {
+        if let Some((last, rest)) = self.sinks.split_last() {
+            for sink in rest {
+                sink.send_all(messages.clone());
+            }
+            last.send_all(messages);
+        }
+    }
+
+    /// The backlogs of all sinks, one after the other.
+    fn drain_backlog(&self) -> Vec<LogMsg> {
+        self.sinks
+            .iter()
+            .flat_map(|sink| sink.drain_backlog())
+            .collect()
+    }
+
+    fn flush(&self) {
+        for sink in &self.sinks {
+            sink.flush();
+        }
+    }
+
+    fn drop_msgs_if_disconnected(&self) {
+        for sink in &self.sinks {
+            sink.drop_msgs_if_disconnected();
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        !self.sinks.is_empty()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// Stream log messages to a Rerun TCP server.
 pub struct TcpSink {
     client: re_sdk_comms::Client,
@@ -138,6 +236,13 @@ impl TcpSink {
             client: re_sdk_comms::Client::new(addr),
         }
     }
+
+    /// Like [`Self::new`], but with control over reconnecting and the backlog of unsent messages.
+    pub fn with_options(addr: std::net::SocketAddr, options: re_sdk_comms::ClientOptions) -> Self {
+        Self {
+            client: re_sdk_comms::Client::with_options(addr, options),
+        }
+    }
 }
 
 impl LogSink for TcpSink {
@@ -153,3 +258,25 @@ impl LogSink for TcpSink {
         self.client.drop_if_disconnected();
     }
 }
+
+// ----------------------------------------------------------------------------
+
+#[test]
+fn tee_sink() {
+    let memory = MemorySink::default();
+    let storage = memory.buffer();
+    let tee = TeeSink::new()
+        .with_sink(Box::new(memory))
+        .with_sink(disabled())
+        .with_sink(Box::new(BufferedSink::new()));
+    assert_eq!(tee.sinks().len(), 2);
+    assert!(tee.is_enabled());
+    assert!(!TeeSink::new().is_enabled());
+
+    let msg = LogMsg::Goodbye(re_log_types::RowId::random());
+    tee.send(msg.clone());
+    tee.send_all(vec![msg.clone()]);
+
+    assert_eq!(tee.drain_backlog(), vec![msg.clone(), msg.clone()]);
+    assert_eq!(*storage.lock(), vec![msg.clone(), msg]);
+}
@@ -1,17 +1,13 @@
-use std::borrow::Borrow;
-
-use re_log_types::{component_types::InstanceKey, DataRow, DataTableError, RecordingId, RowId};
+use re_log_types::{component_types::InstanceKey, DataRow, DataTableError, RowId};
 
 use crate::{
+    archetypes::{Archetype, ArchetypeError},
     components::Transform,
-    log::{DataCell, LogMsg},
-    sink::LogSink,
-    time::{Time, TimeInt, TimePoint, Timeline},
+    log::DataCell,
+    time::{TimeInt, TimePoint, Timeline},
     Component, EntityPath, SerializableComponent, Session,
 };
 
-// TODO(#1619): Rust SDK batching
-
 // ---
 
 /// Errors that can occur when constructing or sending messages
@@ -29,6 +25,10 @@ pub enum MsgSenderError {
     /// A message during packing. See [`DataTableError`].
     #[error(transparent)]
     PackingError(#[from] DataTableError),
+
+    /// The components of an archetype don't fit together. See [`ArchetypeError`].
+    #[error(transparent)]
+    Archetype(#[from] ArchetypeError),
 }
 
 /// Facilitates building and sending component payloads with the Rerun SDK.
@@ -59,9 +59,16 @@ pub struct MsgSender {
 
     /// All the different timestamps for this message.
     ///
-    /// The logging time is automatically inserted during creation ([`Self::new`]).
+    /// The logging time and tick are automatically inserted during creation ([`Self::new`]).
     timepoint: TimePoint,
 
+    /// The time of the calling thread when this message was created, see [`crate::time`],
+    /// or `None` if it should be ignored.
+    ///
+    /// The thread's time for the recording this is sent to is added in [`Self::send`].
+    /// Explicit times in `timepoint` take precedence.
+    thread_time: Option<TimePoint>,
+
     /// If true, all timestamp data associated with this message will be dropped right before
     /// sending it to Rerun.
     ///
@@ -94,12 +101,14 @@ pub struct MsgSender {
 impl MsgSender {
     /// Starts a new `MsgSender` for the given entity path.
     ///
-    /// It is during this call that the logging time for the message is recorded!
+    /// It is during this call that the logging time and tick for the message are recorded,
+    /// along with the current time of the calling thread (see [`crate::time::set_time_sequence`]).
     pub fn new(ent_path: impl Into<EntityPath>) -> Self {
         Self {
             entity_path: ent_path.into(),
 
-            timepoint: [(Timeline::log_time(), Time::now().into())].into(),
+            timepoint: crate::thread_info::log_timepoint(),
+            thread_time: Some(crate::time::thread_time()),
             timeless: false,
 
             num_instances: None,
@@ -141,6 +150,16 @@ impl MsgSender {
         self
     }
 
+    /// Specifies whether the current time of the calling thread is merged into this message.
+    ///
+    /// Defaults to `true`.
+    /// See [`crate::time::set_time_sequence`] and [`Session::set_time_sequence`].
+    #[inline]
+    pub fn with_thread_time(mut self, with_thread_time: bool) -> Self {
+        self.thread_time = with_thread_time.then(crate::time::thread_time);
+        self
+    }
+
     /// Specifies whether the current message is timeless.
     ///
     /// A timeless message will drop all of its timestamp data before being sent to Rerun.
@@ -169,11 +188,35 @@ impl MsgSender {
     //
     // TODO(#589): batch insertions
     pub fn with_component<'a, C: SerializableComponent>(
-        mut self,
+        self,
         data: impl IntoIterator<Item = &'a C>,
     ) -> Result<Self, MsgSenderError> {
         let cell = DataCell::try_from_native(data).map_err(DataTableError::from)?;
+        Ok(self.with_cell(cell))
+    }
+
+    /// Appends a collection of any values that can be serialized with serde, as a component
+    /// with the given name, e.g. `"ext.config"`.
+    ///
+    /// This is useful for logging your own data types without implementing [`Component`]
+    /// for them.
+    /// The viewer shows them as a tree of their fields.
+    /// See [`re_log_types::serde_arrow`] for how the values are turned into arrow.
+    /// The [`serde::Deserialize`] implementation is what decides the arrow datatype,
+    /// so every value of `T` gets the same one.
+    ///
+    /// The same rules as for [`Self::with_component`] apply.
+    #[cfg(feature = "serde")]
+    pub fn with_serde_component<'a, T: serde::Serialize + serde::Deserialize<'static> + 'a>(
+        self,
+        name: impl Into<crate::ComponentName>,
+        data: impl IntoIterator<Item = &'a T>,
+    ) -> Result<Self, MsgSenderError> {
+        let cell = DataCell::try_from_serde(name.into(), data).map_err(DataTableError::from)?;
+        Ok(self.with_cell(cell))
+    }
 
+    fn with_cell(mut self, cell: DataCell) -> Self {
         let num_instances = cell.num_instances();
 
         // If this is the first appended collection, it gets to decide the row-length (i.e. number
@@ -186,7 +229,7 @@ impl MsgSender {
 
         self.instanced.push(cell);
 
-        Ok(self)
+        self
     }
 
     /// Appends a splatted component to the current message.
@@ -227,67 +270,82 @@ impl MsgSender {
         }
     }
 
-    // --- Send ---
-
-    /// Consumes, packs, sanity checks and finally sends the message to the currently configured
-    /// target of the SDK.
-    pub fn send(self, session: &Session) -> Result<(), DataTableError> {
-        self.send_to_sink(session.recording_id(), session.borrow())
+    /// Appends all the components of an [`Archetype`] to the current message,
+    /// e.g. a [`crate::archetypes::Points3D`].
+    ///
+    /// The archetype checks that its components have matching numbers of instances first.
+    pub fn with_archetype(self, archetype: impl Archetype) -> Result<Self, MsgSenderError> {
+        archetype.add_to(self)
     }
 
+    // --- Send ---
+
     /// Consumes, packs, sanity checks and finally sends the message to the currently configured
     /// target of the SDK.
-    fn send_to_sink(
-        self,
-        recording_id: RecordingId,
-        sink: &dyn LogSink,
-    ) -> Result<(), DataTableError> {
-        if !sink.is_enabled() {
+    ///
+    /// The resulting rows are batched with other logged rows before they reach the sink,
+    /// see [`crate::BatcherConfig`].
+    ///
+    /// Code that doesn't have a session at hand can send to [`Session::current`].
+    pub fn send(mut self, session: &Session) -> Result<(), DataTableError> {
+        if !session.is_enabled() {
             return Ok(()); // silently drop the message
         }
 
-        let [row_standard, row_transforms, row_splats] = self.into_rows();
-
-        if let Some(row_transforms) = row_transforms {
-            sink.send(LogMsg::ArrowMsg(
-                recording_id,
-                row_transforms.into_table().to_arrow_msg()?,
-            ));
-        }
-        if let Some(row_splats) = row_splats {
-            sink.send(LogMsg::ArrowMsg(
-                recording_id,
-                row_splats.into_table().to_arrow_msg()?,
-            ));
+        if let Some(thread_time) = &mut self.thread_time {
+            for (timeline, time) in session.thread_time() {
+                thread_time.insert(timeline, time);
+            }
         }
-        // Always the primary component last so range-based queries will include the other data.
-        // Since the primary component can't be splatted it must be in msg_standard, see(#1215).
-        if let Some(row_standard) = row_standard {
-            sink.send(LogMsg::ArrowMsg(
-                recording_id,
-                row_standard.into_table().to_arrow_msg()?,
-            ));
+
+        for row in self.into_data_rows() {
+            session.send_row(row);
         }
 
         Ok(())
     }
 
+    /// The rows of this message, in the order they should be sent.
+    pub(crate) fn into_data_rows(self) -> Vec<DataRow> {
+        let [row_standard, row_transforms, row_splats] = self.into_rows();
+
+        // Always the primary component last so range-based queries will include the other data.
+        // Since the primary component can't be splatted it must be in msg_standard, see(#1215).
+        [row_transforms, row_splats, row_standard]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
     fn into_rows(self) -> [Option<DataRow>; 3] {
         let Self {
             entity_path,
             timepoint,
+            thread_time,
             timeless,
             num_instances,
             instanced,
             mut splatted,
         } = self;
 
-        if timeless && timepoint.times().len() > 1 {
+        if timeless
+            && timepoint
+                .iter()
+                .any(|(timeline, _)| !timeline.is_automatic())
+        {
             re_log::warn_once!("Recorded timepoints in a timeless message, they will be dropped!");
         }
 
         // clear current timepoint if marked as timeless
-        let timepoint = if timeless { [].into() } else { timepoint };
+        let timepoint: TimePoint = if timeless {
+            [].into()
+        } else {
+            thread_time
+                .unwrap_or_default()
+                .into_iter()
+                .chain(timepoint)
+                .collect()
+        };
 
         // separate transforms from the rest
         // TODO(cmc): just use `Vec::drain_filter` once it goes stable...
@@ -418,6 +476,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn timepoint_thread_time() -> Result<(), MsgSenderError> {
+        let frame = Timeline::new_sequence("frame");
+        let sim_time = Timeline::new_sequence("sim_time");
+
+        time::set_time_sequence("frame", 1);
+        time::set_time_sequence("sim_time", 10);
+        let sender = MsgSender::new("some/path")
+            .with_component([components::Label("label1".into())].as_slice())?
+            .with_time(sim_time, 20);
+        time::reset_time();
+
+        let [standard, _, _] = sender.into_rows();
+        let timepoint = standard.unwrap().timepoint;
+        assert_eq!(timepoint.get(&frame), Some(&TimeInt::from(1)));
+        assert_eq!(timepoint.get(&sim_time), Some(&TimeInt::from(20))); // explicit time wins
+        assert!(timepoint.get(&Timeline::log_time()).is_some());
+        assert!(timepoint.get(&Timeline::log_tick()).is_some());
+
+        time::set_time_sequence("frame", 2);
+        let [standard, _, _] = MsgSender::new("some/path")
+            .with_thread_time(false)
+            .with_component([components::Label("label1".into())].as_slice())?
+            .into_rows();
+        time::reset_time();
+        assert_eq!(standard.unwrap().timepoint.get(&frame), None);
+
+        Ok(())
+    }
+
     #[test]
     fn timepoint_timeless() -> Result<(), MsgSenderError> {
         let my_timeline = Timeline::new("my_timeline", time::TimeType::Sequence);
@@ -456,4 +544,53 @@ mod tests {
 
         Ok(())
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_component() -> Result<(), MsgSenderError> {
+        let gains = [vec![0.5, 0.1], vec![0.2]];
+        let [standard, _, _] = MsgSender::new("some/path")
+            .with_serde_component("ext.gains", &gains)?
+            .into_rows();
+
+        let standard = standard.unwrap();
+        let idx = standard.find_cell(&"ext.gains".into()).unwrap();
+        let cell = &standard.cells[idx];
+        assert!(cell.num_instances() == 2);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_component_datatype() -> Result<(), MsgSenderError> {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Limits {
+            max: Option<u32>,
+            gains: Vec<f32>,
+        }
+
+        let datatype = |limits: Limits| -> Result<_, MsgSenderError> {
+            let [standard, _, _] = MsgSender::new("some/path")
+                .with_serde_component("ext.limits", [&limits])?
+                .into_rows();
+            let standard = standard.unwrap();
+            let idx = standard.find_cell(&"ext.limits".into()).unwrap();
+            Ok(standard.cells[idx].datatype().clone())
+        };
+
+        // The store rejects a component whose datatype changes between rows.
+        assert_eq!(
+            datatype(Limits {
+                max: None,
+                gains: Vec::new(),
+            })?,
+            datatype(Limits {
+                max: Some(3),
+                gains: vec![0.5],
+            })?,
+        );
+
+        Ok(())
+    }
 }
@@ -1,8 +1,11 @@
 use std::sync::Arc;
 
-use re_log_types::{ApplicationId, LogMsg, RecordingId, RecordingInfo, RecordingSource, Time};
+use re_log_types::{
+    ApplicationId, LogMsg, RecordingId, RecordingInfo, RecordingSource, Time, TimeInt, TimePoint,
+    Timeline,
+};
 
-use crate::sink::LogSink;
+use crate::{batcher::Batcher, sink::LogSink, thread_info, BatcherConfig};
 
 // ----------------------------------------------------------------------------
 
@@ -20,6 +23,7 @@ pub struct SessionBuilder {
     enabled: Option<bool>,
     default_enabled: bool,
     recording_id: Option<RecordingId>,
+    batcher_config: BatcherConfig,
 }
 
 impl SessionBuilder {
@@ -43,6 +47,7 @@ impl SessionBuilder {
             enabled: None,
             default_enabled: true,
             recording_id: None,
+            batcher_config: BatcherConfig::from_env(),
         }
     }
 
@@ -81,13 +86,26 @@ impl SessionBuilder {
         self
     }
 
+    /// Decide when logged rows are batched together and sent.
+    ///
+    /// The default is [`BatcherConfig::from_env`].
+    pub fn batcher_config(mut self, batcher_config: BatcherConfig) -> Self {
+        self.batcher_config = batcher_config;
+        self
+    }
+
     /// Buffer log messages in RAM.
     ///
     /// Retrieve them later with [`Session::drain_backlog`].
     pub fn buffered(self) -> Session {
+        let batcher_config = self.batcher_config.clone();
         let (rerun_enabled, recording_info) = self.finalize();
         if rerun_enabled {
-            Session::buffered(recording_info)
+            Session::with_batcher_config(
+                recording_info,
+                batcher_config,
+                Box::new(crate::sink::BufferedSink::new()),
+            )
         } else {
             re_log::debug!("Rerun disabled - call to buffered() ignored");
             Session::disabled()
@@ -110,10 +128,12 @@ impl SessionBuilder {
     /// let session = re_sdk::SessionBuilder::new("my_app").connect(re_sdk::default_server_addr());
     /// ```
     pub fn connect(self, addr: std::net::SocketAddr) -> Session {
+        let batcher_config = self.batcher_config.clone();
         let (rerun_enabled, recording_info) = self.finalize();
         if rerun_enabled {
-            Session::new(
+            Session::with_batcher_config(
                 recording_info,
+                batcher_config,
                 Box::new(crate::log_sink::TcpSink::new(addr)),
             )
         } else {
@@ -122,6 +142,42 @@ impl SessionBuilder {
         }
     }
 
+    /// Spawn a Rerun Viewer in a separate process, and stream log data to it.
+    ///
+    /// This needs the `rerun` binary (`cargo install rerun`) to be in the `PATH`.
+    /// If a viewer is already listening on the default port, that one is used instead.
+    ///
+    /// This function blocks until the viewer accepts connections.
+    ///
+    /// ``` no_run
+    /// let session = re_sdk::SessionBuilder::new("my_app").spawn()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// See [`Self::spawn_opts`] to choose the port, memory limit, etc.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn spawn(self) -> Result<Session, crate::SpawnError> {
+        self.spawn_opts(&Default::default())
+    }
+
+    /// Like [`Self::spawn`], but with control over how the viewer is started.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn spawn_opts(self, options: &crate::SpawnOptions) -> Result<Session, crate::SpawnError> {
+        let batcher_config = self.batcher_config.clone();
+        let (rerun_enabled, recording_info) = self.finalize();
+        if rerun_enabled {
+            crate::spawn(options)?;
+            Ok(Session::with_batcher_config(
+                recording_info,
+                batcher_config,
+                Box::new(crate::log_sink::TcpSink::new(options.connect_addr())),
+            ))
+        } else {
+            re_log::debug!("Rerun disabled - call to spawn() ignored");
+            Ok(Session::disabled())
+        }
+    }
+
     /// Stream all log messages to an `.rrd` file.
     ///
     /// ``` no_run
@@ -134,10 +190,12 @@ impl SessionBuilder {
         self,
         path: impl Into<std::path::PathBuf>,
     ) -> Result<Session, crate::sink::FileSinkError> {
+        let batcher_config = self.batcher_config.clone();
         let (rerun_enabled, recording_info) = self.finalize();
         if rerun_enabled {
-            Ok(Session::new(
+            Ok(Session::with_batcher_config(
                 recording_info,
+                batcher_config,
                 Box::new(crate::sink::FileSink::new(path)?),
             ))
         } else {
@@ -146,6 +204,19 @@ impl SessionBuilder {
         }
     }
 
+    /// Send all log messages to the given sink, e.g. a [`crate::sink::TeeSink`] to send them
+    /// to several destinations at once.
+    pub fn sink(self, sink: Box<dyn LogSink>) -> Session {
+        let batcher_config = self.batcher_config.clone();
+        let (rerun_enabled, recording_info) = self.finalize();
+        if rerun_enabled {
+            Session::with_batcher_config(recording_info, batcher_config, sink)
+        } else {
+            re_log::debug!("Rerun disabled - call to sink() ignored");
+            Session::disabled()
+        }
+    }
+
     /// Returns whether or not logging is enabled, plus a [`RecordingInfo`].
     ///
     /// This can be used to then construct a [`Session`] manually using [`Session::new`].
@@ -156,6 +227,7 @@ impl SessionBuilder {
             enabled,
             default_enabled,
             recording_id,
+            batcher_config: _,
         } = self;
 
         let enabled = enabled.unwrap_or_else(|| crate::decide_logging_enabled(default_enabled));
@@ -186,13 +258,23 @@ impl SessionBuilder {
 /// The clone will send its messages to the same sink as the prototype.
 ///
 /// `Session` also implements `Send` and `Sync`.
+///
+/// Any number of sessions can coexist in one process, each with its own [`RecordingId`],
+/// sink and time (see [`Self::set_time_sequence`]), e.g. one per thread to log several
+/// recordings in parallel.
+/// Use [`Self::set_thread_local`] to let code that calls [`Self::current`] log into a specific
+/// session on the current thread.
 #[must_use]
 #[derive(Clone)]
 pub struct Session {
     recording_info: RecordingInfo,
+
+    /// The [`Self::batcher`] if there is one, so that nothing sent to the sink
+    /// can overtake rows that are still waiting to be batched.
     sink: Arc<dyn LogSink>,
-    // TODO(emilk): add convenience `TimePoint` here so that users can
-    // do things like `session.set_time_sequence("frame", frame_idx);`
+
+    /// Packs logged rows into tables; `None` if the sink is disabled.
+    batcher: Option<Arc<Batcher>>,
 }
 
 #[test]
@@ -201,6 +283,84 @@ fn session_impl_send_sync() {
     assert_send_sync::<Session>();
 }
 
+#[test]
+fn concurrent_sessions() {
+    use crate::{components::Label, MsgSender};
+    use re_log_types::DataTable;
+
+    let episodes = (0..2)
+        .map(|episode| {
+            std::thread::spawn(move || {
+                let session = SessionBuilder::new("episodes").enabled(true).buffered();
+                session.set_time_sequence("step", episode);
+
+                // Library code logs to the session of its thread.
+                let previous = Session::set_thread_local(Some(session.clone()));
+                MsgSender::new("agent")
+                    .with_component([Label(format!("episode {episode}"))].as_slice())
+                    .unwrap()
+                    .send(&Session::current())
+                    .unwrap();
+                Session::set_thread_local(previous);
+                assert!(Session::thread_local().is_none());
+
+                (episode, session.recording_id(), session.drain_backlog())
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let step = Timeline::new_sequence("step");
+    let mut recording_ids = std::collections::BTreeSet::new();
+    for handle in episodes {
+        let (episode, recording_id, messages) = handle.join().unwrap();
+        recording_ids.insert(recording_id);
+
+        assert!(messages
+            .iter()
+            .all(|msg| msg.recording_id() == Some(&recording_id)));
+        let rows = messages
+            .iter()
+            .filter_map(|msg| match msg {
+                LogMsg::ArrowMsg(_, msg) => Some(DataTable::from_arrow_msg(msg).unwrap()),
+                _ => None,
+            })
+            .flat_map(|table| table.to_rows().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(
+            rows[0].timepoint().get(&step),
+            Some(&TimeInt::from(episode))
+        );
+    }
+    assert_eq!(recording_ids.len(), 2);
+}
+
+#[test]
+fn sink_keeps_order_with_logged_rows() {
+    use crate::{components::Label, MsgSender};
+
+    let session = SessionBuilder::new("sink_order")
+        .enabled(true)
+        .batcher_config(BatcherConfig::NEVER)
+        .buffered();
+    session.drain_backlog(); // the `BeginRecordingMsg`
+
+    MsgSender::new("some/path")
+        .with_component([Label("row".into())].as_slice())
+        .unwrap()
+        .send(&session)
+        .unwrap();
+    session
+        .sink()
+        .send(LogMsg::Goodbye(re_log_types::RowId::random()));
+
+    let messages = session.drain_backlog();
+    assert!(matches!(
+        messages.as_slice(),
+        [LogMsg::ArrowMsg(..), LogMsg::Goodbye(..)]
+    ));
+}
+
 impl Session {
     /// Construct a new session with a given [`RecordingInfo`] and [`LogSink`].
     ///
@@ -211,8 +371,21 @@ impl Session {
     ///
     /// You can find sinks in [`crate::sink`].
     ///
+    /// Logged rows are batched according to [`BatcherConfig::from_env`].
+    ///
     /// See also: [`SessionBuilder`].
     pub fn new(recording_info: RecordingInfo, sink: Box<dyn LogSink>) -> Self {
+        Self::with_batcher_config(recording_info, BatcherConfig::from_env(), sink)
+    }
+
+    /// Like [`Self::new`], but with explicit control over how logged rows are batched.
+    pub fn with_batcher_config(
+        recording_info: RecordingInfo,
+        batcher_config: BatcherConfig,
+        sink: Box<dyn LogSink>,
+    ) -> Self {
+        let sink: Arc<dyn LogSink> = sink.into();
+
         if sink.is_enabled() {
             re_log::debug!(
                 "Beginning new recording with application_id {:?} and recording id {}",
@@ -227,11 +400,19 @@ impl Session {
                 }
                 .into(),
             );
-        }
 
-        Self {
-            recording_info,
-            sink: sink.into(),
+            let batcher = Batcher::new(batcher_config, recording_info.recording_id, sink);
+            Self {
+                recording_info,
+                sink: batcher.clone(),
+                batcher: Some(batcher),
+            }
+        } else {
+            Self {
+                recording_info,
+                sink,
+                batcher: None,
+            }
         }
     }
 
@@ -251,6 +432,7 @@ impl Session {
                 },
             },
             sink: crate::sink::disabled().into(),
+            batcher: None,
         }
     }
 
@@ -268,16 +450,36 @@ impl Session {
         self.sink.is_enabled()
     }
 
-    /// Access the underlying log sink to where we send out log messages.
+    /// Access the log sink to where we send out log messages.
+    ///
+    /// Messages sent to it go through the same batching as [`Self::send`],
+    /// so they stay in order with the rows logged to the session.
     pub fn sink(&self) -> &Arc<dyn LogSink> {
         &self.sink
     }
 
     /// Send a [`LogMsg`].
+    ///
+    /// Any rows still waiting to be batched are sent first.
     pub fn send(&self, log_msg: LogMsg) {
         self.sink.send(log_msg);
     }
 
+    /// Send a [`DataRow`](re_log_types::DataRow).
+    ///
+    /// Rows are batched into larger tables before they reach the sink,
+    /// see [`BatcherConfig`].
+    pub fn send_row(&self, row: re_log_types::DataRow) {
+        if let Some(batcher) = &self.batcher {
+            batcher.push_row(row);
+        }
+    }
+
+    /// Send all rows waiting to be batched, then flush the sink.
+    pub fn flush(&self) {
+        self.sink.flush();
+    }
+
     /// Send a [`re_log_types::PathOp`].
     ///
     /// This is a convenience wrapper for [`Self::send`].
@@ -305,6 +507,99 @@ impl Session {
     pub fn recording_id(&self) -> RecordingId {
         self.recording_info.recording_id
     }
+
+    // --- Time ---
+
+    /// Set the current time of the calling thread on the given sequence timeline,
+    /// for this recording only.
+    ///
+    /// Every [`crate::MsgSender`] subsequently sent to this recording from this thread will be
+    /// logged at this time, unless it overrides it with [`crate::MsgSender::with_time`].
+    /// This takes precedence over the time of the thread set with
+    /// [`crate::time::set_time_sequence`].
+    pub fn set_time_sequence(&self, timeline: &str, sequence: impl Into<Option<i64>>) {
+        self.set_time(
+            Timeline::new_sequence(timeline),
+            sequence.into().map(TimeInt::from),
+        );
+    }
+
+    /// Set the current time of the calling thread on the given temporal timeline,
+    /// in seconds since the Unix epoch, for this recording only.
+    ///
+    /// See [`Self::set_time_sequence`] for more.
+    pub fn set_time_seconds(&self, timeline: &str, seconds: impl Into<Option<f64>>) {
+        self.set_time(
+            Timeline::new_temporal(timeline),
+            seconds
+                .into()
+                .map(|seconds| Time::from_seconds_since_epoch(seconds).into()),
+        );
+    }
+
+    /// Set the current time of the calling thread on the given temporal timeline,
+    /// in nanoseconds since the Unix epoch, for this recording only.
+    ///
+    /// See [`Self::set_time_sequence`] for more.
+    pub fn set_time_nanos(&self, timeline: &str, nanos: impl Into<Option<i64>>) {
+        self.set_time(
+            Timeline::new_temporal(timeline),
+            nanos
+                .into()
+                .map(|nanos| Time::from_ns_since_epoch(nanos).into()),
+        );
+    }
+
+    /// Set the current time of the calling thread on the given timeline, or clear it with `None`,
+    /// for this recording only.
+    ///
+    /// See [`Self::set_time_sequence`] for more.
+    pub fn set_time(&self, timeline: Timeline, time: Option<TimeInt>) {
+        thread_info::set_recording_time(self.recording_id(), timeline, time);
+    }
+
+    /// Clear the current time of the calling thread for this recording on all timelines.
+    pub fn reset_time(&self) {
+        thread_info::reset_recording_time(self.recording_id());
+    }
+
+    /// The current time of the calling thread for this recording,
+    /// as set by [`Self::set_time_sequence`] and friends.
+    pub fn thread_time(&self) -> TimePoint {
+        thread_info::recording_time(self.recording_id())
+    }
+
+    // --- Current session ---
+
+    /// Make `session` the session of the calling thread, returned by [`Self::current`].
+    ///
+    /// Returns the previous one, so it can be restored once done.
+    /// Pass `None` to go back to the global session.
+    pub fn set_thread_local(session: Option<Session>) -> Option<Session> {
+        thread_info::set_thread_session(session)
+    }
+
+    /// The session of the calling thread, as set by [`Self::set_thread_local`].
+    pub fn thread_local() -> Option<Session> {
+        thread_info::thread_session()
+    }
+
+    /// The session that code without a session at hand should log to.
+    ///
+    /// This is the session of the calling thread if there is one (see [`Self::set_thread_local`]),
+    /// otherwise the global session if the `global_session` feature is enabled,
+    /// otherwise a disabled session.
+    pub fn current() -> Session {
+        if let Some(session) = Self::thread_local() {
+            return session;
+        }
+
+        #[cfg(feature = "global_session")]
+        return crate::global_session().clone();
+
+        #[cfg(not(feature = "global_session"))]
+        Self::disabled()
+    }
 }
 
 impl AsRef<dyn LogSink> for Session {
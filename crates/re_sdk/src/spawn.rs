@@ -0,0 +1,168 @@
+use std::{
+    net::{SocketAddr, TcpStream},
+    path::PathBuf,
+    process::Command,
+    time::{Duration, Instant},
+};
+
+/// Options for spawning a Rerun Viewer in a separate process, see [`spawn`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpawnOptions {
+    /// The TCP port the viewer listens on for SDKs to connect to.
+    ///
+    /// Defaults to [`re_sdk_comms::DEFAULT_SERVER_PORT`].
+    pub port: u16,
+
+    /// An upper limit on how much memory the viewer should use, e.g. `"16GB"`.
+    ///
+    /// When this limit is reached, the viewer drops the oldest data.
+    pub memory_limit: Option<String>,
+
+    /// The `rerun` executable to run.
+    ///
+    /// Defaults to `rerun`, looked up in the `PATH`.
+    pub executable_path: Option<PathBuf>,
+
+    /// Any other arguments to pass to the viewer.
+    pub extra_args: Vec<String>,
+
+    /// How long to wait for the viewer to accept connections.
+    pub timeout: Duration,
+}
+
+impl Default for SpawnOptions {
+    fn default() -> Self {
+        Self {
+            port: re_sdk_comms::DEFAULT_SERVER_PORT,
+            memory_limit: None,
+            executable_path: None,
+            extra_args: Vec::new(),
+            timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+impl SpawnOptions {
+    /// The address the spawned viewer listens on.
+    pub fn connect_addr(&self) -> SocketAddr {
+        SocketAddr::from(([127, 0, 0, 1], self.port))
+    }
+
+    fn executable_path(&self) -> PathBuf {
+        self.executable_path
+            .clone()
+            .unwrap_or_else(|| PathBuf::from("rerun"))
+    }
+}
+
+/// Errors that can occur when spawning a Rerun Viewer.
+#[derive(thiserror::Error, Debug)]
+pub enum SpawnError {
+    /// The executable doesn't exist.
+    #[error(
+        "Couldn't find the Rerun Viewer at {0:?}. \
+        Install it with `cargo install rerun`, or set `SpawnOptions::executable_path`."
+    )]
+    ExecutableNotFound(PathBuf),
+
+    /// The executable couldn't be run.
+    #[error("Failed to run the Rerun Viewer at {0:?}: {1}")]
+    Io(PathBuf, #[source] std::io::Error),
+
+    /// The viewer quit before it accepted connections, e.g. because the port is taken.
+    #[error("The Rerun Viewer exited before accepting connections: {0}")]
+    Exited(std::process::ExitStatus),
+
+    /// The viewer is still running, but didn't accept connections in time.
+    #[error("The Rerun Viewer didn't accept connections on {addr} within {timeout:?}")]
+    Timeout {
+        /// Where the viewer should be listening.
+        addr: SocketAddr,
+
+        /// See [`SpawnOptions::timeout`].
+        timeout: Duration,
+    },
+}
+
+/// Start a Rerun Viewer in a separate process, and wait until it accepts connections
+/// on [`SpawnOptions::connect_addr`].
+///
+/// If something already listens on that port, it is assumed to be a viewer, and no new one
+/// is started.
+///
+/// The viewer keeps running after this process exits.
+///
+/// See also [`crate::SessionBuilder::spawn`].
+pub fn spawn(options: &SpawnOptions) -> Result<(), SpawnError> {
+    let addr = options.connect_addr();
+    if is_listening(addr) {
+        re_log::info!("A Rerun Viewer is already listening on {addr}, reusing it.");
+        return Ok(());
+    }
+
+    let executable_path = options.executable_path();
+    let mut command = Command::new(&executable_path);
+    command.arg("--port").arg(options.port.to_string());
+    if let Some(memory_limit) = &options.memory_limit {
+        command.arg("--memory-limit").arg(memory_limit);
+    }
+    command.args(&options.extra_args);
+
+    // Don't forward Ctrl-C in our terminal to the viewer.
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt as _;
+        command.process_group(0);
+    }
+
+    re_log::debug!("Spawning {command:?}…");
+    let mut child = command.spawn().map_err(|err| {
+        if err.kind() == std::io::ErrorKind::NotFound {
+            SpawnError::ExecutableNotFound(executable_path.clone())
+        } else {
+            SpawnError::Io(executable_path.clone(), err)
+        }
+    })?;
+
+    let start = Instant::now();
+    loop {
+        if is_listening(addr) {
+            re_log::debug!("Rerun Viewer is listening on {addr}");
+            return Ok(());
+        }
+        if let Ok(Some(status)) = child.try_wait() {
+            return Err(SpawnError::Exited(status));
+        }
+        if start.elapsed() >= options.timeout {
+            return Err(SpawnError::Timeout {
+                addr,
+                timeout: options.timeout,
+            });
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+}
+
+/// Does something accept TCP connections at this address?
+fn is_listening(addr: SocketAddr) -> bool {
+    TcpStream::connect_timeout(&addr, Duration::from_millis(100)).is_ok()
+}
+
+#[test]
+fn test_spawn_missing_executable() {
+    // Nothing listens here, so we can't mistake something else for the viewer:
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let options = SpawnOptions {
+        port,
+        executable_path: Some("this/rerun/does/not/exist".into()),
+        ..Default::default()
+    };
+    assert!(matches!(
+        spawn(&options),
+        Err(SpawnError::ExecutableNotFound(_))
+    ));
+}
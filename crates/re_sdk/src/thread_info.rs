@@ -0,0 +1,186 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicI64, Ordering};
+
+use re_log_types::{RecordingId, Time, TimeInt, TimePoint, Timeline};
+
+use crate::Session;
+
+// ----------------------------------------------------------------------------
+
+/// Set the current time of the calling thread on the given sequence timeline, e.g. a frame number.
+///
+/// Every [`crate::MsgSender`] subsequently created on this thread will be logged at this time,
+/// unless it overrides it with [`crate::MsgSender::with_time`].
+///
+/// Used for all subsequent logging on the same thread, until the next call to
+/// [`set_time_sequence`] for the same timeline, or [`reset_time`].
+///
+/// ```
+/// re_sdk::time::set_time_sequence("frame", 42);
+/// ```
+pub fn set_time_sequence(timeline: &str, sequence: impl Into<Option<i64>>) {
+    set_time(
+        Timeline::new_sequence(timeline),
+        sequence.into().map(TimeInt::from),
+    );
+}
+
+/// Set the current time of the calling thread on the given temporal timeline,
+/// in seconds since the Unix epoch.
+///
+/// See [`set_time_sequence`] for more.
+pub fn set_time_seconds(timeline: &str, seconds: impl Into<Option<f64>>) {
+    set_time(
+        Timeline::new_temporal(timeline),
+        seconds
+            .into()
+            .map(|seconds| Time::from_seconds_since_epoch(seconds).into()),
+    );
+}
+
+/// Set the current time of the calling thread on the given temporal timeline,
+/// in nanoseconds since the Unix epoch.
+///
+/// See [`set_time_sequence`] for more.
+pub fn set_time_nanos(timeline: &str, nanos: impl Into<Option<i64>>) {
+    set_time(
+        Timeline::new_temporal(timeline),
+        nanos
+            .into()
+            .map(|nanos| Time::from_ns_since_epoch(nanos).into()),
+    );
+}
+
+/// Set the current time of the calling thread on the given timeline, or clear it with `None`.
+///
+/// See [`set_time_sequence`] for more.
+pub fn set_time(timeline: Timeline, time: Option<TimeInt>) {
+    ThreadInfo::with(|ti| set_or_remove(&mut ti.time_point, timeline, time));
+}
+
+/// Clear the current time of the calling thread on all timelines.
+///
+/// The automatic [`Timeline::log_time`] and [`Timeline::log_tick`] are not affected.
+pub fn reset_time() {
+    ThreadInfo::with(|ti| ti.time_point = TimePoint::default());
+}
+
+/// The current time of the calling thread, as set by [`set_time_sequence`] and friends.
+///
+/// This does not include the automatic [`Timeline::log_time`] and [`Timeline::log_tick`].
+pub fn thread_time() -> TimePoint {
+    ThreadInfo::with(|ti| ti.time_point.clone())
+}
+
+/// Set the current time of the calling thread for one recording only, see [`Session::set_time`].
+pub(crate) fn set_recording_time(
+    recording_id: RecordingId,
+    timeline: Timeline,
+    time: Option<TimeInt>,
+) {
+    ThreadInfo::with(|ti| {
+        let time_point = ti.recording_times.entry(recording_id).or_default();
+        set_or_remove(time_point, timeline, time);
+        if time_point.is_empty() {
+            ti.recording_times.remove(&recording_id);
+        }
+    });
+}
+
+/// Clear the current time of the calling thread for one recording on all timelines.
+pub(crate) fn reset_recording_time(recording_id: RecordingId) {
+    ThreadInfo::with(|ti| ti.recording_times.remove(&recording_id));
+}
+
+/// The current time of the calling thread for one recording, see [`Session::thread_time`].
+pub(crate) fn recording_time(recording_id: RecordingId) -> TimePoint {
+    ThreadInfo::with(|ti| {
+        ti.recording_times
+            .get(&recording_id)
+            .cloned()
+            .unwrap_or_default()
+    })
+}
+
+/// Replace the session of the calling thread, see [`Session::set_thread_local`].
+pub(crate) fn set_thread_session(session: Option<Session>) -> Option<Session> {
+    ThreadInfo::with(|ti| std::mem::replace(&mut ti.session, session))
+}
+
+/// The session of the calling thread, see [`Session::thread_local`].
+pub(crate) fn thread_session() -> Option<Session> {
+    ThreadInfo::with(|ti| ti.session.clone())
+}
+
+fn set_or_remove(time_point: &mut TimePoint, timeline: Timeline, time: Option<TimeInt>) {
+    if let Some(time) = time {
+        time_point.insert(timeline, time);
+    } else {
+        time_point.remove(&timeline);
+    }
+}
+
+/// The automatic timelines every [`crate::MsgSender`] is logged on:
+/// the current [`Timeline::log_time`], and the next [`Timeline::log_tick`].
+///
+/// The log tick is shared by all threads, so it orders all messages of this process.
+pub(crate) fn log_timepoint() -> TimePoint {
+    static LOG_TICK: AtomicI64 = AtomicI64::new(0);
+
+    [
+        (Timeline::log_time(), Time::now().into()),
+        (
+            Timeline::log_tick(),
+            LOG_TICK.fetch_add(1, Ordering::Relaxed).into(),
+        ),
+    ]
+    .into()
+}
+
+// ----------------------------------------------------------------------------
+
+/// Per-thread logging state.
+#[derive(Default)]
+struct ThreadInfo {
+    /// The current time, which can be set by users.
+    time_point: TimePoint,
+
+    /// The current time of specific recordings, which takes precedence over `time_point`.
+    recording_times: BTreeMap<RecordingId, TimePoint>,
+
+    /// The session that overrides the global one on this thread.
+    session: Option<Session>,
+}
+
+impl ThreadInfo {
+    /// Get access to the thread-local [`ThreadInfo`].
+    fn with<R>(f: impl FnOnce(&mut ThreadInfo) -> R) -> R {
+        thread_local! {
+            static THREAD_INFO: RefCell<ThreadInfo> = RefCell::new(ThreadInfo::default());
+        }
+
+        THREAD_INFO.with(|thread_info| f(&mut thread_info.borrow_mut()))
+    }
+}
+
+#[test]
+fn test_thread_time() {
+    let frame = Timeline::new_sequence("frame");
+
+    set_time_sequence("frame", 42);
+    set_time_seconds("sim_time", 1.5);
+    assert_eq!(thread_time().get(&frame), Some(&TimeInt::from(42)));
+    assert_eq!(thread_time().times().len(), 2);
+
+    // Other threads have their own time.
+    std::thread::spawn(|| assert!(thread_time().is_empty()))
+        .join()
+        .unwrap();
+
+    set_time_sequence("frame", None);
+    assert_eq!(thread_time().get(&frame), None);
+
+    reset_time();
+    assert!(thread_time().is_empty());
+}
@@ -1,8 +1,14 @@
-use std::{net::SocketAddr, thread::JoinHandle};
+use std::{
+    collections::VecDeque,
+    net::SocketAddr,
+    sync::{Arc, Condvar, Mutex},
+    thread::JoinHandle,
+    time::{Duration, Instant},
+};
 
-use crossbeam::channel::{select, Receiver, Sender};
+use crossbeam::channel::{after, never, select, Receiver, Sender};
 
-use re_log_types::{LogMsg, RowId};
+use re_log_types::{LogMsg, ReplayState, RowId};
 
 #[derive(Debug, PartialEq, Eq)]
 struct FlushedMsg;
@@ -30,20 +36,118 @@ enum MsgMsg {
 }
 
 enum PacketMsg {
-    Packet(Vec<u8>),
+    Packet(Packet),
     Flush,
 }
 
+struct Packet {
+    bytes: Vec<u8>,
+
+    /// The message itself, if it changes the [`ReplayState`].
+    ///
+    /// The replay state is only updated once the packet has been sent or dropped,
+    /// so that a replay never repeats what is still in the backlog.
+    replay_msg: Option<LogMsg>,
+}
+
+// ----------------------------------------------------------------------------
+
+/// What a [`Client`] does with new messages once its backlog is full,
+/// e.g. because the server can't be reached.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DropPolicy {
+    /// Drop the oldest messages in the backlog to make room for new ones.
+    #[default]
+    DropOldest,
+
+    /// Drop new messages until there is room in the backlog again.
+    DropNewest,
+
+    /// Block [`Client::send`] until there is room in the backlog again.
+    Block,
+}
+
+/// How a [`Client`] deals with a server that can't be reached.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientOptions {
+    /// The most bytes of encoded messages to hold on to while waiting for the server.
+    pub max_backlog_bytes: u64,
+
+    /// What to do once the backlog is full.
+    pub drop_policy: DropPolicy,
+
+    /// How long to wait before the first attempt to reconnect.
+    ///
+    /// Doubles with every failed attempt, up to [`Self::max_retry_delay`].
+    pub min_retry_delay: Duration,
+
+    /// The longest time between two attempts to reconnect.
+    pub max_retry_delay: Duration,
+}
+
+impl Default for ClientOptions {
+    fn default() -> Self {
+        Self {
+            max_backlog_bytes: 512 * 1024 * 1024,
+            drop_policy: DropPolicy::default(),
+            min_retry_delay: Duration::from_millis(100),
+            max_retry_delay: Duration::from_secs(3),
+        }
+    }
+}
+
+/// Number of bytes of encoded messages that have not been sent yet.
+#[derive(Default)]
+struct BacklogSize {
+    num_bytes: Mutex<u64>,
+    has_room: Condvar,
+}
+
+impl BacklogSize {
+    fn add(&self, num_bytes: usize) {
+        *self.num_bytes.lock().unwrap() += num_bytes as u64;
+    }
+
+    fn remove(&self, num_bytes: usize) {
+        let mut total = self.num_bytes.lock().unwrap();
+        *total = total.saturating_sub(num_bytes as u64);
+        self.has_room.notify_all();
+    }
+
+    fn get(&self) -> u64 {
+        *self.num_bytes.lock().unwrap()
+    }
+
+    fn wait_for_room(&self, max_bytes: u64) {
+        let total = self.num_bytes.lock().unwrap();
+        let _total = self
+            .has_room
+            .wait_while(total, |total| *total >= max_bytes)
+            .unwrap();
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// Send [`LogMsg`]es to a server over TCP.
 ///
 /// The messages are encoded and sent on separate threads
-/// so that calling [`Client::send`] is non-blocking.
+/// so that calling [`Client::send`] is non-blocking,
+/// unless the backlog is full and the [`DropPolicy`] is [`DropPolicy::Block`].
+///
+/// If the connection is lost, the client keeps trying to reconnect, see [`ClientOptions`].
+/// After reconnecting, the [`re_log_types::BeginRecordingMsg`]s and the latest timeless data are
+/// sent again, so that a restarted server still sees a coherent recording.
+/// The same goes for the first connection if messages were dropped before it,
+/// e.g. because the viewer was started late.
 pub struct Client {
+    options: ClientOptions,
     msg_tx: Sender<MsgMsg>,
     flushed_rx: Receiver<FlushedMsg>,
     encode_quit_tx: Sender<QuitMsg>,
     send_quit_tx: Sender<InterruptMsg>,
     drop_quit_tx: Sender<QuitMsg>,
+    backlog_size: Arc<BacklogSize>,
     encode_join: Option<JoinHandle<()>>,
     send_join: Option<JoinHandle<()>>,
     drop_join: Option<JoinHandle<()>>,
@@ -58,10 +162,13 @@ impl Default for Client {
 impl Client {
     /// Connect via TCP to this log server.
     pub fn new(addr: SocketAddr) -> Self {
+        Self::with_options(addr, ClientOptions::default())
+    }
+
+    /// Connect via TCP to this log server, with control over how disconnects are handled.
+    pub fn with_options(addr: SocketAddr, options: ClientOptions) -> Self {
         re_log::debug!("Connecting to remote {addr}…");
 
-        // TODO(emilk): keep track of how much memory is in each pipe
-        // and apply back-pressure to not use too much RAM.
         let (msg_tx, msg_rx) = crossbeam::channel::unbounded();
         let (msg_drop_tx, msg_drop_rx) = crossbeam::channel::unbounded();
         let (packet_tx, packet_rx) = crossbeam::channel::unbounded();
@@ -70,19 +177,36 @@ impl Client {
         let (send_quit_tx, send_quit_rx) = crossbeam::channel::unbounded();
         let (drop_quit_tx, drop_quit_rx) = crossbeam::channel::unbounded();
 
+        let backlog_size = Arc::new(BacklogSize::default());
+
         let encode_join = std::thread::Builder::new()
             .name("msg_encoder".into())
-            .spawn(move || {
-                msg_encode(&msg_rx, &msg_drop_tx, &encode_quit_rx, &packet_tx);
-                re_log::debug!("Shutting down msg encoder thread");
+            .spawn({
+                let msg_drop_tx = msg_drop_tx.clone();
+                let backlog_size = backlog_size.clone();
+                move || {
+                    msg_encode(
+                        &msg_rx,
+                        &msg_drop_tx,
+                        &encode_quit_rx,
+                        &packet_tx,
+                        &backlog_size,
+                    );
+                    re_log::debug!("Shutting down msg encoder thread");
+                }
             })
             .expect("Failed to spawn thread");
 
         let send_join = std::thread::Builder::new()
             .name("tcp_sender".into())
-            .spawn(move || {
-                tcp_sender(addr, &packet_rx, &send_quit_rx, &flushed_tx);
-                re_log::debug!("Shutting down TCP sender thread");
+            .spawn({
+                let options = options.clone();
+                let backlog_size = backlog_size.clone();
+                move || {
+                    let mut sender = TcpSender::new(addr, options, backlog_size, msg_drop_tx);
+                    sender.run(&packet_rx, &send_quit_rx, &flushed_tx);
+                    re_log::debug!("Shutting down TCP sender thread");
+                }
             })
             .expect("Failed to spawn thread");
 
@@ -95,11 +219,13 @@ impl Client {
             .expect("Failed to spawn thread");
 
         Self {
+            options,
             msg_tx,
             flushed_rx,
             encode_quit_tx,
             send_quit_tx,
             drop_quit_tx,
+            backlog_size,
             encode_join: Some(encode_join),
             send_join: Some(send_join),
             drop_join: Some(drop_join),
@@ -107,6 +233,10 @@ impl Client {
     }
 
     pub fn send(&self, log_msg: LogMsg) {
+        if self.options.drop_policy == DropPolicy::Block {
+            self.backlog_size
+                .wait_for_room(self.options.max_backlog_bytes);
+        }
         self.send_msg_msg(MsgMsg::LogMsg(log_msg));
     }
 
@@ -130,6 +260,7 @@ impl Client {
     ///
     /// Calling this before a flush (or drop) ensures we won't get stuck trying to send
     /// messages to a closed endpoint, but we will still send all messages to an open endpoint.
+    /// We still try to reconnect, and send all new messages if we succeed.
     pub fn drop_if_disconnected(&self) {
         self.send_quit_tx
             .send(InterruptMsg::DropIfDisconnected)
@@ -184,27 +315,39 @@ fn msg_encode(
     msg_drop_tx: &Sender<MsgMsg>,
     quit_rx: &Receiver<QuitMsg>,
     packet_tx: &Sender<PacketMsg>,
+    backlog_size: &BacklogSize,
 ) {
     loop {
         select! {
             recv(msg_rx) -> msg_msg => {
                 if let Ok(msg_msg) = msg_msg {
-                    let packet_msg = match &msg_msg {
+                    let (packet_msg, msg_msg) = match msg_msg {
                         MsgMsg::LogMsg(log_msg) => {
-                            let packet = crate::encode_log_msg(log_msg);
-                            re_log::trace!("Encoded message of size {}", packet.len());
-                            PacketMsg::Packet(packet)
+                            let bytes = crate::encode_log_msg(&log_msg);
+                            re_log::trace!("Encoded message of size {}", bytes.len());
+                            backlog_size.add(bytes.len());
+
+                            // The sender hands it on to the msg_drop thread once it is done with it.
+                            if ReplayState::is_affected_by(&log_msg) {
+                                let packet = Packet { bytes, replay_msg: Some(log_msg) };
+                                (PacketMsg::Packet(packet), None)
+                            } else {
+                                let packet = Packet { bytes, replay_msg: None };
+                                (PacketMsg::Packet(packet), Some(MsgMsg::LogMsg(log_msg)))
+                            }
                         }
-                        MsgMsg::Flush => PacketMsg::Flush,
+                        MsgMsg::Flush => (PacketMsg::Flush, Some(MsgMsg::Flush)),
                     };
 
                     if packet_tx.send(packet_msg).is_err() {
                         re_log::error!("Failed to send message to tcp_sender thread. Likely a shutdown race-condition.");
                         return;
                     }
-                    if msg_drop_tx.send(msg_msg).is_err() {
-                        re_log::error!("Failed to send message to msg_drop thread. Likely a shutdown race-condition");
-                        return;
+                    if let Some(msg_msg) = msg_msg {
+                        if msg_drop_tx.send(msg_msg).is_err() {
+                            re_log::error!("Failed to send message to msg_drop thread. Likely a shutdown race-condition");
+                            return;
+                        }
                     }
                 } else {
                     return; // channel has closed
@@ -217,99 +360,375 @@ fn msg_encode(
     }
 }
 
-fn tcp_sender(
-    addr: SocketAddr,
-    packet_rx: &Receiver<PacketMsg>,
-    quit_rx: &Receiver<InterruptMsg>,
-    flushed_tx: &Sender<FlushedMsg>,
-) {
-    let mut tcp_client = crate::tcp_client::TcpClient::new(addr);
-    // Once this flag has been set, we will drop all messages if the tcp_client is
-    // no longer connected.
-    let mut drop_if_disconnected = false;
+// ----------------------------------------------------------------------------
 
-    loop {
-        select! {
-            recv(packet_rx) -> packet_msg => {
-                if let Ok(packet_msg) = packet_msg {
-                    match packet_msg {
-                        PacketMsg::Packet(packet) => {
-                            match send_until_success(&mut tcp_client, drop_if_disconnected, &packet, quit_rx) {
-                                Some(InterruptMsg::Quit) => {return;}
-                                Some(InterruptMsg::DropIfDisconnected) => {
-                                    drop_if_disconnected = true;
-                                }
-                                None => {}
-                            }
-                        }
-                        PacketMsg::Flush => {
-                            tcp_client.flush();
-                            flushed_tx
-                                .send(FlushedMsg)
-                                .expect("Main thread should still be alive");
-                        }
-                    }
-                } else {
-                    return; // channel has closed
-                }
-            },
-            recv(quit_rx) -> quit_msg => { match quit_msg {
-                // Don't terminate on receiving a `DropIfDisconnected`. It's a soft-quit that allows
-                // us to flush the pipeline.
-                Ok(InterruptMsg::DropIfDisconnected) => {
-                    drop_if_disconnected = true;
-                }
-                _ => return,
-            }}
+/// Exponential back-off between attempts to connect.
+struct Backoff {
+    min_delay: Duration,
+    max_delay: Duration,
+    delay: Duration,
+
+    /// `None` if we may try right away.
+    next_attempt: Option<Instant>,
+}
+
+impl Backoff {
+    fn new(min_delay: Duration, max_delay: Duration) -> Self {
+        Self {
+            min_delay,
+            max_delay,
+            delay: min_delay,
+            next_attempt: None,
         }
     }
+
+    /// How long until we may try again, if we have to wait.
+    fn time_left(&self) -> Option<Duration> {
+        let left = self.next_attempt?.saturating_duration_since(Instant::now());
+        (!left.is_zero()).then_some(left)
+    }
+
+    fn failed(&mut self) {
+        self.next_attempt = Some(Instant::now() + self.delay);
+        self.delay = (self.delay * 2).min(self.max_delay);
+    }
+
+    fn succeeded(&mut self) {
+        self.delay = self.min_delay;
+        self.next_attempt = None;
+    }
 }
 
-fn send_until_success(
-    tcp_client: &mut crate::tcp_client::TcpClient,
+/// Runs on the `tcp_sender` thread.
+struct TcpSender {
+    tcp_client: crate::tcp_client::TcpClient,
+    options: ClientOptions,
+
+    /// Packets waiting to be sent, oldest first.
+    backlog: VecDeque<PacketMsg>,
+    backlog_size: Arc<BacklogSize>,
+
+    backoff: Backoff,
+
+    /// Once this flag has been set, we will drop all messages if the tcp_client is
+    /// no longer connected.
     drop_if_disconnected: bool,
-    packet: &[u8],
-    quit_rx: &Receiver<InterruptMsg>,
-) -> Option<InterruptMsg> {
-    // Early exit if tcp_client is disconnected
-    if drop_if_disconnected && tcp_client.has_disconnected() {
-        re_log::debug_once!("Dropping messages because we're disconnected.");
-        return None;
-    }
 
-    if let Err(err) = tcp_client.send(packet) {
-        if drop_if_disconnected {
-            re_log::debug_once!("Dropping messages because we're disconnected.");
-            return None;
-        }
-        // If this is the first time we fail to send the message, produce a warning.
-        re_log::warn!("Failed to send message: {err}");
+    /// Have we ever been connected? If so, we need to replay the recording state on reconnect.
+    has_connected: bool,
+
+    /// Have we dropped any packets since we last replayed the recording state?
+    /// They may have been part of it, e.g. the [`re_log_types::BeginRecordingMsg`].
+    has_dropped: bool,
+
+    /// The recording state as of the packets that have been sent or dropped.
+    replay: ReplayState,
 
-        let mut sleep_ms = 100;
+    /// Where messages go once they are no longer needed for the [`Self::replay`].
+    msg_drop_tx: Sender<MsgMsg>,
+}
 
+impl TcpSender {
+    fn new(
+        addr: SocketAddr,
+        options: ClientOptions,
+        backlog_size: Arc<BacklogSize>,
+        msg_drop_tx: Sender<MsgMsg>,
+    ) -> Self {
+        Self {
+            tcp_client: crate::tcp_client::TcpClient::new(addr),
+            backoff: Backoff::new(options.min_retry_delay, options.max_retry_delay),
+            options,
+            backlog: Default::default(),
+            backlog_size,
+            drop_if_disconnected: false,
+            has_connected: false,
+            has_dropped: false,
+            replay: Default::default(),
+            msg_drop_tx,
+        }
+    }
+
+    fn run(
+        &mut self,
+        packet_rx: &Receiver<PacketMsg>,
+        quit_rx: &Receiver<InterruptMsg>,
+        flushed_tx: &Sender<FlushedMsg>,
+    ) {
         loop {
+            // Wait for new packets, or until it is time to retry sending the backlog.
+            let timeout = if self.backlog.is_empty() {
+                never()
+            } else {
+                after(self.backoff.time_left().unwrap_or_default())
+            };
             select! {
-                recv(quit_rx) -> _quit_msg => {
-                    re_log::debug_once!("Dropping messages because we're disconnected or quitting.");
-                    return Some(_quit_msg.unwrap_or(InterruptMsg::Quit));
+                recv(packet_rx) -> packet_msg => {
+                    if let Ok(packet_msg) = packet_msg {
+                        self.push(packet_msg);
+                    } else {
+                        return; // channel has closed
+                    }
+                },
+                recv(quit_rx) -> quit_msg => { match quit_msg {
+                    // Don't terminate on receiving a `DropIfDisconnected`. It's a soft-quit that allows
+                    // us to flush the pipeline.
+                    Ok(InterruptMsg::DropIfDisconnected) => {
+                        self.drop_if_disconnected = true;
+                    }
+                    _ => return,
+                }},
+                recv(timeout) -> _ => {},
+            }
+            while let Ok(packet_msg) = packet_rx.try_recv() {
+                self.push(packet_msg);
+            }
+
+            self.send_backlog(flushed_tx);
+        }
+    }
+
+    /// Add a packet to the backlog, dropping packets if it is full.
+    fn push(&mut self, packet_msg: PacketMsg) {
+        let PacketMsg::Packet(packet) = &packet_msg else {
+            self.backlog.push_back(packet_msg); // never drop flushes
+            return;
+        };
+
+        let max_bytes = self.options.max_backlog_bytes;
+        if self.backlog_size.get() > max_bytes {
+            match self.options.drop_policy {
+                DropPolicy::DropNewest => {
+                    re_log::warn_once!(
+                        "Backlog of unsent messages is full - dropping new messages"
+                    );
+                    self.backlog_size.remove(packet.bytes.len());
+                    self.has_dropped = true;
+                    if let PacketMsg::Packet(packet) = packet_msg {
+                        self.done_with(packet);
+                    }
+                    return;
                 }
-                default(std::time::Duration::from_millis(sleep_ms)) => {
-                    if let Err(new_err) = tcp_client.send(packet) {
-                        const MAX_SLEEP_MS : u64 = 3000;
+                DropPolicy::DropOldest => {
+                    re_log::warn_once!(
+                        "Backlog of unsent messages is full - dropping old messages"
+                    );
+                    while self.backlog_size.get() > max_bytes {
+                        let Some(oldest) = self
+                            .backlog
+                            .iter()
+                            .position(|msg| matches!(msg, PacketMsg::Packet(_)))
+                        else {
+                            break;
+                        };
+                        if let Some(PacketMsg::Packet(oldest)) = self.backlog.remove(oldest) {
+                            self.backlog_size.remove(oldest.bytes.len());
+                            self.has_dropped = true;
+                            self.done_with(oldest);
+                        }
+                    }
+                }
+                DropPolicy::Block => {} // `Client::send` is holding back new messages
+            }
+        }
 
-                        sleep_ms = (sleep_ms * 2).min(MAX_SLEEP_MS);
+        self.backlog.push_back(packet_msg);
+    }
 
-                        // Only produce subsequent warnings once we've saturated the back-off
-                        if sleep_ms == MAX_SLEEP_MS && new_err.to_string() != err.to_string() {
-                            re_log::warn!("Still failing to send message: {err}");
+    /// Send as much of the backlog as we can.
+    fn send_backlog(&mut self, flushed_tx: &Sender<FlushedMsg>) {
+        while let Some(packet_msg) = self.backlog.pop_front() {
+            match packet_msg {
+                PacketMsg::Flush => {
+                    // Everything before this has been sent or dropped.
+                    self.tcp_client.flush();
+                    flushed_tx
+                        .send(FlushedMsg)
+                        .expect("Main thread should still be alive");
+                }
+                PacketMsg::Packet(packet) => {
+                    if self.connect() {
+                        if let Err(err) = self.tcp_client.send(&packet.bytes) {
+                            re_log::warn!("Failed to send message: {err}");
+                            self.backoff.failed();
+                            self.backlog.push_front(PacketMsg::Packet(packet));
+                            continue; // try to reconnect, or drop it
                         }
+                    } else if !self.drop_if_disconnected {
+                        self.backlog.push_front(PacketMsg::Packet(packet));
+                        return; // keep it until we are connected
                     } else {
-                        return None;
+                        re_log::debug_once!("Dropping messages because we're disconnected.");
+                        self.has_dropped = true;
                     }
+                    self.backlog_size.remove(packet.bytes.len());
+                    self.done_with(packet);
                 }
             }
         }
-    } else {
-        None
     }
+
+    /// The packet has been sent or dropped, so it is now part of what we replay.
+    fn done_with(&mut self, packet: Packet) {
+        if let Some(msg) = packet.replay_msg {
+            self.replay.update(&msg);
+            self.msg_drop_tx.send(MsgMsg::LogMsg(msg)).ok();
+        }
+    }
+
+    /// Are we connected? Tries to reconnect if it is time to.
+    fn connect(&mut self) -> bool {
+        if self.tcp_client.is_connected() {
+            return true;
+        }
+        if self.backoff.time_left().is_some() {
+            return false;
+        }
+
+        if let Err(err) = self.tcp_client.connect() {
+            // Only warn when we lose the connection, not on every retry.
+            if self.backoff.delay == self.options.min_retry_delay {
+                re_log::warn!("{err}. Retrying…");
+            } else {
+                re_log::debug!("{err}");
+            }
+            self.backoff.failed();
+            return false;
+        }
+
+        if self.has_connected || self.has_dropped {
+            if self.has_connected {
+                re_log::info!("Reconnected - sending recording state again");
+            } else {
+                re_log::info!(
+                    "Connected - sending recording state, since some messages were dropped"
+                );
+            }
+            let messages = self.replay.messages();
+            for msg in &messages {
+                if let Err(err) = self.tcp_client.send(&crate::encode_log_msg(msg)) {
+                    re_log::warn!("Failed to send message: {err}");
+                    self.backoff.failed();
+                    return false;
+                }
+            }
+        }
+        self.has_connected = true;
+        self.has_dropped = false;
+        self.backoff.succeeded();
+        true
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+fn test_sender(drop_policy: DropPolicy) -> TcpSender {
+    TcpSender::new(
+        crate::default_server_addr(),
+        ClientOptions {
+            max_backlog_bytes: 10,
+            drop_policy,
+            ..Default::default()
+        },
+        Default::default(),
+        crossbeam::channel::unbounded().0,
+    )
+}
+
+#[test]
+fn test_drop_policy() {
+    let sender = test_sender;
+    let push = |sender: &mut TcpSender, byte: u8| {
+        sender.backlog_size.add(4);
+        sender.push(PacketMsg::Packet(Packet {
+            bytes: vec![byte; 4],
+            replay_msg: None,
+        }));
+    };
+    let first_bytes = |sender: &TcpSender| {
+        sender
+            .backlog
+            .iter()
+            .filter_map(|msg| match msg {
+                PacketMsg::Packet(packet) => Some(packet.bytes[0]),
+                PacketMsg::Flush => None,
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut oldest = sender(DropPolicy::DropOldest);
+    for byte in 0..5 {
+        push(&mut oldest, byte);
+    }
+    assert_eq!(first_bytes(&oldest), vec![3, 4]);
+    assert_eq!(oldest.backlog_size.get(), 8);
+    assert!(
+        oldest.has_dropped,
+        "the recording state must be replayed on connect"
+    );
+
+    let mut newest = sender(DropPolicy::DropNewest);
+    for byte in 0..5 {
+        push(&mut newest, byte);
+    }
+    assert_eq!(first_bytes(&newest), vec![0, 1]);
+    assert_eq!(newest.backlog_size.get(), 8);
+    assert!(newest.has_dropped);
+
+    let mut block = sender(DropPolicy::Block);
+    for byte in 0..5 {
+        push(&mut block, byte);
+    }
+    assert_eq!(first_bytes(&block), vec![0, 1, 2, 3, 4]);
+    assert!(!block.has_dropped);
+}
+
+#[test]
+fn test_replay_only_sent_or_dropped() {
+    use re_log_types::{
+        ApplicationId, BeginRecordingMsg, RecordingId, RecordingInfo, RecordingSource, Time,
+    };
+
+    let push = |sender: &mut TcpSender, recording_id: RecordingId| {
+        let msg = LogMsg::BeginRecordingMsg(BeginRecordingMsg {
+            row_id: RowId::random(),
+            info: RecordingInfo {
+                application_id: ApplicationId("test".to_owned()),
+                recording_id,
+                is_official_example: false,
+                started: Time::now(),
+                recording_source: RecordingSource::Unknown,
+            },
+        });
+        sender.backlog_size.add(4);
+        sender.push(PacketMsg::Packet(Packet {
+            bytes: vec![0; 4],
+            replay_msg: Some(msg),
+        }));
+    };
+    let replayed = |sender: &TcpSender| {
+        sender
+            .replay
+            .messages()
+            .iter()
+            .filter_map(|msg| msg.recording_id().copied())
+            .collect::<Vec<_>>()
+    };
+
+    let recordings = [(); 3].map(|_| RecordingId::random());
+    let mut sender = test_sender(DropPolicy::DropOldest);
+    push(&mut sender, recordings[0]);
+    push(&mut sender, recordings[1]);
+    assert!(
+        replayed(&sender).is_empty(),
+        "the backlog sends these itself, so replaying them would duplicate them"
+    );
+
+    push(&mut sender, recordings[2]);
+    assert_eq!(
+        replayed(&sender),
+        [recordings[0]],
+        "dropped messages must be replayed"
+    );
 }
@@ -11,7 +11,7 @@ pub(crate) mod tcp_client;
 mod buffered_client;
 
 #[cfg(feature = "client")]
-pub use buffered_client::Client;
+pub use buffered_client::{Client, ClientOptions, DropPolicy};
 
 #[cfg(feature = "server")]
 mod server;
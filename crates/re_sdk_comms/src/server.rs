@@ -118,7 +118,14 @@ async fn run_client(
     use tokio::io::AsyncReadExt as _;
 
     let mut client_version = [0_u8; 2];
-    stream.read_exact(&mut client_version).await?;
+    if let Err(err) = stream.read_exact(&mut client_version).await {
+        if err.kind() == std::io::ErrorKind::UnexpectedEof {
+            // E.g. an SDK checking whether we are up yet, see `re_sdk::spawn`.
+            re_log::debug!("Client disconnected before sending anything.");
+            return Ok(());
+        }
+        return Err(err.into());
+    }
     let client_version = u16::from_le_bytes(client_version);
 
     match client_version.cmp(&crate::PROTOCOL_VERSION) {
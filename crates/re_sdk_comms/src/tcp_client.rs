@@ -124,6 +124,11 @@ impl TcpClient {
         re_log::trace!("TCP stream flushed.");
     }
 
+    /// Is the underlying [`TcpStream`] in the [`TcpStreamState::Connected`] state?
+    pub fn is_connected(&self) -> bool {
+        matches!(self.stream_state, TcpStreamState::Connected(_))
+    }
+
     /// Check if the underlying [`TcpStream`] has entered the [`TcpStreamState::Disconnected`] state
     ///
     /// Note that this only occurs after a failure to connect or a failure to send.
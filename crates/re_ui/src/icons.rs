@@ -91,5 +91,9 @@ pub const SPACE_VIEW_HISTOGRAM: Icon = Icon::new(
     "spaceview_histogram",
     include_bytes!("../data/icons/spaceview_histogram.png"),
 );
+pub const SPACE_VIEW_MAP: Icon = Icon::new(
+    "spaceview_map",
+    include_bytes!("../data/icons/spaceview_map.png"),
+);
 
 pub const CONTAINER: Icon = Icon::new("container", include_bytes!("../data/icons/container.png"));
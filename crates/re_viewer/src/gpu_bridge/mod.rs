@@ -18,7 +18,7 @@ use re_renderer::{
 /// Errors that can happen when supplying a tensor range to the GPU.
 #[derive(thiserror::Error, Debug, PartialEq, Eq)]
 pub enum RangeError {
-    /// This is weird. Should only happen with JPEGs, and those should have been decoded already
+    /// This is weird. Should only happen with encoded images, and those should have been decoded already
     #[error("Missing a range.")]
     MissingRange,
 
@@ -54,6 +54,11 @@ pub fn tensor_to_gpu(
     }
 }
 
+/// The same tensor payload is uploaded differently depending on its meaning.
+fn texture_key(tensor: &Tensor) -> u64 {
+    hash((tensor.id(), tensor.meaning))
+}
+
 // ----------------------------------------------------------------------------
 // Color textures:
 
@@ -63,7 +68,7 @@ fn color_tensor_to_gpu(
     tensor: &Tensor,
     tensor_stats: &TensorStats,
 ) -> anyhow::Result<ColormappedTexture> {
-    let texture_handle = try_get_or_create_texture(render_ctx, hash(tensor.id()), || {
+    let texture_handle = try_get_or_create_texture(render_ctx, texture_key(tensor), || {
         let [height, width, depth] = height_width_depth(tensor)?;
         let (data, format) = match (depth, &tensor.data) {
             // Use R8Unorm and R8Snorm to get filtering on the GPU:
@@ -81,6 +86,18 @@ fn color_tensor_to_gpu(
                 TextureFormat::Rgba8UnormSrgb,
             ),
 
+            // Chroma-subsampled images are converted to RGB on the CPU:
+            (3, TensorData::NV12(_) | TensorData::YUY2(_)) => {
+                let rgb = tensor.yuv_to_rgb8().ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "{:?} buffer is too small for an image of shape {:?}",
+                        tensor.data,
+                        tensor.shape()
+                    )
+                })?;
+                (pad_and_cast(&rgb, 255), TextureFormat::Rgba8UnormSrgb)
+            }
+
             _ => {
                 // Fallback to general case:
                 return general_texture_creation_desc_from_tensor(debug_name, tensor);
@@ -182,7 +199,7 @@ fn class_id_tensor_to_gpu(
             }
         });
 
-    let main_texture_handle = try_get_or_create_texture(render_ctx, hash(tensor.id()), || {
+    let main_texture_handle = try_get_or_create_texture(render_ctx, texture_key(tensor), || {
         general_texture_creation_desc_from_tensor(debug_name, tensor)
     })?;
 
@@ -211,7 +228,7 @@ fn depth_tensor_to_gpu(
     );
     let (min, max) = depth_tensor_range(tensor, tensor_stats)?;
 
-    let texture = try_get_or_create_texture(render_ctx, hash(tensor.id()), || {
+    let texture = try_get_or_create_texture(render_ctx, texture_key(tensor), || {
         general_texture_creation_desc_from_tensor(debug_name, tensor)
     })?;
 
@@ -272,12 +289,17 @@ fn general_texture_creation_desc_from_tensor<'a>(
                 TensorData::I32(buf) => (cast_slice_to_cow(buf), TextureFormat::R32Sint),
                 TensorData::I64(buf) => (narrow_i64_to_f32s(buf), TextureFormat::R32Float), // narrowing to f32!
 
-                // TensorData::F16(buf) => (cast_slice_to_cow(buf), TextureFormat::R16Float), TODO(#854)
+                TensorData::F16(buf) => {
+                    (cast_slice_to_cow(buf.as_slice()), TextureFormat::R16Float)
+                }
                 TensorData::F32(buf) => (cast_slice_to_cow(buf), TextureFormat::R32Float),
                 TensorData::F64(buf) => (narrow_f64_to_f32s(buf), TextureFormat::R32Float), // narrowing to f32!
 
-                TensorData::JPEG(_) => {
-                    anyhow::bail!("JPEGs should have been decoded at this point")
+                TensorData::JPEG(_) | TensorData::PNG(_) | TensorData::Encoded(_) => {
+                    anyhow::bail!("Encoded images should have been decoded at this point")
+                }
+                TensorData::NV12(_) | TensorData::YUY2(_) => {
+                    anyhow::bail!("Chroma-subsampled images can only be shown as color images")
                 }
             }
         }
@@ -294,12 +316,17 @@ fn general_texture_creation_desc_from_tensor<'a>(
                 TensorData::I32(buf) => (cast_slice_to_cow(buf), TextureFormat::Rg32Sint),
                 TensorData::I64(buf) => (narrow_i64_to_f32s(buf), TextureFormat::Rg32Float), // narrowing to f32!
 
-                // TensorData::F16(buf) => (cast_slice_to_cow(buf), TextureFormat::Rg16Float), TODO(#854)
+                TensorData::F16(buf) => {
+                    (cast_slice_to_cow(buf.as_slice()), TextureFormat::Rg16Float)
+                }
                 TensorData::F32(buf) => (cast_slice_to_cow(buf), TextureFormat::Rg32Float),
                 TensorData::F64(buf) => (narrow_f64_to_f32s(buf), TextureFormat::Rg32Float), // narrowing to f32!
 
-                TensorData::JPEG(_) => {
-                    anyhow::bail!("JPEGs should have been decoded at this point")
+                TensorData::JPEG(_) | TensorData::PNG(_) | TensorData::Encoded(_) => {
+                    anyhow::bail!("Encoded images should have been decoded at this point")
+                }
+                TensorData::NV12(_) | TensorData::YUY2(_) => {
+                    anyhow::bail!("Chroma-subsampled images can only be shown as color images")
                 }
             }
         }
@@ -328,15 +355,21 @@ fn general_texture_creation_desc_from_tensor<'a>(
                     TextureFormat::Rgba32Float,
                 ),
 
-                // TensorData::F16(buf) => (pad_and_cast(buf, 1.0), TextureFormat::Rgba16Float), TODO(#854)
+                TensorData::F16(buf) => (
+                    pad_and_cast(buf.as_slice(), half::f16::ONE),
+                    TextureFormat::Rgba16Float,
+                ),
                 TensorData::F32(buf) => (pad_and_cast(buf, 1.0), TextureFormat::Rgba32Float),
                 TensorData::F64(buf) => (
                     pad_and_narrow_and_cast(buf, 1.0, |x: f64| x as f32),
                     TextureFormat::Rgba32Float,
                 ),
 
-                TensorData::JPEG(_) => {
-                    anyhow::bail!("JPEGs should have been decoded at this point")
+                TensorData::JPEG(_) | TensorData::PNG(_) | TensorData::Encoded(_) => {
+                    anyhow::bail!("Encoded images should have been decoded at this point")
+                }
+                TensorData::NV12(_) | TensorData::YUY2(_) => {
+                    anyhow::bail!("Chroma-subsampled images can only be shown as color images")
                 }
             }
         }
@@ -355,12 +388,18 @@ fn general_texture_creation_desc_from_tensor<'a>(
                 TensorData::I32(buf) => (cast_slice_to_cow(buf), TextureFormat::Rgba32Sint),
                 TensorData::I64(buf) => (narrow_i64_to_f32s(buf), TextureFormat::Rgba32Float), // narrowing to f32!
 
-                // TensorData::F16(buf) => (cast_slice_to_cow(buf), TextureFormat::Rgba16Float), TODO(#854)
+                TensorData::F16(buf) => (
+                    cast_slice_to_cow(buf.as_slice()),
+                    TextureFormat::Rgba16Float,
+                ),
                 TensorData::F32(buf) => (cast_slice_to_cow(buf), TextureFormat::Rgba32Float),
                 TensorData::F64(buf) => (narrow_f64_to_f32s(buf), TextureFormat::Rgba32Float), // narrowing to f32!
 
-                TensorData::JPEG(_) => {
-                    anyhow::bail!("JPEGs should have been decoded at this point")
+                TensorData::JPEG(_) | TensorData::PNG(_) | TensorData::Encoded(_) => {
+                    anyhow::bail!("Encoded images should have been decoded at this point")
+                }
+                TensorData::NV12(_) | TensorData::YUY2(_) => {
+                    anyhow::bail!("Chroma-subsampled images can only be shown as color images")
                 }
             }
         }
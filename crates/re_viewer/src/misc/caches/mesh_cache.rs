@@ -8,9 +8,25 @@ use crate::{mesh_loader::LoadedMesh, ui::view_spatial::MeshSourceData};
 // ----------------------------------------------------------------------------
 
 #[derive(Default)]
-pub struct MeshCache(nohash_hasher::IntMap<MeshId, Option<Arc<LoadedMesh>>>);
+pub struct MeshCache {
+    loaded: nohash_hasher::IntMap<MeshId, Option<Arc<LoadedMesh>>>,
+
+    /// Meshes generated by the viewer itself, e.g. by triangulating 2D shapes.
+    generated: nohash_hasher::IntMap<u64, Option<Arc<LoadedMesh>>>,
+
+    /// Keys of the generated meshes used since the last call to [`Self::begin_frame`].
+    used_generated: nohash_hasher::IntSet<u64>,
+}
 
 impl MeshCache {
+    /// Call once per frame.
+    ///
+    /// Generated meshes are cheap to recreate, so we only keep those used during the last frame.
+    pub fn begin_frame(&mut self) {
+        let used = std::mem::take(&mut self.used_generated);
+        self.generated.retain(|key, _| used.contains(key));
+    }
+
     pub fn load(
         &mut self,
         name: &str,
@@ -21,7 +37,7 @@ impl MeshCache {
 
         let mesh_id = mesh_data.mesh_id();
 
-        self.0
+        self.loaded
             .entry(mesh_id)
             .or_insert_with(|| {
                 re_log::debug!("Loading CPU mesh {name:?}…");
@@ -34,6 +50,7 @@ impl MeshCache {
                         name.to_owned(),
                         MeshFormat::Glb,
                         glb_bytes,
+                        None,
                         render_ctx,
                     ),
                 };
@@ -48,4 +65,31 @@ impl MeshCache {
             })
             .clone()
     }
+
+    /// Returns the generated mesh with the given key, calling `create` if it isn't cached.
+    ///
+    /// The key must change whenever the mesh would, e.g. by hashing the row and instance it was
+    /// generated from together with its color.
+    pub fn get_or_create(
+        &mut self,
+        key: u64,
+        name: &str,
+        render_ctx: &mut RenderContext,
+        create: impl FnOnce(&mut RenderContext) -> anyhow::Result<LoadedMesh>,
+    ) -> Option<Arc<LoadedMesh>> {
+        self.used_generated.insert(key);
+        self.generated
+            .entry(key)
+            .or_insert_with(|| {
+                crate::profile_scope!("create mesh");
+                match create(render_ctx) {
+                    Ok(cpu_mesh) => Some(Arc::new(cpu_mesh)),
+                    Err(err) => {
+                        re_log::warn_once!("Failed to create mesh {name:?}: {err}");
+                        None
+                    }
+                }
+            })
+            .clone()
+    }
 }
@@ -31,6 +31,7 @@ impl Caches {
 
         self.image.begin_frame(max_image_cache_use);
         self.decode.begin_frame(max_decode_cache_use);
+        self.mesh.begin_frame();
     }
 
     pub fn purge_memory(&mut self) {
@@ -53,7 +54,7 @@ impl Caches {
 }
 
 pub struct TensorStats {
-    /// This will currently only be `None` for jpeg-encoded tensors.
+    /// This will currently only be `None` for encoded images (JPEG, PNG, …).
     pub range: Option<(f64, f64)>,
 }
 
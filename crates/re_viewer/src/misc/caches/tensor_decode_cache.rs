@@ -1,4 +1,4 @@
-use re_log_types::component_types::{Tensor, TensorDimension, TensorId};
+use re_log_types::component_types::{ImageEncoding, Tensor, TensorDimension, TensorId};
 
 #[derive(thiserror::Error, Clone, Debug)]
 pub enum TensorDecodeError {
@@ -44,60 +44,44 @@ impl DecodeCache {
     ///
     /// This is a no-op for Tensors that are not compressed.
     ///
-    /// Currently supports JPEG encoded tensors.
+    /// Currently supports JPEG, PNG, BMP and TIFF encoded tensors.
     pub fn try_decode_tensor_if_necessary(
         &mut self,
         maybe_encoded_tensor: Tensor,
     ) -> Result<Tensor, TensorDecodeError> {
         crate::profile_function!();
-        match &maybe_encoded_tensor.data {
-            re_log_types::component_types::TensorData::JPEG(buf) => {
-                let lookup = self
-                    .images
-                    .entry(maybe_encoded_tensor.id())
-                    .or_insert_with(|| {
-                        use image::io::Reader as ImageReader;
-                        let mut reader = ImageReader::new(std::io::Cursor::new(buf.0.as_slice()));
-                        reader.set_format(image::ImageFormat::Jpeg);
-                        let img = {
-                            crate::profile_scope!("decode_jpeg");
-                            reader.decode()
-                        };
-                        let tensor = match img {
-                            Ok(img) => match Tensor::from_image(img) {
-                                Ok(tensor) => {
-                                    if tensor.shape() == maybe_encoded_tensor.shape() {
-                                        Ok(tensor)
-                                    } else {
-                                        Err(TensorDecodeError::InvalidMetaData {
-                                            expected: maybe_encoded_tensor.shape().into(),
-                                            found: tensor.shape().into(),
-                                        })
-                                    }
-                                }
-                                Err(err) => Err(TensorDecodeError::InvalidImage(err.to_string())),
-                            },
-                            Err(err) => Err(TensorDecodeError::CouldNotDecode(err.to_string())),
-                        };
-
-                        let memory_used = match &tensor {
-                            Ok(tensor) => tensor.size_in_bytes() as u64,
-                            Err(_) => 0,
-                        };
-                        self.memory_used += memory_used;
-                        let last_use_generation = 0;
-                        DecodedTensor {
-                            tensor,
-                            memory_used,
-                            last_use_generation,
-                        }
-                    });
-                lookup.last_use_generation = self.generation;
-
-                lookup.tensor.clone()
-            }
-            _ => Ok(maybe_encoded_tensor),
-        }
+        let Some((encoding, bytes)) = maybe_encoded_tensor.data.encoded_image() else {
+            return Ok(maybe_encoded_tensor);
+        };
+
+        let lookup = self
+            .images
+            .entry(maybe_encoded_tensor.id())
+            .or_insert_with(|| {
+                let tensor = decode_image(&maybe_encoded_tensor, encoding, bytes);
+
+                let memory_used = match &tensor {
+                    Ok(tensor) => tensor.size_in_bytes() as u64,
+                    Err(_) => 0,
+                };
+                self.memory_used += memory_used;
+                let last_use_generation = 0;
+                DecodedTensor {
+                    tensor,
+                    memory_used,
+                    last_use_generation,
+                }
+            });
+        lookup.last_use_generation = self.generation;
+
+        // Keep what was logged alongside the encoded image, e.g. dimension names and depth scale.
+        // The meaning and meter are not part of the `TensorId`, so they may differ between hits.
+        lookup.tensor.clone().map(|tensor| Tensor {
+            shape: maybe_encoded_tensor.shape.clone(),
+            meaning: maybe_encoded_tensor.meaning,
+            meter: maybe_encoded_tensor.meter,
+            ..tensor
+        })
     }
 
     /// Call once per frame to (potentially) flush the cache.
@@ -135,3 +119,37 @@ impl DecodeCache {
         );
     }
 }
+
+fn decode_image(
+    encoded_tensor: &Tensor,
+    encoding: ImageEncoding,
+    bytes: &[u8],
+) -> Result<Tensor, TensorDecodeError> {
+    use image::io::Reader as ImageReader;
+    let mut reader = ImageReader::new(std::io::Cursor::new(bytes));
+    reader.set_format(match encoding {
+        ImageEncoding::Jpeg => image::ImageFormat::Jpeg,
+        ImageEncoding::Png => image::ImageFormat::Png,
+        ImageEncoding::Bmp => image::ImageFormat::Bmp,
+        ImageEncoding::Tiff => image::ImageFormat::Tiff,
+    });
+    let img = {
+        crate::profile_scope!("decode_image", encoding.to_string());
+        reader
+            .decode()
+            .map_err(|err| TensorDecodeError::CouldNotDecode(err.to_string()))?
+    };
+
+    let tensor =
+        Tensor::from_image(img).map_err(|err| TensorDecodeError::InvalidImage(err.to_string()))?;
+
+    // A single-channel image may be logged either as `[h, w]` or as `[h, w, 1]`.
+    if tensor.image_height_width_channels() != encoded_tensor.image_height_width_channels() {
+        return Err(TensorDecodeError::InvalidMetaData {
+            expected: encoded_tensor.shape().into(),
+            found: tensor.shape().into(),
+        });
+    }
+
+    Ok(tensor)
+}
@@ -55,18 +55,20 @@ impl<'store, 'cache> ColoredTensorView<'store, 'cache> {
 }
 
 // Use this for the cache index so that we don't cache across
-// changes to the annotations
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+// changes to the annotations or to how the tensor is interpreted
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 struct ImageCacheKey {
     tensor_id: component_types::TensorId,
+    meaning: component_types::TensorDataMeaning,
     annotation_row_id: RowId,
 }
 
 impl ImageCacheKey {
     fn hash64(&self) -> u64 {
         let msg_hash = self.tensor_id.0.as_u128() as u64;
+        let meaning_hash = egui::util::hash(self.meaning);
         let annotation_hash = (self.annotation_row_id.as_u128() >> 1) as u64;
-        msg_hash ^ annotation_hash
+        msg_hash ^ meaning_hash ^ annotation_hash
     }
 }
 
@@ -96,6 +98,7 @@ impl ImageCache {
     ) -> ColoredTensorView<'store, 'cache> {
         let key = ImageCacheKey {
             tensor_id: tensor.id(),
+            meaning: tensor.meaning,
             annotation_row_id: annotations.row_id,
         };
         let ci = self.images.entry(key).or_insert_with(|| {
@@ -277,6 +280,14 @@ fn color_tensor_as_color_image(tensor: &Tensor) -> anyhow::Result<ColorImage> {
 
             Ok(ColorImage { size, pixels })
         }
+        (1, TensorData::F16(buf)) => {
+            let pixels = buf
+                .iter()
+                .map(|pixel| Color32::from_gray(linear_u8_from_linear_f32(pixel.to_f32())))
+                .collect();
+
+            Ok(ColorImage { size, pixels })
+        }
         (1, TensorData::F32(buf)) => {
             let pixels = buf
                 .iter()
@@ -291,6 +302,20 @@ fn color_tensor_as_color_image(tensor: &Tensor) -> anyhow::Result<ColorImage> {
 
             Ok(ColorImage::from_rgb(size, &u8_buf))
         }
+        (3, TensorData::F16(buf)) => {
+            let rgb: &[[half::f16; 3]] = bytemuck::cast_slice(buf.as_slice());
+            let pixels: Vec<Color32> = rgb
+                .iter()
+                .map(|&[r, g, b]| {
+                    let r = gamma_u8_from_linear_f32(r.to_f32());
+                    let g = gamma_u8_from_linear_f32(g.to_f32());
+                    let b = gamma_u8_from_linear_f32(b.to_f32());
+                    Color32::from_rgb(r, g, b)
+                })
+                .collect();
+
+            Ok(ColorImage { size, pixels })
+        }
         (3, TensorData::F32(buf)) => {
             let rgb: &[[f32; 3]] = bytemuck::cast_slice(buf.as_slice());
             let pixels: Vec<Color32> = rgb
@@ -306,12 +331,38 @@ fn color_tensor_as_color_image(tensor: &Tensor) -> anyhow::Result<ColorImage> {
             Ok(ColorImage { size, pixels })
         }
 
+        (3, TensorData::NV12(_) | TensorData::YUY2(_)) => {
+            let rgb = tensor.yuv_to_rgb8().ok_or_else(|| {
+                anyhow::anyhow!(
+                    "{:?} buffer is too small for an image of shape {:?}",
+                    tensor.data,
+                    tensor.shape()
+                )
+            })?;
+            Ok(ColorImage::from_rgb(size, &rgb))
+        }
+
         (4, TensorData::U8(buf)) => Ok(ColorImage::from_rgba_unmultiplied(size, buf.0.as_slice())),
         (4, TensorData::U16(buf)) => {
             let u8_buf: Vec<u8> = buf.iter().map(|pixel| (*pixel / 256) as u8).collect();
 
             Ok(ColorImage::from_rgba_unmultiplied(size, &u8_buf))
         }
+        (4, TensorData::F16(buf)) => {
+            let rgba: &[[half::f16; 4]] = bytemuck::cast_slice(buf.as_slice());
+            let pixels: Vec<Color32> = rgba
+                .iter()
+                .map(|&[r, g, b, a]| {
+                    let r = gamma_u8_from_linear_f32(r.to_f32());
+                    let g = gamma_u8_from_linear_f32(g.to_f32());
+                    let b = gamma_u8_from_linear_f32(b.to_f32());
+                    let a = linear_u8_from_linear_f32(a.to_f32());
+                    Color32::from_rgba_unmultiplied(r, g, b, a)
+                })
+                .collect();
+
+            Ok(ColorImage { size, pixels })
+        }
         (4, TensorData::F32(buf)) => {
             let rgba: &[[f32; 4]] = bytemuck::cast_slice(buf.as_slice());
             let pixels: Vec<Color32> = rgba
@@ -486,6 +537,10 @@ fn depth_tensor_as_color_image(tensor: &Tensor) -> anyhow::Result<ColorImage> {
             Ok(ColorImage { size, pixels })
         }
 
+        TensorData::F16(buf) => {
+            let pixels = buf.iter().map(|&value| colormap(value.to_f64())).collect();
+            Ok(ColorImage { size, pixels })
+        }
         TensorData::F32(buf) => {
             let pixels = buf.iter().map(|&value| colormap(value as _)).collect();
             Ok(ColorImage { size, pixels })
@@ -495,8 +550,11 @@ fn depth_tensor_as_color_image(tensor: &Tensor) -> anyhow::Result<ColorImage> {
             Ok(ColorImage { size, pixels })
         }
 
-        TensorData::JPEG(_) => {
-            anyhow::bail!("Cannot apply colormap to JPEG image")
+        TensorData::JPEG(_) | TensorData::PNG(_) | TensorData::Encoded(_) => {
+            anyhow::bail!("Cannot apply colormap to encoded image")
+        }
+        TensorData::NV12(_) | TensorData::YUY2(_) => {
+            anyhow::bail!("Cannot apply colormap to chroma-subsampled image")
         }
     }
 }
@@ -1,5 +1,11 @@
-use re_log_types::{component_types::ColorRGBA, EncodedMesh3D, Mesh3D, MeshFormat, RawMesh3D};
-use re_renderer::{resource_managers::ResourceLifeTime, RenderContext, Rgba32Unmul};
+use re_log_types::{
+    component_types::{ColorRGBA, Tensor, TensorData},
+    EncodedMesh3D, Mesh3D, MeshFormat, RawMesh3D,
+};
+use re_renderer::{
+    resource_managers::{GpuTexture2DHandle, ResourceLifeTime, Texture2DCreationDesc},
+    RenderContext, Rgba32Unmul,
+};
 
 pub struct LoadedMesh {
     name: String,
@@ -28,10 +34,14 @@ impl LoadedMesh {
         }
     }
 
+    /// Loads a mesh file.
+    ///
+    /// `albedo_texture` is used by formats that don't embed their textures, i.e. OBJ.
     pub fn load_raw(
         name: String,
         format: MeshFormat,
         bytes: &[u8],
+        albedo_texture: Option<GpuTexture2DHandle>,
         render_ctx: &mut RenderContext,
     ) -> anyhow::Result<Self> {
         crate::profile_function!();
@@ -45,8 +55,12 @@ impl LoadedMesh {
                     render_ctx,
                 )
             }
-            // TODO(cmc): support obj
-            MeshFormat::Obj => anyhow::bail!(".obj files are not supported yet"),
+            MeshFormat::Obj => re_renderer::importer::obj::load_obj_from_buffer(
+                bytes,
+                albedo_texture,
+                ResourceLifeTime::LongLived,
+                render_ctx,
+            ),
         }?;
         let bbox = re_renderer::importer::calculate_bounding_box(&mesh_instances);
 
@@ -57,6 +71,31 @@ impl LoadedMesh {
         })
     }
 
+    /// Uploads a mesh that was generated on the CPU, e.g. by triangulating a 2D shape.
+    pub fn from_cpu_mesh(
+        name: String,
+        mesh: &re_renderer::mesh::Mesh,
+        lifetime: ResourceLifeTime,
+        render_ctx: &mut RenderContext,
+    ) -> anyhow::Result<Self> {
+        crate::profile_function!();
+
+        let bbox = macaw::BoundingBox::from_points(mesh.vertex_positions.iter().copied());
+        let mesh_instances = vec![re_renderer::renderer::MeshInstance {
+            gpu_mesh: render_ctx
+                .mesh_manager
+                .write()
+                .create(render_ctx, mesh, lifetime)?,
+            ..Default::default()
+        }];
+
+        Ok(Self {
+            name,
+            bbox,
+            mesh_instances,
+        })
+    }
+
     fn load_encoded_mesh(
         name: String,
         encoded_mesh: &EncodedMesh3D,
@@ -68,9 +107,14 @@ impl LoadedMesh {
             format,
             bytes,
             transform,
+            albedo_texture,
         } = encoded_mesh;
 
-        let mut slf = Self::load_raw(name, *format, bytes.as_slice(), render_ctx)?;
+        let albedo_texture = albedo_texture
+            .as_ref()
+            .map(|texture| albedo_texture_handle(texture, render_ctx))
+            .transpose()?;
+        let mut slf = Self::load_raw(name, *format, bytes.as_slice(), albedo_texture, render_ctx)?;
 
         // TODO(cmc): Why are we creating the matrix twice here?
         let (scale, rotation, translation) =
@@ -101,8 +145,10 @@ impl LoadedMesh {
             vertex_positions,
             vertex_colors,
             vertex_normals,
+            vertex_texcoords,
             indices,
             albedo_factor,
+            albedo_texture,
         } = raw_mesh;
 
         let vertex_positions: &[glam::Vec3] = bytemuck::cast_slice(vertex_positions.as_slice());
@@ -134,13 +180,28 @@ impl LoadedMesh {
                 .collect::<Vec<_>>()
         } else {
             // TODO(andreas): Calculate normals
-            // TODO(cmc): support textured raw meshes
             std::iter::repeat(glam::Vec3::ZERO)
                 .take(num_positions)
                 .collect()
         };
 
-        let vertex_texcoords = vec![glam::Vec2::ZERO; vertex_normals.len()];
+        let vertex_texcoords = if let Some(texcoords) = vertex_texcoords {
+            texcoords
+                .chunks_exact(2)
+                .map(|t| glam::Vec2::from([t[0], t[1]]))
+                .collect::<Vec<_>>()
+        } else {
+            vec![glam::Vec2::ZERO; num_positions]
+        };
+
+        let albedo = if let Some(albedo_texture) = albedo_texture {
+            albedo_texture_handle(albedo_texture, render_ctx)?
+        } else {
+            render_ctx
+                .texture_manager_2d
+                .white_texture_unorm_handle()
+                .clone()
+        };
 
         let bbox = macaw::BoundingBox::from_points(vertex_positions.iter().copied());
 
@@ -154,10 +215,7 @@ impl LoadedMesh {
             materials: smallvec::smallvec![re_renderer::mesh::Material {
                 label: name.clone().into(),
                 index_range: 0..num_indices as _,
-                albedo: render_ctx
-                    .texture_manager_2d
-                    .white_texture_unorm_handle()
-                    .clone(),
+                albedo,
                 albedo_multiplier: albedo_factor.map_or(re_renderer::Rgba::WHITE, |v| {
                     re_renderer::Rgba::from_rgba_unmultiplied(v.x(), v.y(), v.z(), v.w())
                 }),
@@ -189,3 +247,63 @@ impl LoadedMesh {
         &self.bbox
     }
 }
+
+/// Uploads the albedo texture of a mesh as an sRGB(A) texture.
+///
+/// Meshes often share a texture, so it is only uploaded the first time we see it.
+fn albedo_texture_handle(
+    texture: &Tensor,
+    render_ctx: &mut RenderContext,
+) -> anyhow::Result<GpuTexture2DHandle> {
+    let texture_key = egui::util::hash(("albedo", texture.id()));
+    crate::gpu_bridge::try_get_or_create_texture(render_ctx, texture_key, || {
+        albedo_texture_desc(texture)
+    })
+}
+
+/// Encoded images (JPEG, PNG, …) are decoded on the spot.
+fn albedo_texture_desc(texture: &Tensor) -> anyhow::Result<Texture2DCreationDesc<'static>> {
+    crate::profile_function!();
+
+    let (rgba, width, height) = if let Some((_, bytes)) = texture.data.encoded_image() {
+        let image = image::load_from_memory(bytes)?.into_rgba8();
+        let (width, height) = image.dimensions();
+        (image.into_raw(), width, height)
+    } else {
+        let Some([height, width, channels]) = texture.image_height_width_channels() else {
+            anyhow::bail!("Albedo texture must be an image, got shape {:?}", texture.shape());
+        };
+        if let TensorData::U8(buf) = &texture.data {
+            let expected = (height * width * channels) as usize;
+            anyhow::ensure!(
+                buf.num_bytes() == expected,
+                "Albedo texture of shape {height}x{width}x{channels} needs {expected} bytes, got {}",
+                buf.num_bytes()
+            );
+        }
+        let rgba = match (channels, &texture.data) {
+            (1, TensorData::U8(buf)) => buf.iter().flat_map(|&l| [l, l, l, 255]).collect(),
+            (3, TensorData::U8(buf)) => Texture2DCreationDesc::convert_rgb8_to_rgba8(buf),
+            (4, TensorData::U8(buf)) => buf.to_vec(),
+            (3, TensorData::NV12(_) | TensorData::YUY2(_)) => {
+                let rgb = texture
+                    .yuv_to_rgb8()
+                    .ok_or_else(|| anyhow::anyhow!("Malformed chroma-subsampled image"))?;
+                Texture2DCreationDesc::convert_rgb8_to_rgba8(&rgb)
+            }
+            _ => anyhow::bail!(
+                "Albedo texture must be a U8 gray, RGB or RGBA image, got {:?} with {channels} channels",
+                texture.dtype()
+            ),
+        };
+        (rgba, width as u32, height as u32)
+    };
+
+    Ok(Texture2DCreationDesc {
+        label: "albedo texture".into(),
+        data: rgba.into(),
+        format: wgpu::TextureFormat::Rgba8UnormSrgb,
+        width,
+        height,
+    })
+}
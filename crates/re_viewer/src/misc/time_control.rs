@@ -523,16 +523,19 @@ fn range(values: &BTreeSet<TimeInt>) -> TimeRange {
 /// Pick the timeline that should be the default, prioritizing user-defined ones.
 fn default_time_line<'a>(timelines: impl Iterator<Item = &'a Timeline>) -> Option<&'a Timeline> {
     let mut log_time_timeline = None;
+    let mut log_tick_timeline = None;
 
     for timeline in timelines {
-        if timeline.name().as_str() == "log_time" {
+        if *timeline == Timeline::log_time() {
             log_time_timeline = Some(timeline);
+        } else if *timeline == Timeline::log_tick() {
+            log_tick_timeline = Some(timeline);
         } else {
             return Some(timeline); // user timeline - always prefer!
         }
     }
 
-    log_time_timeline
+    log_time_timeline.or(log_tick_timeline)
 }
 
 fn step_fwd_time(time: TimeReal, values: &BTreeSet<TimeInt>) -> TimeInt {
@@ -3,6 +3,7 @@ use re_arrow_store::LatestAtQuery;
 use re_data_store::{
     log_db::EntityDb, query_latest_single, EntityPath, EntityPropertyMap, EntityTree,
 };
+use re_log_types::{component_types::LensDistortion, EntityPathHash};
 
 use crate::misc::TimeControl;
 
@@ -22,6 +23,9 @@ pub struct TransformCache {
     /// All reachable entities.
     reference_from_entity_per_entity: IntMap<EntityPath, glam::Mat4>,
 
+    /// All reachable entities that are projected through a pinhole camera with lens distortion.
+    image_distortion_per_entity: IntMap<EntityPathHash, ImageDistortion>,
+
     /// All unreachable descendant paths of `reference_path`.
     unreachable_descendants: Vec<(EntityPath, UnreachableTransform)>,
 
@@ -29,6 +33,48 @@ pub struct TransformCache {
     first_unreachable_parent: Option<(EntityPath, UnreachableTransform)>,
 }
 
+/// Lens distortion of a pinhole camera that entities are projected through into the reference space.
+///
+/// Distortion is non-linear, so unlike the rest of the projection it can't be part of
+/// [`TransformCache::reference_from_entity`] and has to be applied to every position instead.
+#[derive(Clone, Copy)]
+pub struct ImageDistortion {
+    /// From the image of the pinhole camera to the reference space.
+    reference_from_image: glam::Mat4,
+    image_from_reference: glam::Mat4,
+
+    principal_point: glam::Vec2,
+
+    /// The inverse pinhole projection uses the y focal length for both axes,
+    /// so we do the same to get back normalized image coordinates.
+    focal_length: f32,
+
+    distortion: LensDistortion,
+}
+
+impl ImageDistortion {
+    /// Transforms a position with the given `reference_from_obj` and applies the lens distortion.
+    pub fn distorted_reference_from_obj(
+        &self,
+        reference_from_obj: glam::Mat4,
+        pos_in_obj: glam::Vec3,
+    ) -> glam::Vec3 {
+        let pos = reference_from_obj * pos_in_obj.extend(1.0);
+        let pos_in_reference = pos.truncate() / pos.w;
+        if pos.w <= 0.0 {
+            // Behind the camera, there is nothing to distort.
+            return pos_in_reference;
+        }
+
+        let pos_in_image = self.image_from_reference.transform_point3(pos_in_reference);
+        let normalized = (pos_in_image.truncate() - self.principal_point) / self.focal_length;
+        let distorted = glam::Vec2::from(self.distortion.distort(normalized.into()));
+        let pos_in_image =
+            (self.principal_point + distorted * self.focal_length).extend(pos_in_image.z);
+        self.reference_from_image.transform_point3(pos_in_image)
+    }
+}
+
 #[derive(Clone, Copy)]
 pub enum UnreachableTransform {
     /// [`super::space_info::SpaceInfoCollection`] is outdated and can't find a corresponding space info for the given path.
@@ -78,6 +124,7 @@ impl TransformCache {
         let mut transforms = TransformCache {
             reference_path: space_path.clone(),
             reference_from_entity_per_entity: Default::default(),
+            image_distortion_per_entity: Default::default(),
             unreachable_descendants: Default::default(),
             first_unreachable_parent: None,
         };
@@ -100,10 +147,12 @@ impl TransformCache {
             entity_prop_map,
             glam::Mat4::IDENTITY,
             false,
+            None,
         );
 
         // Walk up from the reference to the highest reachable parent.
         let mut encountered_pinhole = false;
+        let mut image_distortion = None;
         let mut reference_from_ancestor = glam::Mat4::IDENTITY;
         while let Some(parent_path) = current_tree.path.parent() {
             let Some(parent_tree) = &entity_db.tree.subtree(&parent_path) else {
@@ -130,6 +179,14 @@ impl TransformCache {
                 }
                 Ok(None) => {}
                 Ok(Some(child_from_parent)) => {
+                    if encountered_pinhole && image_distortion.is_none() {
+                        image_distortion = image_distortion_at(
+                            &current_tree.path,
+                            entity_db,
+                            &query,
+                            reference_from_ancestor,
+                        );
+                    }
                     reference_from_ancestor *= child_from_parent;
                 }
             }
@@ -142,6 +199,7 @@ impl TransformCache {
                 entity_prop_map,
                 reference_from_ancestor,
                 encountered_pinhole,
+                image_distortion,
             );
 
             current_tree = parent_tree;
@@ -158,6 +216,7 @@ impl TransformCache {
         entity_properties: &EntityPropertyMap,
         reference_from_entity: glam::Mat4,
         encountered_pinhole: bool,
+        image_distortion: Option<ImageDistortion>,
     ) {
         match self
             .reference_from_entity_per_entity
@@ -170,6 +229,10 @@ impl TransformCache {
                 e.insert(reference_from_entity);
             }
         }
+        if let Some(image_distortion) = image_distortion {
+            self.image_distortion_per_entity
+                .insert(tree.path.hash(), image_distortion);
+        }
 
         for child_tree in tree.children.values() {
             let mut encountered_pinhole = encountered_pinhole;
@@ -195,6 +258,7 @@ impl TransformCache {
                 entity_properties,
                 reference_from_child,
                 encountered_pinhole,
+                image_distortion,
             );
         }
     }
@@ -208,6 +272,18 @@ impl TransformCache {
             .cloned()
     }
 
+    /// Retrieves the lens distortion that needs to be applied on top of [`Self::reference_from_entity`].
+    ///
+    /// Returns None if the entity isn't seen through a pinhole camera with lens distortion.
+    pub fn image_distortion(&self, entity_path_hash: EntityPathHash) -> Option<&ImageDistortion> {
+        self.image_distortion_per_entity.get(&entity_path_hash)
+    }
+
+    /// Whether any entity needs [`Self::image_distortion`] applied.
+    pub fn has_image_distortion(&self) -> bool {
+        !self.image_distortion_per_entity.is_empty()
+    }
+
     // This method isn't currently implemented, but we might need it in the future.
     // All the necessary data on why a subtree isn't reachable is already stored.
     //
@@ -306,3 +382,23 @@ fn inverse_transform_at(
         Ok(None)
     }
 }
+
+/// The lens distortion to apply after inverting the pinhole camera at `entity_path`, if any.
+fn image_distortion_at(
+    entity_path: &EntityPath,
+    entity_db: &EntityDb,
+    query: &LatestAtQuery,
+    reference_from_image: glam::Mat4,
+) -> Option<ImageDistortion> {
+    let Some(re_log_types::Transform::Pinhole(pinhole)) =
+        query_latest_single(entity_db, entity_path, query) else {
+        return None;
+    };
+    Some(ImageDistortion {
+        reference_from_image,
+        image_from_reference: reference_from_image.inverse(),
+        principal_point: pinhole.principal_point(),
+        focal_length: pinhole.focal_length_in_pixels().y(),
+        distortion: pinhole.distortion?,
+    })
+}
@@ -77,7 +77,8 @@ pub(crate) fn tree_from_space_views(
                 }
                 ViewCategory::Tensor | ViewCategory::TimeSeries => Some(1.0), // Not sure if we should do `None` here.
                 ViewCategory::Text => Some(2.0),                              // Make text logs wide
-                ViewCategory::BarChart => None,
+                ViewCategory::TextDocument => Some(0.8), // Documents read best in tall views
+                ViewCategory::BarChart | ViewCategory::Histogram | ViewCategory::Map => None,
             };
 
             SpaceMakeInfo {
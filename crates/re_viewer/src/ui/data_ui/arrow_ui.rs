@@ -0,0 +1,101 @@
+//! A generic ui for arrow data, used for components without a [`super::DataUi`] of their own.
+
+use re_log_types::external::arrow2::{
+    self,
+    array::{Array, ListArray, StructArray},
+    datatypes::DataType,
+};
+
+use crate::ui::UiVerbosity;
+
+/// Don't show more list items than this, it is slow and unreadable.
+const MAX_LIST_ITEMS: usize = 100;
+
+/// Show a single arrow value, e.g. one instance of a component.
+///
+/// Structs and lists (e.g. values logged using serde) are shown as a collapsible tree,
+/// unless we only have room for a single line.
+pub(crate) fn arrow_ui(ui: &mut egui::Ui, verbosity: UiVerbosity, array: &dyn Array) {
+    let is_tree = match verbosity {
+        UiVerbosity::Small => false,
+        UiVerbosity::Reduced | UiVerbosity::All => array.len() == 1 && is_nested(array),
+    };
+
+    if is_tree {
+        ui.vertical(|ui| children_ui(ui, array, 0));
+    } else {
+        let bytes = arrow2::compute::aggregate::estimated_bytes_size(array);
+        if bytes < 256 {
+            // For small items, print them
+            ui.label(display_value(array, 0));
+        } else {
+            ui.label(format!("{bytes} bytes"));
+        }
+    }
+}
+
+fn is_nested(array: &dyn Array) -> bool {
+    matches!(
+        array.data_type().to_logical_type(),
+        DataType::Struct(_) | DataType::List(_) | DataType::LargeList(_)
+    )
+}
+
+fn display_value(array: &dyn Array, index: usize) -> String {
+    let mut repr = String::new();
+    let display = arrow2::array::get_display(array, "null");
+    display(&mut repr, index).unwrap();
+    repr
+}
+
+/// The value at `index` of `array`, with a collapsible header if it is a struct or list.
+fn value_ui(ui: &mut egui::Ui, name: &str, array: &dyn Array, index: usize) {
+    if array.is_null(index) || !is_nested(array) {
+        ui.horizontal(|ui| {
+            ui.label(format!("{name}:"));
+            ui.label(display_value(array, index));
+        });
+        return;
+    }
+
+    let header = match num_list_items(array, index) {
+        Some(num_items) => format!("{name} [{num_items}]"),
+        None => name.to_owned(),
+    };
+    egui::CollapsingHeader::new(header)
+        .id_source(name)
+        .show(ui, |ui| children_ui(ui, array, index));
+}
+
+fn num_list_items(array: &dyn Array, index: usize) -> Option<usize> {
+    let any = array.as_any();
+    if let Some(list) = any.downcast_ref::<ListArray<i32>>() {
+        Some(list.value(index).len())
+    } else {
+        any.downcast_ref::<ListArray<i64>>()
+            .map(|list| list.value(index).len())
+    }
+}
+
+/// The fields of the struct, or the items of the list, at `index` of `array`.
+fn children_ui(ui: &mut egui::Ui, array: &dyn Array, index: usize) {
+    let any = array.as_any();
+    if let Some(array) = any.downcast_ref::<StructArray>() {
+        for (field, values) in array.fields().iter().zip(array.values()) {
+            value_ui(ui, &field.name, values.as_ref(), index);
+        }
+    } else if let Some(array) = any.downcast_ref::<ListArray<i32>>() {
+        list_items_ui(ui, array.value(index).as_ref());
+    } else if let Some(array) = any.downcast_ref::<ListArray<i64>>() {
+        list_items_ui(ui, array.value(index).as_ref());
+    }
+}
+
+fn list_items_ui(ui: &mut egui::Ui, items: &dyn Array) {
+    for index in 0..items.len().min(MAX_LIST_ITEMS) {
+        value_ui(ui, &index.to_string(), items, index);
+    }
+    if items.len() > MAX_LIST_ITEMS {
+        ui.weak(format!("…and {} more", items.len() - MAX_LIST_ITEMS));
+    }
+}
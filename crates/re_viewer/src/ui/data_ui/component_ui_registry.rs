@@ -2,8 +2,7 @@ use std::collections::BTreeMap;
 
 use re_arrow_store::LatestAtQuery;
 use re_log_types::{
-    component_types::InstanceKey, external::arrow2, Component, ComponentName,
-    DeserializableComponent,
+    component_types::InstanceKey, Component, ComponentName, DeserializableComponent,
 };
 use re_query::ComponentWithInstances;
 
@@ -38,17 +37,25 @@ impl Default for ComponentUiRegistry {
         // Basically: adding custom UI:s for these out-commented components would be nice, but is not a must.
         registry.add::<re_log_types::component_types::AnnotationContext>();
         // registry.add::<re_log_types::component_types::Arrow3D>();
+        registry.add::<re_log_types::component_types::AxisMetadata>();
         // registry.add::<re_log_types::component_types::Box3D>();
         // registry.add::<re_log_types::component_types::ClassId>();
         registry.add::<re_log_types::component_types::ColorRGBA>();
+        registry.add::<re_log_types::component_types::Ellipse2D>();
+        registry.add::<re_log_types::component_types::Ellipsoid3D>();
+        registry.add::<re_log_types::component_types::GeoLineStrip>();
+        registry.add::<re_log_types::component_types::GeoPoint>();
+        registry.add::<re_log_types::component_types::Histogram>();
         // registry.add::<re_log_types::component_types::InstanceKey>();
         // registry.add::<re_log_types::component_types::KeypointId>();
         // registry.add::<re_log_types::component_types::Label>();
         registry.add::<re_log_types::component_types::LineStrip2D>();
         registry.add::<re_log_types::component_types::LineStrip3D>();
         registry.add::<re_log_types::component_types::Mesh3D>();
+        registry.add::<re_log_types::component_types::OrientedBox2D>();
         // registry.add::<re_log_types::component_types::Point2D>();
         // registry.add::<re_log_types::component_types::Point3D>();
+        registry.add::<re_log_types::component_types::Polygon2D>();
         // registry.add::<re_log_types::component_types::Quaternion>();
         // registry.add::<re_log_types::component_types::Radius>();
         registry.add::<re_log_types::component_types::Rect2D>();
@@ -56,6 +63,7 @@ impl Default for ComponentUiRegistry {
         // registry.add::<re_log_types::component_types::ScalarPlotProps>();
         // registry.add::<re_log_types::component_types::Size3D>();
         registry.add::<re_log_types::component_types::Tensor>();
+        registry.add::<re_log_types::component_types::TextDocument>();
         registry.add::<re_log_types::component_types::TextEntry>();
         registry.add::<re_log_types::component_types::Transform>();
         registry.add::<re_log_types::component_types::Vec2D>();
@@ -110,16 +118,7 @@ impl ComponentUiRegistry {
         } else {
             // No special ui implementation - use a generic one:
             if let Some(value) = component.lookup_arrow(instance_key) {
-                let bytes = arrow2::compute::aggregate::estimated_bytes_size(value.as_ref());
-                if bytes < 256 {
-                    // For small items, print them
-                    let mut repr = String::new();
-                    let display = arrow2::array::get_display(value.as_ref(), "null");
-                    display(&mut repr, 0).unwrap();
-                    ui.label(repr);
-                } else {
-                    ui.label(format!("{bytes} bytes"));
-                }
+                super::arrow_ui::arrow_ui(ui, verbosity, value.as_ref());
             } else {
                 ui.weak("(null)");
             }
@@ -129,6 +128,27 @@ impl ComponentUiRegistry {
 
 // ----------------------------------------------------------------------------
 
+impl DataUi for re_log_types::component_types::TextDocument {
+    fn data_ui(
+        &self,
+        _ctx: &mut ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        verbosity: UiVerbosity,
+        _query: &re_arrow_store::LatestAtQuery,
+    ) {
+        match verbosity {
+            UiVerbosity::Small => {
+                let first_line = self.0.lines().next().unwrap_or_default();
+                ui.label(format!("{first_line:?}")); // Debug format to get quotes and escapes
+            }
+            UiVerbosity::All | UiVerbosity::Reduced => {
+                let id = ui.id().with("text_document");
+                crate::ui::view_text_document::markdown_ui(ui, id, &self.0);
+            }
+        }
+    }
+}
+
 impl DataUi for re_log_types::component_types::TextEntry {
     fn data_ui(
         &self,
@@ -202,8 +222,13 @@ impl DataUi for re_log_types::component_types::RawMesh3D {
         _verbosity: UiVerbosity,
         _query: &re_arrow_store::LatestAtQuery,
     ) {
+        let textured = if self.albedo_texture.is_some() {
+            ", textured"
+        } else {
+            ""
+        };
         ui.label(format!(
-            "mesh ({} triangles)",
+            "mesh ({} triangles{textured})",
             re_format::format_number(self.num_triangles())
         ));
     }
@@ -3,7 +3,11 @@ use egui::Vec2;
 use re_format::format_f32;
 use re_log_types::{
     component_types::ColorRGBA,
-    component_types::{LineStrip2D, LineStrip3D, Mat3x3, Rect2D, Vec2D, Vec3D, Vec4D},
+    component_types::{
+        AxisMetadata, DistortionModel, Ellipse2D, Ellipsoid3D, GeoLineStrip, GeoPoint, Histogram,
+        LensDistortion, LineStrip2D, LineStrip3D, Mat3x3, OrientedBox2D, Polygon2D, Rect2D, Vec2D,
+        Vec3D, Vec4D,
+    },
     Pinhole, Rigid3, Transform, ViewCoordinates,
 };
 
@@ -150,6 +154,7 @@ impl DataUi for Pinhole {
                 let Pinhole {
                     image_from_cam: image_from_view,
                     resolution,
+                    distortion,
                 } = self;
 
                 ui.vertical(|ui| {
@@ -168,6 +173,15 @@ impl DataUi for Pinhole {
                         ui.indent("image_from_view", |ui| {
                             image_from_view.data_ui(ctx, ui, verbosity, query);
                         });
+
+                        ui.horizontal(|ui| {
+                            ui.label("distortion:");
+                            if let Some(distortion) = distortion {
+                                distortion.data_ui(ctx, ui, verbosity, query);
+                            } else {
+                                ui.weak("(none)");
+                            }
+                        });
                     });
                 });
             }
@@ -175,6 +189,31 @@ impl DataUi for Pinhole {
     }
 }
 
+impl DataUi for LensDistortion {
+    fn data_ui(
+        &self,
+        _ctx: &mut crate::misc::ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        _verbosity: UiVerbosity,
+        _query: &re_arrow_store::LatestAtQuery,
+    ) {
+        let [k1, k2, k3, k4] = self.radial.0;
+        let [p1, p2] = self.tangential.0;
+        match self.model {
+            DistortionModel::BrownConrady => {
+                ui.label("Brown-Conrady").on_hover_ui(|ui| {
+                    ui.monospace(format!("k1: {k1}\nk2: {k2}\nk3: {k3}\np1: {p1}\np2: {p2}"));
+                });
+            }
+            DistortionModel::Fisheye => {
+                ui.label("Fisheye").on_hover_ui(|ui| {
+                    ui.monospace(format!("k1: {k1}\nk2: {k2}\nk3: {k3}\nk4: {k4}"));
+                });
+            }
+        }
+    }
+}
+
 impl DataUi for Mat3x3 {
     fn data_ui(
         &self,
@@ -260,6 +299,59 @@ impl DataUi for Rect2D {
     }
 }
 
+impl DataUi for OrientedBox2D {
+    fn data_ui(
+        &self,
+        _ctx: &mut crate::misc::ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        _verbosity: UiVerbosity,
+        _query: &re_arrow_store::LatestAtQuery,
+    ) {
+        ui.label(format!(
+            "center: {}, width: {}, height: {}, rotation: {}°",
+            self.center,
+            format_f32(self.width()),
+            format_f32(self.height()),
+            format_f32(self.rotation.to_degrees()),
+        ))
+        .on_hover_text(format!("area: {}", self.width() * self.height()));
+    }
+}
+
+impl DataUi for Ellipse2D {
+    fn data_ui(
+        &self,
+        _ctx: &mut crate::misc::ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        _verbosity: UiVerbosity,
+        _query: &re_arrow_store::LatestAtQuery,
+    ) {
+        ui.label(format!(
+            "center: {}, half-sizes: {}, rotation: {}°{}",
+            self.center,
+            self.half_sizes,
+            format_f32(self.rotation.to_degrees()),
+            if self.solid { ", solid" } else { "" },
+        ));
+    }
+}
+
+impl DataUi for Ellipsoid3D {
+    fn data_ui(
+        &self,
+        _ctx: &mut crate::misc::ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        _verbosity: UiVerbosity,
+        _query: &re_arrow_store::LatestAtQuery,
+    ) {
+        ui.label(format!(
+            "half-sizes: {}{}",
+            self.half_sizes,
+            if self.solid { ", solid" } else { "" },
+        ));
+    }
+}
+
 impl DataUi for LineStrip2D {
     fn data_ui(
         &self,
@@ -309,6 +401,128 @@ impl DataUi for LineStrip2D {
     }
 }
 
+impl DataUi for Polygon2D {
+    fn data_ui(
+        &self,
+        ctx: &mut crate::misc::ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        verbosity: UiVerbosity,
+        query: &re_arrow_store::LatestAtQuery,
+    ) {
+        let summary = format!(
+            "{:?} polygon, {} outer positions, {} holes",
+            self.mode,
+            self.outer.0.len(),
+            self.holes.len()
+        );
+        match verbosity {
+            UiVerbosity::Small | UiVerbosity::Reduced => {
+                ui.label(summary);
+            }
+            UiVerbosity::All => {
+                ui.label(summary);
+                self.outer.data_ui(ctx, ui, verbosity, query);
+            }
+        }
+    }
+}
+
+impl DataUi for AxisMetadata {
+    fn data_ui(
+        &self,
+        _ctx: &mut crate::misc::ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        _verbosity: UiVerbosity,
+        _query: &re_arrow_store::LatestAtQuery,
+    ) {
+        let target = match self.dimension {
+            Some(dimension) => format!("Dimension {dimension}"),
+            None => "Values".to_owned(),
+        };
+        let label = self.label().unwrap_or_else(|| "(unnamed)".to_owned());
+        let range = match (self.range_min, self.range_max) {
+            (Some(min), Some(max)) => format!(
+                " in {} – {}",
+                self.format_value(re_format::format_f64(min)),
+                self.format_value(re_format::format_f64(max))
+            ),
+            _ => String::new(),
+        };
+        ui.label(format!("{target}: {label}{range}"));
+    }
+}
+
+impl DataUi for GeoPoint {
+    fn data_ui(
+        &self,
+        _ctx: &mut crate::misc::ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        _verbosity: UiVerbosity,
+        _query: &re_arrow_store::LatestAtQuery,
+    ) {
+        ui.label(self.to_string());
+    }
+}
+
+impl DataUi for GeoLineStrip {
+    fn data_ui(
+        &self,
+        _ctx: &mut crate::misc::ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        verbosity: UiVerbosity,
+        _query: &re_arrow_store::LatestAtQuery,
+    ) {
+        match verbosity {
+            UiVerbosity::Small | UiVerbosity::Reduced => {
+                ui.label(format!("{} geographic positions", self.points.len()));
+            }
+            UiVerbosity::All => {
+                egui::ScrollArea::vertical()
+                    .max_height(100.0)
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        for point in &self.points {
+                            ui.label(point.to_string());
+                        }
+                    });
+            }
+        }
+    }
+}
+
+impl DataUi for Histogram {
+    fn data_ui(
+        &self,
+        _ctx: &mut crate::misc::ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        _verbosity: UiVerbosity,
+        _query: &re_arrow_store::LatestAtQuery,
+    ) {
+        let range = self.range().map_or_else(String::new, |(min, max)| {
+            format!(
+                " in [{}, {}]",
+                re_format::format_f64(min),
+                re_format::format_f64(max)
+            )
+        });
+        match self {
+            Histogram::Bins(bins) => {
+                ui.label(format!(
+                    "{} bins with {} samples{range}",
+                    bins.counts.len(),
+                    re_format::format_f64(bins.total_count()),
+                ));
+            }
+            Histogram::Samples(samples) => {
+                ui.label(format!(
+                    "{} samples{range}",
+                    re_format::format_number(samples.len())
+                ));
+            }
+        }
+    }
+}
+
 impl DataUi for LineStrip3D {
     fn data_ui(
         &self,
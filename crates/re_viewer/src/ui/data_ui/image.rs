@@ -178,13 +178,34 @@ pub fn tensor_summary_ui_grid_contents(
         | re_log_types::component_types::TensorData::I16(_)
         | re_log_types::component_types::TensorData::I32(_)
         | re_log_types::component_types::TensorData::I64(_)
+        | re_log_types::component_types::TensorData::F16(_)
         | re_log_types::component_types::TensorData::F32(_)
         | re_log_types::component_types::TensorData::F64(_) => {}
-        re_log_types::component_types::TensorData::JPEG(jpeg_bytes) => {
+        re_log_types::component_types::TensorData::JPEG(_)
+        | re_log_types::component_types::TensorData::PNG(_)
+        | re_log_types::component_types::TensorData::Encoded(_) => {
+            if let Some((encoding, bytes)) = data.encoded_image() {
+                re_ui.grid_left_hand_label(ui, "Encoding");
+                ui.label(format!(
+                    "{} {encoding}",
+                    re_format::format_bytes(bytes.len() as _),
+                ));
+                ui.end_row();
+            }
+        }
+        re_log_types::component_types::TensorData::NV12(bytes) => {
+            re_ui.grid_left_hand_label(ui, "Encoding");
+            ui.label(format!(
+                "{} NV12",
+                re_format::format_bytes(bytes.num_bytes() as _),
+            ));
+            ui.end_row();
+        }
+        re_log_types::component_types::TensorData::YUY2(bytes) => {
             re_ui.grid_left_hand_label(ui, "Encoding");
             ui.label(format!(
-                "{} JPEG",
-                re_format::format_bytes(jpeg_bytes.num_bytes() as _),
+                "{} YUY2",
+                re_format::format_bytes(bytes.num_bytes() as _),
             ));
             ui.end_row();
         }
@@ -530,8 +551,6 @@ fn image_options(
 ) {
     // TODO(emilk): support copying images on web
 
-    use re_log_types::component_types::TensorData;
-
     #[cfg(not(target_arch = "wasm32"))]
     if ui.button("Click to copy image").clicked() {
         let rgba = dynamic_image.to_rgba8();
@@ -546,13 +565,13 @@ fn image_options(
     // TODO(emilk): support saving images on web
     #[cfg(not(target_arch = "wasm32"))]
     if ui.button("Save image…").clicked() {
-        match &tensor.data {
-            TensorData::JPEG(bytes) => {
+        match tensor.data.encoded_image() {
+            Some((encoding, bytes)) => {
                 if let Some(path) = rfd::FileDialog::new()
-                    .set_file_name("image.jpg")
+                    .set_file_name(&format!("image.{}", encoding.file_extension()))
                     .save_file()
                 {
-                    match write_binary(&path, bytes.as_slice()) {
+                    match write_binary(&path, bytes) {
                         Ok(()) => {
                             re_log::info!("Image saved to {path:?}");
                         }
@@ -565,7 +584,7 @@ fn image_options(
                     }
                 }
             }
-            _ => {
+            None => {
                 if let Some(path) = rfd::FileDialog::new()
                     .set_file_name("image.png")
                     .save_file()
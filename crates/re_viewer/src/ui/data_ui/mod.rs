@@ -6,6 +6,7 @@ use re_log_types::{DataCell, PathOp, TimePoint};
 use crate::misc::ViewerContext;
 
 mod annotation_context;
+mod arrow_ui;
 mod component;
 mod component_path;
 mod component_ui_registry;
@@ -116,6 +117,11 @@ impl DataUi for PathOp {
             PathOp::ClearRecursive(entity_path) => {
                 ui.label(format!("ClearRecursive: {entity_path}"))
             }
+            PathOp::ClearComponent(component_path) => ui.label(format!(
+                "ClearComponent: {}.{}",
+                component_path.entity_path,
+                component_path.component_name.short_name()
+            )),
         };
     }
 }
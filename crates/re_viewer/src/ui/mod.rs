@@ -10,8 +10,11 @@ mod space_view_entity_picker;
 mod space_view_heuristics;
 mod view_bar_chart;
 mod view_category;
+mod view_histogram;
+mod view_map;
 mod view_tensor;
 mod view_text;
+mod view_text_document;
 mod view_time_series;
 mod viewport;
 
@@ -12,8 +12,9 @@ use super::{
     space_view_heuristics::default_queried_entities,
     view_bar_chart,
     view_category::ViewCategory,
+    view_histogram, view_map,
     view_spatial::{self},
-    view_tensor, view_text, view_time_series,
+    view_tensor, view_text, view_text_document, view_time_series,
 };
 
 // ----------------------------------------------------------------------------
@@ -174,8 +175,16 @@ impl SpaceView {
             ViewCategory::Text => {
                 self.view_state.state_text.selection_ui(ctx.re_ui, ui);
             }
+            ViewCategory::TextDocument => {
+                self.view_state
+                    .state_text_document
+                    .selection_ui(ctx.re_ui, ui);
+            }
             ViewCategory::TimeSeries => {}
             ViewCategory::BarChart => {}
+            ViewCategory::Histogram => {
+                self.view_state.state_histogram.selection_ui(ctx.re_ui, ui);
+            }
             ViewCategory::Spatial => {
                 self.view_state.state_spatial.selection_ui(
                     ctx,
@@ -194,6 +203,9 @@ impl SpaceView {
                     }
                 }
             }
+            ViewCategory::Map => {
+                self.view_state.state_map.selection_ui(ctx.re_ui, ui);
+            }
         }
     }
 
@@ -225,6 +237,12 @@ impl SpaceView {
                 self.view_state.ui_text(ctx, ui, &scene);
             }
 
+            ViewCategory::TextDocument => {
+                let mut scene = view_text_document::SceneTextDocument::default();
+                scene.load(ctx, &query);
+                self.view_state.ui_text_document(ctx, ui, &scene);
+            }
+
             ViewCategory::TimeSeries => {
                 let mut scene = view_time_series::SceneTimeSeries::default();
                 scene.load(ctx, &query);
@@ -237,6 +255,12 @@ impl SpaceView {
                 self.view_state.ui_bar_chart(ctx, ui, &scene);
             }
 
+            ViewCategory::Histogram => {
+                let mut scene = view_histogram::SceneHistogram::default();
+                scene.load(ctx, &query);
+                self.view_state.ui_histogram(ctx, ui, &scene);
+            }
+
             ViewCategory::Spatial => {
                 let transforms = TransformCache::determine_transforms(
                     &ctx.log_db.entity_db,
@@ -265,6 +289,12 @@ impl SpaceView {
                 scene.load(ctx, &query);
                 self.view_state.ui_tensor(ctx, ui, &scene);
             }
+
+            ViewCategory::Map => {
+                let mut scene = view_map::SceneMap::default();
+                scene.load(ctx, &query);
+                self.view_state.ui_map(ctx, ui, &scene, self.id, highlights);
+            }
         };
     }
 
@@ -323,10 +353,13 @@ pub struct ViewState {
     selected_tensor: Option<InstancePath>,
 
     state_text: view_text::ViewTextState,
+    state_text_document: view_text_document::ViewTextDocumentState,
     state_time_series: view_time_series::ViewTimeSeriesState,
     state_bar_chart: view_bar_chart::BarChartState,
+    state_histogram: view_histogram::ViewHistogramState,
     pub state_spatial: view_spatial::ViewSpatialState,
     state_tensors: ahash::HashMap<InstancePath, view_tensor::ViewTensorState>,
+    state_map: view_map::ViewMapState,
 }
 
 impl ViewState {
@@ -392,7 +425,8 @@ impl ViewState {
                         .state_tensors
                         .entry(selected_tensor.clone())
                         .or_insert_with(|| view_tensor::ViewTensorState::create(tensor));
-                    view_tensor::view_tensor(ctx, ui, state_tensor, tensor);
+                    let value_axis = scene.value_axes.get(selected_tensor);
+                    view_tensor::view_tensor(ctx, ui, state_tensor, tensor, value_axis);
                 }
             }
         }
@@ -413,6 +447,21 @@ impl ViewState {
         });
     }
 
+    fn ui_text_document(
+        &mut self,
+        ctx: &mut ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        scene: &view_text_document::SceneTextDocument,
+    ) {
+        egui::Frame {
+            inner_margin: re_ui::ReUi::view_padding().into(),
+            ..egui::Frame::default()
+        }
+        .show(ui, |ui| {
+            view_text_document::view_text_document(ctx, ui, &mut self.state_text_document, scene);
+        });
+    }
+
     fn ui_bar_chart(
         &mut self,
         ctx: &mut ViewerContext<'_>,
@@ -426,6 +475,19 @@ impl ViewState {
         });
     }
 
+    fn ui_histogram(
+        &mut self,
+        ctx: &mut ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        scene: &view_histogram::SceneHistogram,
+    ) {
+        ui.vertical(|ui| {
+            ui.scope(|ui| {
+                view_histogram::view_histogram(ctx, ui, &mut self.state_histogram, scene);
+            });
+        });
+    }
+
     fn ui_time_series(
         &mut self,
         ctx: &mut ViewerContext<'_>,
@@ -438,4 +500,22 @@ impl ViewState {
             });
         });
     }
+
+    fn ui_map(
+        &mut self,
+        ctx: &mut ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        scene: &view_map::SceneMap,
+        space_view_id: SpaceViewId,
+        highlights: &SpaceViewHighlights,
+    ) {
+        view_map::view_map(
+            ctx,
+            ui,
+            &mut self.state_map,
+            scene,
+            space_view_id,
+            highlights,
+        );
+    }
 }
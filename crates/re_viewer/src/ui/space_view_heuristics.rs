@@ -144,6 +144,15 @@ fn default_created_space_views_from_candidates(
     // Main pass through all candidates.
     // We first check if a candidate is "interesting" and then split it up/modify it further if required.
     for candidate in candidates {
+        // Geographic coordinates are global, so a single map at the root can show all of them.
+        if candidate.category == ViewCategory::Map {
+            if candidate.space_path.is_root() && !candidate.data_blueprint.entity_paths().is_empty()
+            {
+                space_views.push(candidate);
+            }
+            continue;
+        }
+
         if candidate.space_path.is_root() {
             if !categories_with_interesting_roots.contains(candidate.category) {
                 continue;
@@ -157,11 +166,14 @@ fn default_created_space_views_from_candidates(
             continue;
         }
 
-        // For tensors create one space view for each tensor (even though we're able to stack them in one view)
-        if candidate.category == ViewCategory::Tensor {
+        // For tensors and documents create one space view for each entity (even though we're able to stack them in one view)
+        if matches!(
+            candidate.category,
+            ViewCategory::Tensor | ViewCategory::TextDocument
+        ) {
             for entity_path in candidate.data_blueprint.entity_paths() {
                 let mut space_view =
-                    SpaceView::new(ViewCategory::Tensor, entity_path, &[entity_path.clone()]);
+                    SpaceView::new(candidate.category, entity_path, &[entity_path.clone()]);
                 space_view.entities_determined_by_user = true; // Suppress auto adding of entities.
                 space_views.push(space_view);
             }
@@ -274,6 +286,10 @@ pub fn default_queried_entities(
 ) -> Vec<EntityPath> {
     crate::profile_function!();
 
+    if category == ViewCategory::Map {
+        return default_queried_map_entities(ctx, space_path);
+    }
+
     let timeline = Timeline::log_time();
     let log_db = &ctx.log_db;
     let data_store = &log_db.entity_db.data_store;
@@ -318,6 +334,9 @@ fn default_queried_entities_by_category(
             for entity_path in &space_info.descendants_without_transform {
                 if is_default_added_to_space_view(entity_path, space_path, data_store, timeline) {
                     for category in categorize_entity_path(timeline, log_db, entity_path) {
+                        if category == ViewCategory::Map {
+                            continue; // Gathered below.
+                        }
                         groups
                             .entry(category)
                             .or_default()
@@ -328,5 +347,38 @@ fn default_queried_entities_by_category(
         },
     );
 
+    let map_entities = default_queried_map_entities(ctx, space_path);
+    if !map_entities.is_empty() {
+        groups.insert(ViewCategory::Map, map_entities);
+    }
+
     groups
 }
+
+/// List of entities with geographic data a map view queries by default.
+///
+/// Geographic coordinates don't depend on any transform,
+/// so this is every such entity in the subtree, reachable by a transform or not.
+fn default_queried_map_entities(
+    ctx: &ViewerContext<'_>,
+    space_path: &EntityPath,
+) -> Vec<EntityPath> {
+    crate::profile_function!();
+
+    let timeline = Timeline::log_time();
+    let log_db = &ctx.log_db;
+    let data_store = &log_db.entity_db.data_store;
+
+    let mut entities = Vec::new();
+    if let Some(tree) = log_db.entity_db.tree.subtree(space_path) {
+        tree.visit_children_recursively(&mut |entity_path| {
+            if is_default_added_to_space_view(entity_path, space_path, data_store, timeline)
+                && categorize_entity_path(timeline, log_db, entity_path).contains(ViewCategory::Map)
+            {
+                entities.push(entity_path.clone());
+            }
+        });
+    }
+
+    entities
+}
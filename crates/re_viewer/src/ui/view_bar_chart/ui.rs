@@ -85,15 +85,22 @@ pub(crate) fn view_bar_chart(
                         instance_key,
                         data.iter().copied().map(|v| v as f64),
                     ),
+                    component_types::TensorData::F16(data) => {
+                        create_bar_chart(ent_path, instance_key, data.iter().copied())
+                    }
                     component_types::TensorData::F32(data) => {
                         create_bar_chart(ent_path, instance_key, data.iter().copied())
                     }
                     component_types::TensorData::F64(data) => {
                         create_bar_chart(ent_path, instance_key, data.iter().copied())
                     }
-                    component_types::TensorData::JPEG(_) => {
+                    component_types::TensorData::JPEG(_)
+                    | component_types::TensorData::PNG(_)
+                    | component_types::TensorData::Encoded(_)
+                    | component_types::TensorData::NV12(_)
+                    | component_types::TensorData::YUY2(_) => {
                         warn_once!(
-                            "trying to display JPEG data as a bar chart ({:?})",
+                            "trying to display image data as a bar chart ({:?})",
                             ent_path
                         );
                         continue;
@@ -2,7 +2,9 @@ use re_arrow_store::{LatestAtQuery, TimeInt};
 use re_data_store::{EntityPath, LogDb, Timeline};
 use re_log_types::{
     component_types::{
-        Box3D, LineStrip2D, LineStrip3D, Point2D, Point3D, Rect2D, Scalar, Tensor, TextEntry,
+        Box3D, Ellipse2D, Ellipsoid3D, GeoLineStrip, GeoPoint, Histogram, LineStrip2D, LineStrip3D,
+        OrientedBox2D, Point2D, Point3D, Polygon2D, Rect2D, Scalar, Tensor, TextDocument,
+        TextEntry,
     },
     Arrow3D, Component, Mesh3D, Transform,
 };
@@ -17,28 +19,38 @@ pub enum ViewCategory {
     /// Text log view (text over time)
     Text,
 
+    /// Markdown document view
+    TextDocument,
+
     /// Time series plot (scalar over time)
     TimeSeries,
 
     /// Bar-chart plots made from 1D tensor data
     BarChart,
 
+    /// Histogram of a distribution, or its evolution over time
+    Histogram,
+
     /// 2D or 3D view
     #[default]
     Spatial,
 
     /// High-dimensional tensor view
     Tensor,
+
+    /// Map view of geographic coordinates
+    Map,
 }
 
 impl ViewCategory {
     pub fn icon(self) -> &'static re_ui::Icon {
         match self {
-            ViewCategory::Text => &re_ui::icons::SPACE_VIEW_TEXT,
+            ViewCategory::Text | ViewCategory::TextDocument => &re_ui::icons::SPACE_VIEW_TEXT,
             ViewCategory::TimeSeries => &re_ui::icons::SPACE_VIEW_SCATTERPLOT,
-            ViewCategory::BarChart => &re_ui::icons::SPACE_VIEW_HISTOGRAM,
+            ViewCategory::BarChart | ViewCategory::Histogram => &re_ui::icons::SPACE_VIEW_HISTOGRAM,
             ViewCategory::Spatial => &re_ui::icons::SPACE_VIEW_3D,
             ViewCategory::Tensor => &re_ui::icons::SPACE_VIEW_TENSOR,
+            ViewCategory::Map => &re_ui::icons::SPACE_VIEW_MAP,
         }
     }
 }
@@ -47,10 +59,13 @@ impl std::fmt::Display for ViewCategory {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(match self {
             ViewCategory::Text => "Text",
+            ViewCategory::TextDocument => "Document",
             ViewCategory::TimeSeries => "Time Series",
             ViewCategory::BarChart => "Bar Chart",
+            ViewCategory::Histogram => "Histogram",
             ViewCategory::Spatial => "Spatial",
             ViewCategory::Tensor => "Tensor",
+            ViewCategory::Map => "Map",
         })
     }
 }
@@ -77,19 +92,29 @@ pub fn categorize_entity_path(
     {
         if component == TextEntry::name() {
             set.insert(ViewCategory::Text);
+        } else if component == TextDocument::name() {
+            set.insert(ViewCategory::TextDocument);
         } else if component == Scalar::name() {
             set.insert(ViewCategory::TimeSeries);
+        } else if component == Histogram::name() {
+            set.insert(ViewCategory::Histogram);
         } else if component == Point2D::name()
             || component == Point3D::name()
             || component == Rect2D::name()
+            || component == OrientedBox2D::name()
             || component == Box3D::name()
+            || component == Ellipse2D::name()
+            || component == Ellipsoid3D::name()
             || component == LineStrip2D::name()
             || component == LineStrip3D::name()
+            || component == Polygon2D::name()
             || component == Mesh3D::name()
             || component == Arrow3D::name()
             || component == Transform::name()
         {
             set.insert(ViewCategory::Spatial);
+        } else if component == GeoPoint::name() || component == GeoLineStrip::name() {
+            set.insert(ViewCategory::Map);
         } else if component == Tensor::name() {
             let timeline_query = LatestAtQuery::new(timeline, TimeInt::MAX);
 
@@ -0,0 +1,56 @@
+use std::collections::BTreeMap;
+
+use re_arrow_store::{RangeQuery, TimeRange};
+use re_data_store::EntityPath;
+use re_log_types::{
+    component_types::{Histogram, InstanceKey},
+    Component,
+};
+use re_query::{range_entity_with_primary, QueryError};
+
+use crate::{misc::ViewerContext, ui::scene::SceneQuery};
+
+/// A histogram scene, with everything needed to render it.
+#[derive(Default)]
+pub struct SceneHistogram {
+    /// The history of each histogram up to the queried time, ordered by time.
+    ///
+    /// The time is `None` for timeless data.
+    pub histograms: BTreeMap<(EntityPath, InstanceKey), Vec<(Option<i64>, Histogram)>>,
+}
+
+impl SceneHistogram {
+    pub(crate) fn load(&mut self, ctx: &mut ViewerContext<'_>, query: &SceneQuery<'_>) {
+        crate::profile_function!();
+
+        let store = &ctx.log_db.entity_db.data_store;
+        let range_query = RangeQuery::new(
+            query.timeline,
+            TimeRange::new(i64::MIN.into(), query.latest_at),
+        );
+
+        for ent_path in query.entity_paths {
+            let components = [Histogram::name(), InstanceKey::name()];
+            let ent_views = range_entity_with_primary::<Histogram, 2>(
+                store,
+                &range_query,
+                ent_path,
+                components,
+            );
+
+            for (time, ent_view) in ent_views {
+                match ent_view.visit1(|instance_key, histogram: Histogram| {
+                    self.histograms
+                        .entry((ent_path.clone(), instance_key))
+                        .or_default()
+                        .push((time.map(|time| time.as_i64()), histogram));
+                }) {
+                    Ok(_) | Err(QueryError::PrimaryNotFound) => {}
+                    Err(err) => {
+                        re_log::error_once!("Unexpected error querying {ent_path:?}: {err}");
+                    }
+                }
+            }
+        }
+    }
+}
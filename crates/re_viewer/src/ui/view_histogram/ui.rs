@@ -0,0 +1,340 @@
+use std::collections::BTreeMap;
+
+use egui::{
+    plot::{Bar, BarChart, Legend, Plot, PlotImage, PlotPoint},
+    util::hash,
+};
+
+use re_data_store::EntityPath;
+use re_log::warn_once;
+use re_log_types::component_types::{Histogram, HistogramBins, InstanceKey};
+
+use crate::{misc::ViewerContext, ui::annotations::auto_color};
+
+use super::SceneHistogram;
+
+// ---
+
+pub(crate) const HELP_TEXT: &str = "\
+    Pan by dragging, or scroll (+ shift = horizontal).\n\
+    Box zooming: Right click to zoom in and zoom out using a selection.\n\
+    Reset view with double-click.\n\
+    Switch between the latest distribution and its history over time in the selection panel.";
+
+/// Columns of the heatmap texture, at most.
+const MAX_HEATMAP_COLUMNS: usize = 512;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum HistogramMode {
+    /// The distribution at the current time.
+    #[default]
+    Latest,
+
+    /// A time-vs-bin heatmap of the distribution up to the current time.
+    OverTime,
+}
+
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct ViewHistogramState {
+    mode: HistogramMode,
+
+    /// How many bins raw samples are sorted into.
+    num_bins: usize,
+
+    /// Show fractions of the total count rather than counts.
+    normalize: bool,
+
+    /// The last heatmap we built for each histogram, so we don't rebuild it every frame.
+    #[serde(skip)]
+    heatmaps: BTreeMap<(EntityPath, InstanceKey), Heatmap>,
+}
+
+impl Default for ViewHistogramState {
+    fn default() -> Self {
+        Self {
+            mode: HistogramMode::default(),
+            num_bins: 32,
+            normalize: false,
+            heatmaps: Default::default(),
+        }
+    }
+}
+
+impl ViewHistogramState {
+    pub fn selection_ui(&mut self, re_ui: &re_ui::ReUi, ui: &mut egui::Ui) {
+        re_ui
+            .selection_grid(ui, "histogram_settings")
+            .show(ui, |ui| {
+                re_ui.grid_left_hand_label(ui, "Show");
+                ui.vertical(|ui| {
+                    ui.radio_value(&mut self.mode, HistogramMode::Latest, "Latest distribution");
+                    ui.radio_value(&mut self.mode, HistogramMode::OverTime, "Over time");
+                });
+                ui.end_row();
+
+                re_ui.grid_left_hand_label(ui, "Bins");
+                ui.add(egui::DragValue::new(&mut self.num_bins).clamp_range(1..=1024))
+                    .on_hover_text(
+                        "Number of bins for raw samples, and for the rows of the heatmap",
+                    );
+                ui.end_row();
+
+                re_ui.grid_left_hand_label(ui, "Normalize");
+                ui.checkbox(&mut self.normalize, "")
+                    .on_hover_text("Show the fraction of all samples in each bin");
+                ui.end_row();
+            });
+    }
+}
+
+pub(crate) fn view_histogram(
+    ctx: &mut ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    state: &mut ViewHistogramState,
+    scene: &SceneHistogram,
+) -> egui::Response {
+    crate::profile_function!();
+
+    match state.mode {
+        HistogramMode::Latest => latest_ui(ui, state, scene),
+        HistogramMode::OverTime => {
+            state
+                .heatmaps
+                .retain(|key, _| scene.histograms.contains_key(key));
+
+            let num_histograms = scene.histograms.len().max(1);
+            let height = ui.available_height() / num_histograms as f32;
+            ui.vertical(|ui| {
+                for ((ent_path, instance_key), history) in &scene.histograms {
+                    ui.allocate_ui(egui::vec2(ui.available_width(), height), |ui| {
+                        heatmap_ui(ctx, ui, state, ent_path, instance_key, history);
+                    });
+                }
+            })
+            .response
+        }
+    }
+}
+
+fn latest_ui(
+    ui: &mut egui::Ui,
+    state: &ViewHistogramState,
+    scene: &SceneHistogram,
+) -> egui::Response {
+    Plot::new("histogram_plot")
+        .legend(Legend::default())
+        .clamp_grid(true)
+        .show(ui, |plot_ui| {
+            for ((ent_path, instance_key), history) in &scene.histograms {
+                let Some((_, histogram)) = history.last() else {
+                    continue;
+                };
+                let bins = histogram.to_bins(state.num_bins);
+                if !bins.is_valid() {
+                    warn_once!("Invalid histogram bins for {ent_path:?}");
+                    continue;
+                }
+
+                let scale = if state.normalize {
+                    1.0 / bins.total_count().max(f64::MIN_POSITIVE)
+                } else {
+                    1.0
+                };
+                let color = auto_color(hash((ent_path, instance_key)) as _);
+                let fill = color.gamma_multiply(0.75).additive(); // make sure overlapping bars are obvious
+                let name = format!("{ent_path}[#{instance_key}]");
+
+                let bars = bins
+                    .edges
+                    .windows(2)
+                    .zip(&bins.counts)
+                    .map(|(edges, count)| {
+                        Bar::new(0.5 * (edges[0] + edges[1]), scale * count)
+                            .width(edges[1] - edges[0])
+                            .name(format!("{name} {}..{}", edges[0], edges[1]))
+                            .fill(fill)
+                            .stroke(egui::Stroke::NONE)
+                    })
+                    .collect();
+                plot_ui.bar_chart(BarChart::new(bars).name(name).color(color));
+            }
+        })
+        .response
+}
+
+/// What a [`Heatmap`] was built from.
+///
+/// Histograms only ever get added at the end of the history, so the latest time and the number
+/// of histograms tell us whether it changed.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct HeatmapKey {
+    latest_time: Option<i64>,
+    num_histograms: usize,
+    num_bins: usize,
+    normalize: bool,
+}
+
+/// A time-vs-bin heatmap texture, and where to show it.
+#[derive(Clone)]
+struct Heatmap {
+    key: HeatmapKey,
+    texture: egui::TextureHandle,
+
+    /// The time of the first column.
+    min_time: i64,
+
+    /// Width of the texture along the time axis.
+    width: f64,
+
+    /// The value range covered by the rows.
+    min: f64,
+    max: f64,
+}
+
+/// A time-vs-bin heatmap of one histogram, up to the current time.
+fn heatmap_ui(
+    ctx: &mut ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    state: &mut ViewHistogramState,
+    ent_path: &EntityPath,
+    instance_key: &InstanceKey,
+    history: &[(Option<i64>, Histogram)],
+) -> egui::Response {
+    let time_type = ctx.rec_cfg.time_ctrl.time_type();
+
+    let key = HeatmapKey {
+        latest_time: history.last().and_then(|(time, _)| *time),
+        num_histograms: history.len(),
+        num_bins: state.num_bins,
+        normalize: state.normalize,
+    };
+    let heatmap = match state.heatmaps.get(&(ent_path.clone(), *instance_key)) {
+        Some(heatmap) if heatmap.key == key => heatmap.clone(),
+        _ => match build_heatmap(ui.ctx(), key, ent_path, instance_key, history) {
+            Ok(heatmap) => {
+                state
+                    .heatmaps
+                    .insert((ent_path.clone(), *instance_key), heatmap.clone());
+                heatmap
+            }
+            Err(reason) => {
+                state.heatmaps.remove(&(ent_path.clone(), *instance_key));
+                return ui.label(format!("{ent_path}[#{instance_key}]: {reason}"));
+            }
+        },
+    };
+    let Heatmap {
+        texture,
+        min_time,
+        width,
+        min,
+        max,
+        ..
+    } = heatmap;
+
+    // Times relative to the first one, to avoid precision issues with large times.
+    let time_offset = min_time;
+
+    Plot::new(("histogram_heatmap", ent_path, instance_key))
+        .x_axis_formatter(move |time, _| time_type.format((time as i64 + time_offset).into()))
+        .label_formatter(move |_, value| {
+            format!(
+                "{}\nvalue: {:.5}",
+                time_type.format((value.x as i64 + time_offset).into()),
+                value.y
+            )
+        })
+        .show(ui, |plot_ui| {
+            plot_ui.image(
+                PlotImage::new(
+                    &texture,
+                    PlotPoint::new(0.5 * width, 0.5 * (min + max)),
+                    egui::vec2(width as f32, (max - min).max(f64::MIN_POSITIVE) as f32),
+                )
+                .name(format!("{ent_path}[#{instance_key}]")),
+            );
+        })
+        .response
+}
+
+/// Bins the whole history and uploads it as a texture.
+///
+/// Returns why there is nothing to show if there isn't.
+fn build_heatmap(
+    egui_ctx: &egui::Context,
+    key: HeatmapKey,
+    ent_path: &EntityPath,
+    instance_key: &InstanceKey,
+    history: &[(Option<i64>, Histogram)],
+) -> Result<Heatmap, &'static str> {
+    crate::profile_function!();
+
+    // Timeless histograms have no place on the time axis.
+    let history = history
+        .iter()
+        .filter_map(|(time, histogram)| Some(((*time)?, histogram)))
+        .collect::<Vec<_>>();
+    let (Some(&(min_time, _)), Some(&(max_time, _))) = (history.first(), history.last()) else {
+        return Err("no histograms over time");
+    };
+
+    let Some((min, max)) = history
+        .iter()
+        .filter_map(|(_, histogram)| histogram.range())
+        .reduce(|(min_a, max_a), (min_b, max_b)| (min_a.min(min_b), max_a.max(max_b)))
+    else {
+        return Err("empty histograms");
+    };
+
+    // Every column shows the latest histogram at its time, resampled onto common bins.
+    let time_span = (max_time - min_time).max(1);
+    let num_columns = history.len().min(MAX_HEATMAP_COLUMNS);
+    let columns = (0..num_columns)
+        .map(|column| {
+            let time = min_time + (column as i64 * time_span) / num_columns as i64;
+            let index = history
+                .partition_point(|(t, _)| *t <= time)
+                .saturating_sub(1);
+            let mut bins = HistogramBins::uniform(min, max, key.num_bins);
+            bins.add(history[index].1);
+            bins
+        })
+        .collect::<Vec<_>>();
+
+    let global_max = columns
+        .iter()
+        .flat_map(|bins| bins.counts.iter().copied())
+        .fold(0.0, f64::max);
+
+    let num_rows = key.num_bins.max(1);
+    let mut image = egui::ColorImage::new([num_columns, num_rows], egui::Color32::TRANSPARENT);
+    for (x, bins) in columns.iter().enumerate() {
+        let column_max = if key.normalize {
+            bins.counts.iter().copied().fold(0.0, f64::max)
+        } else {
+            global_max
+        };
+        for (row, &count) in bins.counts.iter().enumerate() {
+            if count > 0.0 && column_max > 0.0 {
+                let [r, g, b, a] = re_renderer::colormap_viridis_srgb((count / column_max) as f32);
+                // Row 0 is at the top of the image, but holds the largest values.
+                image[(x, num_rows - 1 - row)] = egui::Color32::from_rgba_unmultiplied(r, g, b, a);
+            }
+        }
+    }
+    let texture = egui_ctx.load_texture(
+        format!("histogram heatmap {ent_path}[#{instance_key}]"),
+        image,
+        egui::TextureOptions::NEAREST,
+    );
+
+    Ok(Heatmap {
+        key,
+        texture,
+        min_time,
+        width: (time_span as f64) * (num_columns as f64 + 1.0) / num_columns as f64,
+        min,
+        max,
+    })
+}
@@ -0,0 +1,7 @@
+mod scene;
+pub(crate) use self::scene::SceneMap;
+
+mod tiles;
+
+mod ui;
+pub(crate) use self::ui::{view_map, ViewMapState, HELP_TEXT};
@@ -0,0 +1,199 @@
+use re_data_store::InstancePath;
+use re_log_types::{
+    component_types::{ClassId, ColorRGBA, GeoLineStrip, GeoPoint, InstanceKey, Label, Radius},
+    Component,
+};
+use re_query::{query_primary_with_history, QueryError};
+use re_renderer::Size;
+
+use crate::{
+    ui::{annotations::AnnotationMap, DefaultColor, SceneQuery},
+    ViewerContext,
+};
+
+// ---
+
+/// Radius of a point on the map, in ui points, if none was logged.
+const DEFAULT_POINT_RADIUS: f32 = 3.0;
+
+/// Width of a line strip on the map, in ui points, if no radius was logged.
+const DEFAULT_LINE_WIDTH: f32 = 1.5;
+
+pub struct MapPoint {
+    pub instance_path: InstancePath,
+    pub geo: GeoPoint,
+
+    /// Position on the Web-Mercator plane, in meters.
+    pub position: [f64; 2],
+    pub color: egui::Color32,
+
+    /// Logged radii are in meters on the ground, like the scene units of the spatial views.
+    pub radius: Size,
+    pub label: Option<String>,
+}
+
+pub struct MapLineStrip {
+    pub instance_path: InstancePath,
+
+    /// Positions on the Web-Mercator plane, in meters.
+    pub positions: Vec<[f64; 2]>,
+    pub color: egui::Color32,
+
+    /// Logged radii are in meters on the ground, like the scene units of the spatial views.
+    pub width: Size,
+}
+
+/// A map scene, with everything needed to render it.
+#[derive(Default)]
+pub struct SceneMap {
+    pub annotation_map: AnnotationMap,
+    pub points: Vec<MapPoint>,
+    pub line_strips: Vec<MapLineStrip>,
+}
+
+impl SceneMap {
+    /// Loads all geographic points and line strips into the scene according to the given query.
+    pub(crate) fn load(&mut self, ctx: &mut ViewerContext<'_>, query: &SceneQuery<'_>) {
+        crate::profile_function!();
+
+        self.annotation_map.load(ctx, query);
+
+        self.load_points(ctx, query);
+        self.load_line_strips(ctx, query);
+    }
+
+    fn load_points(&mut self, ctx: &mut ViewerContext<'_>, query: &SceneQuery<'_>) {
+        crate::profile_function!();
+
+        for (ent_path, props) in query.iter_entities() {
+            let annotations = self.annotation_map.find(ent_path);
+            let default_color = DefaultColor::EntityPath(ent_path);
+
+            match query_primary_with_history::<GeoPoint, 6>(
+                &ctx.log_db.entity_db.data_store,
+                &query.timeline,
+                &query.latest_at,
+                &props.visible_history,
+                ent_path,
+                [
+                    GeoPoint::name(),
+                    InstanceKey::name(),
+                    ColorRGBA::name(),
+                    Radius::name(),
+                    Label::name(),
+                    ClassId::name(),
+                ],
+            )
+            .and_then(|entities| {
+                for entity in entities {
+                    entity.visit5(
+                        |instance_key,
+                         geo: GeoPoint,
+                         color: Option<ColorRGBA>,
+                         radius: Option<Radius>,
+                         label: Option<Label>,
+                         class_id: Option<ClassId>| {
+                            let annotation_info =
+                                annotations.class_description(class_id).annotation_info();
+                            self.points.push(MapPoint {
+                                instance_path: InstancePath::instance(
+                                    ent_path.clone(),
+                                    instance_key,
+                                ),
+                                geo,
+                                position: geo.to_web_mercator(),
+                                color: annotation_info
+                                    .color(color.map(|c| c.to_array()).as_ref(), default_color),
+                                radius: radius
+                                    .map_or(Size::new_points(DEFAULT_POINT_RADIUS), |r| {
+                                        Size::new_scene(r.0)
+                                    }),
+                                label: annotation_info.label(label.map(|l| l.0).as_ref()),
+                            });
+                        },
+                    )?;
+                }
+                Ok(())
+            }) {
+                Ok(_) | Err(QueryError::PrimaryNotFound) => {}
+                Err(err) => {
+                    re_log::error_once!("Unexpected error querying {ent_path:?}: {err}");
+                }
+            }
+        }
+    }
+
+    fn load_line_strips(&mut self, ctx: &mut ViewerContext<'_>, query: &SceneQuery<'_>) {
+        crate::profile_function!();
+
+        for (ent_path, props) in query.iter_entities() {
+            let annotations = self.annotation_map.find(ent_path);
+            let default_color = DefaultColor::EntityPath(ent_path);
+
+            match query_primary_with_history::<GeoLineStrip, 5>(
+                &ctx.log_db.entity_db.data_store,
+                &query.timeline,
+                &query.latest_at,
+                &props.visible_history,
+                ent_path,
+                [
+                    GeoLineStrip::name(),
+                    InstanceKey::name(),
+                    ColorRGBA::name(),
+                    Radius::name(),
+                    ClassId::name(),
+                ],
+            )
+            .and_then(|entities| {
+                for entity in entities {
+                    entity.visit4(
+                        |instance_key,
+                         strip: GeoLineStrip,
+                         color: Option<ColorRGBA>,
+                         radius: Option<Radius>,
+                         class_id: Option<ClassId>| {
+                            let annotation_info =
+                                annotations.class_description(class_id).annotation_info();
+                            self.line_strips.push(MapLineStrip {
+                                instance_path: InstancePath::instance(
+                                    ent_path.clone(),
+                                    instance_key,
+                                ),
+                                positions: strip
+                                    .points
+                                    .iter()
+                                    .map(GeoPoint::to_web_mercator)
+                                    .collect(),
+                                color: annotation_info
+                                    .color(color.map(|c| c.to_array()).as_ref(), default_color),
+                                width: radius.map_or(Size::new_points(DEFAULT_LINE_WIDTH), |r| {
+                                    Size::new_scene(2.0 * r.0)
+                                }),
+                            });
+                        },
+                    )?;
+                }
+                Ok(())
+            }) {
+                Ok(_) | Err(QueryError::PrimaryNotFound) => {}
+                Err(err) => {
+                    re_log::error_once!("Unexpected error querying {ent_path:?}: {err}");
+                }
+            }
+        }
+    }
+
+    /// Bounding box of everything in the scene on the Web-Mercator plane, if there is anything.
+    pub fn bounds(&self) -> Option<([f64; 2], [f64; 2])> {
+        let positions = self
+            .points
+            .iter()
+            .map(|p| &p.position)
+            .chain(self.line_strips.iter().flat_map(|s| &s.positions));
+
+        positions.fold(None, |bounds, &[x, y]| {
+            let ([min_x, min_y], [max_x, max_y]) = bounds.unwrap_or(([x, y], [x, y]));
+            Some(([min_x.min(x), min_y.min(y)], [max_x.max(x), max_y.max(y)]))
+        })
+    }
+}
@@ -0,0 +1,224 @@
+use ahash::HashMap;
+
+/// Deepest zoom level we look for tiles at.
+pub const MAX_ZOOM_LEVEL: u8 = 19;
+
+/// How many tiles we keep around, including missing ones.
+///
+/// Must be comfortably larger than the number of tiles we show at once.
+const MAX_CACHED_TILES: usize = 256;
+
+/// How many tiles can be queued up for decoding at the same time.
+const MAX_PENDING_TILES: usize = 8;
+
+type TileId = (u8, u32, u32);
+
+enum Tile {
+    /// Waiting for the [`TileLoader`].
+    #[cfg(not(target_arch = "wasm32"))]
+    Pending,
+
+    Loaded(egui::TextureHandle),
+
+    /// Missing or broken.
+    Missing,
+}
+
+/// Map tiles loaded from a local directory, laid out as `{zoom}/{x}/{y}.png`
+/// (the usual "slippy map" scheme).
+///
+/// Tiles are decoded on a background thread and show up once they are ready.
+/// Missing or broken tiles are remembered as such, so we only try to load each tile once.
+/// Once we hold too many tiles, the least recently used ones are dropped.
+#[derive(Default)]
+pub struct TileCache {
+    /// Each tile together with the value of [`Self::num_requests`] when it was last asked for.
+    tiles: HashMap<TileId, (Tile, u64)>,
+    num_requests: u64,
+
+    /// Started on the first tile we need to decode.
+    #[cfg(not(target_arch = "wasm32"))]
+    loader: Option<TileLoader>,
+}
+
+impl Clone for TileCache {
+    fn clone(&self) -> Self {
+        // Textures are cheap to reload, and pending tiles can't be shared.
+        Self::default()
+    }
+}
+
+impl TileCache {
+    pub fn clear(&mut self) {
+        self.tiles.clear();
+
+        // Don't pick up tiles that are still being decoded from the old directory.
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.loader = None;
+        }
+    }
+
+    /// Returns the tile if it is loaded, or starts loading it.
+    pub fn get(
+        &mut self,
+        egui_ctx: &egui::Context,
+        tile_dir: &std::path::Path,
+        zoom: u8,
+        x: u32,
+        y: u32,
+    ) -> Option<&egui::TextureHandle> {
+        self.num_requests += 1;
+        let id = (zoom, x, y);
+
+        #[cfg(not(target_arch = "wasm32"))]
+        self.receive_loaded_tiles(egui_ctx);
+
+        if !self.tiles.contains_key(&id) {
+            if self.num_pending() >= MAX_PENDING_TILES {
+                egui_ctx.request_repaint(); // try again next frame
+                return None;
+            }
+            if self.tiles.len() >= MAX_CACHED_TILES {
+                self.evict_least_recently_used();
+            }
+            let tile = self.load_tile(tile_dir, id);
+            self.tiles.insert(id, (tile, self.num_requests));
+        }
+
+        let (tile, last_used) = self.tiles.get_mut(&id)?;
+        *last_used = self.num_requests;
+
+        match tile {
+            Tile::Loaded(texture) => Some(texture),
+            #[cfg(not(target_arch = "wasm32"))]
+            Tile::Pending => {
+                egui_ctx.request_repaint(); // check again next frame
+                None
+            }
+            Tile::Missing => None,
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_tile(&mut self, tile_dir: &std::path::Path, (zoom, x, y): TileId) -> Tile {
+        let path = tile_dir
+            .join(zoom.to_string())
+            .join(x.to_string())
+            .join(format!("{y}.png"));
+        if !path.exists() {
+            return Tile::Missing;
+        }
+
+        let loader = self.loader.get_or_insert_with(TileLoader::spawn);
+        if loader.requests.send(((zoom, x, y), path)).is_ok() {
+            Tile::Pending
+        } else {
+            Tile::Missing
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    #[allow(clippy::unused_self)]
+    fn load_tile(&mut self, _tile_dir: &std::path::Path, _id: TileId) -> Tile {
+        Tile::Missing // No file system access on the web.
+    }
+
+    /// Turns the tiles that finished decoding into textures.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn receive_loaded_tiles(&mut self, egui_ctx: &egui::Context) {
+        let Some(loader) = &self.loader else {
+            return;
+        };
+
+        for ((zoom, x, y), image) in loader.results.try_iter() {
+            // The tile may have been evicted in the meantime.
+            if let Some((tile @ Tile::Pending, _)) = self.tiles.get_mut(&(zoom, x, y)) {
+                *tile = match image {
+                    Some(image) => Tile::Loaded(egui_ctx.load_texture(
+                        format!("map tile {zoom}/{x}/{y}"),
+                        image,
+                        egui::TextureOptions::LINEAR,
+                    )),
+                    None => Tile::Missing,
+                };
+            }
+        }
+    }
+
+    fn num_pending(&self) -> usize {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.tiles
+                .values()
+                .filter(|(tile, _)| matches!(tile, Tile::Pending))
+                .count()
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            0
+        }
+    }
+
+    fn evict_least_recently_used(&mut self) {
+        if let Some(oldest) = self
+            .tiles
+            .iter()
+            .min_by_key(|(_, (_, last_used))| *last_used)
+            .map(|(id, _)| *id)
+        {
+            self.tiles.remove(&oldest);
+        }
+    }
+}
+
+/// Decodes tiles one at a time on a single background thread.
+#[cfg(not(target_arch = "wasm32"))]
+struct TileLoader {
+    requests: std::sync::mpsc::Sender<(TileId, std::path::PathBuf)>,
+    results: std::sync::mpsc::Receiver<(TileId, Option<egui::ColorImage>)>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl TileLoader {
+    /// The thread exits once the loader is dropped.
+    fn spawn() -> Self {
+        let (requests, request_rx) = std::sync::mpsc::channel::<(TileId, std::path::PathBuf)>();
+        let (result_tx, results) = std::sync::mpsc::channel();
+
+        let spawned = std::thread::Builder::new()
+            .name("map_tile_loader".to_owned())
+            .spawn(move || {
+                for (id, path) in request_rx {
+                    if result_tx.send((id, decode_tile(&path))).is_err() {
+                        break;
+                    }
+                }
+            });
+        if let Err(err) = spawned {
+            re_log::warn_once!("Failed to start loading map tiles: {err}");
+        }
+
+        Self { requests, results }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn decode_tile(path: &std::path::Path) -> Option<egui::ColorImage> {
+    crate::profile_function!();
+    match image::open(path) {
+        Ok(image) => {
+            let image = image.to_rgba8();
+            let size = [image.width() as _, image.height() as _];
+            Some(egui::ColorImage::from_rgba_unmultiplied(
+                size,
+                image.as_raw(),
+            ))
+        }
+        Err(err) => {
+            re_log::warn_once!("Failed to load map tile {path:?}: {err}");
+            None
+        }
+    }
+}
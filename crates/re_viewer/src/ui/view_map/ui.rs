@@ -0,0 +1,422 @@
+use egui::{pos2, vec2, Align2, Color32, Pos2, Rect, Shape, Stroke};
+
+use re_log_types::component_types::{GeoPoint, EARTH_RADIUS_METERS, WEB_MERCATOR_MAX_LATITUDE};
+use re_renderer::Size;
+
+use crate::{
+    misc::{HoverHighlight, Item, SelectionHighlight, SpaceViewHighlights},
+    ui::SpaceViewId,
+    ViewerContext,
+};
+
+use super::{tiles::TileCache, SceneMap};
+
+// ---
+
+pub(crate) const HELP_TEXT: &str = "\
+    Pan by dragging.\n\
+    Zoom with the scroll wheel, or with pinch gesture.\n\
+    Reset view with double-click.";
+
+/// Side length of the (square) Web-Mercator plane, in meters.
+const WORLD_SIZE: f64 = 2.0 * std::f64::consts::PI * EARTH_RADIUS_METERS;
+
+/// Graticule lines are at least this far apart, in ui points.
+const MIN_GRATICULE_SPACING: f64 = 80.0;
+
+/// Tiles are drawn at about this size, in ui points.
+const TILE_SIZE: f64 = 256.0;
+
+/// Don't fetch more tiles than this per frame; zoom in further instead.
+const MAX_VISIBLE_TILES: usize = 64;
+
+/// The part of the Web-Mercator plane that is shown.
+#[derive(Clone, Copy, Debug, serde::Deserialize, serde::Serialize)]
+struct MapViewport {
+    /// Web-Mercator coordinates at the center of the view, in meters.
+    center: [f64; 2],
+    meters_per_point: f64,
+}
+
+impl MapViewport {
+    /// Shows everything in `bounds`, or the whole world if there is nothing.
+    fn fit(bounds: Option<([f64; 2], [f64; 2])>, rect: Rect) -> Self {
+        let half_world = 0.5 * WORLD_SIZE;
+        let ([min_x, min_y], [max_x, max_y]) =
+            bounds.unwrap_or(([-half_world, -half_world], [half_world, half_world]));
+
+        // Leave some margin, and don't zoom in further than a street for a single point.
+        let width = ((max_x - min_x) * 1.2).max(200.0);
+        let height = ((max_y - min_y) * 1.2).max(200.0);
+        let meters_per_point = (width / rect.width() as f64).max(height / rect.height() as f64);
+
+        Self {
+            center: [0.5 * (min_x + max_x), 0.5 * (min_y + max_y)],
+            meters_per_point,
+        }
+    }
+
+    fn screen_from_map(&self, rect: Rect, [x, y]: [f64; 2]) -> Pos2 {
+        rect.center()
+            + vec2(
+                ((x - self.center[0]) / self.meters_per_point) as f32,
+                (-(y - self.center[1]) / self.meters_per_point) as f32,
+            )
+    }
+
+    /// A size at the given position on the Web-Mercator plane, in ui points.
+    ///
+    /// Scene sizes are meters on the ground, which Web-Mercator stretches by `1 / cos(latitude)`.
+    fn points_from_size(&self, size: Size, [_, y]: [f64; 2]) -> f32 {
+        if let Some(meters) = size.scene() {
+            let stretch = (y / EARTH_RADIUS_METERS).cosh();
+            (meters as f64 * stretch / self.meters_per_point) as f32
+        } else {
+            size.points().unwrap_or_default()
+        }
+    }
+
+    fn map_from_screen(&self, rect: Rect, pos: Pos2) -> [f64; 2] {
+        let offset = pos - rect.center();
+        [
+            self.center[0] + offset.x as f64 * self.meters_per_point,
+            self.center[1] - offset.y as f64 * self.meters_per_point,
+        ]
+    }
+}
+
+#[derive(Clone, Default, serde::Deserialize, serde::Serialize)]
+pub struct ViewMapState {
+    /// `None` means the view follows the data.
+    viewport: Option<MapViewport>,
+
+    /// Directory with locally cached map tiles, laid out as `{zoom}/{x}/{y}.png`.
+    ///
+    /// Without one, only a graticule is drawn in the background.
+    tile_dir: Option<std::path::PathBuf>,
+
+    #[serde(skip)]
+    tile_cache: TileCache,
+}
+
+impl ViewMapState {
+    pub fn selection_ui(&mut self, re_ui: &re_ui::ReUi, ui: &mut egui::Ui) {
+        re_ui.selection_grid(ui, "map_settings").show(ui, |ui| {
+            ui.label("Tile directory");
+            ui.horizontal(|ui| {
+                let mut tile_dir = self
+                    .tile_dir
+                    .as_ref()
+                    .map(|dir| dir.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                let response = ui
+                    .text_edit_singleline(&mut tile_dir)
+                    .on_hover_text("Folder with map tiles stored as {zoom}/{x}/{y}.png");
+                if response.changed() {
+                    self.tile_dir = (!tile_dir.is_empty()).then(|| tile_dir.into());
+                    self.tile_cache.clear();
+                }
+            });
+            ui.end_row();
+
+            ui.label("View");
+            if ui
+                .add_enabled(self.viewport.is_some(), egui::Button::new("Reset"))
+                .on_hover_text("Fit everything in view")
+                .clicked()
+            {
+                self.viewport = None;
+            }
+            ui.end_row();
+        });
+    }
+}
+
+pub(crate) fn view_map(
+    ctx: &mut ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    state: &mut ViewMapState,
+    scene: &SceneMap,
+    space_view_id: SpaceViewId,
+    highlights: &SpaceViewHighlights,
+) -> egui::Response {
+    crate::profile_function!();
+
+    let (response, painter) =
+        ui.allocate_painter(ui.available_size(), egui::Sense::click_and_drag());
+    let rect = response.rect;
+
+    let mut viewport = state
+        .viewport
+        .unwrap_or_else(|| MapViewport::fit(scene.bounds(), rect));
+
+    // Navigation
+    {
+        let mut interacted = false;
+
+        if response.dragged_by(egui::PointerButton::Primary) {
+            let delta = response.drag_delta();
+            viewport.center[0] -= delta.x as f64 * viewport.meters_per_point;
+            viewport.center[1] += delta.y as f64 * viewport.meters_per_point;
+            interacted = true;
+        }
+
+        if let Some(hover_pos) = response.hover_pos() {
+            let zoom = ui.input(|i| i.zoom_delta() * (i.scroll_delta.y / 200.0).exp());
+            if zoom != 1.0 {
+                // Keep the location under the pointer fixed.
+                let anchor = viewport.map_from_screen(rect, hover_pos);
+                viewport.meters_per_point = (viewport.meters_per_point / zoom as f64)
+                    .clamp(0.01, WORLD_SIZE / rect.width().max(1.0) as f64);
+                let moved = viewport.map_from_screen(rect, hover_pos);
+                viewport.center[0] += anchor[0] - moved[0];
+                viewport.center[1] += anchor[1] - moved[1];
+                interacted = true;
+            }
+        }
+
+        if response.double_clicked() {
+            state.viewport = None;
+        } else if interacted {
+            state.viewport = Some(viewport);
+        }
+    }
+
+    painter.rect_filled(rect, 0.0, ui.visuals().extreme_bg_color);
+
+    if let Some(tile_dir) = &state.tile_dir {
+        paint_tiles(
+            ui,
+            &painter,
+            rect,
+            &viewport,
+            &mut state.tile_cache,
+            tile_dir,
+        );
+    }
+    paint_graticule(ui, &painter, rect, &viewport);
+
+    for strip in &scene.line_strips {
+        let Some(&first) = strip.positions.first() else {
+            continue;
+        };
+        let width = viewport.points_from_size(strip.width, first);
+        let points = strip
+            .positions
+            .iter()
+            .map(|&p| viewport.screen_from_map(rect, p))
+            .collect();
+        painter.add(Shape::line(points, Stroke::new(width, strip.color)));
+    }
+
+    let hover_pos = response.hover_pos();
+    let mut hovered = None;
+    let mut hovered_distance = f32::INFINITY;
+
+    for point in &scene.points {
+        let pos = viewport.screen_from_map(rect, point.position);
+        let radius = viewport.points_from_size(point.radius, point.position);
+        if !rect.expand(radius).contains(pos) {
+            continue;
+        }
+
+        let highlight = highlights
+            .entity_highlight(point.instance_path.entity_path.hash())
+            .index_highlight(point.instance_path.instance_key);
+        let outline = match (highlight.selection, highlight.hover) {
+            (SelectionHighlight::None, HoverHighlight::None) => None,
+            (_, HoverHighlight::Hovered) => Some(ui.visuals().widgets.hovered.fg_stroke.color),
+            (SelectionHighlight::SiblingSelection | SelectionHighlight::Selection, _) => {
+                Some(ui.visuals().widgets.active.bg_fill)
+            }
+        };
+        if let Some(outline) = outline {
+            painter.circle_stroke(pos, radius + 2.0, Stroke::new(2.0, outline));
+        }
+        painter.circle_filled(pos, radius, point.color);
+
+        if let Some(label) = &point.label {
+            painter.text(
+                pos + vec2(radius + 2.0, 0.0),
+                Align2::LEFT_CENTER,
+                label,
+                egui::FontId::proportional(12.0),
+                point.color,
+            );
+        }
+
+        if let Some(hover_pos) = hover_pos {
+            let distance = pos.distance(hover_pos);
+            if distance <= radius + 4.0 && distance < hovered_distance {
+                hovered = Some(point);
+                hovered_distance = distance;
+            }
+        }
+    }
+
+    if let Some(hover_pos) = hover_pos {
+        let geo = GeoPoint::from_web_mercator(viewport.map_from_screen(rect, hover_pos));
+        painter.text(
+            rect.left_bottom() + vec2(4.0, -4.0),
+            Align2::LEFT_BOTTOM,
+            geo.to_string(),
+            egui::TextStyle::Small.resolve(ui.style()),
+            ui.visuals().weak_text_color(),
+        );
+    }
+
+    let response = if let Some(point) = hovered {
+        ctx.set_hovered(std::iter::once(Item::InstancePath(
+            Some(space_view_id),
+            point.instance_path.clone(),
+        )));
+        response.on_hover_ui_at_pointer(|ui| {
+            if let Some(label) = &point.label {
+                ui.strong(label);
+            }
+            ui.label(point.instance_path.to_string());
+            ui.label(point.geo.to_string());
+        })
+    } else {
+        response
+    };
+    ctx.select_hovered_on_click(&response);
+
+    response
+}
+
+/// Lines of constant latitude and longitude, at round multiples of a degree.
+fn paint_graticule(ui: &egui::Ui, painter: &egui::Painter, rect: Rect, viewport: &MapViewport) {
+    let stroke = ui.visuals().widgets.noninteractive.bg_stroke;
+    let text_color = ui.visuals().weak_text_color();
+    let font = egui::TextStyle::Small.resolve(ui.style());
+
+    // At the equator one degree of longitude is as long as one degree of latitude.
+    let meters_per_degree = WORLD_SIZE / 360.0;
+    let step = [
+        90.0, 45.0, 30.0, 15.0, 10.0, 5.0, 2.0, 1.0, 0.5, 0.2, 0.1, 0.05, 0.02, 0.01, 0.005, 0.002,
+        0.001, 0.0005, 0.0002, 0.0001,
+    ]
+    .into_iter()
+    .take_while(|step| {
+        step * meters_per_degree / viewport.meters_per_point >= MIN_GRATICULE_SPACING
+    })
+    .last()
+    .unwrap_or(90.0);
+
+    let top_left = GeoPoint::from_web_mercator(viewport.map_from_screen(rect, rect.left_top()));
+    let bottom_right =
+        GeoPoint::from_web_mercator(viewport.map_from_screen(rect, rect.right_bottom()));
+
+    let min_lon = (top_left.longitude.max(-180.0) / step).ceil() as i64;
+    let max_lon = (bottom_right.longitude.min(180.0) / step).floor() as i64;
+    for i in min_lon..=max_lon {
+        let longitude = i as f64 * step;
+        let x = viewport
+            .screen_from_map(rect, GeoPoint::new(0.0, longitude).to_web_mercator())
+            .x;
+        painter.line_segment([pos2(x, rect.top()), pos2(x, rect.bottom())], stroke);
+        painter.text(
+            pos2(x + 2.0, rect.top() + 2.0),
+            Align2::LEFT_TOP,
+            format_degrees(longitude, step, 'E', 'W'),
+            font.clone(),
+            text_color,
+        );
+    }
+
+    let max_lat = WEB_MERCATOR_MAX_LATITUDE;
+    let min_lat = (bottom_right.latitude.max(-max_lat) / step).ceil() as i64;
+    let top_lat = (top_left.latitude.min(max_lat) / step).floor() as i64;
+    for i in min_lat..=top_lat {
+        let latitude = i as f64 * step;
+        let y = viewport
+            .screen_from_map(rect, GeoPoint::new(latitude, 0.0).to_web_mercator())
+            .y;
+        painter.line_segment([pos2(rect.left(), y), pos2(rect.right(), y)], stroke);
+        painter.text(
+            pos2(rect.left() + 2.0, y - 2.0),
+            Align2::LEFT_BOTTOM,
+            format_degrees(latitude, step, 'N', 'S'),
+            font.clone(),
+            text_color,
+        );
+    }
+}
+
+fn format_degrees(value: f64, step: f64, positive: char, negative: char) -> String {
+    let decimals = (-step.log10()).ceil().max(0.0) as usize;
+    let hemisphere = if value > 0.0 {
+        Some(positive)
+    } else if value < 0.0 {
+        Some(negative)
+    } else {
+        None
+    };
+    match hemisphere {
+        Some(hemisphere) => format!("{:.*}°{hemisphere}", decimals, value.abs()),
+        None => format!("{:.*}°", decimals, value.abs()),
+    }
+}
+
+/// Draws the locally cached tiles that cover the view, at the zoom level closest to the view's.
+fn paint_tiles(
+    ui: &egui::Ui,
+    painter: &egui::Painter,
+    rect: Rect,
+    viewport: &MapViewport,
+    tile_cache: &mut TileCache,
+    tile_dir: &std::path::Path,
+) {
+    let zoom_level = (WORLD_SIZE / (TILE_SIZE * viewport.meters_per_point))
+        .log2()
+        .round()
+        .clamp(0.0, super::tiles::MAX_ZOOM_LEVEL as f64) as u8;
+    let num_tiles = 1_i64 << zoom_level;
+    let tile_meters = WORLD_SIZE / num_tiles as f64;
+    let half_world = 0.5 * WORLD_SIZE;
+
+    // Tile rows count from the top (north) of the map.
+    let tile_index = |[x, y]: [f64; 2]| {
+        (
+            (((x + half_world) / tile_meters).floor() as i64).clamp(0, num_tiles - 1),
+            (((half_world - y) / tile_meters).floor() as i64).clamp(0, num_tiles - 1),
+        )
+    };
+    let (min_x, min_y) = tile_index(viewport.map_from_screen(rect, rect.left_top()));
+    let (max_x, max_y) = tile_index(viewport.map_from_screen(rect, rect.right_bottom()));
+
+    let num_visible = ((max_x - min_x + 1) * (max_y - min_y + 1)) as usize;
+    if num_visible > MAX_VISIBLE_TILES {
+        return;
+    }
+
+    for y in min_y..=max_y {
+        for x in min_x..=max_x {
+            let tile = tile_cache.get(ui.ctx(), tile_dir, zoom_level, x as u32, y as u32);
+            let Some(texture) = tile else {
+                continue;
+            };
+            let left_top = viewport.screen_from_map(
+                rect,
+                [
+                    x as f64 * tile_meters - half_world,
+                    half_world - y as f64 * tile_meters,
+                ],
+            );
+            let right_bottom = viewport.screen_from_map(
+                rect,
+                [
+                    (x + 1) as f64 * tile_meters - half_world,
+                    half_world - (y + 1) as f64 * tile_meters,
+                ],
+            );
+            painter.image(
+                texture.id(),
+                Rect::from_min_max(left_top, right_bottom),
+                Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)),
+                Color32::WHITE,
+            );
+        }
+    }
+}
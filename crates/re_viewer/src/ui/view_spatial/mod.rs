@@ -8,7 +8,7 @@ mod ui_3d;
 pub mod ui_renderer_bridge;
 
 pub use self::scene::{Image, MeshSource, MeshSourceData, SceneSpatial, UiLabel, UiLabelTarget};
-pub use self::space_camera_3d::SpaceCamera3D;
+pub use self::space_camera_3d::{depth_cloud_distortion, SpaceCamera3D};
 pub use ui::{SpatialNavigationMode, ViewSpatialState};
 pub use ui_2d::view_2d;
 pub use ui_3d::{view_3d, SpaceSpecs};
@@ -153,12 +153,15 @@ impl SceneSpatial {
             &scene_part::Points3DPart { max_labels: 10 },
             // --
             &scene_part::Boxes3DPart,
+            &scene_part::Ellipsoids3DPart,
             &scene_part::Lines3DPart,
             &scene_part::Arrows3DPart,
             &scene_part::MeshPart,
             &scene_part::ImagesPart,
             // --
             &scene_part::Boxes2DPart,
+            &scene_part::Polygons2DPart,
+            &scene_part::Ellipses2DPart,
             // --
             // Note: Lines2DPart handles both Segments and LinesPaths since they are unified on the logging-side.
             &scene_part::Lines2DPart,
@@ -172,6 +175,7 @@ impl SceneSpatial {
         }
 
         self.primitives.any_outlines = highlights.any_outlines();
+        self.primitives.apply_image_distortion(transforms);
         self.primitives.recalculate_bounding_box();
     }
 
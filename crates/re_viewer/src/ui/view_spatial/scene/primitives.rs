@@ -6,7 +6,9 @@ use re_renderer::{
     LineStripSeriesBuilder, PointCloudBuilder,
 };
 
-use crate::misc::instance_hash_conversions::picking_layer_id_from_instance_path_hash;
+use crate::misc::{
+    instance_hash_conversions::picking_layer_id_from_instance_path_hash, TransformCache,
+};
 
 use super::MeshSource;
 
@@ -84,6 +86,40 @@ impl SceneSpatialPrimitives {
             + depth_clouds.clouds.len()
     }
 
+    /// Applies the lens distortion of the pinhole cameras that points and lines are seen through.
+    ///
+    /// Bakes the transform into the positions of the affected batches.
+    pub fn apply_image_distortion(&mut self, transforms: &TransformCache) {
+        if !transforms.has_image_distortion() {
+            return;
+        }
+        crate::profile_function!();
+
+        for (batch, vertices) in self.points.iter_vertices_by_batch_mut() {
+            let entity_path_hash = EntityPathHash::from_u64(batch.picking_object_id.0);
+            let Some(distortion) = transforms.image_distortion(entity_path_hash) else {
+                continue;
+            };
+            for vertex in vertices {
+                vertex.position =
+                    distortion.distorted_reference_from_obj(batch.world_from_obj, vertex.position);
+            }
+            batch.world_from_obj = glam::Mat4::IDENTITY;
+        }
+
+        for (batch, vertices) in self.line_strips.iter_vertices_by_batch_mut() {
+            let entity_path_hash = EntityPathHash::from_u64(batch.picking_object_id.0);
+            let Some(distortion) = transforms.image_distortion(entity_path_hash) else {
+                continue;
+            };
+            for vertex in vertices {
+                vertex.position =
+                    distortion.distorted_reference_from_obj(batch.world_from_obj, vertex.position);
+            }
+            batch.world_from_obj = glam::Mat4::IDENTITY;
+        }
+    }
+
     pub fn recalculate_bounding_box(&mut self) {
         crate::profile_function!();
 
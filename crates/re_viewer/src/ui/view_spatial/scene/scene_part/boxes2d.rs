@@ -1,8 +1,8 @@
 use glam::Mat4;
-use re_data_store::EntityPath;
+use re_data_store::{EntityPath, EntityProperties};
 use re_log_types::{
-    component_types::{ClassId, ColorRGBA, InstanceKey, Label, Radius, Rect2D},
-    Component,
+    component_types::{ClassId, ColorRGBA, InstanceKey, Label, OrientedBox2D, Radius, Rect2D},
+    Component, DeserializableComponent, SerializableComponent,
 };
 use re_query::{query_primary_with_history, EntityView, QueryError};
 use re_renderer::Size;
@@ -20,16 +20,56 @@ use crate::{
 
 use super::{instance_key_to_picking_id, ScenePart};
 
+/// The 2D box components drawn by [`Boxes2DPart`].
+trait Box2DShape {
+    /// A corner of the box, and the two edges leaving it as vectors.
+    fn corner_and_extents(&self) -> (glam::Vec2, glam::Vec2, glam::Vec2);
+
+    /// The axis-aligned area covered by the box.
+    fn bounding_rect(&self) -> egui::Rect;
+}
+
+impl Box2DShape for Rect2D {
+    fn corner_and_extents(&self) -> (glam::Vec2, glam::Vec2, glam::Vec2) {
+        (
+            self.top_left_corner().into(),
+            glam::vec2(self.width(), 0.0),
+            glam::vec2(0.0, self.height()),
+        )
+    }
+
+    fn bounding_rect(&self) -> egui::Rect {
+        egui::Rect::from_min_size(
+            self.top_left_corner().into(),
+            egui::vec2(self.width(), self.height()),
+        )
+    }
+}
+
+impl Box2DShape for OrientedBox2D {
+    fn corner_and_extents(&self) -> (glam::Vec2, glam::Vec2, glam::Vec2) {
+        let (u, v) = self.extents();
+        (self.first_corner().into(), u.into(), v.into())
+    }
+
+    fn bounding_rect(&self) -> egui::Rect {
+        egui::Rect::from_points(&self.corners().map(egui::Pos2::from))
+    }
+}
+
 pub struct Boxes2DPart;
 
 impl Boxes2DPart {
-    fn process_entity_view(
+    fn process_entity_view<Primary: Box2DShape + SerializableComponent + DeserializableComponent>(
         scene: &mut SceneSpatial,
-        entity_view: &EntityView<Rect2D>,
+        entity_view: &EntityView<Primary>,
         ent_path: &EntityPath,
         world_from_obj: Mat4,
         highlights: &SpaceViewHighlights,
-    ) -> Result<(), QueryError> {
+    ) -> Result<(), QueryError>
+    where
+        for<'a> &'a Primary::ArrayType: IntoIterator,
+    {
         scene.num_logged_2d_objects += 1;
 
         let annotations = scene.annotation_map.find(ent_path);
@@ -47,7 +87,7 @@ impl Boxes2DPart {
 
         entity_view.visit5(
             |instance_key,
-             rect,
+             rect: Primary,
              color: Option<ColorRGBA>,
              radius: Option<Radius>,
              label: Option<Label>,
@@ -65,12 +105,9 @@ impl Boxes2DPart {
                 let radius = radius.map_or(Size::AUTO, |r| Size::new_scene(r.0));
                 let label = annotation_info.label(label.map(|l| l.0).as_ref());
 
+                let (corner, extent_u, extent_v) = rect.corner_and_extents();
                 let rectangle = line_batch
-                    .add_rectangle_outline_2d(
-                        rect.top_left_corner().into(),
-                        glam::vec2(rect.width(), 0.0),
-                        glam::vec2(0.0, rect.height()),
-                    )
+                    .add_rectangle_outline_2d(corner, extent_u, extent_v)
                     .color(color)
                     .radius(radius)
                     .picking_instance_id(instance_key_to_picking_id(
@@ -89,16 +126,58 @@ impl Boxes2DPart {
                     scene.ui.labels.push(UiLabel {
                         text: label,
                         color,
-                        target: UiLabelTarget::Rect(egui::Rect::from_min_size(
-                            rect.top_left_corner().into(),
-                            egui::vec2(rect.width(), rect.height()),
-                        )),
+                        target: UiLabelTarget::Rect(rect.bounding_rect()),
                         labeled_instance: instance_hash,
                     });
                 }
             },
         )
     }
+
+    fn load_boxes<Primary: Box2DShape + SerializableComponent + DeserializableComponent>(
+        scene: &mut SceneSpatial,
+        ctx: &mut ViewerContext<'_>,
+        query: &SceneQuery<'_>,
+        ent_path: &EntityPath,
+        props: &EntityProperties,
+        world_from_obj: Mat4,
+        highlights: &SpaceViewHighlights,
+    ) where
+        for<'a> &'a Primary::ArrayType: IntoIterator,
+    {
+        match query_primary_with_history::<Primary, 6>(
+            &ctx.log_db.entity_db.data_store,
+            &query.timeline,
+            &query.latest_at,
+            &props.visible_history,
+            ent_path,
+            [
+                Primary::name(),
+                InstanceKey::name(),
+                ColorRGBA::name(),
+                Radius::name(),
+                Label::name(),
+                ClassId::name(),
+            ],
+        )
+        .and_then(|entities| {
+            for entity_view in entities {
+                Self::process_entity_view(
+                    scene,
+                    &entity_view,
+                    ent_path,
+                    world_from_obj,
+                    highlights,
+                )?;
+            }
+            Ok(())
+        }) {
+            Ok(_) | Err(QueryError::PrimaryNotFound) => {}
+            Err(err) => {
+                re_log::error_once!("Unexpected error querying {ent_path:?}: {err}");
+            }
+        }
+    }
 }
 
 impl ScenePart for Boxes2DPart {
@@ -117,38 +196,24 @@ impl ScenePart for Boxes2DPart {
                 continue;
             };
 
-            match query_primary_with_history::<Rect2D, 6>(
-                &ctx.log_db.entity_db.data_store,
-                &query.timeline,
-                &query.latest_at,
-                &props.visible_history,
+            Self::load_boxes::<Rect2D>(
+                scene,
+                ctx,
+                query,
                 ent_path,
-                [
-                    Rect2D::name(),
-                    InstanceKey::name(),
-                    ColorRGBA::name(),
-                    Radius::name(),
-                    Label::name(),
-                    ClassId::name(),
-                ],
-            )
-            .and_then(|entities| {
-                for entity_view in entities {
-                    Self::process_entity_view(
-                        scene,
-                        &entity_view,
-                        ent_path,
-                        world_from_obj,
-                        highlights,
-                    )?;
-                }
-                Ok(())
-            }) {
-                Ok(_) | Err(QueryError::PrimaryNotFound) => {}
-                Err(err) => {
-                    re_log::error_once!("Unexpected error querying {ent_path:?}: {err}");
-                }
-            }
+                &props,
+                world_from_obj,
+                highlights,
+            );
+            Self::load_boxes::<OrientedBox2D>(
+                scene,
+                ctx,
+                query,
+                ent_path,
+                &props,
+                world_from_obj,
+                highlights,
+            );
         }
     }
 }
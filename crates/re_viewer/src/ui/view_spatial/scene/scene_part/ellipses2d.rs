@@ -0,0 +1,196 @@
+use glam::Mat4;
+
+use re_data_store::EntityPath;
+use re_log_types::{
+    component_types::{ClassId, ColorRGBA, Ellipse2D, InstanceKey, Label, Radius},
+    Component,
+};
+use re_query::{query_primary_with_history, EntityView, QueryError};
+use re_renderer::Size;
+
+use crate::{
+    misc::{SpaceViewHighlights, SpaceViewOutlineMasks, TransformCache, ViewerContext},
+    ui::{
+        scene::SceneQuery,
+        view_spatial::{MeshSource, SceneSpatial, UiLabel, UiLabelTarget},
+        DefaultColor,
+    },
+};
+
+use super::{
+    cached_uniform_color_mesh, instance_key_to_picking_id, instance_path_hash_for_picking,
+    ScenePart,
+};
+
+/// Number of line segments (or triangles when solid) used to approximate an ellipse.
+const NUM_SEGMENTS: usize = 64;
+
+pub struct Ellipses2DPart;
+
+impl Ellipses2DPart {
+    fn process_entity_view(
+        scene: &mut SceneSpatial,
+        ctx: &mut ViewerContext<'_>,
+        entity_view: &EntityView<Ellipse2D>,
+        ent_path: &EntityPath,
+        world_from_obj: Mat4,
+        entity_highlight: &SpaceViewOutlineMasks,
+    ) -> Result<(), QueryError> {
+        scene.num_logged_2d_objects += 1;
+
+        let annotations = scene.annotation_map.find(ent_path);
+        let default_color = DefaultColor::EntityPath(ent_path);
+        let world_from_obj_affine = glam::Affine3A::from_mat4(world_from_obj);
+
+        let mut line_batch = scene
+            .primitives
+            .line_strips
+            .batch("ellipses 2d")
+            .world_from_obj(world_from_obj)
+            .outline_mask_ids(entity_highlight.overall)
+            .picking_object_id(re_renderer::PickingLayerObjectId(ent_path.hash64()));
+
+        let mut meshes = Vec::new();
+
+        let visitor = |instance_key: InstanceKey,
+                       ellipse: Ellipse2D,
+                       color: Option<ColorRGBA>,
+                       radius: Option<Radius>,
+                       label: Option<Label>,
+                       class_id: Option<ClassId>| {
+            let instance_hash = instance_path_hash_for_picking(
+                ent_path,
+                instance_key,
+                entity_view,
+                entity_highlight.any_selection_highlight,
+            );
+
+            let annotation_info = annotations.class_description(class_id).annotation_info();
+            let color =
+                annotation_info.color(color.map(move |c| c.to_array()).as_ref(), default_color);
+
+            if ellipse.solid {
+                // All solid ellipses of a color share one unit disc, placed by `world_from_mesh`.
+                let mesh =
+                    cached_uniform_color_mesh(ctx, "unit disc", "unit disc", color, unit_disc);
+
+                let [cx, cy] = ellipse.center.0;
+                let [rx, ry] = ellipse.half_sizes.0;
+                let obj_from_disc = glam::Affine3A::from_scale_rotation_translation(
+                    glam::vec3(rx, ry, 1.0),
+                    glam::Quat::from_rotation_z(ellipse.rotation),
+                    glam::vec3(cx, cy, 0.0),
+                );
+
+                if let Some(mesh) = mesh {
+                    meshes.push(MeshSource {
+                        picking_instance_hash: instance_hash,
+                        world_from_mesh: world_from_obj_affine * obj_from_disc,
+                        mesh,
+                        outline_mask_ids: entity_highlight.index_outline_mask(instance_key),
+                    });
+                }
+            } else {
+                let radius = radius.map_or(Size::AUTO, |r| Size::new_scene(r.0));
+                let outline = ellipse.outline(NUM_SEGMENTS);
+                let lines = line_batch
+                    .add_strip_2d(outline.iter().map(|&p| p.into()))
+                    .color(color)
+                    .radius(radius)
+                    .picking_instance_id(instance_key_to_picking_id(
+                        instance_key,
+                        entity_view,
+                        entity_highlight.any_selection_highlight,
+                    ));
+
+                if let Some(outline_mask_ids) = entity_highlight.instances.get(&instance_key) {
+                    lines.outline_mask_ids(*outline_mask_ids);
+                }
+            }
+
+            if let Some(label) = annotation_info.label(label.as_ref().map(|s| &s.0)) {
+                let [x, y] = ellipse.center.0;
+                scene.ui.labels.push(UiLabel {
+                    text: label,
+                    color,
+                    target: UiLabelTarget::Point2D(egui::pos2(x, y)),
+                    labeled_instance: instance_hash,
+                });
+            }
+        };
+
+        entity_view.visit5(visitor)?;
+
+        scene.primitives.meshes.extend(meshes);
+
+        Ok(())
+    }
+}
+
+/// Positions and triangle indices of a disc with radius one, as a triangle fan around its center.
+fn unit_disc() -> (Vec<glam::Vec3>, Option<Vec<glam::Vec3>>, Vec<u32>) {
+    let positions = std::iter::once(glam::Vec3::ZERO)
+        .chain((0..=NUM_SEGMENTS).map(|i| {
+            let angle = std::f32::consts::TAU * i as f32 / NUM_SEGMENTS as f32;
+            glam::vec3(angle.cos(), angle.sin(), 0.0)
+        }))
+        .collect();
+    let indices = (1..=NUM_SEGMENTS as u32)
+        .flat_map(|i| [0, i, i + 1])
+        .collect();
+    (positions, None, indices)
+}
+
+impl ScenePart for Ellipses2DPart {
+    fn load(
+        &self,
+        scene: &mut SceneSpatial,
+        ctx: &mut ViewerContext<'_>,
+        query: &SceneQuery<'_>,
+        transforms: &TransformCache,
+        highlights: &SpaceViewHighlights,
+    ) {
+        crate::profile_scope!("Ellipses2DPart");
+
+        for (ent_path, props) in query.iter_entities() {
+            let Some(world_from_obj) = transforms.reference_from_entity(ent_path) else {
+                continue;
+            };
+            let entity_highlight = highlights.entity_outline_mask(ent_path.hash());
+
+            match query_primary_with_history::<Ellipse2D, 6>(
+                &ctx.log_db.entity_db.data_store,
+                &query.timeline,
+                &query.latest_at,
+                &props.visible_history,
+                ent_path,
+                [
+                    Ellipse2D::name(),
+                    InstanceKey::name(),
+                    ColorRGBA::name(),
+                    Radius::name(), // stroke_width
+                    Label::name(),
+                    ClassId::name(),
+                ],
+            )
+            .and_then(|entities| {
+                for entity in entities {
+                    Self::process_entity_view(
+                        scene,
+                        ctx,
+                        &entity,
+                        ent_path,
+                        world_from_obj,
+                        entity_highlight,
+                    )?;
+                }
+                Ok(())
+            }) {
+                Ok(_) | Err(QueryError::PrimaryNotFound) => {}
+                Err(err) => {
+                    re_log::error_once!("Unexpected error querying {ent_path:?}: {err}");
+                }
+            }
+        }
+    }
+}
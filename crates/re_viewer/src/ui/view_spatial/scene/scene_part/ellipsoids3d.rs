@@ -0,0 +1,239 @@
+use glam::Mat4;
+
+use re_data_store::EntityPath;
+use re_log_types::{
+    component_types::{
+        ClassId, ColorRGBA, Ellipsoid3D, InstanceKey, Label, Point3D, Quaternion, Radius,
+    },
+    Component,
+};
+use re_query::{query_primary_with_history, EntityView, QueryError};
+use re_renderer::Size;
+
+use crate::{
+    misc::{SpaceViewHighlights, SpaceViewOutlineMasks, TransformCache, ViewerContext},
+    ui::{
+        scene::SceneQuery,
+        view_spatial::{MeshSource, SceneSpatial, UiLabel, UiLabelTarget},
+        DefaultColor,
+    },
+};
+
+use super::{
+    cached_uniform_color_mesh, instance_key_to_picking_id, instance_path_hash_for_picking,
+    ScenePart,
+};
+
+/// Number of line segments used for each of the three principal ellipses of a wireframe.
+const NUM_WIREFRAME_SEGMENTS: usize = 64;
+
+/// Subdivisions of the unit sphere used for solid ellipsoids.
+const NUM_SPHERE_SEGMENTS: u32 = 32;
+const NUM_SPHERE_RINGS: u32 = 16;
+
+pub struct Ellipsoids3DPart;
+
+impl Ellipsoids3DPart {
+    fn process_entity_view(
+        scene: &mut SceneSpatial,
+        ctx: &mut ViewerContext<'_>,
+        entity_view: &EntityView<Ellipsoid3D>,
+        ent_path: &EntityPath,
+        world_from_obj: Mat4,
+        entity_highlight: &SpaceViewOutlineMasks,
+    ) -> Result<(), QueryError> {
+        scene.num_logged_3d_objects += 1;
+
+        let annotations = scene.annotation_map.find(ent_path);
+        let default_color = DefaultColor::EntityPath(ent_path);
+        let world_from_obj_affine = glam::Affine3A::from_mat4(world_from_obj);
+
+        let mut line_batch = scene
+            .primitives
+            .line_strips
+            .batch("ellipsoids 3d")
+            .world_from_obj(world_from_obj)
+            .outline_mask_ids(entity_highlight.overall)
+            .picking_object_id(re_renderer::PickingLayerObjectId(ent_path.hash64()));
+
+        let mut meshes = Vec::new();
+
+        let visitor = |instance_key: InstanceKey,
+                       ellipsoid: Ellipsoid3D,
+                       center: Option<Point3D>,
+                       rotation: Option<Quaternion>,
+                       color: Option<ColorRGBA>,
+                       radius: Option<Radius>,
+                       label: Option<Label>,
+                       class_id: Option<ClassId>| {
+            let instance_hash = instance_path_hash_for_picking(
+                ent_path,
+                instance_key,
+                entity_view,
+                entity_highlight.any_selection_highlight,
+            );
+
+            let annotation_info = annotations.class_description(class_id).annotation_info();
+            let color =
+                annotation_info.color(color.map(move |c| c.to_array()).as_ref(), default_color);
+
+            let center = center.map_or(glam::Vec3::ZERO, glam::Vec3::from);
+            // A flat ellipsoid has no inverse-transpose (see below), so keep it ever so slightly round.
+            let half_sizes = glam::Vec3::from(ellipsoid.half_sizes).max(glam::Vec3::splat(1e-6));
+            let obj_from_sphere = glam::Affine3A::from_scale_rotation_translation(
+                half_sizes,
+                rotation.map(glam::Quat::from).unwrap_or_default(),
+                center,
+            );
+
+            if ellipsoid.solid {
+                // All solid ellipsoids of a color share one unit sphere, placed by `world_from_mesh`.
+                // On the unit sphere every position is also its normal. The mesh renderer
+                // transforms normals by the inverse-transpose of `world_from_mesh`,
+                // so they stay perpendicular to the surface under non-uniform scaling.
+                let mesh =
+                    cached_uniform_color_mesh(ctx, "unit sphere", "unit sphere", color, || {
+                        let (positions, indices) = unit_sphere();
+                        (positions.clone(), Some(positions), indices)
+                    });
+
+                if let Some(mesh) = mesh {
+                    meshes.push(MeshSource {
+                        picking_instance_hash: instance_hash,
+                        world_from_mesh: world_from_obj_affine * obj_from_sphere,
+                        mesh,
+                        outline_mask_ids: entity_highlight.index_outline_mask(instance_key),
+                    });
+                }
+            } else {
+                let radius = radius.map_or(Size::AUTO, |r| Size::new_scene(r.0));
+                let picking_instance_id = instance_key_to_picking_id(
+                    instance_key,
+                    entity_view,
+                    entity_highlight.any_selection_highlight,
+                );
+
+                for (u, v) in [
+                    (glam::Vec3::X, glam::Vec3::Y),
+                    (glam::Vec3::Y, glam::Vec3::Z),
+                    (glam::Vec3::Z, glam::Vec3::X),
+                ] {
+                    let positions = (0..=NUM_WIREFRAME_SEGMENTS).map(|i| {
+                        let angle =
+                            std::f32::consts::TAU * i as f32 / NUM_WIREFRAME_SEGMENTS as f32;
+                        obj_from_sphere.transform_point3(u * angle.cos() + v * angle.sin())
+                    });
+                    let lines = line_batch
+                        .add_strip(positions)
+                        .color(color)
+                        .radius(radius)
+                        .picking_instance_id(picking_instance_id);
+
+                    if let Some(outline_mask_ids) = entity_highlight.instances.get(&instance_key) {
+                        lines.outline_mask_ids(*outline_mask_ids);
+                    }
+                }
+            }
+
+            if let Some(label) = annotation_info.label(label.as_ref().map(|s| &s.0)) {
+                scene.ui.labels.push(UiLabel {
+                    text: label,
+                    target: UiLabelTarget::Position3D(world_from_obj.transform_point3(center)),
+                    color,
+                    labeled_instance: instance_hash,
+                });
+            }
+        };
+
+        entity_view.visit7(visitor)?;
+
+        scene.primitives.meshes.extend(meshes);
+
+        Ok(())
+    }
+}
+
+/// Positions and triangle indices of a UV sphere with radius one.
+fn unit_sphere() -> (Vec<glam::Vec3>, Vec<u32>) {
+    let mut positions =
+        Vec::with_capacity(((NUM_SPHERE_SEGMENTS + 1) * (NUM_SPHERE_RINGS + 1)) as _);
+    for ring in 0..=NUM_SPHERE_RINGS {
+        let polar = std::f32::consts::PI * ring as f32 / NUM_SPHERE_RINGS as f32;
+        for segment in 0..=NUM_SPHERE_SEGMENTS {
+            let azimuth = std::f32::consts::TAU * segment as f32 / NUM_SPHERE_SEGMENTS as f32;
+            positions.push(glam::vec3(
+                polar.sin() * azimuth.cos(),
+                polar.sin() * azimuth.sin(),
+                polar.cos(),
+            ));
+        }
+    }
+
+    let stride = NUM_SPHERE_SEGMENTS + 1;
+    let mut indices = Vec::with_capacity((NUM_SPHERE_SEGMENTS * NUM_SPHERE_RINGS * 6) as _);
+    for ring in 0..NUM_SPHERE_RINGS {
+        for segment in 0..NUM_SPHERE_SEGMENTS {
+            let a = ring * stride + segment;
+            let b = a + stride;
+            indices.extend_from_slice(&[a, b, a + 1, a + 1, b, b + 1]);
+        }
+    }
+
+    (positions, indices)
+}
+
+impl ScenePart for Ellipsoids3DPart {
+    fn load(
+        &self,
+        scene: &mut SceneSpatial,
+        ctx: &mut ViewerContext<'_>,
+        query: &SceneQuery<'_>,
+        transforms: &TransformCache,
+        highlights: &SpaceViewHighlights,
+    ) {
+        crate::profile_scope!("Ellipsoids3DPart");
+
+        for (ent_path, props) in query.iter_entities() {
+            let Some(world_from_obj) = transforms.reference_from_entity(ent_path) else {
+                continue;
+            };
+            let entity_highlight = highlights.entity_outline_mask(ent_path.hash());
+
+            match query_primary_with_history::<Ellipsoid3D, 8>(
+                &ctx.log_db.entity_db.data_store,
+                &query.timeline,
+                &query.latest_at,
+                &props.visible_history,
+                ent_path,
+                [
+                    Ellipsoid3D::name(),
+                    InstanceKey::name(),
+                    Point3D::name(),    // center
+                    Quaternion::name(), // rotation
+                    ColorRGBA::name(),
+                    Radius::name(), // stroke_width
+                    Label::name(),
+                    ClassId::name(),
+                ],
+            )
+            .and_then(|entities| {
+                for entity in entities {
+                    Self::process_entity_view(
+                        scene,
+                        ctx,
+                        &entity,
+                        ent_path,
+                        world_from_obj,
+                        entity_highlight,
+                    )?;
+                }
+                Ok(())
+            }) {
+                Ok(_) | Err(QueryError::PrimaryNotFound) => {}
+                Err(err) => {
+                    re_log::error_once!("Unexpected error querying {ent_path:?}: {err}");
+                }
+            }
+        }
+    }
+}
@@ -19,7 +19,7 @@ use crate::{
     misc::{SpaceViewHighlights, SpaceViewOutlineMasks, TransformCache, ViewerContext},
     ui::{
         scene::SceneQuery,
-        view_spatial::{Image, SceneSpatial},
+        view_spatial::{depth_cloud_distortion, Image, SceneSpatial},
         Annotations, DefaultColor,
     },
 };
@@ -292,6 +292,9 @@ impl ImagesPart {
         let data = match &tensor.data {
             // NOTE: Shallow clone if feature `arrow` is enabled, full alloc + memcpy otherwise.
             TensorData::U16(data) => DepthCloudDepthData::U16(data.clone()),
+            TensorData::F16(data) => {
+                DepthCloudDepthData::F32(data.iter().map(|d| d.to_f32()).collect::<Vec<_>>().into())
+            }
             TensorData::F32(data) => DepthCloudDepthData::F32(data.clone()),
             _ => {
                 return Err(format!(
@@ -341,6 +344,7 @@ impl ImagesPart {
         scene.primitives.depth_clouds.clouds.push(DepthCloud {
             world_from_obj,
             depth_camera_intrinsics: intrinsics.image_from_cam.into(),
+            depth_camera_distortion: depth_cloud_distortion(intrinsics.distortion),
             world_depth_from_data_depth,
             point_radius_from_world_depth,
             max_depth_in_world: world_depth_from_data_depth * max_data_value,
@@ -2,7 +2,7 @@ use glam::Mat4;
 
 use re_data_store::EntityPath;
 use re_log_types::{
-    component_types::{ColorRGBA, InstanceKey, LineStrip2D, Radius},
+    component_types::{ColorRGBA, InstanceKey, LineStrip2D, Radius, VertexColors, VertexRadii},
     Component,
 };
 use re_query::{query_primary_with_history, EntityView, QueryError};
@@ -41,30 +41,43 @@ impl Lines2DPart {
         let visitor = |instance_key: InstanceKey,
                        strip: LineStrip2D,
                        color: Option<ColorRGBA>,
-                       radius: Option<Radius>| {
+                       radius: Option<Radius>,
+                       vertex_colors: Option<VertexColors>,
+                       vertex_radii: Option<VertexRadii>| {
             // TODO(andreas): support class ids for lines
             let annotation_info = annotations.class_description(None).annotation_info();
             let radius = radius.map_or(Size::AUTO, |r| Size::new_scene(r.0));
             let color =
                 annotation_info.color(color.map(move |c| c.to_array()).as_ref(), default_color);
 
-            let lines = line_batch
+            let mut lines = line_batch
                 .add_strip_2d(strip.0.into_iter().map(|v| v.into()))
                 .color(color)
                 .radius(radius)
-                .flags(LineStripFlags::NO_COLOR_GRADIENT)
-                .picking_instance_id(instance_key_to_picking_id(
-                    instance_key,
-                    entity_view,
-                    entity_highlight.any_selection_highlight,
-                ));
+                .flags(LineStripFlags::NO_COLOR_GRADIENT);
+            if let Some(vertex_colors) = vertex_colors {
+                lines = lines.colors(
+                    vertex_colors
+                        .0
+                        .iter()
+                        .map(|c| annotation_info.color(Some(&c.to_array()), default_color)),
+                );
+            }
+            if let Some(vertex_radii) = vertex_radii {
+                lines = lines.radii(vertex_radii.0.iter().map(|r| Size::new_scene(r.0)));
+            }
+            let lines = lines.picking_instance_id(instance_key_to_picking_id(
+                instance_key,
+                entity_view,
+                entity_highlight.any_selection_highlight,
+            ));
 
             if let Some(outline_mask_ids) = entity_highlight.instances.get(&instance_key) {
                 lines.outline_mask_ids(*outline_mask_ids);
             }
         };
 
-        entity_view.visit3(visitor)?;
+        entity_view.visit5(visitor)?;
 
         Ok(())
     }
@@ -87,7 +100,7 @@ impl ScenePart for Lines2DPart {
             };
             let entity_highlight = highlights.entity_outline_mask(ent_path.hash());
 
-            match query_primary_with_history::<LineStrip2D, 4>(
+            match query_primary_with_history::<LineStrip2D, 6>(
                 &ctx.log_db.entity_db.data_store,
                 &query.timeline,
                 &query.latest_at,
@@ -98,6 +111,8 @@ impl ScenePart for Lines2DPart {
                     InstanceKey::name(),
                     ColorRGBA::name(),
                     Radius::name(),
+                    VertexColors::name(),
+                    VertexRadii::name(),
                 ],
             )
             .and_then(|entities| {
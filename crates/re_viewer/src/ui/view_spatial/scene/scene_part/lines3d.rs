@@ -2,7 +2,7 @@ use glam::Mat4;
 
 use re_data_store::EntityPath;
 use re_log_types::{
-    component_types::{ColorRGBA, InstanceKey, LineStrip3D, Radius},
+    component_types::{ColorRGBA, InstanceKey, LineStrip3D, Radius, VertexColors, VertexRadii},
     Component,
 };
 use re_query::{query_primary_with_history, EntityView, QueryError};
@@ -40,7 +40,9 @@ impl Lines3DPart {
         let visitor = |instance_key: InstanceKey,
                        strip: LineStrip3D,
                        color: Option<ColorRGBA>,
-                       radius: Option<Radius>| {
+                       radius: Option<Radius>,
+                       vertex_colors: Option<VertexColors>,
+                       vertex_radii: Option<VertexRadii>| {
             let radius = radius.map_or(Size::AUTO, |r| Size::new_scene(r.0));
 
             // TODO(andreas): support class ids for lines
@@ -48,22 +50,33 @@ impl Lines3DPart {
             let color =
                 annotation_info.color(color.map(move |c| c.to_array()).as_ref(), default_color);
 
-            let lines = line_batch
+            let mut lines = line_batch
                 .add_strip(strip.0.into_iter().map(|v| v.into()))
                 .radius(radius)
-                .color(color)
-                .picking_instance_id(instance_key_to_picking_id(
-                    instance_key,
-                    entity_view,
-                    entity_highlight.any_selection_highlight,
-                ));
+                .color(color);
+            if let Some(vertex_colors) = vertex_colors {
+                lines = lines.colors(
+                    vertex_colors
+                        .0
+                        .iter()
+                        .map(|c| annotation_info.color(Some(&c.to_array()), default_color)),
+                );
+            }
+            if let Some(vertex_radii) = vertex_radii {
+                lines = lines.radii(vertex_radii.0.iter().map(|r| Size::new_scene(r.0)));
+            }
+            let lines = lines.picking_instance_id(instance_key_to_picking_id(
+                instance_key,
+                entity_view,
+                entity_highlight.any_selection_highlight,
+            ));
 
             if let Some(outline_mask_ids) = entity_highlight.instances.get(&instance_key) {
                 lines.outline_mask_ids(*outline_mask_ids);
             }
         };
 
-        entity_view.visit3(visitor)?;
+        entity_view.visit5(visitor)?;
 
         Ok(())
     }
@@ -86,7 +99,7 @@ impl ScenePart for Lines3DPart {
             };
             let entity_highlight = highlights.entity_outline_mask(ent_path.hash());
 
-            match query_primary_with_history::<LineStrip3D, 4>(
+            match query_primary_with_history::<LineStrip3D, 6>(
                 &ctx.log_db.entity_db.data_store,
                 &query.timeline,
                 &query.latest_at,
@@ -97,6 +110,8 @@ impl ScenePart for Lines3DPart {
                     InstanceKey::name(),
                     ColorRGBA::name(),
                     Radius::name(),
+                    VertexColors::name(),
+                    VertexRadii::name(),
                 ],
             )
             .and_then(|entities| {
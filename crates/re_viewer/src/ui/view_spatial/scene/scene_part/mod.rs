@@ -4,12 +4,15 @@ mod arrows3d;
 mod boxes2d;
 mod boxes3d;
 mod cameras;
+mod ellipses2d;
+mod ellipsoids3d;
 mod images;
 mod lines2d;
 mod lines3d;
 mod meshes;
 mod points2d;
 mod points3d;
+mod polygons2d;
 
 use std::sync::Arc;
 
@@ -18,17 +21,20 @@ pub(crate) use arrows3d::Arrows3DPart;
 pub(crate) use boxes2d::Boxes2DPart;
 pub(crate) use boxes3d::Boxes3DPart;
 pub(crate) use cameras::CamerasPart;
+pub(crate) use ellipses2d::Ellipses2DPart;
+pub(crate) use ellipsoids3d::Ellipsoids3DPart;
 pub(crate) use images::ImagesPart;
 pub(crate) use lines2d::Lines2DPart;
 pub(crate) use lines3d::Lines3DPart;
 pub(crate) use meshes::MeshPart;
 pub(crate) use points2d::Points2DPart;
 pub(crate) use points3d::Points3DPart;
+pub(crate) use polygons2d::Polygons2DPart;
 use re_log_types::component_types::{ClassId, ColorRGBA, KeypointId, Radius};
 
 use super::SceneSpatial;
 use crate::{
-    misc::{SpaceViewHighlights, TransformCache, ViewerContext},
+    misc::{mesh_loader::LoadedMesh, SpaceViewHighlights, TransformCache, ViewerContext},
     ui::{
         annotations::ResolvedAnnotationInfo, scene::SceneQuery, view_spatial::scene::Keypoints,
         Annotations, DefaultColor,
@@ -97,6 +103,79 @@ pub fn instance_key_to_picking_id<C: re_log_types::Component>(
     )
 }
 
+/// A single-material, untextured mesh with the same color on every vertex.
+///
+/// Without normals the mesh is drawn unshaded, which is what flat 2D shapes want.
+fn uniform_color_mesh(
+    label: String,
+    vertex_positions: Vec<glam::Vec3>,
+    vertex_normals: Option<Vec<glam::Vec3>>,
+    indices: Vec<u32>,
+    color: egui::Color32,
+    render_ctx: &re_renderer::RenderContext,
+) -> re_renderer::mesh::Mesh {
+    let num_positions = vertex_positions.len();
+    let num_indices = indices.len();
+    re_renderer::mesh::Mesh {
+        label: label.clone().into(),
+        indices,
+        vertex_positions,
+        vertex_colors: vec![
+            re_renderer::Rgba32Unmul::from_rgba_unmul_array(
+                color.to_srgba_unmultiplied()
+            );
+            num_positions
+        ],
+        vertex_normals: vertex_normals.unwrap_or_else(|| vec![glam::Vec3::ZERO; num_positions]),
+        vertex_texcoords: vec![glam::Vec2::ZERO; num_positions],
+        materials: smallvec::smallvec![re_renderer::mesh::Material {
+            label: label.into(),
+            index_range: 0..num_indices as _,
+            albedo: render_ctx
+                .texture_manager_2d
+                .white_texture_unorm_handle()
+                .clone(),
+            albedo_multiplier: re_renderer::Rgba::WHITE,
+        }],
+    }
+}
+
+/// A [`uniform_color_mesh`] that is only generated and uploaded when it isn't cached already.
+///
+/// `key` must identify the positions, normals and indices returned by `create`,
+/// e.g. the row and instance they are generated from. The color is taken into account for you.
+pub fn cached_uniform_color_mesh(
+    ctx: &mut ViewerContext<'_>,
+    key: impl std::hash::Hash,
+    label: &str,
+    color: egui::Color32,
+    create: impl FnOnce() -> (Vec<glam::Vec3>, Option<Vec<glam::Vec3>>, Vec<u32>),
+) -> Option<Arc<LoadedMesh>> {
+    ctx.cache.mesh.get_or_create(
+        egui::util::hash((key, color)),
+        label,
+        ctx.render_ctx,
+        |render_ctx| {
+            let (positions, normals, indices) = create();
+            anyhow::ensure!(!indices.is_empty(), "it has no triangles");
+            let mesh = uniform_color_mesh(
+                label.to_owned(),
+                positions,
+                normals,
+                indices,
+                color,
+                render_ctx,
+            );
+            LoadedMesh::from_cpu_mesh(
+                label.to_owned(),
+                &mesh,
+                re_renderer::resource_managers::ResourceLifeTime::LongLived,
+                render_ctx,
+            )
+        },
+    )
+}
+
 /// Process [`ColorRGBA`] components using annotations and default colors.
 pub fn process_colors<'a, Primary>(
     entity_view: &'a re_query::EntityView<Primary>,
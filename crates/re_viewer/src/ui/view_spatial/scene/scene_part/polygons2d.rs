@@ -0,0 +1,194 @@
+use glam::Mat4;
+
+use re_data_store::EntityPath;
+use re_log_types::{
+    component_types::{ClassId, ColorRGBA, InstanceKey, Label, Polygon2D, Radius},
+    Component,
+};
+use re_query::{query_primary_with_history, EntityView, QueryError};
+use re_renderer::{renderer::LineStripFlags, Size};
+
+use crate::{
+    misc::{SpaceViewHighlights, SpaceViewOutlineMasks, TransformCache, ViewerContext},
+    ui::{
+        scene::SceneQuery,
+        view_spatial::{MeshSource, SceneSpatial, UiLabel, UiLabelTarget},
+        DefaultColor,
+    },
+};
+
+use super::{
+    cached_uniform_color_mesh, instance_key_to_picking_id, instance_path_hash_for_picking,
+    ScenePart,
+};
+
+pub struct Polygons2DPart;
+
+impl Polygons2DPart {
+    fn process_entity_view(
+        scene: &mut SceneSpatial,
+        ctx: &mut ViewerContext<'_>,
+        entity_view: &EntityView<Polygon2D>,
+        ent_path: &EntityPath,
+        world_from_obj: Mat4,
+        entity_highlight: &SpaceViewOutlineMasks,
+    ) -> Result<(), QueryError> {
+        scene.num_logged_2d_objects += 1;
+
+        let annotations = scene.annotation_map.find(ent_path);
+        let default_color = DefaultColor::EntityPath(ent_path);
+        let world_from_obj_affine = glam::Affine3A::from_mat4(world_from_obj);
+
+        let mut line_batch = scene
+            .primitives
+            .line_strips
+            .batch("polygon outlines 2d")
+            .world_from_obj(world_from_obj)
+            .outline_mask_ids(entity_highlight.overall)
+            .picking_object_id(re_renderer::PickingLayerObjectId(ent_path.hash64()));
+
+        let mut meshes = Vec::new();
+
+        let visitor = |instance_key: InstanceKey,
+                       polygon: Polygon2D,
+                       color: Option<ColorRGBA>,
+                       radius: Option<Radius>,
+                       label: Option<Label>,
+                       class_id: Option<ClassId>| {
+            let instance_hash = instance_path_hash_for_picking(
+                ent_path,
+                instance_key,
+                entity_view,
+                entity_highlight.any_selection_highlight,
+            );
+
+            let annotation_info = annotations.class_description(class_id).annotation_info();
+            let color =
+                annotation_info.color(color.map(move |c| c.to_array()).as_ref(), default_color);
+
+            if let Some(label) = annotation_info.label(label.map(|l| l.0).as_ref()) {
+                let mut bounding_rect = egui::Rect::NOTHING;
+                for v in &polygon.outer.0 {
+                    bounding_rect.extend_with(v.0.into());
+                }
+                scene.ui.labels.push(UiLabel {
+                    text: label,
+                    color,
+                    target: UiLabelTarget::Rect(bounding_rect),
+                    labeled_instance: instance_hash,
+                });
+            }
+
+            if polygon.mode.outline() {
+                let radius = radius.map_or(Size::AUTO, |r| Size::new_scene(r.0));
+                let picking_instance_id = instance_key_to_picking_id(
+                    instance_key,
+                    entity_view,
+                    entity_highlight.any_selection_highlight,
+                );
+
+                for ring in std::iter::once(&polygon.outer).chain(&polygon.holes) {
+                    // Close the ring.
+                    let positions = ring.0.iter().chain(ring.0.first()).map(|&v| v.into());
+                    let lines = line_batch
+                        .add_strip_2d(positions)
+                        .color(color)
+                        .radius(radius)
+                        .flags(LineStripFlags::NO_COLOR_GRADIENT)
+                        .picking_instance_id(picking_instance_id);
+
+                    if let Some(outline_mask_ids) = entity_highlight.instances.get(&instance_key) {
+                        lines.outline_mask_ids(*outline_mask_ids);
+                    }
+                }
+            }
+
+            if polygon.mode.fill() {
+                // Triangulating is expensive, so we only do it once per row and instance.
+                let mesh = cached_uniform_color_mesh(
+                    ctx,
+                    (entity_view.row_id(), instance_key),
+                    &format!("{ent_path} polygon"),
+                    color,
+                    || {
+                        let (positions, indices) = polygon.triangulate();
+                        let positions = positions
+                            .into_iter()
+                            .map(|[x, y]| glam::vec3(x, y, 0.0))
+                            .collect();
+                        (positions, None, indices)
+                    },
+                );
+
+                if let Some(mesh) = mesh {
+                    meshes.push(MeshSource {
+                        picking_instance_hash: instance_hash,
+                        world_from_mesh: world_from_obj_affine,
+                        mesh,
+                        outline_mask_ids: entity_highlight.index_outline_mask(instance_key),
+                    });
+                }
+            }
+        };
+
+        entity_view.visit5(visitor)?;
+
+        scene.primitives.meshes.extend(meshes);
+
+        Ok(())
+    }
+}
+
+impl ScenePart for Polygons2DPart {
+    fn load(
+        &self,
+        scene: &mut SceneSpatial,
+        ctx: &mut ViewerContext<'_>,
+        query: &SceneQuery<'_>,
+        transforms: &TransformCache,
+        highlights: &SpaceViewHighlights,
+    ) {
+        crate::profile_scope!("Polygons2DPart");
+
+        for (ent_path, props) in query.iter_entities() {
+            let Some(world_from_obj) = transforms.reference_from_entity(ent_path) else {
+                continue;
+            };
+            let entity_highlight = highlights.entity_outline_mask(ent_path.hash());
+
+            match query_primary_with_history::<Polygon2D, 6>(
+                &ctx.log_db.entity_db.data_store,
+                &query.timeline,
+                &query.latest_at,
+                &props.visible_history,
+                ent_path,
+                [
+                    Polygon2D::name(),
+                    InstanceKey::name(),
+                    ColorRGBA::name(),
+                    Radius::name(), // stroke_width
+                    Label::name(),
+                    ClassId::name(),
+                ],
+            )
+            .and_then(|entities| {
+                for entity in entities {
+                    Self::process_entity_view(
+                        scene,
+                        ctx,
+                        &entity,
+                        ent_path,
+                        world_from_obj,
+                        entity_highlight,
+                    )?;
+                }
+                Ok(())
+            }) {
+                Ok(_) | Err(QueryError::PrimaryNotFound) => {}
+                Err(err) => {
+                    re_log::error_once!("Unexpected error querying {ent_path:?}: {err}");
+                }
+            }
+        }
+    }
+}
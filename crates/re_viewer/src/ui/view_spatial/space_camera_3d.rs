@@ -1,8 +1,12 @@
-use glam::{vec3, Affine3A, Mat3, Quat, Vec2, Vec3};
+use glam::{Affine3A, Mat3, Quat, Vec2, Vec3};
 use macaw::{IsoTransform, Ray3};
 
 use re_data_store::InstancePathHash;
-use re_log_types::ViewCoordinates;
+use re_log_types::{
+    component_types::{DistortionModel, LensDistortion},
+    ViewCoordinates,
+};
+use re_renderer::renderer::DepthCloudDistortion;
 
 /// A logged camera that connects spaces.
 #[derive(Clone)]
@@ -53,6 +57,8 @@ impl SpaceCamera3D {
     }
 
     /// Projects image coordinates into world coordinates
+    ///
+    /// Note that this ignores any lens distortion, use [`Self::unproject_as_ray`] where possible.
     pub fn world_from_image(&self) -> Option<Affine3A> {
         let pinhole = self.pinhole?;
         let world_from_cam = self.world_from_cam();
@@ -62,6 +68,8 @@ impl SpaceCamera3D {
     }
 
     /// Projects world coordinates onto 2D image coordinates
+    ///
+    /// Note that this ignores any lens distortion, use [`Self::project_onto_2d`] where possible.
     pub fn image_from_world(&self) -> Option<Affine3A> {
         let pinhole = self.pinhole?;
         let cam_from_world = self.cam_from_world();
@@ -72,21 +80,48 @@ impl SpaceCamera3D {
     }
 
     /// Returns x, y, and depth in image coordinates.
+    ///
+    /// Takes the lens distortion of the pinhole camera into account.
     pub fn project_onto_2d(&self, pos3d: Vec3) -> Option<Vec3> {
-        self.image_from_world().map(|pixel_from_world| {
-            let point = pixel_from_world.transform_point3(pos3d);
-            vec3(point.x / point.z, point.y / point.z, point.z)
-        })
+        let pinhole = self.pinhole?;
+        Some(pinhole.project(self.cam_from_world().transform_point3(pos3d)))
     }
 
     /// Unproject a 2D image coordinate as a ray in 3D space
+    ///
+    /// Takes the lens distortion of the pinhole camera into account.
     pub fn unproject_as_ray(&self, pos2d: Vec2) -> Option<Ray3> {
-        self.world_from_image().map(|world_from_pixel| {
-            let origin = self.position();
-            let stop = world_from_pixel.transform_point3(pos2d.extend(1.0));
-            let dir = (stop - origin).normalize();
-            Ray3::from_origin_dir(origin, dir)
-        })
+        let pinhole = self.pinhole?;
+        let origin = self.position();
+        let image_from_cam: Mat3 = pinhole.image_from_cam.into();
+        let normalized = image_from_cam.inverse() * pos2d.extend(1.0);
+        let normalized = normalized.truncate() / normalized.z;
+        let dir_in_cam = depth_cloud_distortion(pinhole.distortion)
+            .undistort(normalized)
+            .extend(1.0);
+        let dir = self
+            .world_from_cam()
+            .transform_vector3(dir_in_cam)
+            .normalize();
+        Some(Ray3::from_origin_dir(origin, dir))
+    }
+}
+
+/// The lens distortion as understood by the renderer.
+///
+/// [`DepthCloudDistortion::undistort`] is the inverse of [`LensDistortion::distort`].
+pub fn depth_cloud_distortion(distortion: Option<LensDistortion>) -> DepthCloudDistortion {
+    match distortion {
+        None => DepthCloudDistortion::None,
+        Some(distortion) => match distortion.model {
+            DistortionModel::BrownConrady => DepthCloudDistortion::BrownConrady {
+                radial: glam::Vec4::from(distortion.radial.0).truncate(),
+                tangential: distortion.tangential.into(),
+            },
+            DistortionModel::Fisheye => DepthCloudDistortion::Fisheye {
+                radial: distortion.radial.0.into(),
+            },
+        },
     }
 }
 
@@ -105,3 +140,26 @@ fn from_rub_quat(system: ViewCoordinates) -> Result<Quat, String> {
         ))
     }
 }
+
+#[test]
+fn test_lens_distortion_roundtrip() {
+    let distortions = [
+        LensDistortion::brown_conrady(-0.28, 0.07, 0.0002, 0.00002, 0.0),
+        LensDistortion::brown_conrady(0.1, -0.05, -0.001, 0.002, 0.01),
+        LensDistortion::equidistant(),
+        LensDistortion::fisheye(0.05, -0.01, 0.002, -0.0003),
+    ];
+    for distortion in distortions {
+        let depth_cloud_distortion = depth_cloud_distortion(Some(distortion));
+        for x in [-0.5, -0.1, 0.0, 0.2, 0.6] {
+            for y in [-0.4, 0.0, 0.1, 0.5] {
+                let distorted = Vec2::from(distortion.distort([x, y]));
+                let undistorted = depth_cloud_distortion.undistort(distorted);
+                assert!(
+                    undistorted.abs_diff_eq(glam::vec2(x, y), 1e-4),
+                    "{distortion:?} failed to roundtrip [{x}, {y}]: got {undistorted}"
+                );
+            }
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use re_arrow_store::LatestAtQuery;
 use re_data_store::{EntityPath, EntityProperties, InstancePath};
-use re_log_types::component_types::{InstanceKey, Tensor};
+use re_log_types::component_types::{AxisMetadata, InstanceKey, Tensor};
 use re_query::{query_entity_with_primary, EntityView, QueryError};
 
 use crate::{misc::ViewerContext, ui::SceneQuery};
@@ -11,6 +11,9 @@ use crate::{misc::ViewerContext, ui::SceneQuery};
 #[derive(Default)]
 pub struct SceneTensor {
     pub tensors: std::collections::BTreeMap<InstancePath, Tensor>,
+
+    /// Name, unit and range of the tensor values, for the tensors that have them.
+    pub value_axes: std::collections::BTreeMap<InstancePath, AxisMetadata>,
 }
 
 impl SceneTensor {
@@ -21,13 +24,22 @@ impl SceneTensor {
         for (ent_path, props) in query.iter_entities() {
             let timeline_query = LatestAtQuery::new(query.timeline, query.latest_at);
 
+            let axes = query_entity_with_primary::<AxisMetadata>(
+                &ctx.log_db.entity_db.data_store,
+                &timeline_query,
+                ent_path,
+                &[],
+            )
+            .map(|entity_view| entity_view.iter_primary_flattened().collect::<Vec<_>>())
+            .unwrap_or_default();
+
             match query_entity_with_primary::<Tensor>(
                 &ctx.log_db.entity_db.data_store,
                 &timeline_query,
                 ent_path,
                 &[],
             )
-            .and_then(|entity_view| self.load_tensor_entity(ent_path, &props, &entity_view))
+            .and_then(|entity_view| self.load_tensor_entity(ent_path, &props, &entity_view, &axes))
             {
                 Ok(_) | Err(QueryError::PrimaryNotFound) => {}
                 Err(err) => {
@@ -42,10 +54,28 @@ impl SceneTensor {
         ent_path: &EntityPath,
         _props: &EntityProperties,
         entity_view: &EntityView<Tensor>,
+        axes: &[AxisMetadata],
     ) -> Result<(), QueryError> {
-        entity_view.visit1(|instance_key: InstanceKey, tensor: Tensor| {
+        entity_view.visit1(|instance_key: InstanceKey, mut tensor: Tensor| {
             if !tensor.is_shaped_like_an_image() {
                 let instance_path = InstancePath::instance(ent_path.clone(), instance_key);
+
+                // Dimension names and units show up wherever the tensor view names dimensions.
+                for axis in axes {
+                    match axis.dimension {
+                        Some(dim_idx) => {
+                            if let (Some(dim), Some(label)) =
+                                (tensor.shape.get_mut(dim_idx as usize), axis.label())
+                            {
+                                dim.name = Some(label);
+                            }
+                        }
+                        None => {
+                            self.value_axes.insert(instance_path.clone(), axis.clone());
+                        }
+                    }
+                }
+
                 self.tensors.insert(instance_path, tensor);
             }
         })
@@ -34,7 +34,10 @@ pub fn colormapped_texture(
 ) -> Result<ColormappedTexture, TensorUploadError> {
     crate::profile_function!();
 
-    let range = range(tensor_stats)?;
+    let range = match state.value_range() {
+        Some(range) => range,
+        None => range(tensor_stats)?,
+    };
     let texture = upload_texture_slice_to_gpu(render_ctx, tensor, state.slice())?;
 
     let color_mapping = state.color_mapping();
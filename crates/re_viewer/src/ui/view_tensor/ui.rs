@@ -4,7 +4,7 @@ use eframe::emath::Align2;
 use egui::{epaint::TextShape, NumExt as _, Vec2};
 use ndarray::Axis;
 
-use re_log_types::component_types::{self, Tensor};
+use re_log_types::component_types::{self, AxisMetadata, Tensor};
 use re_renderer::Colormap;
 use re_tensor_ops::dimension_mapping::{DimensionMapping, DimensionSelector};
 
@@ -39,6 +39,10 @@ pub struct ViewTensorState {
     /// Used for the selection view.
     #[serde(skip)]
     tensor: Option<Tensor>,
+
+    /// Name, unit and range of the values of the last viewed tensor, copied each frame.
+    #[serde(skip)]
+    value_axis: Option<AxisMetadata>,
 }
 
 impl ViewTensorState {
@@ -51,6 +55,7 @@ impl ViewTensorState {
             color_mapping: ColorMapping::default(),
             texture_settings: TextureSettings::default(),
             tensor: Some(tensor.clone()),
+            value_axis: None,
         }
     }
 
@@ -62,6 +67,13 @@ impl ViewTensorState {
         &self.color_mapping
     }
 
+    /// The range of values to map to colors, if one was logged.
+    pub fn value_range(&self) -> Option<[f32; 2]> {
+        let axis = self.value_axis.as_ref()?;
+        let (min, max) = (axis.range_min? as f32, axis.range_max? as f32);
+        (min.is_finite() && max.is_finite() && min < max).then_some([min, max])
+    }
+
     pub(crate) fn ui(&mut self, ctx: &mut crate::misc::ViewerContext<'_>, ui: &mut egui::Ui) {
         let Some(tensor) = &self.tensor else {
             ui.label("No Tensor shown in this Space View.");
@@ -77,6 +89,9 @@ impl ViewTensorState {
                     tensor,
                     Some(ctx.cache.tensor_stats(tensor)),
                 );
+                if let Some(axis) = &self.value_axis {
+                    value_axis_ui(ctx.re_ui, ui, axis);
+                }
                 self.texture_settings.ui(ctx.re_ui, ui);
                 self.color_mapping.ui(ctx.render_ctx, ctx.re_ui, ui);
             });
@@ -104,10 +119,12 @@ pub(crate) fn view_tensor(
     ui: &mut egui::Ui,
     state: &mut ViewTensorState,
     tensor: &Tensor,
+    value_axis: Option<&AxisMetadata>,
 ) {
     crate::profile_function!();
 
     state.tensor = Some(tensor.clone());
+    state.value_axis = value_axis.cloned();
 
     if !state.slice.dim_mapping.is_valid(tensor.num_dim()) {
         state.slice.dim_mapping = DimensionMapping::create(tensor.shape());
@@ -503,6 +520,24 @@ pub fn selected_tensor_slice<'a, T: Copy>(
     slice
 }
 
+fn value_axis_ui(re_ui: &re_ui::ReUi, ui: &mut egui::Ui, axis: &AxisMetadata) {
+    if let Some(label) = axis.label() {
+        re_ui.grid_left_hand_label(ui, "Values");
+        ui.label(label);
+        ui.end_row();
+    }
+    if let (Some(min), Some(max)) = (axis.range_min, axis.range_max) {
+        re_ui.grid_left_hand_label(ui, "Color range");
+        ui.label(format!(
+            "{} – {}",
+            axis.format_value(re_format::format_f64(min)),
+            axis.format_value(re_format::format_f64(max))
+        ))
+        .on_hover_text("The range of values mapped onto the color map, as logged");
+        ui.end_row();
+    }
+}
+
 fn dimension_name(shape: &[component_types::TensorDimension], dim_idx: usize) -> String {
     let dim = &shape[dim_idx];
     dim.name.as_ref().map_or_else(
@@ -0,0 +1,486 @@
+//! Shows Markdown documents, parsed with [`pulldown_cmark`]:
+//! CommonMark plus GitHub's tables, strikethrough and task lists.
+//!
+//! The parsed documents are cached per text, so we only parse again when the text changes.
+
+use std::sync::Arc;
+
+use egui::RichText;
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag};
+
+// ----------------------------------------------------------------------------
+// Document
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Block {
+    /// `# Heading`, level 1 to 6.
+    Heading {
+        level: u8,
+        text: Vec<Span>,
+    },
+
+    Paragraph(Vec<Span>),
+
+    /// A fenced or indented code block.
+    CodeBlock {
+        language: String,
+        code: String,
+    },
+
+    /// `> Quoted` blocks.
+    BlockQuote(Vec<Block>),
+
+    /// `- item` or `1. item`.
+    List {
+        /// The number of the first item of an ordered list, `None` for bullet points.
+        start: Option<u64>,
+        items: Vec<Vec<Block>>,
+    },
+
+    /// A GitHub-style table. Column alignments are not kept.
+    Table {
+        header: Vec<Vec<Span>>,
+        rows: Vec<Vec<Vec<Span>>>,
+    },
+
+    /// `---`
+    Rule,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InlineStyle {
+    pub strong: bool,
+    pub emphasis: bool,
+    pub strikethrough: bool,
+    pub code: bool,
+}
+
+/// A run of text with a single style.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Span {
+    pub text: String,
+    pub style: InlineStyle,
+    pub url: Option<String>,
+}
+
+pub fn parse(markdown: &str) -> Vec<Block> {
+    crate::profile_function!();
+
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    parse_blocks(&mut Parser::new_ext(markdown, options))
+}
+
+/// Reads blocks up to the end of the enclosing block quote or list item,
+/// or to the end of the document.
+fn parse_blocks<'a>(events: &mut impl Iterator<Item = Event<'a>>) -> Vec<Block> {
+    let mut blocks = Vec::new();
+
+    // Text that isn't wrapped in a paragraph, e.g. the items of a tight list.
+    let mut loose_text = Inline::default();
+
+    while let Some(event) = events.next() {
+        if loose_text.event(&event) {
+            continue;
+        }
+        blocks.extend(loose_text.take().map(Block::Paragraph));
+
+        match event {
+            Event::Start(Tag::Paragraph) => blocks.push(Block::Paragraph(parse_inline(events))),
+            Event::Start(Tag::Heading(level, ..)) => blocks.push(Block::Heading {
+                level: level as u8,
+                text: parse_inline(events),
+            }),
+            Event::Start(Tag::BlockQuote) => blocks.push(Block::BlockQuote(parse_blocks(events))),
+            Event::Start(Tag::CodeBlock(kind)) => {
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => info
+                        .split_whitespace()
+                        .next()
+                        .unwrap_or_default()
+                        .to_owned(),
+                    CodeBlockKind::Indented => String::new(),
+                };
+                let mut code = String::new();
+                for event in events.by_ref() {
+                    match event {
+                        Event::Text(text) => code.push_str(&text),
+                        Event::End(_) => break,
+                        _ => {}
+                    }
+                }
+                code.truncate(code.trim_end_matches('\n').len());
+                blocks.push(Block::CodeBlock { language, code });
+            }
+            Event::Start(Tag::List(start)) => {
+                let mut items = Vec::new();
+                while let Some(Event::Start(Tag::Item)) = events.next() {
+                    items.push(parse_blocks(events));
+                }
+                blocks.push(Block::List { start, items });
+            }
+            Event::Start(Tag::Table(_)) => {
+                let mut header = Vec::new();
+                let mut rows = Vec::new();
+                while let Some(event) = events.next() {
+                    match event {
+                        Event::Start(Tag::TableHead) => header = parse_table_row(events),
+                        Event::Start(Tag::TableRow) => rows.push(parse_table_row(events)),
+                        _ => break,
+                    }
+                }
+                blocks.push(Block::Table { header, rows });
+            }
+            Event::Rule => blocks.push(Block::Rule),
+            Event::End(_) => break,
+            _ => {}
+        }
+    }
+
+    blocks.extend(loose_text.take().map(Block::Paragraph));
+    blocks
+}
+
+fn parse_table_row<'a>(events: &mut impl Iterator<Item = Event<'a>>) -> Vec<Vec<Span>> {
+    let mut cells = Vec::new();
+    while let Some(Event::Start(Tag::TableCell)) = events.next() {
+        cells.push(parse_inline(events));
+    }
+    cells
+}
+
+/// Reads the text of a paragraph, heading or table cell, up to its end.
+fn parse_inline<'a>(events: &mut impl Iterator<Item = Event<'a>>) -> Vec<Span> {
+    let mut inline = Inline::default();
+    for event in events {
+        if !inline.event(&event) {
+            break;
+        }
+    }
+    inline.spans
+}
+
+#[derive(Default)]
+struct Inline {
+    spans: Vec<Span>,
+    style: InlineStyle,
+    url: Option<String>,
+}
+
+impl Inline {
+    /// Returns `false` for events that aren't part of the text.
+    fn event(&mut self, event: &Event<'_>) -> bool {
+        match event {
+            Event::Text(text) | Event::Html(text) => self.push(text, self.style),
+            Event::Code(code) => self.push(
+                code,
+                InlineStyle {
+                    code: true,
+                    ..self.style
+                },
+            ),
+            Event::SoftBreak => self.push(" ", self.style),
+            Event::HardBreak => self.push("\n", self.style),
+            Event::TaskListMarker(checked) => {
+                self.push(if *checked { "☑ " } else { "☐ " }, self.style);
+            }
+            Event::Start(tag) | Event::End(tag) => {
+                let start = matches!(event, Event::Start(_));
+                match tag {
+                    Tag::Emphasis => self.style.emphasis = start,
+                    Tag::Strong => self.style.strong = start,
+                    Tag::Strikethrough => self.style.strikethrough = start,
+                    Tag::Link(_, url, _) | Tag::Image(_, url, _) => {
+                        self.url = start.then(|| url.to_string());
+                    }
+                    _ => return false,
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn push(&mut self, text: &str, style: InlineStyle) {
+        match self.spans.last_mut() {
+            Some(last) if last.style == style && last.url == self.url => last.text.push_str(text),
+            _ => self.spans.push(Span {
+                text: text.to_owned(),
+                style,
+                url: self.url.clone(),
+            }),
+        }
+    }
+
+    fn take(&mut self) -> Option<Vec<Span>> {
+        (!self.spans.is_empty()).then(|| std::mem::take(&mut self.spans))
+    }
+}
+
+#[derive(Default)]
+struct MarkdownParser;
+
+impl egui::util::cache::ComputerMut<&str, Arc<Vec<Block>>> for MarkdownParser {
+    fn compute(&mut self, markdown: &str) -> Arc<Vec<Block>> {
+        Arc::new(parse(markdown))
+    }
+}
+
+/// Documents that haven't been shown for a frame are evicted.
+type MarkdownCache = egui::util::cache::FrameCache<Arc<Vec<Block>>, MarkdownParser>;
+
+// ----------------------------------------------------------------------------
+// Ui
+
+/// Shows a Markdown document.
+pub fn markdown_ui(ui: &mut egui::Ui, id: egui::Id, markdown: &str) {
+    crate::profile_function!();
+
+    let blocks = ui.memory_mut(|mem| mem.caches.cache::<MarkdownCache>().get(markdown));
+    blocks_ui(ui, id, &blocks);
+}
+
+fn blocks_ui(ui: &mut egui::Ui, id: egui::Id, blocks: &[Block]) {
+    for (i, block) in blocks.iter().enumerate() {
+        block_ui(ui, id.with(i), block);
+    }
+}
+
+fn block_ui(ui: &mut egui::Ui, id: egui::Id, block: &Block) {
+    match block {
+        Block::Heading { level, text } => {
+            let size = egui::TextStyle::Heading.resolve(ui.style()).size;
+            let scale = match level {
+                1 => 1.0,
+                2 => 0.85,
+                3 => 0.75,
+                _ => 0.65,
+            };
+            ui.add_space(0.5 * size);
+            ui.horizontal_wrapped(|ui| {
+                for span in text {
+                    span_ui(ui, span, |text| text.size(scale * size).strong());
+                }
+            });
+            ui.add_space(0.25 * size);
+        }
+        Block::Paragraph(text) => {
+            inline_ui(ui, text);
+            ui.add_space(ui.spacing().item_spacing.y);
+        }
+        Block::CodeBlock { language, code } => {
+            egui::Frame::none()
+                .fill(ui.visuals().code_bg_color)
+                .inner_margin(egui::Margin::same(6.0))
+                .rounding(ui.visuals().widgets.noninteractive.rounding)
+                .show(ui, |ui| {
+                    if !language.is_empty() {
+                        ui.label(RichText::new(language).small().weak());
+                    }
+                    egui::ScrollArea::horizontal().id_source(id).show(ui, |ui| {
+                        ui.add(egui::Label::new(RichText::new(code).monospace()).wrap(false));
+                    });
+                });
+        }
+        Block::BlockQuote(blocks) => {
+            ui.indent(id, |ui| {
+                ui.visuals_mut().override_text_color = Some(ui.visuals().weak_text_color());
+                blocks_ui(ui, id, blocks);
+            });
+        }
+        Block::List { start, items } => {
+            for (i, item) in items.iter().enumerate() {
+                ui.with_layout(egui::Layout::left_to_right(egui::Align::TOP), |ui| {
+                    ui.add_space(ui.spacing().indent);
+                    let marker = match start {
+                        Some(start) => format!("{}.", start + i as u64),
+                        None => "•".to_owned(),
+                    };
+                    ui.label(marker);
+                    ui.vertical(|ui| blocks_ui(ui, id.with(i), item));
+                });
+            }
+        }
+        Block::Table { header, rows } => {
+            egui::Grid::new(id).striped(true).show(ui, |ui| {
+                for cell in header {
+                    ui.horizontal(|ui| {
+                        for span in cell {
+                            span_ui(ui, span, RichText::strong);
+                        }
+                    });
+                }
+                ui.end_row();
+
+                for row in rows {
+                    for cell in row {
+                        ui.horizontal(|ui| inline_ui(ui, cell));
+                    }
+                    ui.end_row();
+                }
+            });
+            ui.add_space(ui.spacing().item_spacing.y);
+        }
+        Block::Rule => {
+            ui.separator();
+        }
+    }
+}
+
+fn inline_ui(ui: &mut egui::Ui, text: &[Span]) {
+    ui.horizontal_wrapped(|ui| {
+        for span in text {
+            span_ui(ui, span, |text| text);
+        }
+    });
+}
+
+fn span_ui(ui: &mut egui::Ui, span: &Span, base_style: impl Fn(RichText) -> RichText) {
+    ui.spacing_mut().item_spacing.x = 0.0;
+
+    let mut text = base_style(RichText::new(&span.text));
+    if span.style.strong {
+        text = text.strong();
+    }
+    if span.style.emphasis {
+        text = text.italics();
+    }
+    if span.style.strikethrough {
+        text = text.strikethrough();
+    }
+    if span.style.code {
+        text = text.code();
+    }
+
+    if let Some(url) = &span.url {
+        ui.hyperlink_to(text, url);
+    } else {
+        ui.label(text);
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(text: &str) -> Span {
+        Span {
+            text: text.to_owned(),
+            style: InlineStyle::default(),
+            url: None,
+        }
+    }
+
+    fn styled(text: &str, style: InlineStyle) -> Span {
+        Span {
+            style,
+            ..plain(text)
+        }
+    }
+
+    #[test]
+    fn test_parse_blocks() {
+        let markdown = "\
+# Experiment 42 #
+
+Trained for **10** epochs
+on the full dataset.
+
+- first
+  continued
+  - nested
+3. third
+
+> Quoted
+> twice
+
+```python
+import rerun as rr
+```
+
+| Model | Score |
+|-------|------:|
+| a     | 0.9   |
+
+---";
+        let strong = InlineStyle {
+            strong: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse(markdown),
+            vec![
+                Block::Heading {
+                    level: 1,
+                    text: vec![plain("Experiment 42")],
+                },
+                Block::Paragraph(vec![
+                    plain("Trained for "),
+                    styled("10", strong),
+                    plain(" epochs on the full dataset."),
+                ]),
+                Block::List {
+                    start: None,
+                    items: vec![vec![
+                        Block::Paragraph(vec![plain("first continued")]),
+                        Block::List {
+                            start: None,
+                            items: vec![vec![Block::Paragraph(vec![plain("nested")])]],
+                        },
+                    ]],
+                },
+                Block::List {
+                    start: Some(3),
+                    items: vec![vec![Block::Paragraph(vec![plain("third")])]],
+                },
+                Block::BlockQuote(vec![Block::Paragraph(vec![plain("Quoted twice")])]),
+                Block::CodeBlock {
+                    language: "python".to_owned(),
+                    code: "import rerun as rr".to_owned(),
+                },
+                Block::Table {
+                    header: vec![vec![plain("Model")], vec![plain("Score")]],
+                    rows: vec![vec![vec![plain("a")], vec![plain("0.9")]]],
+                },
+                Block::Rule,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_inline() {
+        let code = InlineStyle {
+            code: true,
+            ..Default::default()
+        };
+        let emphasis = InlineStyle {
+            emphasis: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            parse("a `snake_case` and _it_ \\*not\\* [rerun](https://rerun.io)"),
+            vec![Block::Paragraph(vec![
+                plain("a "),
+                styled("snake_case", code),
+                plain(" and "),
+                styled("it", emphasis),
+                plain(" *not* "),
+                Span {
+                    url: Some("https://rerun.io".to_owned()),
+                    ..plain("rerun")
+                },
+            ])]
+        );
+
+        // Neither underscores within words nor lone asterisks are emphasis.
+        assert_eq!(
+            parse("snake_case_name is 2 * 3 * 4"),
+            vec![Block::Paragraph(vec![plain(
+                "snake_case_name is 2 * 3 * 4"
+            )])]
+        );
+    }
+}
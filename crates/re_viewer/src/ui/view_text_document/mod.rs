@@ -0,0 +1,8 @@
+mod markdown;
+pub(crate) use self::markdown::markdown_ui;
+
+mod scene;
+pub(crate) use self::scene::{SceneTextDocument, TextDocumentEntry};
+
+mod ui;
+pub(crate) use self::ui::{view_text_document, ViewTextDocumentState};
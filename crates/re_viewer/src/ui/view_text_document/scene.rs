@@ -0,0 +1,50 @@
+use re_arrow_store::LatestAtQuery;
+use re_data_store::EntityPath;
+use re_log_types::component_types::TextDocument;
+use re_query::{query_entity_with_primary, QueryError};
+
+use crate::{ui::SceneQuery, ViewerContext};
+
+// ---
+
+#[derive(Debug, Clone)]
+pub struct TextDocumentEntry {
+    pub entity_path: EntityPath,
+
+    /// In Markdown.
+    pub body: String,
+}
+
+/// A text document scene, with everything needed to render it.
+#[derive(Default)]
+pub struct SceneTextDocument {
+    pub text_entries: Vec<TextDocumentEntry>,
+}
+
+impl SceneTextDocument {
+    /// Loads the latest text documents into the scene according to the given query.
+    pub(crate) fn load(&mut self, ctx: &ViewerContext<'_>, query: &SceneQuery<'_>) {
+        crate::profile_function!();
+
+        let store = &ctx.log_db.entity_db.data_store;
+        let latest_at = LatestAtQuery::new(query.timeline, query.latest_at);
+
+        for entity_path in query.entity_paths {
+            match query_entity_with_primary::<TextDocument>(store, &latest_at, entity_path, &[])
+                .and_then(|ent_view| {
+                    for document in ent_view.iter_primary()?.flatten() {
+                        self.text_entries.push(TextDocumentEntry {
+                            entity_path: entity_path.clone(),
+                            body: document.0,
+                        });
+                    }
+                    Ok(())
+                }) {
+                Ok(_) | Err(QueryError::PrimaryNotFound) => {}
+                Err(err) => {
+                    re_log::error_once!("Unexpected error querying {entity_path:?}: {err}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use egui::RichText;
+
+use crate::ViewerContext;
+
+use super::{markdown::markdown_ui, SceneTextDocument};
+
+// --- Main view ---
+
+#[derive(Clone, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct ViewTextDocumentState {
+    /// Show the Markdown source instead of rendering it.
+    show_source: bool,
+}
+
+impl ViewTextDocumentState {
+    pub fn selection_ui(&mut self, re_ui: &re_ui::ReUi, ui: &mut egui::Ui) {
+        re_ui
+            .selection_grid(ui, "text_document_config")
+            .show(ui, |ui| {
+                re_ui.grid_left_hand_label(ui, "Text style");
+                ui.vertical(|ui| {
+                    ui.radio_value(&mut self.show_source, false, "Markdown");
+                    ui.radio_value(&mut self.show_source, true, "Raw");
+                });
+                ui.end_row();
+            });
+    }
+}
+
+pub(crate) fn view_text_document(
+    ctx: &mut ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    state: &mut ViewTextDocumentState,
+    scene: &SceneTextDocument,
+) -> egui::Response {
+    crate::profile_function!();
+
+    ui.scope(|ui| {
+        egui::ScrollArea::both()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                if scene.text_entries.is_empty() {
+                    ui.label(ctx.re_ui.warning_text("No TextDocument entries found."));
+                }
+
+                for (i, entry) in scene.text_entries.iter().enumerate() {
+                    if scene.text_entries.len() > 1 {
+                        if i > 0 {
+                            ui.separator();
+                        }
+                        ctx.entity_path_button(ui, None, &entry.entity_path);
+                    }
+
+                    if state.show_source {
+                        ui.label(RichText::new(&entry.body).monospace());
+                    } else {
+                        let id = ui.id().with(i);
+                        markdown_ui(ui, id, &entry.body);
+                    }
+                }
+            });
+    })
+    .response
+}
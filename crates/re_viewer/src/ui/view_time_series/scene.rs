@@ -2,12 +2,15 @@ use crate::{
     ui::{annotations::AnnotationMap, DefaultColor, SceneQuery},
     ViewerContext,
 };
-use re_arrow_store::TimeRange;
+use std::collections::BTreeMap;
+
+use re_arrow_store::{LatestAtQuery, TimeRange};
+use re_data_store::EntityPath;
 use re_log_types::{
-    component_types::{self, InstanceKey},
+    component_types::{self, AxisMetadata, InstanceKey},
     Component,
 };
-use re_query::{range_entity_with_primary, QueryError};
+use re_query::{query_entity_with_primary, range_entity_with_primary, QueryError};
 
 // ---
 
@@ -63,6 +66,9 @@ pub struct PlotSeries {
 pub struct SceneTimeSeries {
     pub annotation_map: AnnotationMap,
     pub lines: Vec<PlotSeries>,
+
+    /// Name, unit and range of the plotted values, for the entities that have them.
+    pub value_axes: BTreeMap<EntityPath, AxisMetadata>,
 }
 
 impl SceneTimeSeries {
@@ -83,6 +89,8 @@ impl SceneTimeSeries {
         for entity_path in query.entity_paths {
             let ent_path = entity_path;
 
+            let value_axis = query_value_axis(ctx, query, ent_path);
+
             let mut points = Vec::new();
             let annotations = self.annotation_map.find(ent_path);
             let annotation_info = annotations.class_description(None).annotation_info();
@@ -145,6 +153,10 @@ impl SceneTimeSeries {
                 continue;
             }
 
+            if let Some(value_axis) = value_axis {
+                self.value_axes.insert(ent_path.clone(), value_axis);
+            }
+
             // If all points within a line share the label (and it isn't `None`), then we use it
             // as the whole line label for the plot legend.
             // Otherwise, we just use the entity path as-is.
@@ -228,3 +240,22 @@ impl SceneTimeSeries {
         }
     }
 }
+
+/// The latest [`AxisMetadata`] describing the values (rather than a tensor dimension) of an entity.
+fn query_value_axis(
+    ctx: &ViewerContext<'_>,
+    query: &SceneQuery<'_>,
+    ent_path: &EntityPath,
+) -> Option<AxisMetadata> {
+    let entity_view = query_entity_with_primary::<AxisMetadata>(
+        &ctx.log_db.entity_db.data_store,
+        &LatestAtQuery::new(query.timeline, query.latest_at),
+        ent_path,
+        &[],
+    )
+    .ok()?;
+
+    entity_view
+        .iter_primary_flattened()
+        .find(|axis| axis.dimension.is_none())
+}
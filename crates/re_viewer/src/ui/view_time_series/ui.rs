@@ -56,6 +56,31 @@ pub(crate) fn view_time_series(
         min_time
     };
 
+    // All values share the y axis, so their units should agree.
+    let y_axis = scene
+        .value_axes
+        .values()
+        .find(|axis| axis.unit().is_some())
+        .or_else(|| scene.value_axes.values().next())
+        .cloned()
+        .unwrap_or_default();
+    if let Some((ent_path, axis)) = scene
+        .value_axes
+        .iter()
+        .find(|(_, axis)| !axis.is_compatible_with(&y_axis))
+    {
+        let warning = format!(
+            "{ent_path} is in {:?}, but other plots in this view are in {:?}",
+            axis.unit().unwrap_or_default(),
+            y_axis.unit().unwrap_or_default(),
+        );
+        re_log::warn_once!("Mixing incompatible units on one plot axis: {warning}");
+        ui.label(ctx.re_ui.warning_text(warning));
+    } else if let Some(label) = y_axis.label() {
+        ui.label(egui::RichText::new(label).strong());
+    }
+    let (y_min, y_max) = (y_axis.range_min, y_axis.range_max);
+
     // use timeline_name as part of id, so that egui stores different pan/zoom for different timelines
     let plot_id_src = ("plot", &timeline_name);
 
@@ -70,13 +95,19 @@ pub(crate) fn view_time_series(
             let is_integer = value.y.round() == value.y;
             let decimals = if is_integer { 0 } else { 5 };
             format!(
-                "{timeline_name}: {}\n{name}: {:.*}",
+                "{timeline_name}: {}\n{name}: {}",
                 time_type.format((value.x as i64 + time_offset).into()),
-                decimals,
-                value.y,
+                y_axis.format_value(format!("{:.*}", decimals, value.y)),
             )
         });
 
+    if let Some(y_min) = y_min {
+        plot = plot.include_y(y_min);
+    }
+    if let Some(y_max) = y_max {
+        plot = plot.include_y(y_max);
+    }
+
     if timeline.typ() == TimeType::Time {
         let canvas_size = ui.available_size();
         plot = plot.x_grid_spacer(move |spacer| ns_grid_spacer(canvas_size, &spacer));
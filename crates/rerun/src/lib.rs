@@ -61,6 +61,16 @@
 //! let mut rr_session = rerun::SessionBuilder::new("my_app").connect(rerun::default_server_addr());
 //! ```
 //!
+//! #### Spawning
+//! To start a viewer in a separate process and stream log data to it, you can do this:
+//!
+//! ``` no_run
+//! let mut rr_session = rerun::SessionBuilder::new("my_app").spawn()?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! This runs the `rerun` binary, so it needs to be installed (see below).
+//!
 //! #### Buffering
 //!
 //! ``` no_run
@@ -153,6 +153,7 @@ def run_log_cleared() -> None:
     rr.log_rect("null_demo/rect/1", [10, 5, 4, 4], label="Rect2", color=(0, 255, 0))
     rr.set_time_seconds("sim_time", 2)
     rr.log_cleared("null_demo/rect/0")
+    rr.log_cleared_component("null_demo/rect/1", "rerun.label")
     rr.set_time_seconds("sim_time", 3)
     rr.log_cleared("null_demo/rect", recursive=True)
     rr.set_time_seconds("sim_time", 4)
@@ -292,6 +293,12 @@ def run_image_tensors() -> None:
     img_gray = cv2.cvtColor(img_rgb, cv2.COLOR_RGB2GRAY)
     rr.log_image("img_gray", img_gray)
 
+    # The same gray image as NV12, with neutral chroma:
+    height, width = img_gray.shape
+    chroma = np.full(((height + 1) // 2) * ((width + 1) // 2) * 2, 128, dtype=np.uint8)
+    img_nv12 = np.concatenate([img_gray.flatten(), chroma]).tobytes()
+    rr.log_yuv_image("img_nv12", img_nv12, width=width, height=height, yuv_format=rr.YuvFormat.NV12)
+
     dtypes = [
         "uint8",
         "uint16",
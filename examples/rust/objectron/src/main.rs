@@ -202,6 +202,7 @@ fn log_ar_camera(
         .with_component(&[Transform::Pinhole(Pinhole {
             image_from_cam: intrinsics.into(),
             resolution: Some(resolution.into()),
+            distortion: None,
         })])?
         .send(session)?;
 
@@ -12,7 +12,10 @@ use std::path::PathBuf;
 
 use anyhow::anyhow;
 use bytes::Bytes;
-use rerun::components::{ColorRGBA, Mesh3D, MeshId, RawMesh3D, Transform, Vec4D, ViewCoordinates};
+use rerun::components::{
+    ColorRGBA, Mesh3D, MeshId, RawMesh3D, Tensor, TensorData, TensorDataMeaning, TensorDimension,
+    Transform, Vec4D, ViewCoordinates,
+};
 use rerun::time::{TimeType, Timeline};
 use rerun::{
     external::{re_log, re_memory::AccountingAllocator},
@@ -29,21 +32,26 @@ impl From<GltfPrimitive> for Mesh3D {
     fn from(primitive: GltfPrimitive) -> Self {
         let GltfPrimitive {
             albedo_factor,
+            albedo_texture,
             indices,
             vertex_positions,
             vertex_colors,
             vertex_normals,
-            vertex_texcoords: _, // TODO(cmc) support mesh texturing
+            vertex_texcoords,
         } = primitive;
 
         let raw = RawMesh3D {
-            mesh_id: MeshId::random(),
+            mesh_id: MeshId::NIL,
             albedo_factor: albedo_factor.map(Vec4D),
             indices: indices.map(|i| i.into()),
             vertex_positions: vertex_positions.into_iter().flatten().collect(),
             vertex_normals: vertex_normals.map(|normals| normals.into_iter().flatten().collect()),
             vertex_colors: vertex_colors.map(|colors| colors.into_iter().map(|c| c.0).collect()),
-        };
+            vertex_texcoords: vertex_texcoords
+                .map(|texcoords| texcoords.into_iter().flatten().collect()),
+            albedo_texture,
+        }
+        .with_content_id();
 
         raw.sanity_check().unwrap();
 
@@ -173,8 +181,9 @@ impl Args {
 
 fn run(session: &Session, args: &Args) -> anyhow::Result<()> {
     // Read glTF scene
-    let (doc, buffers, _) = gltf::import_slice(Bytes::from(std::fs::read(args.scene_path()?)?))?;
-    let nodes = load_gltf(&doc, &buffers);
+    let (doc, buffers, images) =
+        gltf::import_slice(Bytes::from(std::fs::read(args.scene_path()?)?))?;
+    let nodes = load_gltf(&doc, &buffers, &images);
 
     // Log raw glTF nodes and their transforms with Rerun
     for root in nodes {
@@ -211,11 +220,11 @@ struct GltfNode {
 
 struct GltfPrimitive {
     albedo_factor: Option<[f32; 4]>,
+    albedo_texture: Option<Tensor>,
     indices: Option<Vec<u32>>,
     vertex_positions: Vec<[f32; 3]>,
     vertex_colors: Option<Vec<ColorRGBA>>,
     vertex_normals: Option<Vec<[f32; 3]>>,
-    #[allow(dead_code)]
     vertex_texcoords: Option<Vec<[f32; 2]>>,
 }
 
@@ -227,18 +236,22 @@ struct GltfTransform {
 }
 
 impl GltfNode {
-    fn from_gltf(buffers: &[gltf::buffer::Data], node: &gltf::Node<'_>) -> Self {
+    fn from_gltf(
+        buffers: &[gltf::buffer::Data],
+        images: &[gltf::image::Data],
+        node: &gltf::Node<'_>,
+    ) -> Self {
         let name = node_name(node);
 
         let transform = {
             let (t, r, s) = node.transform().decomposed();
             GltfTransform { t, r, s }
         };
-        let primitives = node_primitives(buffers, node).collect();
+        let primitives = node_primitives(buffers, images, node).collect();
 
         let children = node
             .children()
-            .map(|child| GltfNode::from_gltf(buffers, &child))
+            .map(|child| GltfNode::from_gltf(buffers, images, &child))
             .collect();
 
         Self {
@@ -257,17 +270,20 @@ fn node_name(node: &gltf::Node<'_>) -> String {
 
 fn node_primitives<'data>(
     buffers: &'data [gltf::buffer::Data],
+    images: &'data [gltf::image::Data],
     node: &'data gltf::Node<'_>,
 ) -> impl Iterator<Item = GltfPrimitive> + 'data {
     node.mesh().into_iter().flat_map(|mesh| {
         mesh.primitives().map(|primitive| {
             assert!(primitive.mode() == gltf::mesh::Mode::Triangles);
 
-            let albedo_factor = primitive
-                .material()
-                .pbr_metallic_roughness()
-                .base_color_factor()
-                .into();
+            let pbr = primitive.material().pbr_metallic_roughness();
+
+            let albedo_factor = pbr.base_color_factor().into();
+
+            let albedo_texture = pbr
+                .base_color_texture()
+                .and_then(|info| image_to_tensor(&images[info.texture().source().index()]));
 
             let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
 
@@ -293,6 +309,7 @@ fn node_primitives<'data>(
 
             GltfPrimitive {
                 albedo_factor,
+                albedo_texture,
                 vertex_positions,
                 indices,
                 vertex_normals,
@@ -303,9 +320,34 @@ fn node_primitives<'data>(
     })
 }
 
+/// Turns a decoded glTF image into an RGB(A) `Tensor`, if it is in a format we support.
+fn image_to_tensor(image: &gltf::image::Data) -> Option<Tensor> {
+    let channels = match image.format {
+        gltf::image::Format::R8 => 1,
+        gltf::image::Format::R8G8B8 => 3,
+        gltf::image::Format::R8G8B8A8 => 4,
+        format => {
+            re_log::warn!(?format, "unsupported glTF texture format, ignoring");
+            return None;
+        }
+    };
+
+    Some(Tensor::from_content(
+        vec![
+            TensorDimension::height(image.height as _),
+            TensorDimension::width(image.width as _),
+            TensorDimension::depth(channels),
+        ],
+        TensorData::U8(image.pixels.clone().into()),
+        TensorDataMeaning::Unknown,
+        None,
+    ))
+}
+
 fn load_gltf<'data>(
     doc: &'data gltf::Document,
     buffers: &'data [gltf::buffer::Data],
+    images: &'data [gltf::image::Data],
 ) -> impl Iterator<Item = GltfNode> + 'data {
     doc.scenes().map(move |scene| {
         let name = scene
@@ -320,7 +362,7 @@ fn load_gltf<'data>(
             primitives: Default::default(),
             children: scene
                 .nodes()
-                .map(|node| GltfNode::from_gltf(buffers, &node))
+                .map(|node| GltfNode::from_gltf(buffers, images, &node))
                 .collect(),
         }
     })
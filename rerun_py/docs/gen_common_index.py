@@ -70,18 +70,26 @@ SECTION_TABLE: Final[List[Section]] = [
             "log_rect",
             "log_rects",
             "log_obb",
+            "log_ellipsoid",
+            "log_ellipse",
             "log_line_strip",
             "log_line_segments",
+            "log_polygon",
             "log_arrow",
             "log_mesh",
             "log_meshes",
             "log_mesh_file",
         ],
     ),
+    Section(
+        title="Geographic",
+        module_summary=None,
+        func_list=["log_geo_points", "log_geo_line_strip"],
+    ),
     Section(
         title="Images",
         module_summary=None,
-        func_list=["log_image", "log_image_file", "log_depth_image", "log_segmentation_image"],
+        func_list=["log_image", "log_image_file", "log_depth_image", "log_segmentation_image", "log_yuv_image"],
     ),
     Section(
         title="Tensors",
@@ -101,7 +109,7 @@ SECTION_TABLE: Final[List[Section]] = [
     Section(
         title="Plotting",
         module_summary=None,
-        func_list=["log_scalar"],
+        func_list=["log_scalar", "log_histogram", "log_axis_metadata"],
     ),
     Section(
         title="Transforms",
@@ -113,7 +121,7 @@ SECTION_TABLE: Final[List[Section]] = [
         module_summary=None,
         # TODO(#1251): Classes aren't supported yet
         # "LogLevel", "LoggingHandler"
-        func_list=["log_text_entry"],
+        func_list=["log_text_entry", "log_text_document"],
     ),
     Section(
         title="Helpers",
@@ -5,21 +5,26 @@ from typing import Optional
 
 import rerun_bindings as bindings  # type: ignore[attr-defined]
 
-from rerun.log import log_cleared
+from rerun.log import log_cleared, log_cleared_component
 from rerun.log.annotation import AnnotationInfo, ClassDescription, log_annotation_context
 from rerun.log.arrow import log_arrow
+from rerun.log.axis_metadata import log_axis_metadata
 from rerun.log.bounding_box import log_obb
 from rerun.log.camera import log_pinhole
+from rerun.log.ellipsoids import log_ellipse, log_ellipsoid
+from rerun.log.geo import log_geo_line_strip, log_geo_points
 from rerun.log.extension_components import log_extension_components
 from rerun.log.file import ImageFormat, MeshFormat, log_image_file, log_mesh_file
-from rerun.log.image import log_depth_image, log_image, log_segmentation_image
+from rerun.log.histogram import log_histogram
+from rerun.log.image import YuvFormat, log_depth_image, log_image, log_segmentation_image, log_yuv_image
 from rerun.log.lines import log_line_segments, log_line_strip, log_path
 from rerun.log.mesh import log_mesh, log_meshes
 from rerun.log.points import log_point, log_points
+from rerun.log.polygons import PolygonDrawMode, log_polygon
 from rerun.log.rects import RectFormat, log_rect, log_rects
 from rerun.log.scalar import log_scalar
 from rerun.log.tensor import log_tensor
-from rerun.log.text import LoggingHandler, LogLevel, log_text_entry
+from rerun.log.text import LoggingHandler, LogLevel, log_text_document, log_text_entry
 from rerun.log.transform import log_rigid3, log_unknown_transform, log_view_coordinates
 from rerun.recording import MemoryRecording
 from rerun.script_helpers import script_add_args, script_setup, script_teardown
@@ -32,10 +37,18 @@ __all__ = [
     "components",
     "inline_show",
     "ImageFormat",
+    "YuvFormat",
     "log_annotation_context",
     "log_arrow",
+    "log_axis_metadata",
     "log_cleared",
+    "log_cleared_component",
     "log_depth_image",
+    "log_ellipse",
+    "log_ellipsoid",
+    "log_geo_line_strip",
+    "log_geo_points",
+    "log_histogram",
     "log_extension_components",
     "log_image_file",
     "log_image",
@@ -49,18 +62,22 @@ __all__ = [
     "log_pinhole",
     "log_point",
     "log_points",
+    "log_polygon",
     "log_rect",
     "log_rects",
     "log_rigid3",
     "log_scalar",
     "log_segmentation_image",
     "log_tensor",
+    "log_text_document",
     "log_text_entry",
     "log_unknown_transform",
+    "log_yuv_image",
     "log_view_coordinates",
     "notebook",
     "LogLevel",
     "MeshFormat",
+    "PolygonDrawMode",
     "RectFormat",
     "script_add_args",
     "script_setup",
@@ -131,6 +148,45 @@ def set_recording_id(value: str) -> None:
     bindings.set_recording_id(value)
 
 
+def new_recording() -> bindings.PyRecording:
+    """
+    Create a new recording, with its own recording id and sink, that can coexist with the global one.
+
+    It starts out with the application id and settings of the global recording.
+    Everything logged to it is buffered until you e.g. call [`rerun.connect`] or [`rerun.save`]
+    while it is the recording of the thread, see [`rerun.set_thread_local_recording`].
+
+    Returns
+    -------
+    bindings.PyRecording
+        The new recording.
+
+    """
+    return bindings.new_recording()
+
+
+def set_thread_local_recording(recording: Optional[bindings.PyRecording]) -> Optional[bindings.PyRecording]:
+    """
+    Make `recording` the recording that everything logged from the calling thread goes to.
+
+    This lets several threads log to separate recordings in parallel, e.g. one per episode,
+    including library code that doesn't know about recordings.
+    Pass `None` to go back to the global recording.
+
+    Parameters
+    ----------
+    recording : Optional[bindings.PyRecording]
+        The recording to log to, as created by [`rerun.new_recording`].
+
+    Returns
+    -------
+    Optional[bindings.PyRecording]
+        The previous recording of the thread, so it can be restored once done.
+
+    """
+    return bindings.set_thread_local_recording(recording)
+
+
 def init(application_id: str, spawn: bool = False, default_enabled: bool = True, strict: bool = False) -> None:
     """
     Initialize the Rerun SDK with a user-chosen application id (name).
@@ -11,16 +11,22 @@ from rerun import bindings
 all = [
     "annotation",
     "arrow",
+    "axis_metadata",
     "box",
     "color",
+    "ellipsoid",
+    "geo",
+    "histogram",
     "label",
     "point",
+    "polygon",
     "quaternion",
     "radius",
     "rect2d",
     "scalar",
     "scalar_plot_props",
     "tensor",
+    "text_document",
     "text_entry",
     "vec",
 ]
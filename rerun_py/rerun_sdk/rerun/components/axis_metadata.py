@@ -0,0 +1,28 @@
+from __future__ import annotations
+
+from typing import Any, Sequence
+
+import pyarrow as pa
+
+from rerun.components import REGISTERED_COMPONENT_NAMES, ComponentTypeFactory
+
+__all__ = [
+    "AxisMetadataArray",
+    "AxisMetadataType",
+]
+
+
+class AxisMetadataArray(pa.ExtensionArray):  # type: ignore[misc]
+    def from_axes(axes: Sequence[dict[str, Any]]) -> AxisMetadataArray:
+        """Build an `AxisMetadataArray` from dicts with `dimension`, `name`, `unit`, `range_min` and `range_max`."""
+        storage = pa.array(axes, type=AxisMetadataType.storage_type)
+        # TODO(john) enable extension type wrapper
+        # return cast(AxisMetadataArray, pa.ExtensionArray.from_storage(AxisMetadataType(), storage))
+        return storage  # type: ignore[no-any-return]
+
+
+AxisMetadataType = ComponentTypeFactory(
+    "AxisMetadataType", AxisMetadataArray, REGISTERED_COMPONENT_NAMES["rerun.axis_metadata"]
+)
+
+pa.register_extension_type(AxisMetadataType())
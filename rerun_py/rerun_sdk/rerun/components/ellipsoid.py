@@ -0,0 +1,71 @@
+from __future__ import annotations
+
+import numpy as np
+import numpy.typing as npt
+import pyarrow as pa
+
+from rerun.components import REGISTERED_COMPONENT_NAMES, ComponentTypeFactory
+
+__all__ = [
+    "Ellipse2DArray",
+    "Ellipse2DType",
+    "Ellipsoid3DArray",
+    "Ellipsoid3DType",
+]
+
+
+class Ellipsoid3DArray(pa.ExtensionArray):  # type: ignore[misc]
+    def from_numpy(half_sizes: npt.NDArray[np.float32], solid: bool = False) -> Ellipsoid3DArray:
+        """Build an `Ellipsoid3DArray` from an Nx3 numpy array of half-sizes."""
+        half_sizes = np.asarray(half_sizes, dtype=np.float32).reshape(-1, 3)
+
+        half_sizes_type, solid_type = (field.type for field in Ellipsoid3DType.storage_type)
+        storage = pa.StructArray.from_arrays(
+            [
+                pa.FixedSizeListArray.from_arrays(half_sizes.flatten(), type=half_sizes_type),
+                pa.array([solid] * len(half_sizes), type=solid_type),
+            ],
+            fields=list(Ellipsoid3DType.storage_type),
+        )
+        # TODO(john) enable extension type wrapper
+        # return cast(Ellipsoid3DArray, pa.ExtensionArray.from_storage(Ellipsoid3DType(), storage))
+        return storage  # type: ignore[no-any-return]
+
+
+Ellipsoid3DType = ComponentTypeFactory(
+    "Ellipsoid3DType", Ellipsoid3DArray, REGISTERED_COMPONENT_NAMES["rerun.ellipsoid3d"]
+)
+
+pa.register_extension_type(Ellipsoid3DType())
+
+
+class Ellipse2DArray(pa.ExtensionArray):  # type: ignore[misc]
+    def from_numpy(
+        centers: npt.NDArray[np.float32],
+        half_sizes: npt.NDArray[np.float32],
+        rotations: npt.NDArray[np.float32],
+        solid: bool = False,
+    ) -> Ellipse2DArray:
+        """Build an `Ellipse2DArray` from Nx2 centers, Nx2 half-sizes and N rotations, in radians."""
+        centers = np.asarray(centers, dtype=np.float32).reshape(-1, 2)
+        half_sizes = np.asarray(half_sizes, dtype=np.float32).reshape(-1, 2)
+        rotations = np.broadcast_to(np.asarray(rotations, dtype=np.float32), (len(centers),))
+
+        center_type, half_sizes_type, rotation_type, solid_type = (field.type for field in Ellipse2DType.storage_type)
+        storage = pa.StructArray.from_arrays(
+            [
+                pa.FixedSizeListArray.from_arrays(centers.flatten(), type=center_type),
+                pa.FixedSizeListArray.from_arrays(half_sizes.flatten(), type=half_sizes_type),
+                pa.array(rotations, type=rotation_type),
+                pa.array([solid] * len(centers), type=solid_type),
+            ],
+            fields=list(Ellipse2DType.storage_type),
+        )
+        # TODO(john) enable extension type wrapper
+        # return cast(Ellipse2DArray, pa.ExtensionArray.from_storage(Ellipse2DType(), storage))
+        return storage  # type: ignore[no-any-return]
+
+
+Ellipse2DType = ComponentTypeFactory("Ellipse2DType", Ellipse2DArray, REGISTERED_COMPONENT_NAMES["rerun.ellipse2d"])
+
+pa.register_extension_type(Ellipse2DType())
@@ -0,0 +1,72 @@
+from __future__ import annotations
+
+import itertools
+from typing import Iterable
+
+import numpy as np
+import numpy.typing as npt
+import pyarrow as pa
+
+from rerun.components import REGISTERED_COMPONENT_NAMES, ComponentTypeFactory
+
+__all__ = [
+    "GeoLineStripArray",
+    "GeoLineStripType",
+    "GeoPointArray",
+    "GeoPointType",
+]
+
+
+def _geo_point_storage(positions: npt.NDArray[np.float64], struct_type: pa.StructType) -> pa.StructArray:
+    """Build the storage of geographic points from an Nx2 array of [lat, lon] or Nx3 array of [lat, lon, alt]."""
+    if positions.shape[1] not in (2, 3):
+        raise TypeError("Geographic positions should be either Nx2 or Nx3")
+
+    latitude_type, longitude_type, altitude_type = (field.type for field in struct_type)
+    altitudes = positions[:, 2] if positions.shape[1] == 3 else [None] * len(positions)
+    return pa.StructArray.from_arrays(
+        [
+            pa.array(positions[:, 0], type=latitude_type),
+            pa.array(positions[:, 1], type=longitude_type),
+            pa.array(altitudes, type=altitude_type),
+        ],
+        fields=list(struct_type),
+    )
+
+
+class GeoPointArray(pa.ExtensionArray):  # type: ignore[misc]
+    def from_numpy(positions: npt.NDArray[np.float64]) -> GeoPointArray:
+        """Build a `GeoPointArray` from an Nx2 array of [lat, lon] or Nx3 array of [lat, lon, alt]."""
+        positions = np.asarray(positions, dtype=np.float64)
+        storage = _geo_point_storage(positions, GeoPointType.storage_type)
+        # TODO(john) enable extension type wrapper
+        # return cast(GeoPointArray, pa.ExtensionArray.from_storage(GeoPointType(), storage))
+        return storage  # type: ignore[no-any-return]
+
+
+GeoPointType = ComponentTypeFactory("GeoPointType", GeoPointArray, REGISTERED_COMPONENT_NAMES["rerun.geopoint"])
+
+pa.register_extension_type(GeoPointType())
+
+
+class GeoLineStripArray(pa.ExtensionArray):  # type: ignore[misc]
+    def from_numpy_arrays(array: Iterable[npt.NDArray[np.float64]]) -> GeoLineStripArray:
+        """Build a `GeoLineStripArray` from an array of [Nx2 or Nx3 numpy array] of [lat, lon(, alt)]."""
+        array = [np.asarray(strip, dtype=np.float64) for strip in array]
+
+        (points_field,) = GeoLineStripType.storage_type
+        offsets = itertools.chain([0], itertools.accumulate(len(strip) for strip in array))
+        values = _geo_point_storage(np.concatenate(array), points_field.type.value_type)
+        points = pa.ListArray.from_arrays(offsets, values, type=points_field.type)
+        storage = pa.StructArray.from_arrays([points], fields=[points_field])
+
+        # TODO(john) enable extension type wrapper
+        # return cast(GeoLineStripArray, pa.ExtensionArray.from_storage(GeoLineStripType(), storage))
+        return storage  # type: ignore[no-any-return]
+
+
+GeoLineStripType = ComponentTypeFactory(
+    "GeoLineStripType", GeoLineStripArray, REGISTERED_COMPONENT_NAMES["rerun.geo_line_strip"]
+)
+
+pa.register_extension_type(GeoLineStripType())
@@ -0,0 +1,53 @@
+from __future__ import annotations
+
+import numpy as np
+import numpy.typing as npt
+import pyarrow as pa
+
+from rerun.components import (
+    REGISTERED_COMPONENT_NAMES,
+    ComponentTypeFactory,
+    build_dense_union,
+)
+
+__all__ = [
+    "HistogramArray",
+    "HistogramType",
+]
+
+
+class HistogramArray(pa.ExtensionArray):  # type: ignore[misc]
+    def from_samples(samples: npt.ArrayLike) -> HistogramArray:
+        """Build a `HistogramArray` from raw samples, binned by the viewer."""
+        samples = np.asarray(samples, dtype=np.float64).flatten()
+        samples_type = HistogramType.storage_type[1].type
+        inner = pa.array([samples], type=samples_type)
+        storage = build_dense_union(data_type=HistogramType.storage_type, discriminant="Samples", child=inner)
+        storage.validate(full=True)
+        # TODO(john) enable extension type wrapper
+        # return cast(HistogramArray, pa.ExtensionArray.from_storage(HistogramType(), storage))
+        return storage  # type: ignore[no-any-return]
+
+    def from_bins(bin_edges: npt.ArrayLike, counts: npt.ArrayLike) -> HistogramArray:
+        """Build a `HistogramArray` from N+1 increasing bin edges and N counts."""
+        bin_edges = np.asarray(bin_edges, dtype=np.float64).flatten()
+        counts = np.asarray(counts, dtype=np.float64).flatten()
+        if len(bin_edges) != len(counts) + 1:
+            raise ValueError(f"Expected {len(counts) + 1} bin edges for {len(counts)} counts, got {len(bin_edges)}")
+
+        bins_type = HistogramType.storage_type[0].type
+        edges_type, counts_type = (field.type for field in bins_type)
+        inner = pa.StructArray.from_arrays(
+            [pa.array([bin_edges], type=edges_type), pa.array([counts], type=counts_type)],
+            fields=list(bins_type),
+        )
+        storage = build_dense_union(data_type=HistogramType.storage_type, discriminant="Bins", child=inner)
+        storage.validate(full=True)
+        # TODO(john) enable extension type wrapper
+        # return cast(HistogramArray, pa.ExtensionArray.from_storage(HistogramType(), storage))
+        return storage  # type: ignore[no-any-return]
+
+
+HistogramType = ComponentTypeFactory("HistogramType", HistogramArray, REGISTERED_COMPONENT_NAMES["rerun.histogram"])
+
+pa.register_extension_type(HistogramType())
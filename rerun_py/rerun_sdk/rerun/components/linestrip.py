@@ -7,6 +7,7 @@ import numpy as np
 import numpy.typing as npt
 import pyarrow as pa
 
+from rerun.color_conversion import u8_array_to_rgba
 from rerun.components import REGISTERED_COMPONENT_NAMES, ComponentTypeFactory
 
 __all__ = [
@@ -14,6 +15,10 @@ __all__ = [
     "LineStrip2DType",
     "LineStrip3DArray",
     "LineStrip3DType",
+    "VertexColorsArray",
+    "VertexColorsType",
+    "VertexRadiiArray",
+    "VertexRadiiType",
 ]
 
 
@@ -61,3 +66,41 @@ LineStrip3DType = ComponentTypeFactory(
 )
 
 pa.register_extension_type(LineStrip3DType())
+
+
+class VertexColorsArray(pa.ExtensionArray):  # type: ignore[misc]
+    def from_numpy_arrays(array: Iterable[npt.NDArray[np.uint8]]) -> VertexColorsArray:
+        """Build a `VertexColorsArray` from an array of [Nx4 numpy array] of RGBA colors, one per line strip."""
+        offsets = itertools.chain([0], itertools.accumulate(len(colors) for colors in array))
+        values = pa.array(u8_array_to_rgba(np.concatenate(array)), type=VertexColorsType.storage_type.value_type)
+        storage = pa.ListArray.from_arrays(offsets, values, type=VertexColorsType.storage_type)
+
+        # TODO(john) enable extension type wrapper
+        # return cast(VertexColorsArray, pa.ExtensionArray.from_storage(VertexColorsType(), storage))
+        return storage  # type: ignore[no-any-return]
+
+
+VertexColorsType = ComponentTypeFactory(
+    "VertexColorsType", VertexColorsArray, REGISTERED_COMPONENT_NAMES["rerun.vertex_colors"]
+)
+
+pa.register_extension_type(VertexColorsType())
+
+
+class VertexRadiiArray(pa.ExtensionArray):  # type: ignore[misc]
+    def from_numpy_arrays(array: Iterable[npt.NDArray[np.float32]]) -> VertexRadiiArray:
+        """Build a `VertexRadiiArray` from an array of [N numpy array] of radii, one per line strip."""
+        offsets = itertools.chain([0], itertools.accumulate(len(radii) for radii in array))
+        values = pa.array(np.concatenate(array), type=VertexRadiiType.storage_type.value_type)
+        storage = pa.ListArray.from_arrays(offsets, values, type=VertexRadiiType.storage_type)
+
+        # TODO(john) enable extension type wrapper
+        # return cast(VertexRadiiArray, pa.ExtensionArray.from_storage(VertexRadiiType(), storage))
+        return storage  # type: ignore[no-any-return]
+
+
+VertexRadiiType = ComponentTypeFactory(
+    "VertexRadiiType", VertexRadiiArray, REGISTERED_COMPONENT_NAMES["rerun.vertex_radii"]
+)
+
+pa.register_extension_type(VertexRadiiType())
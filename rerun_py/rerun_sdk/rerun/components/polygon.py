@@ -0,0 +1,76 @@
+from __future__ import annotations
+
+import itertools
+from enum import Enum
+from typing import Sequence
+
+import numpy as np
+import numpy.typing as npt
+import pyarrow as pa
+
+from rerun.components import REGISTERED_COMPONENT_NAMES, ComponentTypeFactory, build_dense_union
+
+__all__ = [
+    "Polygon2DArray",
+    "Polygon2DType",
+    "PolygonDrawMode",
+]
+
+
+class PolygonDrawMode(Enum):
+    """How to draw a polygon."""
+
+    FILL = "Fill"
+    """Only the area is drawn."""
+
+    OUTLINE = "Outline"
+    """Only the outer ring and the holes are drawn, as closed line strips."""
+
+    FILL_AND_OUTLINE = "FillAndOutline"
+    """Both the area and its outline are drawn."""
+
+
+def _rings_to_arrow(rings: Sequence[npt.NDArray[np.float32]], data_type: pa.ListType) -> pa.ListArray:
+    for ring in rings:
+        assert ring.shape[1] == 2
+
+    offsets = itertools.chain([0], itertools.accumulate(len(ring) for ring in rings))
+    values = np.concatenate(rings) if rings else np.zeros((0, 2), dtype=np.float32)
+    fixed = pa.FixedSizeListArray.from_arrays(values.flatten(), type=data_type.value_type)
+    return pa.ListArray.from_arrays(offsets, fixed, type=data_type)
+
+
+class Polygon2DArray(pa.ExtensionArray):  # type: ignore[misc]
+    def from_numpy_rings(
+        outers: Sequence[npt.NDArray[np.float32]],
+        holes: Sequence[Sequence[npt.NDArray[np.float32]]],
+        mode: PolygonDrawMode,
+    ) -> Polygon2DArray:
+        """Build a `Polygon2DArray` from one Nx2 outer ring and a list of Nx2 holes per polygon."""
+        assert len(outers) == len(holes)
+
+        storage_type = Polygon2DType.storage_type
+        outer_type, holes_type, mode_type = (field.type for field in storage_type)
+
+        outer = _rings_to_arrow(outers, outer_type)
+
+        hole_offsets = itertools.chain([0], itertools.accumulate(len(h) for h in holes))
+        all_holes = _rings_to_arrow([hole for h in holes for hole in h], holes_type.value_type)
+        holes_array = pa.ListArray.from_arrays(hole_offsets, all_holes, type=holes_type)
+
+        modes = build_dense_union(
+            data_type=mode_type,
+            discriminant=mode.value,
+            child=pa.array([True] * len(outers), type=pa.bool_()),
+        )
+
+        storage = pa.StructArray.from_arrays([outer, holes_array, modes], fields=list(storage_type))
+        storage.validate(full=True)
+        # TODO(john) enable extension type wrapper
+        # return cast(Polygon2DArray, pa.ExtensionArray.from_storage(Polygon2DType(), storage))
+        return storage  # type: ignore[no-any-return]
+
+
+Polygon2DType = ComponentTypeFactory("Polygon2DType", Polygon2DArray, REGISTERED_COMPONENT_NAMES["rerun.polygon2d"])
+
+pa.register_extension_type(Polygon2DType())
@@ -13,6 +13,8 @@ from rerun.components import (
 )
 
 __all__ = [
+    "OrientedBox2DArray",
+    "OrientedBox2DType",
     "Rect2DArray",
     "Rect2DType",
     "RectFormat",
@@ -57,3 +59,50 @@ class Rect2DArray(pa.ExtensionArray):  # type: ignore[misc]
 Rect2DType = ComponentTypeFactory("Rect2DType", Rect2DArray, REGISTERED_COMPONENT_NAMES["rerun.rect2d"])
 
 pa.register_extension_type(Rect2DType())
+
+
+def _centers_and_sizes(array: npt.NDArray[np.float_], rect_format: RectFormat) -> npt.NDArray[np.float32]:
+    """Convert an Nx4 array of rectangles into an Nx4 array of [x_center, y_center, width, height]."""
+    a, b, c, d = array.astype(np.float32).reshape(-1, 4).T
+    if rect_format == RectFormat.XYWH:
+        cols = [a + c / 2, b + d / 2, c, d]
+    elif rect_format == RectFormat.YXHW:
+        cols = [b + d / 2, a + c / 2, d, c]
+    elif rect_format == RectFormat.XYXY:
+        cols = [(a + c) / 2, (b + d) / 2, c - a, d - b]
+    elif rect_format == RectFormat.YXYX:
+        cols = [(b + d) / 2, (a + c) / 2, d - b, c - a]
+    elif rect_format == RectFormat.XCYCWH:
+        cols = [a, b, c, d]
+    elif rect_format == RectFormat.XCYCW2H2:
+        cols = [a, b, 2 * c, 2 * d]
+    else:
+        raise ValueError(f"Unknown rect format: {rect_format}")
+    return np.stack(cols, axis=1)
+
+
+class OrientedBox2DArray(pa.ExtensionArray):  # type: ignore[misc]
+    def from_numpy_and_format(
+        array: npt.NDArray[np.float_], rect_format: RectFormat, rotations: npt.NDArray[np.float32]
+    ) -> OrientedBox2DArray:
+        """Build an `OrientedBox2DArray` from an Nx4 numpy array and N rotations, in radians."""
+        boxes = _centers_and_sizes(array, rect_format)
+        rotations = np.broadcast_to(np.asarray(rotations, dtype=np.float32), (len(boxes),))
+
+        center_type, half_size_type, rotation_type = (field.type for field in OrientedBox2DType.storage_type)
+        centers = pa.FixedSizeListArray.from_arrays(boxes[:, 0:2].flatten(), type=center_type)
+        half_sizes = pa.FixedSizeListArray.from_arrays((boxes[:, 2:4] / 2).flatten(), type=half_size_type)
+        storage = pa.StructArray.from_arrays(
+            [centers, half_sizes, pa.array(rotations, type=rotation_type)],
+            fields=list(OrientedBox2DType.storage_type),
+        )
+        # TODO(john) enable extension type wrapper
+        # return cast(OrientedBox2DArray, pa.ExtensionArray.from_storage(OrientedBox2DType(), storage))
+        return storage  # type: ignore[no-any-return]
+
+
+OrientedBox2DType = ComponentTypeFactory(
+    "OrientedBox2DType", OrientedBox2DArray, REGISTERED_COMPONENT_NAMES["rerun.oriented_box2d"]
+)
+
+pa.register_extension_type(OrientedBox2DType())
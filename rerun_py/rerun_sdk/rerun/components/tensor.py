@@ -1,7 +1,6 @@
 from __future__ import annotations
 
-import uuid
-from typing import Final, Iterable, Union, cast
+from typing import Any, Final, Iterable, Union, cast
 
 import numpy as np
 import numpy.typing as npt
@@ -50,6 +49,18 @@ DTYPE_MAP: Final[dict[npt.DTypeLike, str]] = {
 }
 
 
+def _content_id(array: npt.NDArray[TensorDType], shape: list[dict[str, Any]]) -> bytes:
+    """Hash the shape and payload of a tensor into a 16-byte id, the same way the Rust SDK does."""
+    return cast(
+        bytes,
+        bindings.tensor_content_id(
+            DTYPE_MAP[cast(TensorDType, array.dtype.type)],
+            [(dim["name"], dim["size"]) for dim in shape],
+            np.ascontiguousarray(array).tobytes(),
+        ),
+    )
+
+
 class TensorArray(pa.ExtensionArray):  # type: ignore[misc]
     def from_numpy(
         array: npt.NDArray[TensorDType],
@@ -58,12 +69,14 @@ class TensorArray(pa.ExtensionArray):  # type: ignore[misc]
         meter: float | None = None,
     ) -> TensorArray:
         """Build a `TensorArray` from an numpy array."""
-        # Build a random tensor_id
-        tensor_id = pa.repeat(pa.scalar(uuid.uuid4().bytes, type=TensorType.storage_type["tensor_id"].type), 1)
-
         if not names:
             names = [None] * len(array.shape)
         shape_data = [[{"name": x[0], "size": x[1]} for x in zip(names, array.shape)]]
+
+        # Derive the tensor_id from the contents, so identical tensors are only stored once
+        tensor_id = pa.repeat(
+            pa.scalar(_content_id(array, shape_data[0]), type=TensorType.storage_type["tensor_id"].type), 1
+        )
         shape = pa.array(shape_data, type=TensorType.storage_type["shape"].type)
 
         if array.dtype == np.uint8:
@@ -0,0 +1,28 @@
+from __future__ import annotations
+
+from typing import Sequence
+
+import pyarrow as pa
+
+from rerun.components import REGISTERED_COMPONENT_NAMES, ComponentTypeFactory
+
+__all__ = [
+    "TextDocumentArray",
+    "TextDocumentType",
+]
+
+
+class TextDocumentArray(pa.ExtensionArray):  # type: ignore[misc]
+    def new(documents: Sequence[str]) -> TextDocumentArray:
+        """Build a `TextDocumentArray` from a sequence of Markdown str."""
+        storage = pa.array(documents, type=TextDocumentType.storage_type)
+        # TODO(john) enable extension type wrapper
+        # return cast(TextDocumentArray, pa.ExtensionArray.from_storage(TextDocumentType(), storage))
+        return storage  # type: ignore[no-any-return]
+
+
+TextDocumentType = ComponentTypeFactory(
+    "TextDocumentType", TextDocumentArray, REGISTERED_COMPONENT_NAMES["rerun.text_document"]
+)
+
+pa.register_extension_type(TextDocumentType())
@@ -8,14 +8,19 @@ from rerun import bindings
 __all__ = [
     "annotation",
     "arrow",
+    "axis_metadata",
     "bounding_box",
     "camera",
+    "ellipsoids",
     "error_utils",
     "file",
+    "geo",
+    "histogram",
     "image",
     "lines",
     "mesh",
     "points",
+    "polygons",
     "rects",
     "scalar",
     "tensor",
@@ -94,6 +99,29 @@ def log_cleared(entity_path: str, *, recursive: bool = False) -> None:
     bindings.log_cleared(entity_path, recursive)
 
 
+def log_cleared_component(entity_path: str, component_name: str) -> None:
+    """
+    Indicate that a single component of an entity should no longer be displayed, e.g. a stale label.
+
+    The other components of the entity are left alone.
+
+    Example:
+    --------
+    ```
+    rr.log_cleared_component("tracked/42", "rerun.label")
+    ```
+
+    Parameters
+    ----------
+    entity_path:
+        Path to the entity.
+    component_name:
+        The full name of the component to clear, e.g. `"rerun.label"`.
+
+    """
+    bindings.log_cleared_component(entity_path, component_name)
+
+
 def set_visible(entity_path: str, visible: bool) -> None:
     """
     set_visible has been deprecated.
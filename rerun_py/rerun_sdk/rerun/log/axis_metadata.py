@@ -0,0 +1,92 @@
+from typing import Any, Dict, Optional, Sequence, Tuple
+
+from rerun import bindings
+from rerun.components.axis_metadata import AxisMetadataArray
+from rerun.components.instance import InstanceArray
+from rerun.log.extension_components import _add_extension_components
+from rerun.log.log_decorator import log_decorator
+
+__all__ = [
+    "log_axis_metadata",
+]
+
+
+@log_decorator
+def log_axis_metadata(
+    entity_path: str,
+    *,
+    name: Optional[str] = None,
+    unit: Optional[str] = None,
+    range: Optional[Sequence[float]] = None,
+    dimensions: Optional[Dict[int, Tuple[Optional[str], Optional[str]]]] = None,
+    ext: Optional[Dict[str, Any]] = None,
+    timeless: bool = True,
+) -> None:
+    """
+    Log the physical unit, display name and expected range of the scalars or tensors at an entity path.
+
+    Time series plots label their value axis with these and warn when units on the same axis
+    don't match. Tensor views use them to name dimensions and to choose the color range.
+
+    Example:
+    --------
+    ```
+    rr.log_axis_metadata("car/speed", name="Speed", unit="m/s", range=[0, 60])
+    rr.log_axis_metadata("spectrogram", unit="dB", dimensions={0: ("Frequency", "Hz"), 1: ("Time", "s")})
+    ```
+
+    Parameters
+    ----------
+    entity_path:
+        The path to the scalar or tensor entity this describes.
+    name:
+        Display name of the values, e.g. "Speed".
+    unit:
+        Physical unit of the values, e.g. "m/s".
+    range:
+        The expected `[min, max]` of the values.
+    dimensions:
+        Display name and unit of tensor dimensions, by dimension index.
+    ext:
+        Optional dictionary of extension components. See [rerun.log_extension_components][]
+    timeless:
+        If true, the metadata applies at all times (default: True).
+
+    """
+
+    if range is not None and len(range) != 2:
+        raise ValueError(f"Expected range to be [min, max], got {range}")
+
+    axes = [
+        {
+            "dimension": None,
+            "name": name,
+            "unit": unit,
+            "range_min": float(range[0]) if range is not None else None,
+            "range_max": float(range[1]) if range is not None else None,
+        }
+    ]
+    for dimension, (dimension_name, dimension_unit) in (dimensions or {}).items():
+        axes.append(
+            {
+                "dimension": int(dimension),
+                "name": dimension_name,
+                "unit": dimension_unit,
+                "range_min": None,
+                "range_max": None,
+            }
+        )
+
+    instanced: Dict[str, Any] = {}
+    splats: Dict[str, Any] = {}
+
+    if ext:
+        _add_extension_components(instanced, splats, ext, None)
+
+    if splats:
+        splats["rerun.instance_key"] = InstanceArray.splat()
+        bindings.log_arrow_msg(entity_path, components=splats, timeless=timeless)
+
+    # Always the primary component last so range-based queries will include the other data. See(#1215)
+    instanced["rerun.axis_metadata"] = AxisMetadataArray.from_axes(axes)
+    bindings.log_arrow_msg(entity_path, components=instanced, timeless=timeless)
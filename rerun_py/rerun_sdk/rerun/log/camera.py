@@ -1,3 +1,5 @@
+from typing import Optional
+
 import numpy as np
 import numpy.typing as npt
 
@@ -17,6 +19,8 @@ def log_pinhole(
     width: int,
     height: int,
     timeless: bool = False,
+    distortion: Optional[npt.ArrayLike] = None,
+    distortion_model: str = "brown_conrady",
 ) -> None:
     """
     Log a perspective camera model.
@@ -29,6 +33,9 @@ def log_pinhole(
 
     Where `point_image_hom` is the projected point in the image space expressed in homogeneous coordinates.
 
+    If `distortion` is given, the intrinsics describe the ideal pinhole camera and the lens distortion
+    is applied on top of it, following the OpenCV conventions.
+
     Example
     -------
     ```
@@ -58,6 +65,12 @@ def log_pinhole(
         Height of the image in pixels.
     timeless:
         If true, the camera will be timeless (default: False).
+    distortion:
+        Optional lens distortion coefficients.
+        For `"brown_conrady"` these are `[k1, k2, p1, p2]` or `[k1, k2, p1, p2, k3]` (same order as OpenCV).
+        For `"fisheye"` these are `[k1, k2, k3, k4]` (same as OpenCV's fisheye module).
+    distortion_model:
+        Either `"brown_conrady"` (default) or `"fisheye"`.
 
     """
 
@@ -67,4 +80,6 @@ def log_pinhole(
         resolution=[width, height],
         child_from_parent=np.asarray(child_from_parent).T.tolist(),
         timeless=timeless,
+        distortion_model=distortion_model,
+        distortion=None if distortion is None else np.asarray(distortion, dtype=np.float32).flatten().tolist(),
     )
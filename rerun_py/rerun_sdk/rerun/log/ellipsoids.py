@@ -0,0 +1,266 @@
+from typing import Any, Dict, Optional, Tuple
+
+import numpy as np
+import numpy.typing as npt
+
+from rerun import bindings
+from rerun.components.annotation import ClassIdArray
+from rerun.components.color import ColorRGBAArray
+from rerun.components.ellipsoid import Ellipse2DArray, Ellipsoid3DArray
+from rerun.components.instance import InstanceArray
+from rerun.components.label import LabelArray
+from rerun.components.point import Point3DArray
+from rerun.components.quaternion import QuaternionArray
+from rerun.components.radius import RadiusArray
+from rerun.log import Color, _normalize_colors, _normalize_ids, _normalize_radii
+from rerun.log.extension_components import _add_extension_components
+from rerun.log.log_decorator import log_decorator
+
+__all__ = [
+    "log_ellipse",
+    "log_ellipsoid",
+]
+
+
+def _principal_axes(covariance: npt.ArrayLike, n_sigma: float, dim: int) -> Tuple[npt.NDArray[np.float32], Any]:
+    """Return the `n_sigma` half-sizes and the matrix of principal axes (as columns) of a covariance."""
+    covariance = np.asarray(covariance, dtype=np.float64)
+    if covariance.shape != (dim, dim):
+        raise TypeError(f"covariance should be {dim}x{dim}")
+
+    eigenvalues, eigenvectors = np.linalg.eigh(covariance)
+    if np.linalg.det(eigenvectors) < 0:
+        eigenvectors[:, -1] *= -1
+    half_sizes = n_sigma * np.sqrt(np.maximum(eigenvalues, 0.0))
+    return half_sizes.astype(np.float32), eigenvectors
+
+
+def _quaternion_from_matrix(m: Any) -> npt.NDArray[np.float32]:
+    """Convert a 3x3 rotation matrix into a [x, y, z, w] quaternion."""
+    trace = np.trace(m)
+    if trace > 0:
+        s = 0.5 / np.sqrt(trace + 1.0)
+        q = [(m[2, 1] - m[1, 2]) * s, (m[0, 2] - m[2, 0]) * s, (m[1, 0] - m[0, 1]) * s, 0.25 / s]
+    elif m[0, 0] > m[1, 1] and m[0, 0] > m[2, 2]:
+        s = 2.0 * np.sqrt(1.0 + m[0, 0] - m[1, 1] - m[2, 2])
+        q = [0.25 * s, (m[0, 1] + m[1, 0]) / s, (m[0, 2] + m[2, 0]) / s, (m[2, 1] - m[1, 2]) / s]
+    elif m[1, 1] > m[2, 2]:
+        s = 2.0 * np.sqrt(1.0 + m[1, 1] - m[0, 0] - m[2, 2])
+        q = [(m[0, 1] + m[1, 0]) / s, 0.25 * s, (m[1, 2] + m[2, 1]) / s, (m[0, 2] - m[2, 0]) / s]
+    else:
+        s = 2.0 * np.sqrt(1.0 + m[2, 2] - m[0, 0] - m[1, 1])
+        q = [(m[0, 2] + m[2, 0]) / s, (m[1, 2] + m[2, 1]) / s, 0.25 * s, (m[1, 0] - m[0, 1]) / s]
+    q = np.asarray(q, dtype=np.float64)
+    return (q / np.linalg.norm(q)).astype(np.float32)
+
+
+def _add_common_components(
+    instanced: Dict[str, Any],
+    color: Optional[Color],
+    stroke_width: Optional[float],
+    label: Optional[str],
+    class_id: Optional[int],
+) -> None:
+    if color:
+        colors = _normalize_colors([color])
+        instanced["rerun.colorrgba"] = ColorRGBAArray.from_numpy(colors)
+
+    # We store the stroke_width in radius
+    if stroke_width:
+        radii = _normalize_radii([stroke_width / 2])
+        instanced["rerun.radius"] = RadiusArray.from_numpy(radii)
+
+    if label:
+        instanced["rerun.label"] = LabelArray.new([label])
+
+    if class_id:
+        class_ids = _normalize_ids([class_id])
+        instanced["rerun.class_id"] = ClassIdArray.from_numpy(class_ids)
+
+
+@log_decorator
+def log_ellipsoid(
+    entity_path: str,
+    *,
+    half_sizes: Optional[npt.ArrayLike] = None,
+    position: Optional[npt.ArrayLike] = None,
+    rotation_q: Optional[npt.ArrayLike] = None,
+    covariance: Optional[npt.ArrayLike] = None,
+    n_sigma: float = 3.0,
+    solid: bool = False,
+    color: Optional[Color] = None,
+    stroke_width: Optional[float] = None,
+    label: Optional[str] = None,
+    class_id: Optional[int] = None,
+    ext: Optional[Dict[str, Any]] = None,
+    timeless: bool = False,
+) -> None:
+    """
+    Log a 3D ellipsoid, e.g. the uncertainty of a position estimate.
+
+    Either pass `half_sizes` (and optionally `rotation_q`) directly, or a `covariance`
+    from which the `n_sigma` ellipsoid is computed.
+
+    Example:
+    --------
+    ```
+    rr.log_ellipsoid("world/robot/uncertainty", position=mean, covariance=cov, n_sigma=3.0)
+    ```
+
+    Parameters
+    ----------
+    entity_path:
+        The path to the ellipsoid in the space hierarchy.
+    half_sizes:
+        Array with the [x, y, z] semi-axis lengths of the ellipsoid, before rotation.
+    position:
+        Optional array with the [x, y, z] center of the ellipsoid.
+    rotation_q:
+        Optional array with quaternion coordinates [x, y, z, w] for the rotation of the principal axes.
+    covariance:
+        Optional 3x3 covariance matrix. Mutually exclusive with `half_sizes` and `rotation_q`.
+    n_sigma:
+        How many standard deviations the ellipsoid spans when logging a `covariance`.
+    solid:
+        Draw a shaded surface instead of a wireframe.
+    color:
+        Optional RGB or RGBA in sRGB gamma-space as either 0-1 floats or 0-255 integers, with separate alpha.
+    stroke_width:
+        Optional width of the wireframe lines.
+    label:
+        Optional text label placed at `position`.
+    class_id:
+        Optional class id for the ellipsoid.  The class id provides colors and labels if not specified explicitly.
+    ext:
+        Optional dictionary of extension components. See [rerun.log_extension_components][]
+    timeless:
+        If true, the ellipsoid will be timeless (default: False).
+
+    """
+
+    if covariance is not None:
+        if half_sizes is not None or rotation_q is not None:
+            raise TypeError("covariance can't be combined with half_sizes or rotation_q")
+        half_sizes, axes = _principal_axes(covariance, n_sigma, 3)
+        rotation_q = _quaternion_from_matrix(axes)
+    elif half_sizes is None:
+        raise TypeError("either half_sizes or covariance is required")
+
+    instanced: Dict[str, Any] = {}
+    splats: Dict[str, Any] = {}
+
+    half_sizes = np.require(half_sizes, dtype="float32")
+    if half_sizes.shape != (3,):
+        raise TypeError("half_sizes should be 1x3")
+
+    if position is not None:
+        position = np.require(position, dtype="float32")
+
+        if position.shape[0] == 3:
+            instanced["rerun.point3d"] = Point3DArray.from_numpy(position.reshape(1, 3))
+        else:
+            raise TypeError("position should be 1x3")
+
+    if rotation_q is not None:
+        rotation = np.require(rotation_q, dtype="float32")
+
+        if rotation.shape[0] == 4:
+            instanced["rerun.quaternion"] = QuaternionArray.from_numpy(rotation.reshape(1, 4))
+        else:
+            raise TypeError("rotation should be 1x4")
+
+    _add_common_components(instanced, color, stroke_width, label, class_id)
+
+    if ext:
+        _add_extension_components(instanced, splats, ext, None)
+
+    if splats:
+        splats["rerun.instance_key"] = InstanceArray.splat()
+        bindings.log_arrow_msg(entity_path, components=splats, timeless=timeless)
+
+    # Always the primary component last so range-based queries will include the other data. See(#1215)
+    instanced["rerun.ellipsoid3d"] = Ellipsoid3DArray.from_numpy(half_sizes.reshape(1, 3), solid)
+    bindings.log_arrow_msg(entity_path, components=instanced, timeless=timeless)
+
+
+@log_decorator
+def log_ellipse(
+    entity_path: str,
+    *,
+    center: npt.ArrayLike,
+    half_sizes: Optional[npt.ArrayLike] = None,
+    rotation: float = 0.0,
+    covariance: Optional[npt.ArrayLike] = None,
+    n_sigma: float = 3.0,
+    solid: bool = False,
+    color: Optional[Color] = None,
+    stroke_width: Optional[float] = None,
+    label: Optional[str] = None,
+    class_id: Optional[int] = None,
+    ext: Optional[Dict[str, Any]] = None,
+    timeless: bool = False,
+) -> None:
+    """
+    Log a 2D ellipse, e.g. the uncertainty of a position estimate in the plane.
+
+    Either pass `half_sizes` (and optionally `rotation`) directly, or a `covariance`
+    from which the `n_sigma` ellipse is computed.
+
+    Parameters
+    ----------
+    entity_path:
+        The path to the ellipse in the space hierarchy.
+    center:
+        The [x, y] center of the ellipse.
+    half_sizes:
+        The [x, y] semi-axis lengths of the ellipse, before rotation.
+    rotation:
+        Rotation around `center` in radians, turning the x-axis towards the y-axis.
+    covariance:
+        Optional 2x2 covariance matrix. Mutually exclusive with `half_sizes`.
+    n_sigma:
+        How many standard deviations the ellipse spans when logging a `covariance`.
+    solid:
+        Fill the ellipse instead of only drawing its outline.
+    color:
+        Optional RGB or RGBA in sRGB gamma-space as either 0-1 floats or 0-255 integers, with separate alpha.
+    stroke_width:
+        Optional width of the outline.
+    label:
+        Optional text label placed at `center`.
+    class_id:
+        Optional class id for the ellipse.  The class id provides colors and labels if not specified explicitly.
+    ext:
+        Optional dictionary of extension components. See [rerun.log_extension_components][]
+    timeless:
+        If true, the ellipse will be timeless (default: False).
+
+    """
+
+    if covariance is not None:
+        if half_sizes is not None:
+            raise TypeError("covariance can't be combined with half_sizes")
+        half_sizes, axes = _principal_axes(covariance, n_sigma, 2)
+        # `eigh` sorts the axes by ascending variance, so the rotation follows the second one.
+        half_sizes = half_sizes[::-1]
+        rotation = float(np.arctan2(axes[1, 1], axes[0, 1]))
+    elif half_sizes is None:
+        raise TypeError("either half_sizes or covariance is required")
+
+    instanced: Dict[str, Any] = {}
+    splats: Dict[str, Any] = {}
+
+    _add_common_components(instanced, color, stroke_width, label, class_id)
+
+    if ext:
+        _add_extension_components(instanced, splats, ext, None)
+
+    if splats:
+        splats["rerun.instance_key"] = InstanceArray.splat()
+        bindings.log_arrow_msg(entity_path, components=splats, timeless=timeless)
+
+    # Always the primary component last so range-based queries will include the other data. See(#1215)
+    instanced["rerun.ellipse2d"] = Ellipse2DArray.from_numpy(
+        np.asarray(center, dtype=np.float32), np.asarray(half_sizes, dtype=np.float32), rotation, solid
+    )
+    bindings.log_arrow_msg(entity_path, components=instanced, timeless=timeless)
@@ -26,8 +26,6 @@ class MeshFormat(Enum):
     GLB = "GLB"
     """glTF binary format."""
 
-    # Needs some way of logging materials too, or adding some default material to the
-    # viewer.
     OBJ = "OBJ"
     """Wavefront .obj format."""
 
@@ -39,13 +37,17 @@ class ImageFormat(Enum):
     JPEG = "jpeg"
     """JPEG format."""
 
+    PNG = "png"
+    """PNG format."""
+
 
 @log_decorator
 def log_mesh_file(
     entity_path: str,
     mesh_format: MeshFormat,
-    mesh_file: bytes,
+    mesh_file: Optional[bytes] = None,
     *,
+    mesh_path: Optional[Path] = None,
     transform: Optional[npt.ArrayLike] = None,
     timeless: bool = False,
 ) -> None:
@@ -54,6 +56,9 @@ def log_mesh_file(
 
     You can also use [`rerun.log_mesh`] to log raw mesh data.
 
+    You must pass either `mesh_file` or `mesh_path`.
+    OBJ files keep their textures next to them, so these are only logged if you pass `mesh_path`.
+
     Example:
     -------
     ```
@@ -72,6 +77,9 @@ def log_mesh_file(
         Format of the mesh file
     mesh_file:
         Contents of the mesh file
+    mesh_path:
+        Path to the mesh file on disk. Used to read `mesh_file` if it isn't given,
+        and to find the albedo texture (`map_Kd`) of OBJ materials.
     transform:
         Optional 3x4 affine transform matrix applied to the mesh
     timeless:
@@ -85,7 +93,7 @@ def log_mesh_file(
         transform = np.require(transform, dtype="float32")
 
     # Mesh arrow handling happens inside the python bridge
-    bindings.log_mesh_file(entity_path, mesh_format.value, mesh_file, transform, timeless)
+    bindings.log_mesh_file(entity_path, mesh_format.value, mesh_file, mesh_path, transform, timeless)
 
 
 @log_decorator
@@ -100,7 +108,9 @@ def log_image_file(
     """
     Log an image file given its contents or path on disk.
 
-    Only JPEGs are supported right now.
+    Only JPEGs and PNGs are supported right now.
+
+    PNGs are lossless, which makes them a good fit for segmentation and depth images.
 
     You must pass either `img_bytes` or `img_path`.
 
@@ -111,9 +121,9 @@ def log_image_file(
     entity_path:
         Path to the image in the space hierarchy.
     img_bytes:
-        Content of an image file, e.g. a `.jpg`.
+        Content of an image file, e.g. a `.jpg` or `.png`.
     img_path:
-        Path to an image file, e.g. a `.jpg`.
+        Path to an image file, e.g. a `.jpg` or `.png`.
     img_format:
         Format of the image file.
     timeless:
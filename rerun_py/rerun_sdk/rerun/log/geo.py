@@ -0,0 +1,157 @@
+from typing import Any, Dict, Optional, Sequence, Union
+
+import numpy as np
+import numpy.typing as npt
+
+from rerun import bindings
+from rerun.components.color import ColorRGBAArray
+from rerun.components.geo import GeoLineStripArray, GeoPointArray
+from rerun.components.instance import InstanceArray
+from rerun.components.label import LabelArray
+from rerun.components.radius import RadiusArray
+from rerun.log import Color, Colors, _normalize_colors, _normalize_labels, _normalize_radii
+from rerun.log.extension_components import _add_extension_components
+from rerun.log.log_decorator import log_decorator
+
+__all__ = [
+    "log_geo_points",
+    "log_geo_line_strip",
+]
+
+
+@log_decorator
+def log_geo_points(
+    entity_path: str,
+    positions: npt.ArrayLike,
+    *,
+    colors: Optional[Union[Color, Colors]] = None,
+    radii: Optional[npt.ArrayLike] = None,
+    labels: Optional[Sequence[str]] = None,
+    ext: Optional[Dict[str, Any]] = None,
+    timeless: bool = False,
+) -> None:
+    """
+    Log geographic points, e.g. GPS fixes, to be shown in a map view.
+
+    Logging again to the same `entity_path` will replace all the previous points.
+
+    Example:
+    --------
+    ```
+    rr.log_geo_points("world/gps", [[47.376887, 8.541694]], labels=["Zürich"])
+    ```
+
+    Parameters
+    ----------
+    entity_path:
+        Path to the points in the space hierarchy.
+    positions:
+        Nx2 array of [latitude, longitude] in degrees, or Nx3 array of [latitude, longitude, altitude in meters].
+    colors:
+        Optional colors of the points.
+        The colors are interpreted as RGB or RGBA in sRGB gamma-space,
+        as either 0-1 floats or 0-255 integers, with separate alpha.
+    radii:
+        Optional radii of the points, in ui points.
+    labels:
+        Optional per-point text to show with the points.
+    ext:
+        Optional dictionary of extension components. See [rerun.log_extension_components][]
+    timeless:
+        If true, the points will be timeless (default: False).
+
+    """
+
+    positions = np.require(positions, dtype="float64")
+    if positions.ndim != 2:
+        raise TypeError("Geographic positions should be either Nx2 or Nx3")
+
+    colors = _normalize_colors(colors)
+    radii = _normalize_radii(radii)
+    labels = _normalize_labels(labels)
+
+    # 0 = instanced, 1 = splat
+    comps = [{}, {}]  # type: ignore[var-annotated]
+
+    if len(colors):
+        is_splat = len(colors.shape) == 1
+        if is_splat:
+            colors = colors.reshape(1, len(colors))
+        comps[is_splat]["rerun.colorrgba"] = ColorRGBAArray.from_numpy(colors)
+
+    if len(radii):
+        is_splat = len(radii) == 1
+        comps[is_splat]["rerun.radius"] = RadiusArray.from_numpy(radii)
+
+    if len(labels):
+        is_splat = len(labels) == 1
+        comps[is_splat]["rerun.label"] = LabelArray.new(labels)
+
+    if ext:
+        _add_extension_components(comps[0], comps[1], ext, None)
+
+    if comps[1]:
+        comps[1]["rerun.instance_key"] = InstanceArray.splat()
+        bindings.log_arrow_msg(entity_path, components=comps[1], timeless=timeless)
+
+    # Always the primary component last so range-based queries will include the other data. See(#1215)
+    comps[0]["rerun.geopoint"] = GeoPointArray.from_numpy(positions)
+    bindings.log_arrow_msg(entity_path, components=comps[0], timeless=timeless)
+
+
+@log_decorator
+def log_geo_line_strip(
+    entity_path: str,
+    positions: npt.ArrayLike,
+    *,
+    stroke_width: Optional[float] = None,
+    color: Optional[Color] = None,
+    ext: Optional[Dict[str, Any]] = None,
+    timeless: bool = False,
+) -> None:
+    """
+    Log a path on the earth, e.g. a GPS track, to be shown in a map view.
+
+    Parameters
+    ----------
+    entity_path:
+        Path to the line strip in the space hierarchy.
+    positions:
+        Nx2 array of [latitude, longitude] in degrees, or Nx3 array of [latitude, longitude, altitude in meters].
+    stroke_width:
+        Optional width of the line, in ui points.
+    color:
+        Optional RGB or RGBA in sRGB gamma-space as either 0-1 floats or 0-255 integers, with separate alpha.
+    ext:
+        Optional dictionary of extension components. See [rerun.log_extension_components][]
+    timeless:
+        If true, the line strip will be timeless (default: False).
+
+    """
+
+    positions = np.require(positions, dtype="float64")
+    if positions.ndim != 2:
+        raise TypeError("Geographic positions should be either Nx2 or Nx3")
+
+    instanced: Dict[str, Any] = {}
+    splats: Dict[str, Any] = {}
+
+    if color:
+        colors = _normalize_colors([color])
+        instanced["rerun.colorrgba"] = ColorRGBAArray.from_numpy(colors)
+
+    # We store the stroke_width in radius
+    if stroke_width:
+        radii = _normalize_radii([stroke_width / 2])
+        instanced["rerun.radius"] = RadiusArray.from_numpy(radii)
+
+    if ext:
+        _add_extension_components(instanced, splats, ext, None)
+
+    if splats:
+        splats["rerun.instance_key"] = InstanceArray.splat()
+        bindings.log_arrow_msg(entity_path, components=splats, timeless=timeless)
+
+    # Always the primary component last so range-based queries will include the other data. See(#1215)
+    instanced["rerun.geo_line_strip"] = GeoLineStripArray.from_numpy_arrays([positions])
+    bindings.log_arrow_msg(entity_path, components=instanced, timeless=timeless)
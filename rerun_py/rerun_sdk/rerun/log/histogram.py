@@ -0,0 +1,81 @@
+from typing import Any, Dict, Optional
+
+import numpy.typing as npt
+
+from rerun import bindings
+from rerun.components.histogram import HistogramArray
+from rerun.components.instance import InstanceArray
+from rerun.log.extension_components import _add_extension_components
+from rerun.log.log_decorator import log_decorator
+
+__all__ = [
+    "log_histogram",
+]
+
+
+@log_decorator
+def log_histogram(
+    entity_path: str,
+    samples: Optional[npt.ArrayLike] = None,
+    *,
+    bin_edges: Optional[npt.ArrayLike] = None,
+    counts: Optional[npt.ArrayLike] = None,
+    ext: Optional[Dict[str, Any]] = None,
+    timeless: bool = False,
+) -> None:
+    """
+    Log a distribution of values, e.g. of network weights, as a histogram.
+
+    Either pass the raw `samples`, which the viewer sorts into bins, or already binned
+    data as N+1 increasing `bin_edges` and N `counts`.
+
+    The histogram view shows either the latest distribution, or a heatmap of how the
+    distribution evolves over time.
+
+    Example:
+    --------
+    ```
+    rr.log_histogram("weights/layer1", model.layer1.weight.flatten())
+    rr.log_histogram("latency", bin_edges=[0, 10, 50, 100], counts=[120, 30, 2])
+    ```
+
+    Parameters
+    ----------
+    entity_path:
+        Path to the histogram in the space hierarchy.
+    samples:
+        Raw samples to be binned by the viewer.
+    bin_edges:
+        N+1 increasing bin edges. Bin `i` spans `bin_edges[i]` to `bin_edges[i + 1]`.
+    counts:
+        N counts, one per bin. May be fractional for weighted histograms.
+    ext:
+        Optional dictionary of extension components. See [rerun.log_extension_components][]
+    timeless:
+        If true, the histogram will be timeless (default: False).
+
+    """
+
+    if samples is not None and (bin_edges is not None or counts is not None):
+        raise ValueError("Pass either samples, or bin_edges and counts, but not both")
+
+    if samples is not None:
+        histogram = HistogramArray.from_samples(samples)
+    elif bin_edges is not None and counts is not None:
+        histogram = HistogramArray.from_bins(bin_edges, counts)
+    else:
+        raise ValueError("Expected either samples, or both bin_edges and counts")
+
+    instanced: Dict[str, Any] = {}
+    splats: Dict[str, Any] = {}
+
+    if ext:
+        _add_extension_components(instanced, splats, ext, None)
+
+    if splats:
+        splats["rerun.instance_key"] = InstanceArray.splat()
+        bindings.log_arrow_msg(entity_path, components=splats, timeless=timeless)
+
+    # Always the primary component last so range-based queries will include the other data. See(#1215)
+    instanced["rerun.histogram"] = histogram
+    bindings.log_arrow_msg(entity_path, components=instanced, timeless=timeless)
@@ -1,3 +1,4 @@
+from enum import Enum
 from typing import Any, Dict, Optional
 
 import numpy as np
@@ -12,6 +13,8 @@ __all__ = [
     "log_image",
     "log_depth_image",
     "log_segmentation_image",
+    "YuvFormat",
+    "log_yuv_image",
 ]
 
 
@@ -201,3 +204,48 @@ def log_segmentation_image(
             ext=ext,
             timeless=timeless,
         )
+
+
+class YuvFormat(Enum):
+    """Chroma-subsampled image format."""
+
+    NV12 = "NV12"
+    """A full-resolution Y plane, followed by an interleaved UV plane at half resolution."""
+
+    YUY2 = "YUY2"
+    """Interleaved `Y0 U Y1 V` for each pair of pixels."""
+
+
+@log_decorator
+def log_yuv_image(
+    entity_path: str,
+    data: bytes,
+    *,
+    width: int,
+    height: int,
+    yuv_format: YuvFormat,
+    timeless: bool = False,
+) -> None:
+    """
+    Log a chroma-subsampled image, as delivered by many cameras and video decoders.
+
+    The bytes are stored as-is and converted to RGB by the viewer.
+
+    Parameters
+    ----------
+    entity_path:
+        Path to the image in the space hierarchy.
+    data:
+        The raw image bytes. Their length must match `width`, `height` and `yuv_format`.
+    width:
+        Width of the image in pixels.
+    height:
+        Height of the image in pixels.
+    yuv_format:
+        How the bytes are laid out.
+    timeless:
+        If true, the image will be timeless (default: False).
+
+    """
+
+    bindings.log_yuv_image(entity_path, data, width, height, yuv_format.value, timeless)
@@ -7,9 +7,14 @@ from deprecated import deprecated
 from rerun import bindings
 from rerun.components.color import ColorRGBAArray
 from rerun.components.instance import InstanceArray
-from rerun.components.linestrip import LineStrip2DArray, LineStrip3DArray
+from rerun.components.linestrip import (
+    LineStrip2DArray,
+    LineStrip3DArray,
+    VertexColorsArray,
+    VertexRadiiArray,
+)
 from rerun.components.radius import RadiusArray
-from rerun.log import Color, _normalize_colors, _normalize_radii
+from rerun.log import Color, Colors, _normalize_colors, _normalize_radii
 from rerun.log.extension_components import _add_extension_components
 from rerun.log.log_decorator import log_decorator
 
@@ -40,6 +45,8 @@ def log_line_strip(
     *,
     stroke_width: Optional[float] = None,
     color: Optional[Color] = None,
+    vertex_colors: Optional[Colors] = None,
+    vertex_stroke_widths: Optional[npt.ArrayLike] = None,
     ext: Optional[Dict[str, Any]] = None,
     timeless: bool = False,
 ) -> None:
@@ -66,6 +73,11 @@ def log_line_strip(
         Optional width of the line.
     color:
         Optional RGB or RGBA in sRGB gamma-space as either 0-1 floats or 0-255 integers, with separate alpha.
+    vertex_colors:
+        Optional Nx3 or Nx4 array with one color per point, e.g. to color a trajectory by speed.
+        Takes precedence over `color`.
+    vertex_stroke_widths:
+        Optional array with one line width per point. Takes precedence over `stroke_width`.
     ext:
         Optional dictionary of extension components. See [rerun.log_extension_components][]
     timeless:
@@ -96,6 +108,13 @@ def log_line_strip(
         radii = _normalize_radii([stroke_width / 2])
         instanced["rerun.radius"] = RadiusArray.from_numpy(radii)
 
+    if vertex_colors is not None:
+        instanced["rerun.vertex_colors"] = VertexColorsArray.from_numpy_arrays([_normalize_colors(vertex_colors)])
+
+    if vertex_stroke_widths is not None:
+        vertex_radii = _normalize_radii(vertex_stroke_widths) / 2
+        instanced["rerun.vertex_radii"] = VertexRadiiArray.from_numpy_arrays([vertex_radii])
+
     if ext:
         _add_extension_components(instanced, splats, ext, None)
 
@@ -25,6 +25,8 @@ def log_mesh(
     normals: Optional[Any] = None,
     albedo_factor: Optional[Any] = None,
     vertex_colors: Optional[Colors] = None,
+    texcoords: Optional[Any] = None,
+    albedo_texture: Optional[Any] = None,
     timeless: bool = False,
 ) -> None:
     """
@@ -72,6 +74,12 @@ def log_mesh(
     vertex_colors:
         Optional array of RGB(A) vertex colors, in sRGB gamma space, either as 0-1 floats or 0-255 integers.
         If specified, the alpha is considered separate (unmultiplied).
+    texcoords:
+        If specified, is a (potentially flattened) array of 2D texture coordinates (u, v), one for each vertex,
+        used to sample the `albedo_texture`.
+    albedo_texture:
+        Optional `uint8` gray (HxW), RGB (HxWx3) or RGBA (HxWx4) image, in sRGB gamma space.
+        It is sampled using `texcoords` and multiplied with the vertex colors and `albedo_factor`.
     timeless:
         If true, the mesh will be timeless (default: False)
 
@@ -87,6 +95,10 @@ def log_mesh(
         albedo_factor = np.asarray(albedo_factor, dtype=np.float32).flatten()
     if vertex_colors is not None:
         vertex_colors = _normalize_colors(vertex_colors)
+    if texcoords is not None:
+        texcoords = np.asarray(texcoords, dtype=np.float32).flatten()
+    if albedo_texture is not None:
+        albedo_texture = np.ascontiguousarray(albedo_texture, dtype=np.uint8)
 
     # Mesh arrow handling happens inside the python bridge
     bindings.log_meshes(
@@ -95,7 +107,9 @@ def log_mesh(
         vertex_color_buffers=[vertex_colors],
         index_buffers=[indices],
         normal_buffers=[normals],
+        texcoord_buffers=[texcoords],
         albedo_factors=[albedo_factor],
+        albedo_textures=[albedo_texture],
         timeless=timeless,
     )
 
@@ -109,6 +123,8 @@ def log_meshes(
     index_buffers: Sequence[Optional[npt.ArrayLike]],
     normal_buffers: Sequence[Optional[npt.ArrayLike]],
     albedo_factors: Sequence[Optional[npt.ArrayLike]],
+    texcoord_buffers: Optional[Sequence[Optional[npt.ArrayLike]]] = None,
+    albedo_textures: Optional[Sequence[Optional[npt.ArrayLike]]] = None,
     timeless: bool = False,
 ) -> None:
     """
@@ -131,6 +147,10 @@ def log_meshes(
         An optional sequence of normal buffers, one for each mesh.
     albedo_factors:
         An optional sequence of albedo factors, one for each mesh.
+    texcoord_buffers:
+        An optional sequence of texture coordinate buffers, one for each mesh.
+    albedo_textures:
+        An optional sequence of albedo textures, one for each mesh.
     timeless:
         If true, the mesh will be timeless (default: False)
 
@@ -145,6 +165,18 @@ def log_meshes(
         normal_buffers = [np.asarray(n, dtype=np.float32).flatten() if n else None for n in normal_buffers]
     if albedo_factors is not None:
         albedo_factors = [np.asarray(af, dtype=np.float32).flatten() if af else None for af in albedo_factors]
+    if texcoord_buffers is not None:
+        texcoord_buffers = [
+            np.asarray(t, dtype=np.float32).flatten() if t is not None else None for t in texcoord_buffers
+        ]
+    else:
+        texcoord_buffers = [None] * len(position_buffers)
+    if albedo_textures is not None:
+        albedo_textures = [
+            np.ascontiguousarray(t, dtype=np.uint8) if t is not None else None for t in albedo_textures
+        ]
+    else:
+        albedo_textures = [None] * len(position_buffers)
 
     # Mesh arrow handling happens inside the python bridge
 
@@ -154,6 +186,8 @@ def log_meshes(
         vertex_color_buffers=vertex_color_buffers,
         index_buffers=index_buffers,
         normal_buffers=normal_buffers,
+        texcoord_buffers=texcoord_buffers,
         albedo_factors=albedo_factors,
+        albedo_textures=albedo_textures,
         timeless=timeless,
     )
@@ -0,0 +1,110 @@
+from typing import Any, Dict, Optional, Sequence
+
+import numpy as np
+import numpy.typing as npt
+
+from rerun import bindings
+from rerun.components.annotation import ClassIdArray
+from rerun.components.color import ColorRGBAArray
+from rerun.components.instance import InstanceArray
+from rerun.components.label import LabelArray
+from rerun.components.polygon import Polygon2DArray, PolygonDrawMode
+from rerun.components.radius import RadiusArray
+from rerun.log import Color, _normalize_colors, _normalize_ids, _normalize_radii
+from rerun.log.extension_components import _add_extension_components
+from rerun.log.log_decorator import log_decorator
+
+__all__ = [
+    "PolygonDrawMode",
+    "log_polygon",
+]
+
+
+@log_decorator
+def log_polygon(
+    entity_path: str,
+    positions: npt.ArrayLike,
+    *,
+    holes: Optional[Sequence[npt.ArrayLike]] = None,
+    mode: PolygonDrawMode = PolygonDrawMode.FILL,
+    color: Optional[Color] = None,
+    stroke_width: Optional[float] = None,
+    label: Optional[str] = None,
+    class_id: Optional[int] = None,
+    ext: Optional[Dict[str, Any]] = None,
+    timeless: bool = False,
+) -> None:
+    """
+    Log a 2D polygon, optionally with holes.
+
+    Filled polygons can be hovered and selected anywhere within their area,
+    which makes them a good fit for segmentation results and other region overlays.
+
+    Parameters
+    ----------
+    entity_path:
+        Path to the polygon in the space hierarchy.
+    positions:
+        An Nx2 array of points along the outer boundary.
+        The ring is closed automatically, the first point doesn't need to be repeated.
+    holes:
+        Optional sequence of Nx2 arrays, one for each area to cut out of the polygon.
+    mode:
+        Whether to fill the area, draw the outline, or both.
+    color:
+        Optional RGB or RGBA in sRGB gamma-space as either 0-1 floats or 0-255 integers, with separate alpha.
+        Fills are always drawn opaque.
+    stroke_width:
+        Optional width of the outline.
+    label:
+        Optional text to show with the polygon.
+    class_id:
+        Optional class id for the polygon.
+        The class id provides color and label if not specified explicitly.
+        See [rerun.log_annotation_context][]
+    ext:
+        Optional dictionary of extension components. See [rerun.log_extension_components][]
+    timeless:
+        If true, the polygon will be timeless (default: False).
+
+    """
+
+    positions = np.require(positions, dtype="float32")
+    if positions.ndim != 2 or positions.shape[1] != 2:
+        raise TypeError("Positions should be Nx2")
+
+    hole_rings = [np.require(hole, dtype="float32") for hole in holes or []]
+    for hole in hole_rings:
+        if hole.ndim != 2 or hole.shape[1] != 2:
+            raise TypeError("Holes should be Nx2")
+
+    instanced: Dict[str, Any] = {}
+    splats: Dict[str, Any] = {}
+
+    instanced["rerun.polygon2d"] = Polygon2DArray.from_numpy_rings([positions], [hole_rings], mode)
+
+    if color:
+        colors = _normalize_colors([color])
+        instanced["rerun.colorrgba"] = ColorRGBAArray.from_numpy(colors)
+
+    # We store the stroke_width in radius
+    if stroke_width:
+        radii = _normalize_radii([stroke_width / 2])
+        instanced["rerun.radius"] = RadiusArray.from_numpy(radii)
+
+    if label:
+        instanced["rerun.label"] = LabelArray.new([label])
+
+    if class_id:
+        class_ids = _normalize_ids([class_id])
+        instanced["rerun.class_id"] = ClassIdArray.from_numpy(class_ids)
+
+    if ext:
+        _add_extension_components(instanced, splats, ext, None)
+
+    if splats:
+        splats["rerun.instance_key"] = InstanceArray.splat()
+        bindings.log_arrow_msg(entity_path, components=splats, timeless=timeless)
+
+    # Always the primary component last so range-based queries will include the other data. See(#1215)
+    bindings.log_arrow_msg(entity_path, components=instanced, timeless=timeless)
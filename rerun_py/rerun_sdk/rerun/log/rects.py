@@ -8,7 +8,7 @@ from rerun.components.annotation import ClassIdArray
 from rerun.components.color import ColorRGBAArray
 from rerun.components.instance import InstanceArray
 from rerun.components.label import LabelArray
-from rerun.components.rect2d import Rect2DArray, RectFormat
+from rerun.components.rect2d import OrientedBox2DArray, Rect2DArray, RectFormat
 from rerun.log import (
     Color,
     Colors,
@@ -34,6 +34,7 @@ def log_rect(
     rect: Optional[npt.ArrayLike],
     *,
     rect_format: RectFormat = RectFormat.XYWH,
+    rotation: Optional[float] = None,
     color: Optional[Color] = None,
     label: Optional[str] = None,
     class_id: Optional[int] = None,
@@ -51,6 +52,9 @@ def log_rect(
         the rectangle in [x, y, w, h], or some format you pick with the `rect_format` argument.
     rect_format:
         how to interpret the `rect` argument
+    rotation:
+        Optional rotation of the rectangle around its center, in radians.
+        Turns the x-axis towards the y-axis, i.e. clockwise in image space.
     color:
         Optional RGB or RGBA in sRGB gamma-space as either 0-1 floats or 0-255 integers, with separate alpha.
     label:
@@ -75,7 +79,10 @@ def log_rect(
     instanced: Dict[str, Any] = {}
     splats: Dict[str, Any] = {}
 
-    instanced["rerun.rect2d"] = Rect2DArray.from_numpy_and_format(rects, rect_format)
+    if rotation is None:
+        instanced["rerun.rect2d"] = Rect2DArray.from_numpy_and_format(rects, rect_format)
+    else:
+        instanced["rerun.oriented_box2d"] = OrientedBox2DArray.from_numpy_and_format(rects, rect_format, rotation)
 
     if color:
         colors = _normalize_colors([color])
@@ -106,6 +113,7 @@ def log_rects(
     rects: Optional[npt.ArrayLike],
     *,
     rect_format: RectFormat = RectFormat.XYWH,
+    rotations: Optional[npt.ArrayLike] = None,
     identifiers: Optional[Sequence[int]] = None,
     colors: Optional[Union[Color, Colors]] = None,
     labels: Optional[Sequence[str]] = None,
@@ -136,6 +144,9 @@ def log_rects(
         Nx4 numpy array, where each row is [x, y, w, h], or some format you pick with the `rect_format` argument.
     rect_format:
         how to interpret the `rect` argument
+    rotations:
+        Optional rotation of each rectangle around its center, in radians, or a single rotation for all of them.
+        Turns the x-axis towards the y-axis, i.e. clockwise in image space.
     identifiers:
         Unique numeric id that shows up when you hover or select the point.
     colors:
@@ -174,7 +185,12 @@ def log_rects(
 
     # 0 = instanced, 1 = splat
     comps = [{}, {}]  # type: ignore[var-annotated]
-    comps[0]["rerun.rect2d"] = Rect2DArray.from_numpy_and_format(rects, rect_format)
+    if rotations is None:
+        comps[0]["rerun.rect2d"] = Rect2DArray.from_numpy_and_format(rects, rect_format)
+    else:
+        comps[0]["rerun.oriented_box2d"] = OrientedBox2DArray.from_numpy_and_format(
+            rects, rect_format, np.asarray(rotations, dtype="float32")
+        )
 
     if len(identifiers_np):
         comps[0]["rerun.instance_key"] = InstanceArray.from_numpy(identifiers_np)
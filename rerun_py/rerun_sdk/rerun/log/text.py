@@ -6,6 +6,7 @@ import rerun.log.extension_components
 from rerun import bindings
 from rerun.components.color import ColorRGBAArray
 from rerun.components.instance import InstanceArray
+from rerun.components.text_document import TextDocumentArray
 from rerun.components.text_entry import TextEntryArray
 from rerun.log import Color, _normalize_colors
 from rerun.log.log_decorator import log_decorator
@@ -15,6 +16,7 @@ __all__ = [
     "LogLevel",
     "LoggingHandler",
     "log_text_entry",
+    "log_text_document",
 ]
 
 
@@ -119,3 +121,51 @@ def log_text_entry(
     # Always the primary component last so range-based queries will include the other data. See(#1215)
     if instanced:
         bindings.log_arrow_msg(entity_path, components=instanced, timeless=timeless)
+
+
+@log_decorator
+def log_text_document(
+    entity_path: str,
+    markdown: str,
+    *,
+    ext: Optional[Dict[str, Any]] = None,
+    timeless: bool = False,
+) -> None:
+    """
+    Log a text document in Markdown, e.g. an experiment description or an evaluation report.
+
+    Documents are shown in full in their own space view, with headings, code blocks, tables and links.
+    Logging again to the same `entity_path` replaces the document.
+
+    Example:
+    --------
+    ```
+    rr.log_text_document("description", "# Experiment 42\n\nTrained with `lr = 1e-3`.", timeless=True)
+    ```
+
+    Parameters
+    ----------
+    entity_path:
+        The object path to log the document to.
+    markdown:
+        The document, in Markdown.
+    ext:
+        Optional dictionary of extension components. See [rerun.log_extension_components][]
+    timeless:
+        Whether the document should be timeless.
+
+    """
+
+    instanced: Dict[str, Any] = {}
+    splats: Dict[str, Any] = {}
+
+    if ext:
+        rerun.log.extension_components._add_extension_components(instanced, splats, ext, None)
+
+    if splats:
+        splats["rerun.instance_key"] = InstanceArray.splat()
+        bindings.log_arrow_msg(entity_path, components=splats, timeless=timeless)
+
+    # Always the primary component last so range-based queries will include the other data. See(#1215)
+    instanced["rerun.text_document"] = TextDocumentArray.new([markdown])
+    bindings.log_arrow_msg(entity_path, components=instanced, timeless=timeless)
@@ -2,9 +2,10 @@
 #![allow(clippy::borrow_deref_ref)] // False positive due to #[pufunction] macro
 #![allow(unsafe_op_in_unsafe_fn)] // False positive due to #[pufunction] macro
 
-use std::{borrow::Cow, io::Cursor, path::PathBuf};
+use std::{borrow::Cow, path::PathBuf, sync::Arc};
 
 use itertools::izip;
+use parking_lot::Mutex;
 use pyo3::{
     exceptions::{PyRuntimeError, PyTypeError, PyValueError},
     prelude::*,
@@ -39,14 +40,35 @@ use crate::{arrow::get_registered_component_names, python_session::PythonSession
 
 // ----------------------------------------------------------------------------
 
-/// The global [`PythonSession`] object used by the Python API.
-fn python_session() -> parking_lot::MutexGuard<'static, PythonSession> {
+/// The global [`PythonSession`] object, used by threads that don't have a recording of their own.
+fn global_python_session() -> &'static Arc<Mutex<PythonSession>> {
     use once_cell::sync::OnceCell;
-    use parking_lot::Mutex;
-    static PYTHON_SESSION: OnceCell<Mutex<PythonSession>> = OnceCell::new();
-    PYTHON_SESSION.get_or_init(Default::default).lock()
+    static PYTHON_SESSION: OnceCell<Arc<Mutex<PythonSession>>> = OnceCell::new();
+    PYTHON_SESSION.get_or_init(Default::default)
 }
 
+thread_local! {
+    /// Set with [`set_thread_local_recording`].
+    static THREAD_RECORDING: std::cell::RefCell<Option<PyRecording>> = std::cell::RefCell::new(None);
+}
+
+/// The [`PythonSession`] used by the Python API on the calling thread:
+/// the recording of the thread if it has one, otherwise the global one.
+fn python_session() -> parking_lot::ArcMutexGuard<parking_lot::RawMutex, PythonSession> {
+    THREAD_RECORDING.with(|recording| match &*recording.borrow() {
+        Some(recording) => recording.0.lock_arc(),
+        None => global_python_session().lock_arc(),
+    })
+}
+
+/// A recording with its own [`RecordingId`] and sink, that can coexist with the global one.
+///
+/// Created with [`new_recording`], and logged to by making it the recording of a thread
+/// with [`set_thread_local_recording`].
+#[pyclass]
+#[derive(Clone)]
+struct PyRecording(Arc<Mutex<PythonSession>>);
+
 // ----------------------------------------------------------------------------
 
 /// Thread-local info
@@ -129,6 +151,7 @@ fn rerun_bindings(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(get_registered_component_names, m)?)?;
     m.add_class::<TensorDataMeaning>()?;
     m.add_class::<PyMemorySinkStorage>()?;
+    m.add_class::<PyRecording>()?;
 
     // If this is a special RERUN_APP_ONLY context (launched via .spawn), we
     // can bypass everything else, which keeps us from preparing an SDK session
@@ -146,6 +169,9 @@ fn rerun_bindings(py: Python<'_>, m: &PyModule) -> PyResult<()> {
 
     m.add_function(wrap_pyfunction!(get_recording_id, m)?)?;
     m.add_function(wrap_pyfunction!(set_recording_id, m)?)?;
+    m.add_function(wrap_pyfunction!(new_recording, m)?)?;
+    m.add_function(wrap_pyfunction!(set_thread_local_recording, m)?)?;
+    m.add_function(wrap_pyfunction!(thread_local_recording, m)?)?;
 
     m.add_function(wrap_pyfunction!(connect, m)?)?;
     m.add_function(wrap_pyfunction!(disconnect, m)?)?;
@@ -178,9 +204,13 @@ fn rerun_bindings(py: Python<'_>, m: &PyModule) -> PyResult<()> {
 
     m.add_function(wrap_pyfunction!(log_mesh_file, m)?)?;
     m.add_function(wrap_pyfunction!(log_image_file, m)?)?;
+    m.add_function(wrap_pyfunction!(log_yuv_image, m)?)?;
     m.add_function(wrap_pyfunction!(log_cleared, m)?)?;
+    m.add_function(wrap_pyfunction!(log_cleared_component, m)?)?;
     m.add_function(wrap_pyfunction!(log_arrow_msg, m)?)?;
 
+    m.add_function(wrap_pyfunction!(tensor_content_id, m)?)?;
+
     Ok(())
 }
 
@@ -283,6 +313,28 @@ fn set_recording_id(recording_id: &str) -> PyResult<()> {
     }
 }
 
+/// A new recording, with a random recording id and the settings of the global one.
+#[pyfunction]
+fn new_recording() -> PyRecording {
+    let session = global_python_session().lock().new_recording();
+    PyRecording(Arc::new(Mutex::new(session)))
+}
+
+/// Make `recording` the recording that everything logged from the calling thread goes to,
+/// or go back to the global recording with `None`.
+///
+/// Returns the previous recording of the thread, so it can be restored once done.
+#[pyfunction]
+fn set_thread_local_recording(recording: Option<PyRecording>) -> Option<PyRecording> {
+    THREAD_RECORDING.with(|current| current.replace(recording))
+}
+
+/// The recording of the calling thread, as set with [`set_thread_local_recording`].
+#[pyfunction]
+fn thread_local_recording() -> Option<PyRecording> {
+    THREAD_RECORDING.with(|current| current.borrow().clone())
+}
+
 #[pyfunction]
 #[pyo3(signature = (application_id, application_path=None, default_enabled=true))]
 fn init(application_id: String, application_path: Option<PathBuf>, default_enabled: bool) {
@@ -540,15 +592,50 @@ fn log_rigid3(
 }
 
 #[pyfunction]
+#[pyo3(signature = (entity_path, resolution, child_from_parent, timeless, distortion_model=None, distortion=None))]
 fn log_pinhole(
     entity_path: &str,
     resolution: [f32; 2],
     child_from_parent: [[f32; 3]; 3],
     timeless: bool,
+    distortion_model: Option<&str>,
+    distortion: Option<Vec<f32>>,
 ) -> PyResult<()> {
+    use re_log_types::component_types::LensDistortion;
+
+    let distortion = match (distortion_model, distortion) {
+        (_, None) => None,
+        (None | Some("brown_conrady"), Some(coeffs)) => match coeffs.as_slice() {
+            // OpenCV order: k1, k2, p1, p2[, k3]
+            [k1, k2, p1, p2] => Some(LensDistortion::brown_conrady(*k1, *k2, *p1, *p2, 0.0)),
+            [k1, k2, p1, p2, k3] => Some(LensDistortion::brown_conrady(*k1, *k2, *p1, *p2, *k3)),
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Brown-Conrady distortion expects 4 or 5 coefficients (k1, k2, p1, p2[, k3]), got {}",
+                    coeffs.len()
+                )));
+            }
+        },
+        (Some("fisheye"), Some(coeffs)) => match coeffs.as_slice() {
+            [k1, k2, k3, k4] => Some(LensDistortion::fisheye(*k1, *k2, *k3, *k4)),
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Fisheye distortion expects 4 coefficients (k1, k2, k3, k4), got {}",
+                    coeffs.len()
+                )));
+            }
+        },
+        (Some(model), Some(_)) => {
+            return Err(PyValueError::new_err(format!(
+                "Unknown distortion model {model:?}. Expected 'brown_conrady' or 'fisheye'"
+            )));
+        }
+    };
+
     let transform = re_log_types::Transform::Pinhole(re_log_types::Pinhole {
         image_from_cam: child_from_parent.into(),
         resolution: Some(resolution.into()),
+        distortion,
     });
 
     log_transform(entity_path, transform, timeless)
@@ -682,7 +769,9 @@ fn log_meshes(
     vertex_color_buffers: Vec<Option<numpy::PyReadonlyArray2<'_, u8>>>,
     index_buffers: Vec<Option<numpy::PyReadonlyArray1<'_, u32>>>,
     normal_buffers: Vec<Option<numpy::PyReadonlyArray1<'_, f32>>>,
+    texcoord_buffers: Vec<Option<numpy::PyReadonlyArray1<'_, f32>>>,
     albedo_factors: Vec<Option<numpy::PyReadonlyArray1<'_, f32>>>,
+    albedo_textures: Vec<Option<numpy::PyReadonlyArrayDyn<'_, u8>>>,
     timeless: bool,
 ) -> PyResult<()> {
     let entity_path = parse_entity_path(entity_path_str)?;
@@ -691,16 +780,21 @@ fn log_meshes(
     if position_buffers.len() != vertex_color_buffers.len()
         || position_buffers.len() != index_buffers.len()
         || position_buffers.len() != normal_buffers.len()
+        || position_buffers.len() != texcoord_buffers.len()
         || position_buffers.len() != albedo_factors.len()
+        || position_buffers.len() != albedo_textures.len()
     {
         return Err(PyTypeError::new_err(format!(
-            "Top-level position/index/normal/albedo buffer arrays must be same the length, \
-                got positions={}, vertex_colors={}, indices={}, normals={}, albedo={} instead",
+            "Top-level position/index/normal/texcoord/albedo buffer arrays must be same the length, \
+                got positions={}, vertex_colors={}, indices={}, normals={}, texcoords={}, \
+                albedo={}, albedo_textures={} instead",
             position_buffers.len(),
             vertex_color_buffers.len(),
             index_buffers.len(),
             normal_buffers.len(),
+            texcoord_buffers.len(),
             albedo_factors.len(),
+            albedo_textures.len(),
         )));
     }
 
@@ -710,12 +804,22 @@ fn log_meshes(
 
     let mut meshes = Vec::with_capacity(position_buffers.len());
 
-    for (vertex_positions, vertex_colors, indices, normals, albedo_factor) in izip!(
+    for (
+        vertex_positions,
+        vertex_colors,
+        indices,
+        normals,
+        texcoords,
+        albedo_factor,
+        albedo_texture,
+    ) in izip!(
         position_buffers,
         vertex_color_buffers,
         index_buffers,
         normal_buffers,
+        texcoord_buffers,
         albedo_factors,
+        albedo_textures,
     ) {
         let albedo_factor =
             if let Some(v) = albedo_factor.map(|albedo_factor| albedo_factor.as_array().to_vec()) {
@@ -757,14 +861,52 @@ fn log_meshes(
             None
         };
 
+        let albedo_texture = if let Some(albedo_texture) = albedo_texture {
+            let shape = match albedo_texture.shape() {
+                [h, w] => vec![
+                    TensorDimension::height(*h as _),
+                    TensorDimension::width(*w as _),
+                ],
+                [h, w, c @ (1 | 3 | 4)] => vec![
+                    TensorDimension::height(*h as _),
+                    TensorDimension::width(*w as _),
+                    TensorDimension::depth(*c as _),
+                ],
+                shape => {
+                    return Err(PyTypeError::new_err(format!(
+                        "Expected albedo texture to have a HxW, HxWx1, HxWx3 or HxWx4 shape, \
+                        got {shape:?} instead",
+                    )));
+                }
+            };
+            Some(Tensor::from_content(
+                shape,
+                TensorData::U8(
+                    albedo_texture
+                        .as_array()
+                        .iter()
+                        .copied()
+                        .collect::<Vec<_>>()
+                        .into(),
+                ),
+                re_log_types::component_types::TensorDataMeaning::Unknown,
+                None,
+            ))
+        } else {
+            None
+        };
+
         let raw = RawMesh3D {
-            mesh_id: MeshId::random(),
+            mesh_id: MeshId::NIL,
             vertex_positions: vertex_positions.as_array().to_vec().into(),
             vertex_colors,
             indices: indices.map(|indices| indices.as_array().to_vec().into()),
             vertex_normals: normals.map(|normals| normals.as_array().to_vec().into()),
+            vertex_texcoords: texcoords.map(|texcoords| texcoords.as_array().to_vec().into()),
             albedo_factor,
-        };
+            albedo_texture,
+        }
+        .with_content_id();
         raw.sanity_check()
             .map_err(|err| PyTypeError::new_err(err.to_string()))?;
 
@@ -789,11 +931,16 @@ fn log_meshes(
     session.send_row(row)
 }
 
+/// Log a mesh file given its contents or path on disk.
+///
+/// The textures of OBJ files are only found if `mesh_path` is given.
 #[pyfunction]
+#[pyo3(signature = (entity_path_str, mesh_format, bytes, mesh_path, transform, timeless))]
 fn log_mesh_file(
     entity_path_str: &str,
     mesh_format: &str,
-    bytes: &[u8],
+    bytes: Option<Vec<u8>>,
+    mesh_path: Option<PathBuf>,
     transform: numpy::PyReadonlyArray2<'_, f32>,
     timeless: bool,
 ) -> PyResult<()> {
@@ -809,7 +956,18 @@ fn log_mesh_file(
             )));
         }
     };
-    let bytes: Vec<u8> = bytes.into();
+    let bytes = match (bytes, &mesh_path) {
+        (Some(bytes), _) => bytes,
+        (None, Some(mesh_path)) => std::fs::read(mesh_path)?,
+        (None, None) => Err(PyTypeError::new_err(
+            "log_mesh_file: You must pass either bytes or mesh_path",
+        ))?,
+    };
+    let albedo_texture = match (format, mesh_path) {
+        (MeshFormat::Obj, Some(mesh_path)) => EncodedMesh3D::load_obj_albedo_texture(mesh_path)
+            .map_err(|err| PyTypeError::new_err(err.to_string()))?,
+        _ => None,
+    };
     let transform = if transform.is_empty() {
         [
             [1.0, 0.0, 0.0], // col 0
@@ -839,12 +997,16 @@ fn log_mesh_file(
 
     let time_point = time(timeless);
 
-    let mesh3d = Mesh3D::Encoded(EncodedMesh3D {
-        mesh_id: MeshId::random(),
-        format,
-        bytes: bytes.into(),
-        transform,
-    });
+    let mesh3d = Mesh3D::Encoded(
+        EncodedMesh3D {
+            mesh_id: MeshId::NIL,
+            format,
+            bytes: bytes.into(),
+            transform,
+            albedo_texture,
+        }
+        .with_content_id(),
+    );
 
     // We currently log `Mesh3D` from inside the bridge.
     //
@@ -897,47 +1059,62 @@ fn log_image_file(
         }
     };
 
-    use image::ImageDecoder as _;
-    let (w, h) = match img_format {
-        image::ImageFormat::Jpeg => {
-            use image::codecs::jpeg::JpegDecoder;
-            let jpeg = JpegDecoder::new(Cursor::new(&img_bytes))
-                .map_err(|err| PyTypeError::new_err(err.to_string()))?;
-
-            let color_format = jpeg.color_type();
-            if !matches!(color_format, image::ColorType::Rgb8) {
-                // TODO(emilk): support gray-scale jpeg aswell
-                return Err(PyTypeError::new_err(format!(
-                    "Unsupported color format {color_format:?}. \
-                    Expected one of: RGB8"
-                )));
-            }
-
-            jpeg.dimensions()
-        }
+    let tensor = match img_format {
+        image::ImageFormat::Jpeg => Tensor::tensor_from_jpeg_bytes(img_bytes),
+        image::ImageFormat::Png => Tensor::tensor_from_png_bytes(img_bytes),
         _ => {
             return Err(PyTypeError::new_err(format!(
                 "Unsupported image format {img_format:?}. \
-                Expected one of: JPEG"
+                Expected one of: JPEG, PNG"
             )))
         }
-    };
+    }
+    .map_err(|err| PyTypeError::new_err(err.to_string()))?;
 
     let mut session = python_session();
 
     let time_point = time(timeless);
 
-    let tensor = re_log_types::component_types::Tensor {
-        tensor_id: TensorId::random(),
-        shape: vec![
-            TensorDimension::height(h as _),
-            TensorDimension::width(w as _),
-            TensorDimension::depth(3),
-        ],
-        data: re_log_types::component_types::TensorData::JPEG(img_bytes.into()),
-        meaning: re_log_types::component_types::TensorDataMeaning::Unknown,
-        meter: None,
-    };
+    let row = DataRow::from_cells1(
+        RowId::random(),
+        entity_path,
+        time_point,
+        1,
+        [tensor].as_slice(),
+    );
+
+    session.send_row(row)
+}
+
+/// Log a chroma-subsampled image, as delivered by many cameras and video decoders.
+///
+/// `yuv_format` is one of `"NV12"` or `"YUY2"`.
+#[pyfunction]
+fn log_yuv_image(
+    entity_path: &str,
+    data: Vec<u8>,
+    width: u32,
+    height: u32,
+    yuv_format: &str,
+    timeless: bool,
+) -> PyResult<()> {
+    let entity_path = parse_entity_path(entity_path)?;
+
+    let tensor = match yuv_format {
+        "NV12" => Tensor::from_nv12(width, height, data),
+        "YUY2" => Tensor::from_yuy2(width, height, data),
+        _ => {
+            return Err(PyTypeError::new_err(format!(
+                "Unsupported YUV format {yuv_format:?}. \
+                Expected one of: NV12, YUY2"
+            )))
+        }
+    }
+    .map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+    let mut session = python_session();
+
+    let time_point = time(timeless);
 
     let row = DataRow::from_cells1(
         RowId::random(),
@@ -1035,6 +1212,21 @@ fn log_cleared(entity_path: &str, recursive: bool) -> PyResult<()> {
     Ok(())
 }
 
+#[pyfunction]
+fn log_cleared_component(entity_path: &str, component_name: &str) -> PyResult<()> {
+    let entity_path = parse_entity_path(entity_path)?;
+    let mut session = python_session();
+
+    let time_point = time(false);
+
+    session.send_path_op(
+        &time_point,
+        PathOp::clear_component(entity_path, component_name.into()),
+    );
+
+    Ok(())
+}
+
 #[pyfunction]
 fn log_arrow_msg(entity_path: &str, components: &PyDict, timeless: bool) -> PyResult<()> {
     let entity_path = parse_entity_path(entity_path)?;
@@ -1056,6 +1248,25 @@ fn log_arrow_msg(entity_path: &str, components: &PyDict, timeless: bool) -> PyRe
     Ok(())
 }
 
+/// The id of a tensor with the given contents, see [`TensorId::from_raw_content`].
+///
+/// `kind` is the name of the [`TensorData`] variant, `shape` a list of `(name, size)` pairs and
+/// `bytes` the payload in native byte order.
+#[pyfunction]
+fn tensor_content_id<'py>(
+    py: Python<'py>,
+    kind: &str,
+    shape: Vec<(Option<String>, u64)>,
+    bytes: &[u8],
+) -> &'py PyBytes {
+    let shape = shape
+        .into_iter()
+        .map(|(name, size)| TensorDimension { size, name })
+        .collect::<Vec<_>>();
+    let tensor_id = TensorId::from_raw_content(&shape, kind, bytes);
+    PyBytes::new(py, tensor_id.0.as_bytes())
+}
+
 // ----------------------------------------------------------------------------
 
 fn slice_from_np_array<'a, T: numpy::Element, D: numpy::ndarray::Dimension>(
@@ -105,6 +105,22 @@ impl Default for PythonSession {
 }
 
 impl PythonSession {
+    /// A new recording with a random [`RecordingId`] and its own sink (a
+    /// [`rerun::sink::BufferedSink`] until told otherwise), that otherwise
+    /// starts out with the settings of this one.
+    pub fn new_recording(&self) -> Self {
+        Self {
+            enabled: self.enabled,
+            recording_meta_data: RecordingMetaData {
+                recording_source: self.recording_meta_data.recording_source.clone(),
+                application_id: self.recording_meta_data.application_id.clone(),
+                recording_id: RecordingId::random(),
+                is_official_example: self.recording_meta_data.is_official_example,
+            },
+            ..Default::default()
+        }
+    }
+
     pub fn set_python_version(&mut self, python_version: re_log_types::PythonVersion) {
         self.recording_meta_data.recording_source =
             re_log_types::RecordingSource::PythonSdk(python_version);